@@ -5,7 +5,10 @@ use {
     },
     base64::{prelude::BASE64_STANDARD, Engine},
     bincode::{deserialize, serialized_size},
-    solana_sdk::{bpf_loader_upgradeable::UpgradeableLoaderState, pubkey::Pubkey},
+    solana_sdk::{
+        bpf_loader_upgradeable::{sysvar_dependencies_to_ids, UpgradeableLoaderState},
+        pubkey::Pubkey,
+    },
 };
 
 pub fn parse_bpf_upgradeable_loader(
@@ -57,6 +60,14 @@ pub fn parse_bpf_upgradeable_loader(
                 ),
             })
         }
+        UpgradeableLoaderState::ProgramMetadata {
+            sysvar_dependencies,
+        } => BpfUpgradeableLoaderAccountType::ProgramMetadata(UiProgramMetadata {
+            sysvar_dependencies: sysvar_dependencies_to_ids(sysvar_dependencies)
+                .iter()
+                .map(|pubkey| pubkey.to_string())
+                .collect(),
+        }),
     };
     Ok(parsed_account)
 }
@@ -68,6 +79,7 @@ pub enum BpfUpgradeableLoaderAccountType {
     Buffer(UiBuffer),
     Program(UiProgram),
     ProgramData(UiProgramData),
+    ProgramMetadata(UiProgramMetadata),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -91,9 +103,21 @@ pub struct UiProgramData {
     pub data: UiAccountData,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiProgramMetadata {
+    pub sysvar_dependencies: Vec<String>,
+}
+
 #[cfg(test)]
 mod test {
-    use {super::*, bincode::serialize, solana_sdk::pubkey::Pubkey};
+    use {
+        super::*,
+        bincode::serialize,
+        solana_sdk::{
+            bpf_loader_upgradeable::sysvar_dependencies_from_ids, pubkey::Pubkey, sysvar,
+        },
+    };
 
     #[test]
     fn test_parse_bpf_upgradeable_loader_accounts() {
@@ -190,5 +214,32 @@ mod test {
                 ),
             })
         );
+
+        let bpf_loader_state = UpgradeableLoaderState::ProgramMetadata {
+            sysvar_dependencies: 0,
+        };
+        let account_data = serialize(&bpf_loader_state).unwrap();
+        assert_eq!(
+            parse_bpf_upgradeable_loader(&account_data).unwrap(),
+            BpfUpgradeableLoaderAccountType::ProgramMetadata(UiProgramMetadata {
+                sysvar_dependencies: vec![],
+            })
+        );
+
+        let sysvar_dependencies =
+            sysvar_dependencies_from_ids(&[sysvar::clock::id(), sysvar::signatures::id()]).unwrap();
+        let bpf_loader_state = UpgradeableLoaderState::ProgramMetadata {
+            sysvar_dependencies,
+        };
+        let account_data = serialize(&bpf_loader_state).unwrap();
+        assert_eq!(
+            parse_bpf_upgradeable_loader(&account_data).unwrap(),
+            BpfUpgradeableLoaderAccountType::ProgramMetadata(UiProgramMetadata {
+                sysvar_dependencies: vec![
+                    sysvar::clock::id().to_string(),
+                    sysvar::signatures::id().to_string(),
+                ],
+            })
+        );
     }
 }
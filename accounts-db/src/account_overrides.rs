@@ -4,7 +4,7 @@ use {
 };
 
 /// Encapsulates overridden accounts, typically used for transaction simulations
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct AccountOverrides {
     accounts: HashMap<Pubkey, AccountSharedData>,
 }
@@ -24,6 +24,14 @@ impl AccountOverrides {
         self.set_account(&sysvar::slot_history::id(), slot_history);
     }
 
+    /// Sets the signatures sysvar loaded for every transaction, instead of the
+    /// one constructed from the transaction's signatures
+    ///
+    /// Note: no checks are performed on the correctness of the contained data
+    pub fn set_signatures_sysvar(&mut self, signatures_sysvar: Option<AccountSharedData>) {
+        self.set_account(&sysvar::signatures::id(), signatures_sysvar);
+    }
+
     /// Gets the account if it's found in the list of overrides
     pub fn get(&self, pubkey: &Pubkey) -> Option<&AccountSharedData> {
         self.accounts.get(pubkey)
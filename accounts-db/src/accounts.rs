@@ -35,13 +35,18 @@ use {
         bpf_loader_upgradeable::{self, UpgradeableLoaderState},
         clock::{BankId, Slot},
         feature_set::{
-            self, add_set_tx_loaded_accounts_data_size_instruction,
-            include_loaded_accounts_data_size_in_fee_calculation,
-            remove_congestion_multiplier_from_fee_calculation, remove_deprecated_request_unit_ix,
-            simplify_writable_program_account_check, FeatureSet,
+            self, add_set_tx_loaded_accounts_data_size_instruction, enable_signatures_sysvar,
+            enable_transaction_guard_instruction, header_sysvar,
+            include_address_table_lookups_in_signatures_sysvar,
+            include_loaded_accounts_data_size_in_fee_calculation, integrity_checksum_in_sysvars,
+            message_header_in_header_sysvar, remove_congestion_multiplier_from_fee_calculation,
+            remove_deprecated_request_unit_ix, scratch_sysvar, signer_pubkeys_in_signatures_sysvar,
+            simplify_writable_program_account_check, tick_hash_sysvar, transaction_context_sysvar,
+            u16_signature_count_in_signatures_sysvar, FeatureSet,
         },
         fee::FeeStructure,
         genesis_config::ClusterType,
+        hash::Hash,
         message::{
             v0::{LoadedAddresses, MessageAddressTableLookup},
             SanitizedMessage,
@@ -55,7 +60,22 @@ use {
         saturating_add_assign,
         signature::Signature,
         slot_hashes::SlotHashes,
-        sysvar::{self, instructions::construct_instructions_data, signatures::construct_signatures_data},
+        sysvar::{
+            self,
+            header::{
+                construct_header_data, construct_header_data_with_message_header, FreshnessTag,
+            },
+            instructions::construct_instructions_data,
+            scratch::construct_scratch_data,
+            signatures::{
+                address_table_lookup_digests, construct_signatures_data_from_iter,
+                construct_signatures_data_with_address_table_lookups,
+                construct_signer_signatures_data, construct_wide_count_signatures_data,
+                validate_signatures_data, AddressTableLookupDigest, SysvarSerializeError,
+            },
+            tick_hash::construct_tick_hash_data,
+            transaction_context::construct_transaction_context_data,
+        },
         transaction::{Result, SanitizedTransaction, TransactionAccountLocks, TransactionError},
         transaction_context::{IndexOfAccount, TransactionAccount},
     },
@@ -228,24 +248,124 @@ impl Accounts {
         }
     }
 
-    fn construct_instructions_account(message: &SanitizedMessage) -> AccountSharedData {
+    /// Construct the instructions sysvar account loaded for `message`
+    pub fn construct_instructions_account(
+        message: &SanitizedMessage,
+        feature_set: &FeatureSet,
+    ) -> AccountSharedData {
+        let mut data = construct_instructions_data(&message.decompile_instructions());
+        if feature_set.is_active(&integrity_checksum_in_sysvars::id()) {
+            sysvar::instructions::append_integrity_checksum(&mut data);
+        }
+        AccountSharedData::from(Account {
+            data,
+            owner: sysvar::id(),
+            ..Account::default()
+        })
+    }
+
+    /// Construct the header sysvar account loaded for `message`
+    pub fn construct_header_account(
+        message: &SanitizedMessage,
+        freshness_tag: &FreshnessTag,
+        feature_set: &FeatureSet,
+    ) -> AccountSharedData {
+        let data = if feature_set.is_active(&message_header_in_header_sysvar::id()) {
+            construct_header_data_with_message_header(message, freshness_tag)
+        } else {
+            construct_header_data(message, freshness_tag)
+        };
+        AccountSharedData::from(Account {
+            data,
+            owner: sysvar::id(),
+            ..Account::default()
+        })
+    }
+
+    /// Construct the zeroed scratch sysvar account loaded for a transaction
+    pub fn construct_scratch_account() -> AccountSharedData {
+        AccountSharedData::from(Account {
+            data: construct_scratch_data(),
+            owner: sysvar::id(),
+            ..Account::default()
+        })
+    }
+
+    /// Construct the tick hash sysvar account loaded for a transaction
+    /// executing after the PoH tick `tick_hash`
+    pub fn construct_tick_hash_account(tick_hash: &Hash) -> AccountSharedData {
         AccountSharedData::from(Account {
-            data: construct_instructions_data(&message.decompile_instructions()),
+            data: construct_tick_hash_data(tick_hash),
             owner: sysvar::id(),
             ..Account::default()
         })
     }
 
-    fn construct_signatures_account(signatures: &[Signature]) -> AccountSharedData {
-        // Convert signatures to bytes here first to avoid dependency of Solana SDK in sysvar program
-        let signature_array: Vec<[u8;64]> = signatures.iter().map(|s| <[u8;64]>::from(*s)).collect();
+    /// Construct the transaction context sysvar account loaded for `tx`
+    pub fn construct_transaction_context_account(tx: &SanitizedTransaction) -> AccountSharedData {
         AccountSharedData::from(Account {
-            data: construct_signatures_data(&signature_array),
+            data: construct_transaction_context_data(
+                tx.message(),
+                tx.signatures().iter().map(|signature| signature.as_bytes()),
+            ),
             owner: sysvar::id(),
             ..Account::default()
         })
     }
 
+    /// Construct the signatures sysvar account loaded for `tx`
+    ///
+    /// The signatures are serialized as they are, whether or not they were
+    /// verified, so that banking, replay and simulation build the same data.
+    pub fn construct_signatures_account(
+        tx: &SanitizedTransaction,
+        feature_set: &FeatureSet,
+    ) -> std::result::Result<AccountSharedData, SysvarSerializeError> {
+        let account_keys = tx.message().account_keys();
+        let signers: Vec<(&Pubkey, &Signature)> =
+            account_keys.iter().zip(tx.signatures()).collect();
+        Self::construct_signatures_account_from_signers(
+            &signers,
+            &address_table_lookup_digests(tx.message()),
+            feature_set,
+        )
+    }
+
+    /// Construct the signatures sysvar account holding the `signers` and
+    /// their signatures, in the layout selected by `feature_set`
+    pub fn construct_signatures_account_from_signers(
+        signers: &[(&Pubkey, &Signature)],
+        address_table_lookups: &[AddressTableLookupDigest],
+        feature_set: &FeatureSet,
+    ) -> std::result::Result<AccountSharedData, SysvarSerializeError> {
+        let signatures = signers.iter().map(|(_, signature)| signature.as_bytes());
+        let include_address_table_lookups =
+            feature_set.is_active(&include_address_table_lookups_in_signatures_sysvar::id());
+        let mut data = if feature_set.is_active(&signer_pubkeys_in_signatures_sysvar::id()) {
+            construct_signer_signatures_data(
+                signers.iter().map(|(signer, _)| *signer).zip(signatures),
+                include_address_table_lookups.then_some(address_table_lookups),
+            )
+        } else if feature_set.is_active(&u16_signature_count_in_signatures_sysvar::id()) {
+            construct_wide_count_signatures_data(
+                signatures,
+                include_address_table_lookups.then_some(address_table_lookups),
+            )
+        } else if include_address_table_lookups {
+            construct_signatures_data_with_address_table_lookups(signatures, address_table_lookups)
+        } else {
+            construct_signatures_data_from_iter(signatures)
+        }?;
+        if feature_set.is_active(&integrity_checksum_in_sysvars::id()) {
+            sysvar::signatures::append_integrity_checksum(&mut data);
+        }
+        Ok(AccountSharedData::from(Account {
+            data,
+            owner: sysvar::id(),
+            ..Account::default()
+        }))
+    }
+
     /// If feature `cap_transaction_accounts_data_size` is active, total accounts data a
     /// transaction can load is limited to
     ///   if `set_tx_loaded_accounts_data_size` instruction is not activated or not used, then
@@ -264,6 +384,7 @@ impl Accounts {
                 tx.message().program_instructions_iter(),
                 !feature_set.is_active(&remove_deprecated_request_unit_ix::id()),
                 feature_set.is_active(&add_set_tx_loaded_accounts_data_size_instruction::id()),
+                feature_set.is_active(&enable_transaction_guard_instruction::id()),
             );
             // sanitize against setting size limit to zero
             NonZeroUsize::new(compute_budget.loaded_accounts_data_size_limit).map_or(
@@ -320,6 +441,8 @@ impl Accounts {
     fn load_transaction_accounts(
         &self,
         ancestors: &Ancestors,
+        slot: Slot,
+        tick_hash: &Hash,
         tx: &SanitizedTransaction,
         fee: u64,
         error_counters: &mut TransactionErrorMetrics,
@@ -368,9 +491,46 @@ impl Accounts {
                 let mut account_found = true;
                 #[allow(clippy::collapsible_else_if)]
                 let account = if solana_sdk::sysvar::instructions::check_id(key) {
-                    Self::construct_instructions_account(message)
-                } else if solana_sdk::sysvar::signatures::check_id(key) {
-                    Self::construct_signatures_account(tx.signatures())
+                    Self::construct_instructions_account(message, feature_set)
+                } else if solana_sdk::sysvar::signatures::check_id(key)
+                    && feature_set.is_active(&enable_signatures_sysvar::id())
+                {
+                    match account_overrides.and_then(|overrides| overrides.get(key)) {
+                        // Overrides aren't built by the runtime, so their
+                        // data is checked before programs can read it
+                        Some(account_override) => {
+                            validate_signatures_data(account_override.data())
+                                .map_err(|_| TransactionError::SanitizeFailure)?;
+                            account_override.clone()
+                        }
+                        // Unreachable for sanitized transactions, which have
+                        // between one and a packet's worth of signatures
+                        None => Self::construct_signatures_account(tx, feature_set)
+                            .map_err(|_| TransactionError::SanitizeFailure)?,
+                    }
+                } else if solana_sdk::sysvar::header::check_id(key)
+                    && feature_set.is_active(&header_sysvar::id())
+                {
+                    Self::construct_header_account(
+                        message,
+                        &FreshnessTag {
+                            slot,
+                            message_hash: *tx.message_hash(),
+                        },
+                        feature_set,
+                    )
+                } else if solana_sdk::sysvar::scratch::check_id(key)
+                    && feature_set.is_active(&scratch_sysvar::id())
+                {
+                    Self::construct_scratch_account()
+                } else if solana_sdk::sysvar::tick_hash::check_id(key)
+                    && feature_set.is_active(&tick_hash_sysvar::id())
+                {
+                    Self::construct_tick_hash_account(tick_hash)
+                } else if solana_sdk::sysvar::transaction_context::check_id(key)
+                    && feature_set.is_active(&transaction_context_sysvar::id())
+                {
+                    Self::construct_transaction_context_account(tx)
                 } else {
                     let instruction_account = u8::try_from(i)
                         .map(|i| instruction_accounts.contains(&&i))
@@ -709,6 +869,8 @@ impl Accounts {
     pub fn load_accounts(
         &self,
         ancestors: &Ancestors,
+        slot: Slot,
+        tick_hash: &Hash,
         txs: &[SanitizedTransaction],
         lock_results: Vec<TransactionCheckResult>,
         hash_queue: &BlockhashQueue,
@@ -745,6 +907,8 @@ impl Accounts {
 
                     let loaded_transaction = match self.load_transaction_accounts(
                         ancestors,
+                        slot,
+                        tick_hash,
                         tx,
                         fee,
                         error_counters,
@@ -1498,12 +1662,12 @@ mod tests {
             genesis_config::ClusterType,
             hash::Hash,
             instruction::{CompiledInstruction, InstructionError},
-            message::{Message, MessageHeader},
+            message::{v0, Message, MessageHeader, SimpleAddressLoader, VersionedMessage},
             nonce, nonce_account,
             rent::Rent,
-            signature::{keypair_from_seed, signers::Signers, Keypair, Signer},
+            signature::{keypair_from_seed, signers::Signers, Keypair, Signature, Signer},
             system_instruction, system_program,
-            transaction::{Transaction, MAX_TX_ACCOUNT_LOCKS},
+            transaction::{MessageHash, Transaction, VersionedTransaction, MAX_TX_ACCOUNT_LOCKS},
         },
         std::{
             borrow::Cow,
@@ -1569,6 +1733,8 @@ mod tests {
         let sanitized_tx = SanitizedTransaction::from_transaction_for_tests(tx);
         accounts.load_accounts(
             &ancestors,
+            0,
+            &Hash::default(),
             &[sanitized_tx],
             vec![(Ok(()), None)],
             &hash_queue,
@@ -1945,6 +2111,474 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_accounts_signatures_sysvar() {
+        let mut accounts: Vec<TransactionAccount> = Vec::new();
+        let mut error_counters = TransactionErrorMetrics::default();
+
+        let keypair0 = Keypair::new();
+        let keypair1 = Keypair::new();
+        let signatures_key = sysvar::signatures::id();
+
+        let mut account = AccountSharedData::new(1, 0, &Pubkey::default());
+        account.set_rent_epoch(1);
+        accounts.push((keypair0.pubkey(), account));
+
+        let instructions = vec![CompiledInstruction::new(3, &(), vec![0, 1, 2])];
+        let tx = Transaction::new_with_compiled_instructions(
+            &[&keypair0, &keypair1],
+            &[signatures_key],
+            Hash::default(),
+            vec![native_loader::id()],
+            instructions,
+        );
+        assert!(tx.verify().is_ok());
+
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx.clone(),
+            &accounts,
+            &mut error_counters,
+            Some(&[
+                include_address_table_lookups_in_signatures_sysvar::id(),
+                integrity_checksum_in_sysvars::id(),
+                signer_pubkeys_in_signatures_sysvar::id(),
+                u16_signature_count_in_signatures_sysvar::id(),
+            ]),
+        );
+
+        assert_eq!(loaded_accounts.len(), 1);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, signatures_account) = &loaded_transaction.accounts[2];
+        assert_eq!(*key, signatures_key);
+        assert_eq!(signatures_account.owner(), &sysvar::id());
+
+        // The sysvar holds exactly the transaction's verified signatures, in
+        // the order of the signers in the sanitized message
+        let data = signatures_account.data();
+        assert_eq!(data.len(), sysvar::signatures::sysvar_data_len(2));
+        assert_eq!(data[0], 2);
+        let message_data = tx.message_data();
+        for (index, keypair) in [&keypair0, &keypair1].iter().enumerate() {
+            let start = sysvar::signatures::signature_offset(index);
+            let signature =
+                Signature::try_from(&data[start..start + sysvar::signatures::SIGNATURE_LEN])
+                    .unwrap();
+            assert_eq!(signature, tx.signatures[index]);
+            assert!(signature.verify(keypair.pubkey().as_ref(), &message_data));
+        }
+
+        // Once address table lookups are included, the signatures of a legacy
+        // transaction are followed by an empty list of lookups
+        let signatures_data = data.to_vec();
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx.clone(),
+            &accounts,
+            &mut error_counters,
+            Some(&[
+                integrity_checksum_in_sysvars::id(),
+                signer_pubkeys_in_signatures_sysvar::id(),
+                u16_signature_count_in_signatures_sysvar::id(),
+            ]),
+        );
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let data = loaded_transaction.accounts[2].1.data();
+        assert_eq!(data.len(), signatures_data.len() + 1);
+        assert_eq!(&data[..signatures_data.len()], &signatures_data[..]);
+        assert_eq!(data[signatures_data.len()], 0);
+
+        // With the wide count layout, the number of signatures is a u16
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx.clone(),
+            &accounts,
+            &mut error_counters,
+            Some(&[
+                integrity_checksum_in_sysvars::id(),
+                signer_pubkeys_in_signatures_sysvar::id(),
+            ]),
+        );
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let data = loaded_transaction.accounts[2].1.data();
+        assert_eq!(
+            data[..sysvar::signatures::WIDE_COUNT_HEADER_LEN],
+            [
+                sysvar::signatures::VERSIONED_LAYOUT_MARKER,
+                sysvar::signatures::WIDE_COUNT_LAYOUT_VERSION,
+                2,
+                0
+            ]
+        );
+        assert_eq!(
+            &data[sysvar::signatures::WIDE_COUNT_HEADER_LEN..data.len() - 1],
+            &signatures_data[sysvar::signatures::NUM_SIGNATURES_LEN..]
+        );
+        assert_eq!(data.last(), Some(&0));
+
+        // Once signer pubkeys are included, each signature is paired with the
+        // key of its signer
+        let loaded_accounts =
+            load_accounts_with_excluded_features(tx.clone(), &accounts, &mut error_counters, None);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let signatures_data = sysvar::signatures::SignaturesData::deserialize(
+            loaded_transaction.accounts[2].1.data(),
+        )
+        .unwrap();
+        assert!(signatures_data
+            .signatures
+            .iter()
+            .map(|signature| &signature.0)
+            .eq(tx.signatures.iter().map(Signature::as_bytes)));
+        assert_eq!(
+            signatures_data.signers,
+            Some(vec![keypair0.pubkey(), keypair1.pubkey()])
+        );
+        assert_eq!(signatures_data.address_table_lookups, Some(vec![]));
+
+        // The signatures and lookups are followed by their checksum
+        let data = loaded_transaction.accounts[2].1.data();
+        let (payload, checksum) = data.split_at(data.len() - sysvar::INTEGRITY_CHECKSUM_LEN);
+        assert_eq!(checksum, solana_sdk::hash::hash(payload).as_ref());
+    }
+
+    #[test]
+    fn test_load_accounts_header_sysvar() {
+        let mut accounts: Vec<TransactionAccount> = Vec::new();
+        let mut error_counters = TransactionErrorMetrics::default();
+
+        let keypair = Keypair::new();
+        let header_key = sysvar::header::id();
+
+        let mut account = AccountSharedData::new(1, 0, &Pubkey::default());
+        account.set_rent_epoch(1);
+        accounts.push((keypair.pubkey(), account));
+
+        let instructions = vec![CompiledInstruction::new(2, &(), vec![0, 1])];
+        let tx = Transaction::new_with_compiled_instructions(
+            &[&keypair],
+            &[header_key],
+            Hash::default(),
+            vec![native_loader::id()],
+            instructions,
+        );
+
+        let loaded_accounts =
+            load_accounts_with_excluded_features(tx.clone(), &accounts, &mut error_counters, None);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, header_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, header_key);
+        assert_eq!(header_account.owner(), &sysvar::id());
+        assert_eq!(
+            sysvar::header::TransactionVersion::from(header_account.data()[0]),
+            sysvar::header::TransactionVersion::Legacy
+        );
+        let freshness_tag = FreshnessTag {
+            slot: 0,
+            message_hash: tx.message.hash(),
+        };
+        let sanitized_tx = SanitizedTransaction::from_transaction_for_tests(tx.clone());
+        assert_eq!(
+            header_account.data(),
+            &construct_header_data_with_message_header(sanitized_tx.message(), &freshness_tag)[..]
+        );
+        assert_eq!(
+            &header_account.data()[sysvar::header::MESSAGE_HEADER_OFFSET..],
+            &[1, 0, 1]
+        );
+
+        // Without the message header, the data ends with the freshness tag
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx.clone(),
+            &accounts,
+            &mut error_counters,
+            Some(&[message_header_in_header_sysvar::id()]),
+        );
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        assert_eq!(
+            loaded_transaction.accounts[1].1.data(),
+            &construct_header_data(sanitized_tx.message(), &freshness_tag)[..]
+        );
+
+        // Until the feature is active the sysvar is loaded like any other
+        // account, and doesn't exist
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx,
+            &accounts,
+            &mut error_counters,
+            Some(&[header_sysvar::id()]),
+        );
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, header_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, header_key);
+        assert_eq!(header_account.lamports(), 0);
+        assert!(header_account.data().is_empty());
+    }
+
+    #[test]
+    fn test_load_accounts_scratch_sysvar() {
+        let mut accounts: Vec<TransactionAccount> = Vec::new();
+        let mut error_counters = TransactionErrorMetrics::default();
+
+        let keypair = Keypair::new();
+        let scratch_key = sysvar::scratch::id();
+
+        let mut account = AccountSharedData::new(1, 0, &Pubkey::default());
+        account.set_rent_epoch(1);
+        accounts.push((keypair.pubkey(), account));
+        // A stored account at the scratch address is never loaded
+        accounts.push((
+            scratch_key,
+            AccountSharedData::new(1, sysvar::scratch::SCRATCH_LEN, &sysvar::id()),
+        ));
+
+        let instructions = vec![CompiledInstruction::new(2, &(), vec![0, 1])];
+        let tx = Transaction::new_with_compiled_instructions(
+            &[&keypair],
+            &[scratch_key],
+            Hash::default(),
+            vec![native_loader::id()],
+            instructions,
+        );
+        assert!(!tx.message.is_writable(1));
+
+        let loaded_accounts =
+            load_accounts_with_excluded_features(tx, &accounts, &mut error_counters, None);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, scratch_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, scratch_key);
+        assert_eq!(
+            scratch_account,
+            &AccountSharedData::from(Account {
+                data: vec![0; sysvar::scratch::SCRATCH_LEN],
+                owner: sysvar::id(),
+                ..Account::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_accounts_tick_hash_sysvar() {
+        let mut accounts: Vec<TransactionAccount> = Vec::new();
+        let mut error_counters = TransactionErrorMetrics::default();
+
+        let keypair = Keypair::new();
+        let tick_hash_key = sysvar::tick_hash::id();
+
+        let mut account = AccountSharedData::new(1, 0, &Pubkey::default());
+        account.set_rent_epoch(1);
+        accounts.push((keypair.pubkey(), account));
+
+        let instructions = vec![CompiledInstruction::new(2, &(), vec![0, 1])];
+        let tx = Transaction::new_with_compiled_instructions(
+            &[&keypair],
+            &[tick_hash_key],
+            Hash::default(),
+            vec![native_loader::id()],
+            instructions,
+        );
+
+        let loaded_accounts =
+            load_accounts_with_excluded_features(tx.clone(), &accounts, &mut error_counters, None);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, tick_hash_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, tick_hash_key);
+        assert_eq!(
+            tick_hash_account,
+            &Accounts::construct_tick_hash_account(&Hash::default())
+        );
+        assert_eq!(
+            tick_hash_account.data().len(),
+            sysvar::tick_hash::TICK_HASH_LEN
+        );
+
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx,
+            &accounts,
+            &mut error_counters,
+            Some(&[tick_hash_sysvar::id()]),
+        );
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, tick_hash_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, tick_hash_key);
+        assert!(tick_hash_account.data().is_empty());
+    }
+
+    #[test]
+    fn test_load_accounts_transaction_context_sysvar() {
+        let mut accounts: Vec<TransactionAccount> = Vec::new();
+        let mut error_counters = TransactionErrorMetrics::default();
+
+        let keypair = Keypair::new();
+        let transaction_context_key = sysvar::transaction_context::id();
+
+        let mut account = AccountSharedData::new(1, 0, &Pubkey::default());
+        account.set_rent_epoch(1);
+        accounts.push((keypair.pubkey(), account));
+
+        let instructions = vec![CompiledInstruction::new(2, &(), vec![0, 1])];
+        let tx = Transaction::new_with_compiled_instructions(
+            &[&keypair],
+            &[transaction_context_key],
+            Hash::default(),
+            vec![native_loader::id()],
+            instructions,
+        );
+
+        let loaded_accounts =
+            load_accounts_with_excluded_features(tx.clone(), &accounts, &mut error_counters, None);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, transaction_context_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, transaction_context_key);
+        assert_eq!(
+            transaction_context_account,
+            &Accounts::construct_transaction_context_account(
+                &SanitizedTransaction::from_transaction_for_tests(tx.clone())
+            )
+        );
+        // The fixed fields, three account keys and one signature hash
+        assert_eq!(
+            transaction_context_account.data().len(),
+            sysvar::transaction_context::FIXED_LEN + 4 * 32
+        );
+
+        let loaded_accounts = load_accounts_with_excluded_features(
+            tx,
+            &accounts,
+            &mut error_counters,
+            Some(&[transaction_context_sysvar::id()]),
+        );
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, transaction_context_account) = &loaded_transaction.accounts[1];
+        assert_eq!(*key, transaction_context_key);
+        assert!(transaction_context_account.data().is_empty());
+    }
+
+    #[test]
+    fn test_load_accounts_sysvars_from_lookup_table() {
+        let mut error_counters = TransactionErrorMetrics::default();
+        let keypair = Keypair::new();
+        let signatures_key = sysvar::signatures::id();
+        let instructions_key = sysvar::instructions::id();
+        let clock_key = sysvar::clock::id();
+
+        // Sysvars, including per-transaction sysvars, are loaded from a lookup
+        // table rather than from the static account keys. The signatures
+        // sysvar is requested as writable.
+        let message = v0::Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![keypair.pubkey(), native_loader::id()],
+            recent_blockhash: Hash::default(),
+            instructions: vec![CompiledInstruction::new(1, &(), vec![0, 2, 3, 4])],
+            address_table_lookups: vec![MessageAddressTableLookup {
+                account_key: Pubkey::new_unique(),
+                writable_indexes: vec![0],
+                readonly_indexes: vec![1, 2],
+            }],
+        };
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&keypair]).unwrap();
+        let sanitized_tx = SanitizedTransaction::try_create(
+            tx,
+            MessageHash::Compute,
+            None,
+            SimpleAddressLoader::Enabled(LoadedAddresses {
+                writable: vec![signatures_key],
+                readonly: vec![instructions_key, clock_key],
+            }),
+        )
+        .unwrap();
+
+        // Writable sysvars are demoted, whether static or loaded
+        assert!(!sanitized_tx.message().is_writable(2));
+        assert!(!sanitized_tx.message().is_writable(3));
+
+        let accounts = Accounts::new_with_config_for_tests(
+            Vec::new(),
+            &ClusterType::Development,
+            AccountSecondaryIndexes::default(),
+            AccountShrinkThreshold::default(),
+        );
+        let mut account = AccountSharedData::new(1, 0, &Pubkey::default());
+        account.set_rent_epoch(1);
+        accounts.store_for_tests(0, &keypair.pubkey(), &account);
+        let clock_account = AccountSharedData::new(1, 0, &sysvar::id());
+        accounts.store_for_tests(0, &clock_key, &clock_account);
+
+        let mut hash_queue = BlockhashQueue::new(100);
+        hash_queue.register_hash(&Hash::default(), 0);
+        let ancestors = vec![(0, 0)].into_iter().collect();
+        let loaded_accounts = accounts.load_accounts(
+            &ancestors,
+            0,
+            &Hash::default(),
+            &[sanitized_tx.clone()],
+            vec![(Ok(()), None)],
+            &hash_queue,
+            &mut error_counters,
+            &RentCollector::default(),
+            &FeatureSet::all_enabled(),
+            &FeeStructure::default(),
+            None,
+            RewardInterval::OutsideInterval,
+            &HashMap::new(),
+            &LoadedProgramsForTxBatch::default(),
+        );
+
+        assert_eq!(loaded_accounts.len(), 1);
+        let loaded_transaction = match &loaded_accounts[0] {
+            (Ok(loaded_transaction), _nonce) => loaded_transaction,
+            (Err(e), _nonce) => panic!("{e}"),
+        };
+        let (key, signatures_account) = &loaded_transaction.accounts[2];
+        assert_eq!(*key, signatures_key);
+        assert_eq!(signatures_account.owner(), &sysvar::id());
+        assert_eq!(signatures_account.data()[0], 1);
+        let (key, instructions_account) = &loaded_transaction.accounts[3];
+        assert_eq!(*key, instructions_key);
+        let mut instructions_data =
+            construct_instructions_data(&sanitized_tx.message().decompile_instructions());
+        sysvar::instructions::append_integrity_checksum(&mut instructions_data);
+        assert_eq!(instructions_account.data(), instructions_data);
+        assert_eq!(loaded_transaction.accounts[4], (clock_key, clock_account));
+    }
+
     #[test]
     fn test_load_accounts_bad_owner() {
         let mut accounts: Vec<TransactionAccount> = Vec::new();
@@ -3380,6 +4014,8 @@ mod tests {
         let mut error_counters = TransactionErrorMetrics::default();
         accounts.load_accounts(
             &ancestors,
+            0,
+            &Hash::default(),
             &[tx],
             vec![(Ok(()), None)],
             &hash_queue,
@@ -3455,6 +4091,58 @@ mod tests {
         assert_eq!(loaded_transaction.accounts[1].1.lamports(), 42);
     }
 
+    #[test]
+    fn test_signatures_sysvar_override_validated() {
+        solana_logger::setup();
+        let accounts = Accounts::new_with_config_for_tests(
+            Vec::new(),
+            &ClusterType::Development,
+            AccountSecondaryIndexes::default(),
+            AccountShrinkThreshold::default(),
+        );
+        let keypair = Keypair::new();
+        let account = AccountSharedData::new(1_000_000, 0, &Pubkey::default());
+        accounts.store_slow_uncached(0, &keypair.pubkey(), &account);
+
+        let signatures_key = sysvar::signatures::id();
+        let instructions = vec![CompiledInstruction::new(2, &(), vec![0, 1])];
+        let tx = Transaction::new_with_compiled_instructions(
+            &[&keypair],
+            &[signatures_key],
+            Hash::default(),
+            vec![native_loader::id()],
+            instructions,
+        );
+
+        let signature = Signature::from([7; 64]);
+        let override_data =
+            construct_signatures_data_from_iter([signature.as_bytes()].into_iter()).unwrap();
+        let mut account_overrides = AccountOverrides::default();
+        account_overrides.set_signatures_sysvar(Some(AccountSharedData::from(Account {
+            data: override_data.clone(),
+            owner: sysvar::id(),
+            ..Account::default()
+        })));
+        let loaded_accounts =
+            load_accounts_no_store(&accounts, tx.clone(), Some(&account_overrides));
+        let loaded_transaction = loaded_accounts[0].0.as_ref().unwrap();
+        assert_eq!(loaded_transaction.accounts[1].1.data(), override_data);
+
+        // An override whose count exceeds its signatures is rejected
+        let mut overcounted_data = override_data;
+        overcounted_data[0] = 3;
+        account_overrides.set_signatures_sysvar(Some(AccountSharedData::from(Account {
+            data: overcounted_data,
+            owner: sysvar::id(),
+            ..Account::default()
+        })));
+        let loaded_accounts = load_accounts_no_store(&accounts, tx, Some(&account_overrides));
+        assert_eq!(
+            loaded_accounts[0].0.as_ref().unwrap_err(),
+            &TransactionError::SanitizeFailure
+        );
+    }
+
     fn create_accounts_prepare_if_nonce_account() -> (
         Pubkey,
         AccountSharedData,
@@ -31,6 +31,7 @@ use {
         tpu_info::NullTpuInfo,
     },
     std::{
+        collections::HashMap,
         convert::TryFrom,
         io,
         net::{Ipv4Addr, SocketAddr},
@@ -49,12 +50,16 @@ use {
     tokio_serde::formats::Bincode,
 };
 
+/// The transactions processed by a local server, by their first signature.
+pub type TransactionHistory = Arc<RwLock<HashMap<Signature, VersionedTransaction>>>;
+
 #[derive(Clone)]
 struct BanksServer {
     bank_forks: Arc<RwLock<BankForks>>,
     block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
     transaction_sender: Sender<TransactionInfo>,
     poll_signature_status_sleep_duration: Duration,
+    transaction_history: Option<TransactionHistory>,
 }
 
 impl BanksServer {
@@ -67,16 +72,22 @@ impl BanksServer {
         block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
         transaction_sender: Sender<TransactionInfo>,
         poll_signature_status_sleep_duration: Duration,
+        transaction_history: Option<TransactionHistory>,
     ) -> Self {
         Self {
             bank_forks,
             block_commitment_cache,
             transaction_sender,
             poll_signature_status_sleep_duration,
+            transaction_history,
         }
     }
 
-    fn run(bank_forks: Arc<RwLock<BankForks>>, transaction_receiver: Receiver<TransactionInfo>) {
+    fn run(
+        bank_forks: Arc<RwLock<BankForks>>,
+        transaction_receiver: Receiver<TransactionInfo>,
+        transaction_history: Option<TransactionHistory>,
+    ) {
         while let Ok(info) = transaction_receiver.recv() {
             let mut transaction_infos = vec![info];
             while let Ok(info) = transaction_receiver.try_recv() {
@@ -86,6 +97,9 @@ impl BanksServer {
                 .into_iter()
                 .map(|info| deserialize(&info.wire_transaction).unwrap())
                 .collect();
+            if let Some(transaction_history) = &transaction_history {
+                record_transactions(transaction_history, &transactions);
+            }
             loop {
                 let bank = bank_forks.read().unwrap().working_bank();
                 // bank forks lock released, now verify bank hasn't been frozen yet
@@ -106,6 +120,7 @@ impl BanksServer {
         bank_forks: Arc<RwLock<BankForks>>,
         block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
         poll_signature_status_sleep_duration: Duration,
+        transaction_history: Option<TransactionHistory>,
     ) -> Self {
         let (transaction_sender, transaction_receiver) = unbounded();
         let bank = bank_forks.read().unwrap().working_bank();
@@ -116,15 +131,23 @@ impl BanksServer {
             w_block_commitment_cache.set_all_slots(slot, slot);
         }
         let server_bank_forks = bank_forks.clone();
+        let server_transaction_history = transaction_history.clone();
         Builder::new()
             .name("solBankForksCli".to_string())
-            .spawn(move || Self::run(server_bank_forks, transaction_receiver))
+            .spawn(move || {
+                Self::run(
+                    server_bank_forks,
+                    transaction_receiver,
+                    server_transaction_history,
+                )
+            })
             .unwrap();
         Self::new(
             bank_forks,
             block_commitment_cache,
             transaction_sender,
             poll_signature_status_sleep_duration,
+            transaction_history,
         )
     }
 
@@ -161,6 +184,18 @@ impl BanksServer {
     }
 }
 
+fn record_transactions(
+    transaction_history: &TransactionHistory,
+    transactions: &[VersionedTransaction],
+) {
+    let mut transaction_history = transaction_history.write().unwrap();
+    for transaction in transactions {
+        if let Some(signature) = transaction.signatures.first() {
+            transaction_history.insert(*signature, transaction.clone());
+        }
+    }
+}
+
 fn verify_transaction(
     transaction: &SanitizedTransaction,
     feature_set: &Arc<FeatureSet>,
@@ -174,7 +209,7 @@ fn simulate_transaction(
     bank: &Bank,
     transaction: VersionedTransaction,
 ) -> BanksTransactionResultWithSimulation {
-    let sanitized_transaction = match SanitizedTransaction::try_create(
+    let mut sanitized_transaction = match SanitizedTransaction::try_create(
         transaction,
         MessageHash::Compute,
         Some(false), // is_simple_vote_tx
@@ -188,6 +223,7 @@ fn simulate_transaction(
         }
         Ok(tx) => tx,
     };
+    sanitized_transaction.demote_account_keys(bank.demoted_sysvar_ids());
     let TransactionSimulationResult {
         result,
         logs,
@@ -325,7 +361,7 @@ impl Banks for BanksServer {
         commitment: CommitmentLevel,
     ) -> Option<transaction::Result<()>> {
         let bank = self.bank(commitment);
-        let sanitized_transaction = match SanitizedTransaction::try_create(
+        let mut sanitized_transaction = match SanitizedTransaction::try_create(
             transaction.clone(),
             MessageHash::Compute,
             Some(false), // is_simple_vote_tx
@@ -334,6 +370,7 @@ impl Banks for BanksServer {
             Ok(tx) => tx,
             Err(err) => return Some(Err(err)),
         };
+        sanitized_transaction.demote_account_keys(bank.demoted_sysvar_ids());
 
         if let Err(err) = verify_transaction(&sanitized_transaction, &bank.feature_set) {
             return Some(Err(err));
@@ -363,6 +400,9 @@ impl Banks for BanksServer {
         _: Context,
         transaction: VersionedTransaction,
     ) -> BanksTransactionResultWithMetadata {
+        if let Some(transaction_history) = &self.transaction_history {
+            record_transactions(transaction_history, std::slice::from_ref(&transaction));
+        }
         let bank = self.bank_forks.read().unwrap().working_bank();
         match bank.process_transaction_with_metadata(transaction) {
             TransactionExecutionResult::NotExecuted(error) => BanksTransactionResultWithMetadata {
@@ -424,11 +464,42 @@ pub async fn start_local_server(
     bank_forks: Arc<RwLock<BankForks>>,
     block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
     poll_signature_status_sleep_duration: Duration,
+) -> UnboundedChannel<Response<BanksResponse>, ClientMessage<BanksRequest>> {
+    start_loopback_server(
+        bank_forks,
+        block_commitment_cache,
+        poll_signature_status_sleep_duration,
+        None,
+    )
+}
+
+/// Start a local server like [`start_local_server`], recording every
+/// transaction it processes in `transaction_history`.
+pub async fn start_local_server_with_transaction_history(
+    bank_forks: Arc<RwLock<BankForks>>,
+    block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+    poll_signature_status_sleep_duration: Duration,
+    transaction_history: TransactionHistory,
+) -> UnboundedChannel<Response<BanksResponse>, ClientMessage<BanksRequest>> {
+    start_loopback_server(
+        bank_forks,
+        block_commitment_cache,
+        poll_signature_status_sleep_duration,
+        Some(transaction_history),
+    )
+}
+
+fn start_loopback_server(
+    bank_forks: Arc<RwLock<BankForks>>,
+    block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+    poll_signature_status_sleep_duration: Duration,
+    transaction_history: Option<TransactionHistory>,
 ) -> UnboundedChannel<Response<BanksResponse>, ClientMessage<BanksRequest>> {
     let banks_server = BanksServer::new_loopback(
         bank_forks,
         block_commitment_cache,
         poll_signature_status_sleep_duration,
+        transaction_history,
     );
     let (client_transport, server_transport) = transport::channel::unbounded();
     let server = server::BaseChannel::with_defaults(server_transport).execute(banks_server.serve());
@@ -478,6 +549,7 @@ pub async fn start_tcp_server(
                 block_commitment_cache.clone(),
                 sender,
                 Duration::from_millis(200),
+                None,
             );
             chan.execute(server.serve())
         })
@@ -3052,6 +3052,93 @@ impl fmt::Display for CliFindProgramDerivedAddress {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliSysvar {
+    pub name: String,
+    pub address: String,
+    pub contents: CliSysvarContents,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CliSysvarContents {
+    Signatures(CliSignaturesSysvar),
+    /// Sysvars decoded by `solana_account_decoder::parse_sysvar`
+    Parsed(Value),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliSignaturesSysvar {
+    pub num_signatures: usize,
+    pub signatures: Vec<CliSysvarSignature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_table_lookups: Option<Vec<CliSysvarAddressTableLookup>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliSysvarSignature {
+    /// Hex encoded signature bytes
+    pub signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliSysvarAddressTableLookup {
+    pub account_key: String,
+    pub writable_start: u16,
+    pub writable_len: u16,
+    pub readonly_start: u16,
+    pub readonly_len: u16,
+}
+
+impl QuietDisplay for CliSysvar {}
+impl VerboseDisplay for CliSysvar {}
+
+impl fmt::Display for CliSysvar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln_name_value(f, "Sysvar:", &self.name)?;
+        writeln_name_value(f, "Address:", &self.address)?;
+        match &self.contents {
+            CliSysvarContents::Signatures(signatures) => {
+                writeln_name_value(f, "Signatures:", &signatures.num_signatures.to_string())?;
+                for (index, entry) in signatures.signatures.iter().enumerate() {
+                    writeln!(f, "  {index}: {}", entry.signature)?;
+                    if let Some(signer) = &entry.signer {
+                        writeln!(f, "     Signer: {signer}")?;
+                    }
+                }
+                if let Some(lookups) = &signatures.address_table_lookups {
+                    writeln_name_value(f, "Address Table Lookups:", &lookups.len().to_string())?;
+                    for lookup in lookups {
+                        writeln!(
+                            f,
+                            "  {}: writable {}..{}, readonly {}..{}",
+                            lookup.account_key,
+                            lookup.writable_start,
+                            u32::from(lookup.writable_start) + u32::from(lookup.writable_len),
+                            lookup.readonly_start,
+                            u32::from(lookup.readonly_start) + u32::from(lookup.readonly_len),
+                        )?;
+                    }
+                }
+            }
+            CliSysvarContents::Parsed(value) => {
+                writeln!(
+                    f,
+                    "{}",
+                    serde_json::to_string_pretty(value).map_err(|_| fmt::Error)?
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
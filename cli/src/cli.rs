@@ -416,6 +416,10 @@ pub enum CliCommand {
         seed: String,
         program_id: Pubkey,
     },
+    DecodeSysvar {
+        pubkey: Pubkey,
+        data_file: Option<String>,
+    },
     DecodeTransaction(VersionedTransaction),
     ResolveSigner(Option<String>),
     ShowAccount {
@@ -818,6 +822,7 @@ pub fn parse_command(
         ("find-program-derived-address", Some(matches)) => {
             parse_find_program_derived_address(matches)
         }
+        ("decode-sysvar", Some(matches)) => parse_decode_sysvar(matches),
         ("decode-transaction", Some(matches)) => parse_decode_transaction(matches),
         ("resolve-signer", Some(matches)) => {
             let signer_path = resolve_signer(matches, "signer", wallet_manager)?;
@@ -1600,6 +1605,9 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
         } => process_balance(&rpc_client, config, pubkey, *use_lamports_unit),
         // Confirm the last client transaction by signature
         CliCommand::Confirm(signature) => process_confirm(&rpc_client, config, signature),
+        CliCommand::DecodeSysvar { pubkey, data_file } => {
+            process_decode_sysvar(&rpc_client, config, pubkey, data_file)
+        }
         CliCommand::DecodeTransaction(transaction) => {
             process_decode_transaction(config, transaction)
         }
@@ -2007,6 +2015,45 @@ mod tests {
             }
         );
 
+        // Test DecodeSysvar Subcommand, by name and by address
+        let test_decode_sysvar = test_commands.clone().get_matches_from(vec![
+            "test",
+            "decode-sysvar",
+            "signatures",
+            "--data-file",
+            "signatures.bin",
+        ]);
+        assert_eq!(
+            parse_command(&test_decode_sysvar, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::DecodeSysvar {
+                    pubkey: solana_sdk::sysvar::signatures::id(),
+                    data_file: Some("signatures.bin".to_string()),
+                },
+                signers: vec![],
+            }
+        );
+        let clock_string = solana_sdk::sysvar::clock::id().to_string();
+        let test_decode_sysvar =
+            test_commands
+                .clone()
+                .get_matches_from(vec!["test", "decode-sysvar", &clock_string]);
+        assert_eq!(
+            parse_command(&test_decode_sysvar, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::DecodeSysvar {
+                    pubkey: solana_sdk::sysvar::clock::id(),
+                    data_file: None,
+                },
+                signers: vec![],
+            }
+        );
+        let test_decode_sysvar =
+            test_commands
+                .clone()
+                .get_matches_from(vec!["test", "decode-sysvar", &pubkey_string]);
+        assert!(parse_command(&test_decode_sysvar, &default_signer, &mut None).is_err());
+
         // Test SignOffchainMessage
         let test_sign_offchain = test_commands.clone().get_matches_from(vec![
             "test",
@@ -2073,6 +2120,24 @@ mod tests {
         };
         assert_eq!(process_command(&config).unwrap(), "0.00000005 SOL");
 
+        // Per-transaction sysvars are only decoded from a data file
+        let signatures_file = make_tmp_path("signatures_sysvar");
+        std::fs::write(
+            &signatures_file,
+            solana_sdk::sysvar::signatures::construct_signatures_data(&[[7; 64].into()]).unwrap(),
+        )
+        .unwrap();
+        config.command = CliCommand::DecodeSysvar {
+            pubkey: solana_sdk::sysvar::signatures::id(),
+            data_file: Some(signatures_file),
+        };
+        assert!(process_command(&config).unwrap().contains(&"07".repeat(64)));
+        config.command = CliCommand::DecodeSysvar {
+            pubkey: solana_sdk::sysvar::signatures::id(),
+            data_file: None,
+        };
+        assert!(process_command(&config).is_err());
+
         let good_signature = bs58::decode(SIGNATURE)
             .into_vec()
             .map(Signature::try_from)
@@ -56,11 +56,7 @@ use {
         slot_history,
         stake::{self, state::StakeStateV2},
         system_instruction,
-        sysvar::{
-            self,
-            slot_history::SlotHistory,
-            stake_history::{self},
-        },
+        sysvar::{self, slot_history::SlotHistory, stake_history::StakeHistory},
         transaction::Transaction,
     },
     solana_transaction_status::{
@@ -1793,16 +1789,10 @@ pub fn process_show_stakes(
 
     let all_stake_accounts = rpc_client
         .get_program_accounts_with_config(&stake::program::id(), program_accounts_config)?;
-    let stake_history_account = rpc_client.get_account(&stake_history::id())?;
-    let clock_account = rpc_client.get_account(&sysvar::clock::id())?;
-    let clock: Clock = from_account(&clock_account).ok_or_else(|| {
-        CliError::RpcRequestError("Failed to deserialize clock sysvar".to_string())
-    })?;
+    let stake_history: StakeHistory = rpc_client.get_parsed_sysvar()?;
+    let clock: Clock = rpc_client.get_parsed_sysvar()?;
     progress_bar.finish_and_clear();
 
-    let stake_history = from_account(&stake_history_account).ok_or_else(|| {
-        CliError::RpcRequestError("Failed to deserialize stake history".to_string())
-    })?;
     let new_rate_activation_epoch =
         get_feature_activation_epoch(rpc_client, &feature_set::reduce_stake_warmup_cooldown::id())?;
 
@@ -2194,8 +2184,7 @@ pub fn process_calculate_rent(
     data_length: usize,
     use_lamports_unit: bool,
 ) -> ProcessResult {
-    let rent_account = rpc_client.get_account(&sysvar::rent::id())?;
-    let rent: Rent = rent_account.deserialize_data()?;
+    let rent: Rent = rpc_client.get_parsed_sysvar()?;
     let rent_exempt_minimum_lamports = rent.minimum_balance(data_length);
     let cli_rent_calculation = CliRentCalculation {
         lamports_per_byte_year: 0,
@@ -21,7 +21,7 @@ use {
         clock::Slot,
         epoch_schedule::EpochSchedule,
         feature::{self, Feature},
-        feature_set::FEATURE_NAMES,
+        feature_set::{feature_groups_of, FEATURE_GROUPS, FEATURE_NAMES},
         genesis_config::ClusterType,
         message::Message,
         pubkey::Pubkey,
@@ -432,6 +432,17 @@ impl FeatureSubCommands for App<'_, '_> {
                                 .multiple(true)
                                 .help("Feature status to query [default: all known features]"),
                         )
+                        .arg(
+                            Arg::with_name("group")
+                                .long("group")
+                                .value_name("GROUP")
+                                .takes_value(true)
+                                .conflicts_with("features")
+                                .help(
+                                    "Query the status of the features of a feature group, \
+                                    e.g. transaction-introspection",
+                                ),
+                        )
                         .arg(
                             Arg::with_name("display_all")
                                 .long("display-all")
@@ -479,6 +490,13 @@ fn known_feature(feature: &Pubkey) -> Result<(), CliError> {
     }
 }
 
+fn known_feature_group(group: &str) -> Result<&'static [Pubkey], CliError> {
+    FEATURE_GROUPS
+        .get(group)
+        .map(Vec::as_slice)
+        .ok_or_else(|| CliError::BadParameter(format!("Unknown feature group: {group}")))
+}
+
 pub fn parse_feature_subcommand(
     matches: &ArgMatches<'_>,
     default_signer: &DefaultSigner,
@@ -523,6 +541,8 @@ pub fn parse_feature_subcommand(
                     known_feature(feature)?;
                 }
                 features
+            } else if let Some(group) = matches.value_of("group") {
+                known_feature_group(group)?.to_vec()
             } else {
                 FEATURE_NAMES.keys().cloned().collect()
             };
@@ -956,6 +976,30 @@ fn process_activate(
     let mut transaction = Transaction::new_unsigned(message);
     transaction.try_sign(&config.signers, blockhash)?;
 
+    for group in feature_groups_of(&feature_id) {
+        let group_features = &FEATURE_GROUPS[group];
+        let inactive_features: Vec<_> = rpc_client
+            .get_multiple_accounts(group_features)?
+            .into_iter()
+            .zip(group_features)
+            .filter_map(|(account, id)| {
+                (*id != feature_id && account.and_then(status_from_account).is_none())
+                    .then(|| id.to_string())
+            })
+            .collect();
+        if !inactive_features.is_empty() {
+            println!(
+                "{}",
+                style(format!(
+                    "{feature_id} is part of the {group} feature group. Its other inactive \
+                    features should be activated alongside it: {}",
+                    inactive_features.join(", ")
+                ))
+                .yellow()
+            );
+        }
+    }
+
     println!(
         "Activating {} ({})",
         FEATURE_NAMES.get(&feature_id).unwrap(),
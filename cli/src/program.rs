@@ -959,6 +959,12 @@ fn process_program_deploy(
                     )
                     .into())
                 }
+                Ok(UpgradeableLoaderState::ProgramMetadata { .. }) => {
+                    return Err(format!(
+                        "Cannot use program metadata account {buffer_pubkey} as buffer",
+                    )
+                    .into())
+                }
                 Ok(UpgradeableLoaderState::Uninitialized) => {
                     return Err(format!("Buffer account {buffer_pubkey} is not initialized").into());
                 }
@@ -928,6 +928,7 @@ fn build_retract_instruction(
         slot: _,
         authority_address,
         status,
+        min_transaction_signers: _,
     }) = solana_loader_v4_program::get_state(&account.data)
     {
         if authority != authority_address {
@@ -967,6 +968,7 @@ fn build_truncate_instructions(
             slot: _,
             authority_address,
             status,
+            min_transaction_signers: _,
         }) = solana_loader_v4_program::get_state(&account.data)
         {
             if authority != authority_address {
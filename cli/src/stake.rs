@@ -38,7 +38,6 @@ use {
     },
     solana_rpc_client_nonce_utils::blockhash_query::BlockhashQuery,
     solana_sdk::{
-        account::from_account,
         account_utils::StateMut,
         clock::{Clock, UnixTimestamp, SECONDS_PER_DAY},
         commitment_config::CommitmentConfig,
@@ -56,7 +55,6 @@ use {
         },
         stake_history::{Epoch, StakeHistory},
         system_instruction::{self, SystemError},
-        sysvar::{clock, stake_history},
         transaction::Transaction,
     },
     std::{ops::Deref, rc::Rc},
@@ -2469,14 +2467,8 @@ pub fn process_show_stake_account(
     }
     match stake_account.state() {
         Ok(stake_state) => {
-            let stake_history_account = rpc_client.get_account(&stake_history::id())?;
-            let stake_history = from_account(&stake_history_account).ok_or_else(|| {
-                CliError::RpcRequestError("Failed to deserialize stake history".to_string())
-            })?;
-            let clock_account = rpc_client.get_account(&clock::id())?;
-            let clock: Clock = from_account(&clock_account).ok_or_else(|| {
-                CliError::RpcRequestError("Failed to deserialize clock sysvar".to_string())
-            })?;
+            let stake_history: StakeHistory = rpc_client.get_parsed_sysvar()?;
+            let clock: Clock = rpc_client.get_parsed_sysvar()?;
             let new_rate_activation_epoch = get_feature_activation_epoch(
                 rpc_client,
                 &feature_set::reduce_stake_warmup_cooldown::id(),
@@ -2519,11 +2511,7 @@ pub fn process_show_stake_history(
     use_lamports_unit: bool,
     limit_results: usize,
 ) -> ProcessResult {
-    let stake_history_account = rpc_client.get_account(&stake_history::id())?;
-    let stake_history =
-        from_account::<StakeHistory, _>(&stake_history_account).ok_or_else(|| {
-            CliError::RpcRequestError("Failed to deserialize stake history".to_string())
-        })?;
+    let stake_history: StakeHistory = rpc_client.get_parsed_sysvar()?;
 
     let limit_results = match config.output_format {
         OutputFormat::Json | OutputFormat::JsonCompact => std::usize::MAX,
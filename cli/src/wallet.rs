@@ -11,6 +11,7 @@ use {
     },
     clap::{value_t_or_exit, App, Arg, ArgMatches, SubCommand},
     hex::FromHex,
+    solana_account_decoder::parse_sysvar::parse_sysvar,
     solana_clap_utils::{
         compute_unit_price::{compute_unit_price_arg, COMPUTE_UNIT_PRICE_ARG},
         fee_payer::*,
@@ -25,8 +26,9 @@ use {
     solana_cli_output::{
         display::{build_balance_message, BuildBalanceMessageConfig},
         return_signers_with_config, CliAccount, CliBalance, CliFindProgramDerivedAddress,
-        CliSignatureVerificationStatus, CliTransaction, CliTransactionConfirmation, OutputFormat,
-        ReturnSignersConfig,
+        CliSignatureVerificationStatus, CliSignaturesSysvar, CliSysvar,
+        CliSysvarAddressTableLookup, CliSysvarContents, CliSysvarSignature, CliTransaction,
+        CliTransactionConfirmation, OutputFormat, ReturnSignersConfig,
     },
     solana_remote_wallet::remote_wallet::RemoteWalletManager,
     solana_rpc_client::rpc_client::RpcClient,
@@ -41,6 +43,7 @@ use {
         stake,
         system_instruction::{self, SystemError},
         system_program,
+        sysvar::{self, signatures::SignaturesData},
         transaction::{Transaction, VersionedTransaction},
     },
     solana_transaction_status::{
@@ -237,6 +240,31 @@ impl WalletSubCommands for App<'_, '_> {
                         .help("transaction encoding"),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("decode-sysvar")
+                .about("Fetch a sysvar account and decode its contents")
+                .arg(
+                    Arg::with_name("sysvar")
+                        .index(1)
+                        .value_name("SYSVAR_ADDRESS_OR_NAME")
+                        .takes_value(true)
+                        .required(true)
+                        .help(
+                            "The address of the sysvar, or its name, e.g. clock or signatures",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("data_file")
+                        .long("data-file")
+                        .value_name("FILEPATH")
+                        .takes_value(true)
+                        .help(
+                            "Decode the account data in this file instead of fetching the \
+                            account. Required for sysvars constructed for each transaction, \
+                            such as the signatures sysvar",
+                        ),
+                ),
+        )
         .subcommand(
             SubCommand::with_name("resolve-signer")
                 .about("Checks that a signer is valid, and returns its specific path; useful for signers that may be specified generally, eg. usb://ledger")
@@ -467,6 +495,50 @@ pub fn parse_decode_transaction(matches: &ArgMatches<'_>) -> Result<CliCommandIn
     }
 }
 
+/// The names `decode-sysvar` accepts, and the sysvars they refer to
+#[allow(deprecated)]
+fn sysvar_names() -> [(&'static str, Pubkey); 19] {
+    [
+        ("clock", sysvar::clock::id()),
+        ("epoch-rewards", sysvar::epoch_rewards::id()),
+        ("epoch-schedule", sysvar::epoch_schedule::id()),
+        ("epoch-stake", sysvar::epoch_stake::id()),
+        ("fees", sysvar::fees::id()),
+        ("header", sysvar::header::id()),
+        ("instructions", sysvar::instructions::id()),
+        ("last-restart-slot", sysvar::last_restart_slot::id()),
+        ("recent-blockhashes", sysvar::recent_blockhashes::id()),
+        ("recent-signatures", sysvar::recent_signatures::id()),
+        ("rent", sysvar::rent::id()),
+        ("rewards", sysvar::rewards::id()),
+        ("scratch", sysvar::scratch::id()),
+        ("signatures", sysvar::signatures::id()),
+        ("slot-hashes", sysvar::slot_hashes::id()),
+        ("slot-history", sysvar::slot_history::id()),
+        ("stake-history", sysvar::stake_history::id()),
+        ("tick-hash", sysvar::tick_hash::id()),
+        ("transaction-context", sysvar::transaction_context::id()),
+    ]
+}
+
+pub fn parse_decode_sysvar(matches: &ArgMatches<'_>) -> Result<CliCommandInfo, CliError> {
+    let sysvar = matches.value_of("sysvar").unwrap();
+    let name = sysvar.to_ascii_lowercase().replace('_', "-");
+    let pubkey = sysvar_names()
+        .into_iter()
+        .find(|(sysvar_name, _)| *sysvar_name == name)
+        .map(|(_, pubkey)| pubkey)
+        .or_else(|| Pubkey::from_str(sysvar).ok().filter(sysvar::is_sysvar_id))
+        .ok_or_else(|| CliError::BadParameter(format!("{sysvar} is not a sysvar")))?;
+    Ok(CliCommandInfo {
+        command: CliCommand::DecodeSysvar {
+            pubkey,
+            data_file: matches.value_of("data_file").map(ToString::to_string),
+        },
+        signers: vec![],
+    })
+}
+
 pub fn parse_create_address_with_seed(
     matches: &ArgMatches<'_>,
     default_signer: &DefaultSigner,
@@ -826,6 +898,73 @@ pub fn process_decode_transaction(
     Ok(config.output_format.formatted_string(&decode_transaction))
 }
 
+pub fn process_decode_sysvar(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    pubkey: &Pubkey,
+    data_file: &Option<String>,
+) -> ProcessResult {
+    let name = sysvar_names()
+        .into_iter()
+        .find(|(_, sysvar_id)| sysvar_id == pubkey)
+        .map_or_else(|| pubkey.to_string(), |(name, _)| name.to_string());
+    let data = if let Some(data_file) = data_file {
+        std::fs::read(data_file)?
+    } else if pubkey.is_per_transaction_sysvar() {
+        return Err(CliError::BadParameter(format!(
+            "The {name} sysvar is constructed for each transaction and has no account to \
+            fetch, use --data-file to decode its data"
+        ))
+        .into());
+    } else {
+        rpc_client.get_account(pubkey)?.data
+    };
+
+    let contents = if sysvar::signatures::check_id(pubkey) {
+        let signatures_data = SignaturesData::deserialize(&data)
+            .map_err(|err| CliError::BadParameter(format!("Invalid {name} sysvar data: {err}")))?;
+        let signers = signatures_data
+            .signers
+            .map(|signers| signers.into_iter().map(Some).collect())
+            .unwrap_or_else(|| vec![None; signatures_data.signatures.len()]);
+        CliSysvarContents::Signatures(CliSignaturesSysvar {
+            num_signatures: signatures_data.signatures.len(),
+            signatures: signatures_data
+                .signatures
+                .iter()
+                .zip(signers)
+                .map(|(signature, signer)| CliSysvarSignature {
+                    signature: hex::encode(signature.as_ref()),
+                    signer: signer.map(|signer: Pubkey| signer.to_string()),
+                })
+                .collect(),
+            address_table_lookups: signatures_data.address_table_lookups.map(|lookups| {
+                lookups
+                    .iter()
+                    .map(|lookup| CliSysvarAddressTableLookup {
+                        account_key: lookup.account_key.to_string(),
+                        writable_start: lookup.writable_start,
+                        writable_len: lookup.writable_len,
+                        readonly_start: lookup.readonly_start,
+                        readonly_len: lookup.readonly_len,
+                    })
+                    .collect()
+            }),
+        })
+    } else {
+        let parsed = parse_sysvar(&data, pubkey).map_err(|err| {
+            CliError::BadParameter(format!("Unable to decode the {name} sysvar: {err}"))
+        })?;
+        CliSysvarContents::Parsed(serde_json::to_value(parsed)?)
+    };
+
+    Ok(config.output_format.formatted_string(&CliSysvar {
+        name,
+        address: pubkey.to_string(),
+        contents,
+    }))
+}
+
 pub fn process_create_address_with_seed(
     config: &CliConfig,
     from_pubkey: Option<&Pubkey>,
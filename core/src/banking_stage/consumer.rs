@@ -26,7 +26,7 @@ use {
     },
     solana_sdk::{
         clock::{Slot, FORWARD_TRANSACTIONS_TO_LEADER_AT_SLOT_OFFSET, MAX_PROCESSING_AGE},
-        feature_set, saturating_add_assign,
+        feature_set, saturating_add_assign, sysvar,
         timing::timestamp,
         transaction::{self, AddressLoader, SanitizedTransaction, TransactionError},
     },
@@ -545,6 +545,10 @@ impl Consumer {
         });
         execute_and_commit_timings.collect_balances_us = collect_balances_us;
 
+        // Transactions reading the tick hash sysvar observe the last tick
+        // registered when they execute, so they must be recorded before the
+        // next tick for replay to observe the same one
+        let executed_tick_height = bank.tick_height();
         let (load_and_execute_transactions_output, load_execute_us) = measure_us!(bank
             .load_and_execute_transactions(
                 batch,
@@ -583,13 +587,27 @@ impl Consumer {
                     }
                 })
                 .collect_vec());
+        let reads_tick_hash = execution_results
+            .iter()
+            .zip(batch.sanitized_transactions())
+            .any(|(execution_result, tx)| {
+                execution_result.was_executed()
+                    && tx
+                        .message()
+                        .account_keys()
+                        .iter()
+                        .any(sysvar::tick_hash::check_id)
+            });
 
         let (freeze_lock, freeze_lock_us) = measure_us!(bank.freeze_lock());
         execute_and_commit_timings.freeze_lock_us = freeze_lock_us;
 
-        let (record_transactions_summary, record_us) = measure_us!(self
-            .transaction_recorder
-            .record_transactions(bank.slot(), executed_transactions));
+        let (record_transactions_summary, record_us) =
+            measure_us!(self.transaction_recorder.record_transactions(
+                bank.slot(),
+                executed_transactions,
+                reads_tick_hash.then_some(executed_tick_height),
+            ));
         execute_and_commit_timings.record_us = record_us;
 
         let RecordTransactionsSummary {
@@ -7,6 +7,7 @@ use {
         feature_set,
         hash::Hash,
         message::Message,
+        pubkey::Pubkey,
         sanitize::SanitizeError,
         short_vec::decode_shortu16_len,
         signature::Signature,
@@ -97,23 +98,25 @@ impl ImmutableDeserializedPacket {
     }
 
     // This function deserializes packets into transactions, computes the blake3 hash of transaction
-    // messages, and verifies secp256k1 instructions.
+    // messages, demotes `demoted_account_keys` to readonly, and verifies secp256k1 instructions.
     pub fn build_sanitized_transaction(
         &self,
         feature_set: &Arc<feature_set::FeatureSet>,
         votes_only: bool,
         address_loader: impl AddressLoader,
+        demoted_account_keys: &[Pubkey],
     ) -> Option<SanitizedTransaction> {
         if votes_only && !self.is_simple_vote() {
             return None;
         }
-        let tx = SanitizedTransaction::try_new(
+        let mut tx = SanitizedTransaction::try_new(
             self.transaction().clone(),
             *self.message_hash(),
             self.is_simple_vote(),
             address_loader,
         )
         .ok()?;
+        tx.demote_account_keys(demoted_account_keys);
         tx.verify_precompiles(feature_set).ok()?;
         Some(tx)
     }
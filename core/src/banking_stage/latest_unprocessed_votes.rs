@@ -283,6 +283,7 @@ impl LatestUnprocessedVotes {
                                 &bank.feature_set,
                                 bank.vote_only_bank(),
                                 bank.as_ref(),
+                                bank.demoted_sysvar_ids(),
                             )
                         {
                             if forward_packet_batches_by_accounts.try_add_packet(
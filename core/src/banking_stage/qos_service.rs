@@ -107,6 +107,10 @@ impl QosService {
                             Ok(current_block_cost) => {
                                 debug!("slot {:?}, transaction {:?}, cost {:?}, fit into current block, current block cost {}", bank.slot(), tx, cost, current_block_cost);
                                 self.metrics.stats.selected_txs_count.fetch_add(1, Ordering::Relaxed);
+                                if tx.message().references_per_transaction_sysvar() {
+                                    self.metrics.stats.selected_introspection_txs_count.fetch_add(1, Ordering::Relaxed);
+                                    self.metrics.stats.estimated_introspection_cu.fetch_add(cost.sum(), Ordering::Relaxed);
+                                }
                                 num_included += 1;
                                 Ok(cost)
                             },
@@ -418,6 +422,14 @@ struct QosServiceMetricsStats {
     /// number of transactions to be included in blocks
     selected_txs_count: AtomicU64,
 
+    /// number of transactions to be included in blocks that reference per-transaction sysvars,
+    /// e.g. the signatures sysvar
+    selected_introspection_txs_count: AtomicU64,
+
+    /// accumulated estimated Compute Units of the selected transactions that reference
+    /// per-transaction sysvars
+    estimated_introspection_cu: AtomicU64,
+
     /// accumulated estimated signature Compute Unites to be packed into block
     estimated_signature_cu: AtomicU64,
 
@@ -495,6 +507,20 @@ impl QosServiceMetrics {
                     self.stats.selected_txs_count.swap(0, Ordering::Relaxed),
                     i64
                 ),
+                (
+                    "selected_introspection_txs_count",
+                    self.stats
+                        .selected_introspection_txs_count
+                        .swap(0, Ordering::Relaxed),
+                    i64
+                ),
+                (
+                    "estimated_introspection_cu",
+                    self.stats
+                        .estimated_introspection_cu
+                        .swap(0, Ordering::Relaxed),
+                    i64
+                ),
                 (
                     "estimated_signature_cu",
                     self.stats.estimated_signature_cu.swap(0, Ordering::Relaxed),
@@ -693,6 +719,15 @@ mod tests {
         assert!(results[1].is_ok());
         assert!(results[2].is_err());
         assert!(results[3].is_err());
+        // neither references a per-transaction sysvar
+        assert_eq!(
+            qos_service
+                .metrics
+                .stats
+                .selected_introspection_txs_count
+                .load(Ordering::Relaxed),
+            0
+        );
     }
 
     #[test]
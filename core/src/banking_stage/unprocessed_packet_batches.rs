@@ -475,6 +475,7 @@ mod tests {
                     &Arc::new(FeatureSet::default()),
                     votes_only,
                     SimpleAddressLoader::Disabled,
+                    &[],
                 )
             });
             assert_eq!(2, txs.count());
@@ -485,6 +486,7 @@ mod tests {
                     &Arc::new(FeatureSet::default()),
                     votes_only,
                     SimpleAddressLoader::Disabled,
+                    &[],
                 )
             });
             assert_eq!(0, txs.count());
@@ -504,6 +506,7 @@ mod tests {
                     &Arc::new(FeatureSet::default()),
                     votes_only,
                     SimpleAddressLoader::Disabled,
+                    &[],
                 )
             });
             assert_eq!(3, txs.count());
@@ -514,6 +517,7 @@ mod tests {
                     &Arc::new(FeatureSet::default()),
                     votes_only,
                     SimpleAddressLoader::Disabled,
+                    &[],
                 )
             });
             assert_eq!(2, txs.count());
@@ -533,6 +537,7 @@ mod tests {
                     &Arc::new(FeatureSet::default()),
                     votes_only,
                     SimpleAddressLoader::Disabled,
+                    &[],
                 )
             });
             assert_eq!(3, txs.count());
@@ -543,6 +548,7 @@ mod tests {
                     &Arc::new(FeatureSet::default()),
                     votes_only,
                     SimpleAddressLoader::Disabled,
+                    &[],
                 )
             });
             assert_eq!(3, txs.count());
@@ -1,5 +1,5 @@
 use {
-    solana_ledger::{blockstore::Blockstore, blockstore_meta::PerfSampleV2},
+    solana_ledger::{blockstore::Blockstore, blockstore_meta::PerfSampleV3},
     solana_runtime::bank_forks::BankForks,
     std::{
         sync::{
@@ -56,13 +56,17 @@ impl SamplePerformanceService {
                 now = Instant::now();
                 let new_snapshot = StatsSnapshot::from_forks(&bank_forks);
 
-                let (num_transactions, num_non_vote_transactions, num_slots) =
-                    new_snapshot.diff_since(&snapshot);
+                let (
+                    num_transactions,
+                    num_non_vote_transactions,
+                    num_introspection_transactions,
+                    num_slots,
+                ) = new_snapshot.diff_since(&snapshot);
 
                 // Store the new snapshot to compare against in the next iteration of the loop.
                 snapshot = new_snapshot;
 
-                let perf_sample = PerfSampleV2 {
+                let perf_sample = PerfSampleV3 {
                     // Note: since num_slots is computed from the highest slot and not the bank
                     // slot, this value should not be used in conjunction with num_transactions or
                     // num_non_vote_transactions to draw any conclusions about number of
@@ -70,6 +74,7 @@ impl SamplePerformanceService {
                     num_slots,
                     num_transactions,
                     num_non_vote_transactions,
+                    num_introspection_transactions,
                     sample_period_secs: elapsed.as_secs() as u16,
                 };
 
@@ -91,6 +96,7 @@ impl SamplePerformanceService {
 struct StatsSnapshot {
     pub num_transactions: u64,
     pub num_non_vote_transactions: u64,
+    pub num_introspection_transactions: u64,
     pub highest_slot: u64,
 }
 
@@ -101,16 +107,19 @@ impl StatsSnapshot {
         Self {
             num_transactions: bank.transaction_count(),
             num_non_vote_transactions: bank.non_vote_transaction_count_since_restart(),
+            num_introspection_transactions: bank.introspection_transaction_count_since_restart(),
             highest_slot: forks.highest_slot(),
         }
     }
 
-    fn diff_since(&self, predecessor: &Self) -> (u64, u64, u64) {
+    fn diff_since(&self, predecessor: &Self) -> (u64, u64, u64, u64) {
         (
             self.num_transactions
                 .saturating_sub(predecessor.num_transactions),
             self.num_non_vote_transactions
                 .saturating_sub(predecessor.num_non_vote_transactions),
+            self.num_introspection_transactions
+                .saturating_sub(predecessor.num_introspection_transactions),
             self.highest_slot.saturating_sub(predecessor.highest_slot),
         )
     }
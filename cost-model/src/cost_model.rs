@@ -15,7 +15,7 @@ use {
         borsh0_10::try_from_slice_unchecked,
         compute_budget::{self, ComputeBudgetInstruction},
         feature_set::{
-            add_set_tx_loaded_accounts_data_size_instruction,
+            add_set_tx_loaded_accounts_data_size_instruction, enable_transaction_guard_instruction,
             include_loaded_accounts_data_size_in_fee_calculation,
             remove_deprecated_request_unit_ix, FeatureSet,
         },
@@ -134,6 +134,7 @@ impl CostModel {
             transaction.message().program_instructions_iter(),
             !feature_set.is_active(&remove_deprecated_request_unit_ix::id()),
             feature_set.is_active(&add_set_tx_loaded_accounts_data_size_instruction::id()),
+            feature_set.is_active(&enable_transaction_guard_instruction::id()),
         );
 
         // if failed to process compute_budget instructions, the transaction will not be executed
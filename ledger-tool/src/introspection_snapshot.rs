@@ -0,0 +1,265 @@
+//! Compact index of the transaction signatures of a ledger, for bootstrapping
+//! signature pointer resolvers from an archived ledger without replaying it.
+//!
+//! The index starts with [`MAGIC`] and the format [`VERSION`], followed by one
+//! [`RECORD_LEN`] byte record per transaction, in ledger order: the first
+//! signature of the transaction, the slot as a little-endian `u64`, the index
+//! of the transaction within the slot as a little-endian `u32`, and a
+//! [`TransactionStatus`] byte.
+//!
+//! The index of a transaction is its position among the transactions of all
+//! entries of the slot, as recorded in the ledger. Programs can't observe it
+//! when executing the transaction, see the header sysvar documentation.
+//!
+//! Statuses are read from the transaction status column of the blockstore,
+//! which is only written by nodes running with transaction history enabled.
+//! Transactions without a recorded status are marked
+//! [`TransactionStatus::Unknown`].
+
+use {
+    solana_ledger::blockstore::Blockstore,
+    solana_sdk::{clock::Slot, signature::Signature},
+    std::io::Write,
+};
+
+/// Bytes starting an introspection snapshot.
+pub const MAGIC: &[u8; 7] = b"SIGIDX\0";
+
+/// Version of the introspection snapshot format.
+pub const VERSION: u8 = 1;
+
+/// Length in bytes of the index header.
+pub const HEADER_LEN: usize = MAGIC.len() + 1;
+
+/// Length in bytes of each transaction record.
+pub const RECORD_LEN: usize = 64 + 8 + 4 + 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TransactionStatus {
+    Succeeded = 0,
+    Failed = 1,
+    /// No status was recorded for the transaction
+    Unknown = 2,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexRecord {
+    pub signature: Signature,
+    pub slot: Slot,
+    pub index: u32,
+    pub status: TransactionStatus,
+}
+
+impl IndexRecord {
+    pub fn to_bytes(&self) -> [u8; RECORD_LEN] {
+        let mut bytes = [0; RECORD_LEN];
+        bytes[..64].copy_from_slice(self.signature.as_ref());
+        bytes[64..72].copy_from_slice(&self.slot.to_le_bytes());
+        bytes[72..76].copy_from_slice(&self.index.to_le_bytes());
+        bytes[76] = self.status as u8;
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; RECORD_LEN]) -> Option<Self> {
+        let status = match bytes[76] {
+            0 => TransactionStatus::Succeeded,
+            1 => TransactionStatus::Failed,
+            2 => TransactionStatus::Unknown,
+            _ => return None,
+        };
+        Some(Self {
+            signature: Signature::try_from(&bytes[..64]).ok()?,
+            slot: Slot::from_le_bytes(bytes[64..72].try_into().unwrap()),
+            index: u32::from_le_bytes(bytes[72..76].try_into().unwrap()),
+            status,
+        })
+    }
+}
+
+/// The records of the transactions of `slot`, in the order they appear in
+/// the slot's entries.
+pub fn slot_index_records(blockstore: &Blockstore, slot: Slot) -> Result<Vec<IndexRecord>, String> {
+    let entries = blockstore
+        .get_slot_entries(slot, 0)
+        .map_err(|err| format!("Failed to load entries for slot {slot}: {err:?}"))?;
+    entries
+        .iter()
+        .flat_map(|entry| &entry.transactions)
+        .enumerate()
+        .map(|(index, transaction)| {
+            let signature = transaction.signatures[0];
+            let status = match blockstore.read_transaction_status((signature, slot)) {
+                Ok(Some(meta)) if meta.status.is_ok() => TransactionStatus::Succeeded,
+                Ok(Some(_)) => TransactionStatus::Failed,
+                Ok(None) => TransactionStatus::Unknown,
+                Err(err) => {
+                    return Err(format!(
+                        "Failed to read status of {signature} in slot {slot}: {err:?}"
+                    ))
+                }
+            };
+            Ok(IndexRecord {
+                signature,
+                slot,
+                index: index as u32,
+                status,
+            })
+        })
+        .collect()
+}
+
+/// Write the index of the transactions of the full slots between
+/// `starting_slot` and `ending_slot` inclusive to `output`.
+///
+/// Returns the number of transactions written. Dead slots and slots whose
+/// entries can't be loaded are skipped with a warning.
+pub fn write_introspection_snapshot<W: Write>(
+    blockstore: &Blockstore,
+    starting_slot: Slot,
+    ending_slot: Slot,
+    only_rooted: bool,
+    output: &mut W,
+) -> Result<usize, String> {
+    let slot_iterator = blockstore
+        .slot_meta_iterator(starting_slot)
+        .map_err(|err| {
+            format!("Failed to load entries starting from slot {starting_slot}: {err:?}")
+        })?;
+
+    let write_err = |err: std::io::Error| format!("Failed to write introspection snapshot: {err}");
+    output.write_all(MAGIC).map_err(write_err)?;
+    output.write_all(&[VERSION]).map_err(write_err)?;
+
+    let mut num_records = 0;
+    for (slot, slot_meta) in slot_iterator {
+        if slot > ending_slot {
+            break;
+        }
+        if !slot_meta.is_full() || (only_rooted && !blockstore.is_root(slot)) {
+            continue;
+        }
+        if blockstore.is_dead(slot) {
+            eprintln!("Skipping dead slot {slot}");
+            continue;
+        }
+
+        let records = match slot_index_records(blockstore, slot) {
+            Ok(records) => records,
+            Err(err) => {
+                eprintln!("{err}");
+                continue;
+            }
+        };
+        for record in &records {
+            output.write_all(&record.to_bytes()).map_err(write_err)?;
+        }
+        num_records += records.len();
+    }
+    output.flush().map_err(write_err)?;
+    Ok(num_records)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_entry::entry::Entry,
+        solana_ledger::{blockstore::entries_to_test_shreds, get_tmp_ledger_path_auto_delete},
+        solana_sdk::{
+            hash::Hash, instruction::InstructionError, pubkey::Pubkey, signature::Keypair,
+            system_transaction, transaction::TransactionError,
+        },
+        solana_transaction_status::TransactionStatusMeta,
+    };
+
+    #[test]
+    fn test_write_introspection_snapshot() {
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+
+        let keypair = Keypair::new();
+        let transactions: Vec<_> = (0..3)
+            .map(|lamports| {
+                system_transaction::transfer(
+                    &keypair,
+                    &Pubkey::new_unique(),
+                    lamports,
+                    Hash::default(),
+                )
+            })
+            .collect();
+        let entries = vec![
+            Entry::new(&Hash::default(), 1, transactions[..2].to_vec()),
+            Entry::new(&Hash::default(), 1, vec![]),
+            Entry::new(&Hash::default(), 1, transactions[2..].to_vec()),
+        ];
+        let slot = 1;
+        let shreds = entries_to_test_shreds(&entries, slot, 0, true, 0, true);
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+
+        let signatures: Vec<_> = transactions.iter().map(|tx| tx.signatures[0]).collect();
+        blockstore
+            .write_transaction_status(
+                slot,
+                signatures[0],
+                vec![],
+                vec![],
+                TransactionStatusMeta::default(),
+            )
+            .unwrap();
+        blockstore
+            .write_transaction_status(
+                slot,
+                signatures[2],
+                vec![],
+                vec![],
+                TransactionStatusMeta {
+                    status: Err(TransactionError::InstructionError(
+                        0,
+                        InstructionError::Custom(0),
+                    )),
+                    ..TransactionStatusMeta::default()
+                },
+            )
+            .unwrap();
+
+        let mut output = vec![];
+        assert_eq!(
+            write_introspection_snapshot(&blockstore, 0, Slot::MAX, false, &mut output),
+            Ok(3)
+        );
+        assert_eq!(output.len(), HEADER_LEN + 3 * RECORD_LEN);
+        assert_eq!(&output[..MAGIC.len()], MAGIC);
+        assert_eq!(output[MAGIC.len()], VERSION);
+
+        let records: Vec<_> = output[HEADER_LEN..]
+            .chunks_exact(RECORD_LEN)
+            .map(|bytes| IndexRecord::from_bytes(bytes.try_into().unwrap()).unwrap())
+            .collect();
+        let expected_statuses = [
+            TransactionStatus::Succeeded,
+            TransactionStatus::Unknown,
+            TransactionStatus::Failed,
+        ];
+        for (index, record) in records.iter().enumerate() {
+            assert_eq!(
+                record,
+                &IndexRecord {
+                    signature: signatures[index],
+                    slot,
+                    index: index as u32,
+                    status: expected_statuses[index],
+                }
+            );
+        }
+
+        // The slot isn't rooted
+        let mut output = vec![];
+        assert_eq!(
+            write_introspection_snapshot(&blockstore, 0, Slot::MAX, true, &mut output),
+            Ok(0)
+        );
+        assert_eq!(output.len(), HEADER_LEN);
+    }
+}
@@ -76,6 +76,7 @@ use {
         shred_version::compute_shred_version,
         stake::{self, state::StakeStateV2},
         system_program,
+        sysvar::{self, SysvarKind},
         transaction::{
             MessageHash, SanitizedTransaction, SimpleAddressLoader, VersionedTransaction,
         },
@@ -89,7 +90,7 @@ use {
         collections::{BTreeMap, BTreeSet, HashMap, HashSet},
         ffi::OsStr,
         fs::File,
-        io::{self, stdout, BufRead, BufReader, Write},
+        io::{self, stdout, BufRead, BufReader, BufWriter, Write},
         num::NonZeroUsize,
         path::{Path, PathBuf},
         process::{exit, Command, Stdio},
@@ -104,6 +105,7 @@ use {
 
 mod args;
 mod bigtable;
+mod introspection_snapshot;
 mod ledger_path;
 mod ledger_utils;
 mod output;
@@ -819,6 +821,7 @@ fn analyze_storage(database: &Database) {
     analyze_column::<ShredCode>(database, "ShredCode");
     analyze_column::<TransactionStatus>(database, "TransactionStatus");
     analyze_column::<AddressSignatures>(database, "AddressSignatures");
+    analyze_column::<SecondarySignatures>(database, "SecondarySignatures");
     analyze_column::<TransactionMemos>(database, "TransactionMemos");
     analyze_column::<TransactionStatusIndex>(database, "TransactionStatusIndex");
     analyze_column::<Rewards>(database, "Rewards");
@@ -847,6 +850,9 @@ fn raw_key_to_slot(key: &[u8], column_name: &str) -> Option<Slot> {
         cf::AddressSignatures::NAME => Some(cf::AddressSignatures::slot(
             cf::AddressSignatures::index(key),
         )),
+        cf::SecondarySignatures::NAME => Some(cf::SecondarySignatures::slot(
+            cf::SecondarySignatures::index(key),
+        )),
         cf::TransactionMemos::NAME => None, // does not implement slot()
         cf::TransactionStatusIndex::NAME => None, // does not implement slot()
         cf::Rewards::NAME => Some(cf::Rewards::slot(cf::Rewards::index(key))),
@@ -929,6 +935,11 @@ fn compute_slot_cost(blockstore: &Blockstore, slot: Slot) -> Result<(), String>
                 })
                 .ok()
             })
+            .map(|mut transaction| {
+                // Costs are computed with all features enabled
+                transaction.demote_account_keys(&sysvar::FEATURE_GATED_IDS);
+                transaction
+            })
             .for_each(|transaction| {
                 num_programs += transaction.message().instructions().len();
 
@@ -1930,6 +1941,21 @@ fn main() {
                     .takes_value(false)
                     .help("Include sysvars too"),
             )
+            .arg(
+                Arg::with_name("sysvars_only")
+                    .long("sysvars-only")
+                    .takes_value(false)
+                    .conflicts_with("include_sysvars")
+                    .help("Only include sysvars. Per-transaction sysvar accounts found in \
+                           the accounts state are reported as warnings in any case"),
+            )
+            .arg(
+                Arg::with_name("exclude_sysvars")
+                    .long("exclude-sysvars")
+                    .takes_value(false)
+                    .conflicts_with_all(&["include_sysvars", "sysvars_only"])
+                    .help("Exclude sysvars [default]"),
+            )
             .arg(
                 Arg::with_name("no_account_contents")
                     .long("no-account-contents")
@@ -2155,6 +2181,27 @@ fn main() {
                     .help("Slots that their blocks are computed for cost, default to all slots in ledger"),
             )
         )
+        .subcommand(
+            SubCommand::with_name("create-introspection-snapshot")
+            .about("Write a compact index of the signatures of the transactions in a slot \
+                    range, with their slot, index and status, without replaying the ledger")
+            .arg(&starting_slot_arg)
+            .arg(&ending_slot_arg)
+            .arg(
+                Arg::with_name("only_rooted")
+                    .long("only-rooted")
+                    .takes_value(false)
+                    .help("Only index root slots"),
+            )
+            .arg(
+                Arg::with_name("output_file")
+                    .index(1)
+                    .value_name("FILE")
+                    .takes_value(true)
+                    .required(true)
+                    .help("File to write the index to"),
+            )
+        )
         .subcommand(
             SubCommand::with_name("print-file-metadata")
             .about("Print the metadata of the specified ledger-store file. \
@@ -3283,6 +3330,7 @@ fn main() {
                 };
                 let genesis_config = open_genesis_config_by(&ledger_path, arg_matches);
                 let include_sysvars = arg_matches.is_present("include_sysvars");
+                let sysvars_only = arg_matches.is_present("sysvars_only");
                 let blockstore = open_blockstore(
                     &ledger_path,
                     get_access_type(&process_options),
@@ -3325,7 +3373,20 @@ fn main() {
                     if let Some((pubkey, account, slot)) = some_account_tuple
                         .filter(|(_, account, _)| Accounts::is_loadable(account.lamports()))
                     {
-                        if !include_sysvars && solana_sdk::sysvar::is_sysvar_id(pubkey) {
+                        let sysvar_kind = pubkey.sysvar_kind();
+                        if sysvar_kind == SysvarKind::PerTransaction {
+                            warn!(
+                                "Per-transaction sysvar {pubkey} is stored in the accounts state \
+                                 at slot {slot}"
+                            );
+                        }
+                        let include = match sysvar_kind {
+                            SysvarKind::NotSysvar => !sysvars_only,
+                            SysvarKind::Cached | SysvarKind::PerTransaction => {
+                                include_sysvars || sysvars_only
+                            }
+                        };
+                        if !include {
                             return;
                         }
 
@@ -4201,6 +4262,42 @@ fn main() {
                     }
                 }
             }
+            ("create-introspection-snapshot", Some(arg_matches)) => {
+                let starting_slot = value_t_or_exit!(arg_matches, "starting_slot", Slot);
+                let ending_slot = value_t!(arg_matches, "ending_slot", Slot).unwrap_or(Slot::MAX);
+                let only_rooted = arg_matches.is_present("only_rooted");
+                let output_file = value_t_or_exit!(arg_matches, "output_file", String);
+                let blockstore = open_blockstore(
+                    &ledger_path,
+                    AccessType::Secondary,
+                    wal_recovery_mode,
+                    force_update_to_open,
+                    enforce_ulimit_nofile,
+                );
+
+                let mut output = match File::create(&output_file) {
+                    Ok(file) => BufWriter::new(file),
+                    Err(err) => {
+                        eprintln!("Unable to create {output_file}: {err}");
+                        exit(1);
+                    }
+                };
+                match introspection_snapshot::write_introspection_snapshot(
+                    &blockstore,
+                    starting_slot,
+                    ending_slot,
+                    only_rooted,
+                    &mut output,
+                ) {
+                    Ok(num_records) => {
+                        println!("Wrote {num_records} transactions to {output_file}")
+                    }
+                    Err(err) => {
+                        eprintln!("{err}");
+                        exit(1);
+                    }
+                }
+            }
             ("print-file-metadata", Some(arg_matches)) => {
                 let blockstore = open_blockstore(
                     &ledger_path,
@@ -52,6 +52,7 @@ use {
         hash::Hash,
         pubkey::Pubkey,
         signature::{Keypair, Signature, Signer},
+        sysvar::recent_signatures,
         timing::timestamp,
         transaction::{SanitizedVersionedTransaction, VersionedTransaction},
     },
@@ -70,6 +71,7 @@ use {
         fmt::Write,
         fs,
         io::{Error as IoError, ErrorKind},
+        ops::Range,
         path::{Path, PathBuf},
         rc::Rc,
         sync::{
@@ -183,6 +185,45 @@ pub struct CompletedDataSetInfo {
     pub end_index: u32,
 }
 
+/// The data [`Shred`]s that carry a transaction within a slot.
+///
+/// Each shred is signed by the slot leader, and merkle shreds also carry the
+/// merkle proof of their payload against the signed root of their erasure
+/// set. A consumer that knows the leader can therefore verify that the
+/// transaction was part of the block without trusting the node that served
+/// the shreds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionShreds {
+    /// [`Slot`] to which the [`Shred`]s belong.
+    pub slot: Slot,
+
+    /// Serialized payloads of consecutive data shreds, in index order.
+    pub shreds: Vec<Vec<u8>>,
+
+    /// Byte range of the bincode serialized transaction within the
+    /// concatenated data sections of `shreds`.
+    pub transaction_range: Range<usize>,
+}
+
+/// Proof that a transaction signature is part of the root of its slot in the
+/// recent signatures sysvar.
+///
+/// See [`recent_signatures::verify_signature_proof`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureMerkleProof {
+    /// [`Slot`] in which the transaction was processed.
+    pub slot: Slot,
+
+    /// Root of the merkle tree over the sorted signatures of the slot.
+    pub root: Hash,
+
+    /// Index of the signature among the sorted signatures of the slot.
+    pub index: usize,
+
+    /// Sibling hashes along the path from the signature up to `root`.
+    pub proof: Vec<Hash>,
+}
+
 pub struct BlockstoreSignals {
     pub blockstore: Blockstore,
     pub ledger_signal_receiver: Receiver<bool>,
@@ -204,6 +245,7 @@ pub struct Blockstore {
     code_shred_cf: LedgerColumn<cf::ShredCode>,
     transaction_status_cf: LedgerColumn<cf::TransactionStatus>,
     address_signatures_cf: LedgerColumn<cf::AddressSignatures>,
+    secondary_signatures_cf: LedgerColumn<cf::SecondarySignatures>,
     transaction_memos_cf: LedgerColumn<cf::TransactionMemos>,
     transaction_status_index_cf: LedgerColumn<cf::TransactionStatusIndex>,
     active_transaction_status_index: RwLock<u64>,
@@ -306,6 +348,7 @@ impl Blockstore {
         let code_shred_cf = db.column();
         let transaction_status_cf = db.column();
         let address_signatures_cf = db.column();
+        let secondary_signatures_cf = db.column();
         let transaction_memos_cf = db.column();
         let transaction_status_index_cf = db.column();
         let rewards_cf = db.column();
@@ -358,6 +401,7 @@ impl Blockstore {
             code_shred_cf,
             transaction_status_cf,
             address_signatures_cf,
+            secondary_signatures_cf,
             transaction_memos_cf,
             transaction_status_index_cf,
             active_transaction_status_index: RwLock::new(active_transaction_status_index),
@@ -728,6 +772,7 @@ impl Blockstore {
         self.code_shred_cf.submit_rocksdb_cf_metrics();
         self.transaction_status_cf.submit_rocksdb_cf_metrics();
         self.address_signatures_cf.submit_rocksdb_cf_metrics();
+        self.secondary_signatures_cf.submit_rocksdb_cf_metrics();
         self.transaction_memos_cf.submit_rocksdb_cf_metrics();
         self.transaction_status_index_cf.submit_rocksdb_cf_metrics();
         self.rewards_cf.submit_rocksdb_cf_metrics();
@@ -2122,8 +2167,8 @@ impl Blockstore {
     }
 
     /// Initializes the TransactionStatusIndex column family with two records, `0` and `1`,
-    /// which are used as the primary index for entries in the TransactionStatus and
-    /// AddressSignatures columns. At any given time, one primary index is active (ie. new records
+    /// which are used as the primary index for entries in the TransactionStatus,
+    /// AddressSignatures and SecondarySignatures columns. At any given time, one primary index is active (ie. new records
     /// are stored under this index), the other is frozen.
     fn initialize_transaction_status_index(&self) -> Result<()> {
         self.transaction_status_index_cf
@@ -2276,6 +2321,48 @@ impl Blockstore {
         Ok(())
     }
 
+    /// Indexes the signatures of a transaction following its first,
+    /// `signature`, so that the transaction can be looked up by any of them
+    pub fn write_secondary_signatures(
+        &self,
+        slot: Slot,
+        signature: Signature,
+        secondary_signatures: &[Signature],
+    ) -> Result<()> {
+        // This write lock prevents interleaving issues with the transaction_status_index_cf by gating
+        // writes to that column
+        let w_active_transaction_status_index =
+            self.active_transaction_status_index.write().unwrap();
+        let primary_index =
+            self.get_primary_index_to_write(slot, &w_active_transaction_status_index)?;
+        for secondary_signature in secondary_signatures {
+            self.secondary_signatures_cf
+                .put((primary_index, *secondary_signature, slot), &signature)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the first signature of the transaction that carries
+    /// `signature`, which is `signature` itself unless it is one of the
+    /// transaction's other signatures
+    pub fn get_first_signature(&self, signature: Signature) -> Result<Signature> {
+        let (_lock, _) = self.ensure_lowest_cleanup_slot();
+        let first_available_block = self.get_first_available_block()?;
+
+        for primary_index in 0..=1 {
+            let mut index_iterator = self.secondary_signatures_cf.iter(IteratorMode::From(
+                (primary_index, signature, first_available_block),
+                IteratorDirection::Forward,
+            ))?;
+            if let Some(((i, secondary_signature, _slot), data)) = index_iterator.next() {
+                if i == primary_index && secondary_signature == signature {
+                    return Ok(deserialize(&data)?);
+                }
+            }
+        }
+        Ok(signature)
+    }
+
     pub fn read_transaction_memos(&self, signature: Signature) -> Result<Option<String>> {
         self.transaction_memos_cf.get(signature)
     }
@@ -2414,6 +2501,130 @@ impl Blockstore {
         self.get_transaction_with_status(signature, &confirmed_unrooted_slots)
     }
 
+    /// Returns the data shreds carrying a transaction processed in a root,
+    /// along with the location of the transaction within them
+    pub fn get_rooted_transaction_shreds(
+        &self,
+        signature: Signature,
+    ) -> Result<Option<TransactionShreds>> {
+        datapoint_info!(
+            "blockstore-rpc-api",
+            ("method", "get_rooted_transaction_shreds", String)
+        );
+        let Some((slot, _meta)) = self.get_rooted_transaction_status(signature)? else {
+            return Ok(None);
+        };
+        self.get_transaction_shreds(slot, signature)
+    }
+
+    /// Returns the data shreds of `slot` carrying the transaction with the
+    /// given signature, along with the location of the transaction within
+    /// them
+    pub fn get_transaction_shreds(
+        &self,
+        slot: Slot,
+        signature: Signature,
+    ) -> Result<Option<TransactionShreds>> {
+        let _lock = self.check_lowest_cleanup_slot(slot)?;
+        let (completed_ranges, _slot_meta) = self.get_completed_ranges(slot, 0)?;
+        let Some(&(_, last_index)) = completed_ranges.last() else {
+            return Ok(None);
+        };
+        let data_shreds = self.get_data_shreds_for_slot(slot, 0)?;
+        let invalid_shred_data = |err: String| {
+            BlockstoreError::InvalidShredData(Box::new(bincode::ErrorKind::Custom(err)))
+        };
+        if data_shreds.len() <= last_index as usize {
+            return Err(invalid_shred_data(format!(
+                "Missing data shreds for slot {slot}"
+            )));
+        }
+
+        for (start_index, end_index) in completed_ranges {
+            let data_shreds = &data_shreds[start_index as usize..=end_index as usize];
+            let deshred_payload = Shredder::deshred(data_shreds).map_err(|err| {
+                invalid_shred_data(format!(
+                    "Could not reconstruct data block from constituent shreds, error: {err:?}"
+                ))
+            })?;
+            let entries = deserialize::<Vec<Entry>>(&deshred_payload).map_err(|err| {
+                invalid_shred_data(format!("could not reconstruct entries: {err:?}"))
+            })?;
+            let Some(transaction_range) = find_transaction_range(&entries, &signature)? else {
+                continue;
+            };
+
+            // Keep only the shreds whose data overlaps the transaction
+            let mut shreds = Vec::new();
+            let mut shred_offset = 0;
+            let mut first_shred_offset = None;
+            for shred in data_shreds {
+                let data_len = shred
+                    .data()
+                    .map_err(|err| invalid_shred_data(format!("Invalid data shred: {err:?}")))?
+                    .len();
+                let shred_range = shred_offset..shred_offset.saturating_add(data_len);
+                shred_offset = shred_range.end;
+                if shred_range.end <= transaction_range.start {
+                    continue;
+                }
+                if shred_range.start >= transaction_range.end {
+                    break;
+                }
+                first_shred_offset.get_or_insert(shred_range.start);
+                shreds.push(shred.payload().clone());
+            }
+            let first_shred_offset = first_shred_offset.unwrap_or_default();
+            return Ok(Some(TransactionShreds {
+                slot,
+                shreds,
+                transaction_range: transaction_range.start.saturating_sub(first_shred_offset)
+                    ..transaction_range.end.saturating_sub(first_shred_offset),
+            }));
+        }
+        Ok(None)
+    }
+
+    /// Returns the proof that the transaction with the given signature is
+    /// part of the root of `slot` in the recent signatures sysvar.
+    ///
+    /// The signatures of the slot are regenerated from its data shreds, so
+    /// proofs are available whether or not the transaction status columns
+    /// were populated or have since been purged.
+    pub fn get_signature_merkle_proof(
+        &self,
+        slot: Slot,
+        signature: Signature,
+    ) -> Result<Option<SignatureMerkleProof>> {
+        datapoint_info!(
+            "blockstore-rpc-api",
+            ("method", "get_signature_merkle_proof", String)
+        );
+        let _lock = self.check_lowest_cleanup_slot(slot)?;
+        let (entries, _num_shreds, is_full) =
+            self.get_slot_entries_with_shred_info(slot, 0, false)?;
+        // The root covers every transaction of the slot
+        if !is_full {
+            return Ok(None);
+        }
+        let mut signatures: Vec<Signature> = entries
+            .iter()
+            .flat_map(|entry| &entry.transactions)
+            .filter_map(|transaction| transaction.signatures.first().copied())
+            .collect();
+        signatures.sort_unstable();
+        let Ok(index) = signatures.binary_search(&signature) else {
+            return Ok(None);
+        };
+        Ok(Some(SignatureMerkleProof {
+            slot,
+            root: recent_signatures::compute_signature_root(&signatures),
+            index,
+            proof: recent_signatures::compute_signature_proof(&signatures, index)
+                .unwrap_or_default(),
+        }))
+    }
+
     fn get_transaction_with_status(
         &self,
         signature: Signature,
@@ -2818,39 +3029,47 @@ impl Blockstore {
     }
 
     pub fn get_recent_perf_samples(&self, num: usize) -> Result<Vec<(Slot, PerfSample)>> {
-        // When reading `PerfSamples`, the database may contain samples with either `PerfSampleV1`
-        // or `PerfSampleV2` encoding.  We expect `PerfSampleV1` to be a prefix of the
-        // `PerfSampleV2` encoding (see [`perf_sample_v1_is_prefix_of_perf_sample_v2`]), so we try
-        // them in order.
+        // When reading `PerfSamples`, the database may contain samples with `PerfSampleV1`,
+        // `PerfSampleV2` or `PerfSampleV3` encoding.  We expect each version to be a prefix of the
+        // next one (see [`perf_sample_v1_is_prefix_of_perf_sample_v2`] and
+        // [`perf_sample_v2_is_prefix_of_perf_sample_v3`]), so we try them from the newest.
+        fn not_enough_bytes(err: &bincode::Error) -> bool {
+            matches!(
+                &**err,
+                bincode::ErrorKind::Io(io_err) if matches!(io_err.kind(), ErrorKind::UnexpectedEof)
+            )
+        }
+
         let samples = self
             .db
             .iter::<cf::PerfSamples>(IteratorMode::End)?
             .take(num)
             .map(|(slot, data)| {
-                deserialize::<PerfSampleV2>(&data)
-                    .map(|sample| (slot, sample.into()))
+                deserialize::<PerfSampleV3>(&data)
+                    .map(PerfSample::from)
                     .or_else(|err| {
-                        match &*err {
-                            bincode::ErrorKind::Io(io_err)
-                                if matches!(io_err.kind(), ErrorKind::UnexpectedEof) =>
-                            {
-                                // Not enough bytes to deserialize as `PerfSampleV2`.
-                            }
-                            _ => return Err(err),
+                        if !not_enough_bytes(&err) {
+                            return Err(err);
                         }
-
-                        deserialize::<PerfSampleV1>(&data).map(|sample| (slot, sample.into()))
+                        deserialize::<PerfSampleV2>(&data).map(PerfSample::from)
                     })
+                    .or_else(|err| {
+                        if !not_enough_bytes(&err) {
+                            return Err(err);
+                        }
+                        deserialize::<PerfSampleV1>(&data).map(PerfSample::from)
+                    })
+                    .map(|sample| (slot, sample))
                     .map_err(Into::into)
             });
 
         samples.collect()
     }
 
-    pub fn write_perf_sample(&self, index: Slot, perf_sample: &PerfSampleV2) -> Result<()> {
+    pub fn write_perf_sample(&self, index: Slot, perf_sample: &PerfSampleV3) -> Result<()> {
         // Always write as the current version.
         let bytes =
-            serialize(&perf_sample).expect("`PerfSampleV2` can be serialized with `bincode`");
+            serialize(&perf_sample).expect("`PerfSampleV3` can be serialized with `bincode`");
         self.perf_samples_cf.put_bytes(index, &bytes)
     }
 
@@ -3926,6 +4145,40 @@ fn get_last_hash<'a>(iterator: impl Iterator<Item = &'a Entry> + 'a) -> Option<H
     iterator.last().map(|entry| entry.hash)
 }
 
+/// Returns the byte range of the transaction with the given signature within
+/// the bincode serialization of `entries`
+fn find_transaction_range(
+    entries: &[Entry],
+    signature: &Signature,
+) -> Result<Option<Range<usize>>> {
+    fn serialized_size<T: serde::Serialize>(value: &T) -> Result<usize> {
+        Ok(bincode::serialized_size(value)? as usize)
+    }
+
+    let mut offset = serialized_size(&Vec::<Entry>::new())?;
+    for entry in entries {
+        let Some(position) = entry
+            .transactions
+            .iter()
+            .position(|transaction| transaction.signatures.first() == Some(signature))
+        else {
+            offset = offset.saturating_add(serialized_size(entry)?);
+            continue;
+        };
+        offset = offset.saturating_add(serialized_size(&Entry {
+            num_hashes: entry.num_hashes,
+            hash: entry.hash,
+            transactions: vec![],
+        })?);
+        for transaction in &entry.transactions[..position] {
+            offset = offset.saturating_add(serialized_size(transaction)?);
+        }
+        let len = serialized_size(&entry.transactions[position])?;
+        return Ok(Some(offset..offset.saturating_add(len)));
+    }
+    Ok(None)
+}
+
 fn send_signals(
     new_shreds_signals: &[Sender<bool>],
     completed_slots_senders: &[Sender<Vec<u64>>],
@@ -8157,6 +8410,207 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_get_first_signature() {
+        let slot = 2;
+        let keypairs = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let transaction = Transaction::new_with_compiled_instructions(
+            &[&keypairs[0], &keypairs[1], &keypairs[2]],
+            &[],
+            Hash::default(),
+            vec![solana_sdk::pubkey::new_rand()],
+            vec![CompiledInstruction::new(3, &(), vec![0])],
+        );
+        let signatures = transaction.signatures.clone();
+        let mut entries = vec![next_entry_mut(&mut Hash::default(), 0, vec![transaction])];
+        entries.extend(create_ticks(1, 0, Hash::default()));
+        let shreds = entries_to_test_shreds(
+            &entries,
+            slot,
+            slot - 1, // parent_slot
+            true,     // is_full_slot
+            0,        // version
+            true,     // merkle_variant
+        );
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+        blockstore.set_roots([slot - 1, slot].iter()).unwrap();
+        blockstore
+            .write_transaction_status(
+                slot,
+                signatures[0],
+                vec![],
+                vec![],
+                TransactionStatusMeta::default(),
+            )
+            .unwrap();
+        blockstore
+            .write_secondary_signatures(slot, signatures[0], &signatures[1..])
+            .unwrap();
+
+        for signature in &signatures {
+            let first_signature = blockstore.get_first_signature(*signature).unwrap();
+            assert_eq!(first_signature, signatures[0]);
+            let confirmed_transaction = blockstore
+                .get_rooted_transaction(first_signature)
+                .unwrap()
+                .unwrap();
+            assert_eq!(confirmed_transaction.slot, slot);
+            assert_eq!(
+                confirmed_transaction.get_transaction().signatures,
+                signatures
+            );
+        }
+        let unknown_signature = Signature::new_unique();
+        assert_eq!(
+            blockstore.get_first_signature(unknown_signature).unwrap(),
+            unknown_signature
+        );
+
+        // Secondary signatures are purged along with the transaction status
+        blockstore.run_purge(0, slot, PurgeType::Exact).unwrap();
+        assert_eq!(
+            blockstore.get_first_signature(signatures[1]).unwrap(),
+            signatures[1]
+        );
+        assert!(blockstore
+            .secondary_signatures_cf
+            .iter(IteratorMode::Start)
+            .unwrap()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_get_signature_merkle_proof() {
+        let slot = 2;
+        let entries = make_slot_entries_with_transactions(10);
+        let shreds = entries_to_test_shreds(
+            &entries[..entries.len() - 1],
+            slot,
+            slot - 1, // parent_slot
+            false,    // is_full_slot
+            0,        // version
+            true,     // merkle_variant
+        );
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+
+        // No proofs until the slot is full
+        let signature = entries[0].transactions[0].signatures[0];
+        assert_eq!(
+            blockstore
+                .get_signature_merkle_proof(slot, signature)
+                .unwrap(),
+            None
+        );
+
+        let slot = slot + 1;
+        let shreds = entries_to_test_shreds(
+            &entries,
+            slot,
+            slot - 1, // parent_slot
+            true,     // is_full_slot
+            0,        // version
+            true,     // merkle_variant
+        );
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+
+        let mut signatures: Vec<Signature> = entries
+            .iter()
+            .flat_map(|entry| &entry.transactions)
+            .map(|transaction| transaction.signatures[0])
+            .collect();
+        signatures.sort_unstable();
+        let root = recent_signatures::compute_signature_root(&signatures);
+        for signature in &signatures {
+            let proof = blockstore
+                .get_signature_merkle_proof(slot, *signature)
+                .unwrap()
+                .unwrap();
+            assert_eq!(proof.slot, slot);
+            assert_eq!(proof.root, root);
+            assert_eq!(signatures[proof.index], *signature);
+            assert!(recent_signatures::verify_signature_proof(
+                signature.as_ref(),
+                proof.index,
+                &proof.proof,
+                &proof.root
+            ));
+        }
+        assert_eq!(
+            blockstore
+                .get_signature_merkle_proof(slot, Signature::new_unique())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_transaction_shreds() {
+        let slot = 2;
+        let entries = make_slot_entries_with_transactions(100);
+        let shreds = entries_to_test_shreds(
+            &entries,
+            slot,
+            slot - 1, // parent_slot
+            true,     // is_full_slot
+            0,        // version
+            true,     // merkle_variant
+        );
+        let num_shreds = shreds.len();
+        assert!(num_shreds > 1);
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+
+        let mut num_spanning_transactions = 0;
+        for transaction in entries.iter().flat_map(|entry| &entry.transactions) {
+            let transaction_shreds = blockstore
+                .get_transaction_shreds(slot, transaction.signatures[0])
+                .unwrap()
+                .unwrap();
+            assert_eq!(transaction_shreds.slot, slot);
+            assert!(!transaction_shreds.shreds.is_empty());
+            assert!(transaction_shreds.shreds.len() < num_shreds);
+            if transaction_shreds.shreds.len() > 1 {
+                num_spanning_transactions += 1;
+            }
+
+            // The transaction can be recovered from the shred payloads alone
+            let data: Vec<u8> = transaction_shreds
+                .shreds
+                .into_iter()
+                .flat_map(|payload| {
+                    Shred::new_from_serialized_shred(payload)
+                        .unwrap()
+                        .data()
+                        .unwrap()
+                        .to_vec()
+                })
+                .collect();
+            let recovered: VersionedTransaction =
+                deserialize(&data[transaction_shreds.transaction_range]).unwrap();
+            assert_eq!(&recovered, transaction);
+        }
+        assert!(num_spanning_transactions > 0);
+
+        assert_eq!(
+            blockstore
+                .get_transaction_shreds(slot, Signature::new_unique())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            blockstore
+                .get_transaction_shreds(slot + 1, entries[0].transactions[0].signatures[0])
+                .unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_get_complete_transaction() {
         let ledger_path = get_tmp_ledger_path_auto_delete!();
@@ -9150,6 +9604,66 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_get_recent_perf_samples_all_versions() {
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+
+        let num_entries: usize = 10;
+
+        let slot_sample_v1 = |i: u64| PerfSampleV1 {
+            num_transactions: 1633 + i,
+            num_slots: 98 + i / 2,
+            sample_period_secs: (41 + i / 5) as u16,
+        };
+
+        let slot_sample_v2 = |i: u64| PerfSampleV2 {
+            num_transactions: 4710 + i,
+            num_slots: 77 + i / 2,
+            sample_period_secs: (36 + i / 5) as u16,
+            num_non_vote_transactions: 1933 + i,
+        };
+
+        let slot_sample_v3 = |i: u64| PerfSampleV3 {
+            num_transactions: 6028 + i,
+            num_slots: 64 + i / 2,
+            sample_period_secs: (39 + i / 5) as u16,
+            num_non_vote_transactions: 2714 + i,
+            num_introspection_transactions: 158 + i,
+        };
+
+        let mut perf_samples: Vec<(Slot, PerfSample)> = vec![];
+        for i in 0..num_entries {
+            let slot = (i + 1) as u64 * 50;
+
+            let (bytes, sample) = match i % 3 {
+                0 => {
+                    let sample = slot_sample_v1(i as u64);
+                    (serialize(&sample).unwrap(), sample.into())
+                }
+                1 => {
+                    let sample = slot_sample_v2(i as u64);
+                    (serialize(&sample).unwrap(), sample.into())
+                }
+                _ => {
+                    let sample = slot_sample_v3(i as u64);
+                    (serialize(&sample).unwrap(), sample.into())
+                }
+            };
+            blockstore.perf_samples_cf.put_bytes(slot, &bytes).unwrap();
+            perf_samples.push((slot, sample));
+        }
+
+        for i in 0..num_entries {
+            let mut expected_samples = perf_samples[num_entries - 1 - i..].to_vec();
+            expected_samples.sort_by(|a, b| b.0.cmp(&a.0));
+            assert_eq!(
+                blockstore.get_recent_perf_samples(i + 1).unwrap(),
+                expected_samples
+            );
+        }
+    }
+
     #[test]
     fn test_write_perf_samples() {
         let ledger_path = get_tmp_ledger_path_auto_delete!();
@@ -9159,15 +9673,16 @@ pub mod tests {
         let mut perf_samples: Vec<(Slot, PerfSample)> = vec![];
         for x in 1..num_entries + 1 {
             let slot = x as u64 * 50;
-            let sample = PerfSampleV2 {
+            let sample = PerfSampleV3 {
                 num_transactions: 1000 + x as u64,
                 num_slots: 50,
                 sample_period_secs: 20,
                 num_non_vote_transactions: 300 + x as u64,
+                num_introspection_transactions: 7 + x as u64,
             };
 
             blockstore.write_perf_sample(slot, &sample).unwrap();
-            perf_samples.push((slot, PerfSample::V2(sample)));
+            perf_samples.push((slot, PerfSample::V3(sample)));
         }
 
         for x in 0..num_entries {
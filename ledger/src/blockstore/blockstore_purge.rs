@@ -403,6 +403,13 @@ impl Blockstore {
                 if let Some(&signature) = transaction.signatures.get(0) {
                     for primary_index in &primary_indexes {
                         batch.delete::<cf::TransactionStatus>((*primary_index, signature, slot))?;
+                        for secondary_signature in &transaction.signatures[1..] {
+                            batch.delete::<cf::SecondarySignatures>((
+                                *primary_index,
+                                *secondary_signature,
+                                slot,
+                            ))?;
+                        }
                     }
 
                     let meta = self.read_transaction_status((signature, slot))?;
@@ -462,6 +469,14 @@ impl Blockstore {
                         purged_index,
                         purged_index,
                     )
+                    .is_ok()
+                & self
+                    .db
+                    .delete_range_cf::<cf::SecondarySignatures>(
+                        write_batch,
+                        purged_index,
+                        purged_index,
+                    )
                     .is_ok();
         }
         Ok(())
@@ -85,6 +85,8 @@ const CODE_SHRED_CF: &str = "code_shred";
 const TRANSACTION_STATUS_CF: &str = "transaction_status";
 /// Column family for Address Signatures
 const ADDRESS_SIGNATURES_CF: &str = "address_signatures";
+/// Column family for Secondary Signatures
+const SECONDARY_SIGNATURES_CF: &str = "secondary_signatures";
 /// Column family for TransactionMemos
 const TRANSACTION_MEMOS_CF: &str = "transaction_memos";
 /// Column family for the Transaction Status Index.
@@ -281,6 +283,14 @@ pub mod columns {
     /// * value type: [`blockstore_meta::AddressSignatureMeta`]
     pub struct AddressSignatures;
 
+    #[derive(Debug)]
+    /// The secondary signatures column, mapping each signature of a
+    /// transaction other than the first to the first
+    ///
+    /// * index type: `(u64, `[`Signature`]`, `[`Slot`])`
+    /// * value type: [`Signature`]
+    pub struct SecondarySignatures;
+
     #[derive(Debug)]
     /// The transaction memos column
     ///
@@ -453,6 +463,7 @@ impl Rocks {
             cf_descriptor_shred_code,
             new_cf_descriptor::<TransactionStatus>(options, oldest_slot),
             new_cf_descriptor::<AddressSignatures>(options, oldest_slot),
+            new_cf_descriptor::<SecondarySignatures>(options, oldest_slot),
             new_cf_descriptor::<TransactionMemos>(options, oldest_slot),
             new_cf_descriptor::<TransactionStatusIndex>(options, oldest_slot),
             new_cf_descriptor::<Rewards>(options, oldest_slot),
@@ -480,6 +491,7 @@ impl Rocks {
             ShredCode::NAME,
             TransactionStatus::NAME,
             AddressSignatures::NAME,
+            SecondarySignatures::NAME,
             TransactionMemos::NAME,
             TransactionStatusIndex::NAME,
             Rewards::NAME,
@@ -693,6 +705,10 @@ impl TypedColumn for columns::AddressSignatures {
     type Type = blockstore_meta::AddressSignatureMeta;
 }
 
+impl TypedColumn for columns::SecondarySignatures {
+    type Type = Signature;
+}
+
 impl TypedColumn for columns::TransactionMemos {
     type Type = String;
 }
@@ -808,6 +824,29 @@ impl ColumnName for columns::AddressSignatures {
     const NAME: &'static str = ADDRESS_SIGNATURES_CF;
 }
 
+impl Column for columns::SecondarySignatures {
+    type Index = (u64, Signature, Slot);
+
+    fn key((index, signature, slot): (u64, Signature, Slot)) -> Vec<u8> {
+        <columns::TransactionStatus as Column>::key((index, signature, slot))
+    }
+
+    fn index(key: &[u8]) -> (u64, Signature, Slot) {
+        <columns::TransactionStatus as Column>::index(key)
+    }
+
+    fn slot(index: Self::Index) -> Slot {
+        index.2
+    }
+
+    fn as_index(index: u64) -> Self::Index {
+        (index, Signature::default(), 0)
+    }
+}
+impl ColumnName for columns::SecondarySignatures {
+    const NAME: &'static str = SECONDARY_SIGNATURES_CF;
+}
+
 impl Column for columns::TransactionMemos {
     type Index = Signature;
 
@@ -1882,7 +1921,9 @@ fn should_enable_cf_compaction(cf_name: &str) -> bool {
     // completed on a given range or file.
     matches!(
         cf_name,
-        columns::TransactionStatus::NAME | columns::AddressSignatures::NAME
+        columns::TransactionStatus::NAME
+            | columns::AddressSignatures::NAME
+            | columns::SecondarySignatures::NAME
     )
 }
 
@@ -1970,6 +2011,7 @@ pub mod tests {
         let columns_to_compact = [
             columns::TransactionStatus::NAME,
             columns::AddressSignatures::NAME,
+            columns::SecondarySignatures::NAME,
         ];
         columns_to_compact.iter().for_each(|cf_name| {
             assert!(should_enable_cf_compaction(cf_name));
@@ -421,6 +421,7 @@ pub struct AddressSignatureMeta {
 pub enum PerfSample {
     V1(PerfSampleV1),
     V2(PerfSampleV2),
+    V3(PerfSampleV3),
 }
 
 impl From<PerfSampleV1> for PerfSample {
@@ -435,6 +436,12 @@ impl From<PerfSampleV2> for PerfSample {
     }
 }
 
+impl From<PerfSampleV3> for PerfSample {
+    fn from(value: PerfSampleV3) -> PerfSample {
+        PerfSample::V3(value)
+    }
+}
+
 /// Version of [`PerfSample`] used before 1.15.x.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct PerfSampleV1 {
@@ -455,6 +462,20 @@ pub struct PerfSampleV2 {
     pub num_non_vote_transactions: u64,
 }
 
+/// Version of the [`PerfSample`] that counts transactions referencing
+/// per-transaction sysvars.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PerfSampleV3 {
+    // `PerfSampleV2` part
+    pub num_transactions: u64,
+    pub num_slots: u64,
+    pub sample_period_secs: u16,
+    pub num_non_vote_transactions: u64,
+
+    // New fields.
+    pub num_introspection_transactions: u64,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ProgramCost {
     pub cost: u64,
@@ -668,4 +689,30 @@ mod test {
 
         assert_eq!(actual, expected);
     }
+
+    // `PerfSampleV3` should contain `PerfSampleV2` as a prefix, in order for the column to be
+    // backward and forward compatible.
+    #[test]
+    fn perf_sample_v2_is_prefix_of_perf_sample_v3() {
+        let v3 = PerfSampleV3 {
+            num_transactions: 2930574118,
+            num_slots: 1830224962,
+            sample_period_secs: 27403,
+            num_non_vote_transactions: 3368542091,
+            num_introspection_transactions: 1592117436,
+        };
+
+        let v3_bytes = bincode::serialize(&v3).expect("`PerfSampleV3` can be serialized");
+
+        let actual: PerfSampleV2 = bincode::deserialize(&v3_bytes)
+            .expect("Bytes encoded as `PerfSampleV3` can be decoded as `PerfSampleV2`");
+        let expected = PerfSampleV2 {
+            num_transactions: v3.num_transactions,
+            num_slots: v3.num_slots,
+            sample_period_secs: v3.sample_period_secs,
+            num_non_vote_transactions: v3.num_non_vote_transactions,
+        };
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -474,6 +474,12 @@ fn process_entries(
     // accumulator for entries that can be processed in parallel
     let mut batches = vec![];
     let mut tick_hashes = vec![];
+    // Transactions read the hash of the most recent tick through the tick
+    // hash sysvar, so ticks can't be deferred past the transactions that
+    // follow them
+    let register_ticks_in_order = bank
+        .feature_set
+        .is_active(&feature_set::tick_hash_sysvar::id());
 
     for ReplayEntry {
         entry,
@@ -484,9 +490,12 @@ fn process_entries(
             EntryType::Tick(hash) => {
                 // If it's a tick, save it for later
                 tick_hashes.push(hash);
-                if bank.is_block_boundary(bank.tick_height() + tick_hashes.len() as u64) {
+                if register_ticks_in_order
+                    || bank.is_block_boundary(bank.tick_height() + tick_hashes.len() as u64)
+                {
                     // If it's a tick that will cause a new blockhash to be created,
-                    // execute the group and register the tick
+                    // or that later transactions observe, execute the group and
+                    // register the tick
                     execute_batches(
                         bank,
                         &batches,
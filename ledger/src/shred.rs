@@ -434,7 +434,9 @@ impl Shred {
         self.common_header().index
     }
 
-    pub(crate) fn data(&self) -> Result<&[u8], Error> {
+    /// Returns the data section of a data shred, i.e. its share of the
+    /// serialized entries.
+    pub fn data(&self) -> Result<&[u8], Error> {
         match self {
             Self::ShredCode(_) => Err(Error::InvalidShredType),
             Self::ShredData(shred) => shred.data(),
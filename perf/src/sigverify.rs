@@ -19,6 +19,7 @@ use {
         pubkey::Pubkey,
         short_vec::decode_shortu16_len,
         signature::Signature,
+        transaction::VersionedTransaction,
     },
     std::{convert::TryFrom, mem::size_of},
 };
@@ -675,6 +676,44 @@ pub fn ed25519_verify(
     inc_new_counter_debug!("ed25519_verify_gpu", valid_packet_count);
 }
 
+/// Verify the signatures of `transactions` with the sigverify pipeline used
+/// by the TPU.
+///
+/// Verification is offloaded to the GPU when perf-libs are available and the
+/// batch is large enough to benefit, and runs on the CPU otherwise. Returns
+/// whether the signatures of each transaction verified, in order.
+/// Transactions that do not fit in a packet fail verification.
+pub fn verify_batch_gpu(transactions: &[VersionedTransaction]) -> Vec<bool> {
+    let mut batches: Vec<PacketBatch> = transactions
+        .chunks(VERIFY_PACKET_CHUNK_SIZE)
+        .map(|chunk| {
+            let mut batch = PacketBatch::with_capacity(chunk.len());
+            batch.resize(chunk.len(), Packet::default());
+            for (transaction, packet) in chunk.iter().zip(batch.iter_mut()) {
+                if Packet::populate_packet(packet, None, transaction).is_err() {
+                    packet.meta_mut().set_discard(true);
+                }
+            }
+            batch
+        })
+        .collect();
+    let valid_packet_count = count_valid_packets(&batches, |_| ());
+    let recycler = Recycler::default();
+    let recycler_out = Recycler::default();
+    ed25519_verify(
+        &mut batches,
+        &recycler,
+        &recycler_out,
+        false, // reject_non_vote
+        valid_packet_count,
+    );
+    batches
+        .iter()
+        .flat_map(|batch| batch.iter())
+        .map(|packet| !packet.meta().discard())
+        .collect()
+}
+
 #[cfg(test)]
 #[allow(clippy::arithmetic_side_effects)]
 mod tests {
@@ -689,7 +728,7 @@ mod tests {
         curve25519_dalek::{edwards::CompressedEdwardsY, scalar::Scalar},
         rand::{thread_rng, Rng},
         solana_sdk::{
-            instruction::CompiledInstruction,
+            instruction::{AccountMeta, CompiledInstruction, Instruction},
             message::{Message, MessageHeader},
             signature::{Keypair, Signature, Signer},
             transaction::Transaction,
@@ -1126,6 +1165,36 @@ mod tests {
         sigverify::ed25519_verify(batches, &recycler, &recycler_out, false, packet_count);
     }
 
+    #[test]
+    fn test_verify_batch_gpu() {
+        let mut transactions: Vec<VersionedTransaction> = (0..200)
+            .map(|i| {
+                let transaction = if i % 3 == 0 {
+                    test_multisig_tx()
+                } else {
+                    test_tx()
+                };
+                VersionedTransaction::from(transaction)
+            })
+            .collect();
+        transactions[5].signatures[0] = Signature::default();
+        transactions[150]
+            .message
+            .set_recent_blockhash(Hash::new_unique());
+        // Too large to fit in a packet
+        transactions[199].signatures.resize(
+            PACKET_DATA_SIZE / size_of::<Signature>() + 1,
+            Signature::default(),
+        );
+
+        let verified = sigverify::verify_batch_gpu(&transactions);
+        assert_eq!(verified.len(), transactions.len());
+        for (i, verified) in verified.into_iter().enumerate() {
+            assert_eq!(verified, ![5, 150, 199].contains(&i), "transaction {i}");
+        }
+        assert!(sigverify::verify_batch_gpu(&[]).is_empty());
+    }
+
     #[test]
     fn test_verify_tampered_sig_len() {
         let mut tx = test_tx();
@@ -1213,6 +1282,46 @@ mod tests {
             }));
     }
 
+    #[test]
+    fn test_verify_max_signers_per_packet() {
+        solana_logger::setup();
+
+        // The signer count of a transaction is bounded by the packet size long
+        // before the u8 header count or the u16 signatures sysvar count
+        let signed_tx = |num_signers: usize| {
+            let keypairs: Vec<_> = repeat_with(Keypair::new).take(num_signers).collect();
+            let instruction = Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                keypairs
+                    .iter()
+                    .map(|keypair| AccountMeta::new_readonly(keypair.pubkey(), true))
+                    .collect(),
+            );
+            let message = Message::new(&[instruction], Some(&keypairs[0].pubkey()));
+            Transaction::new(
+                &keypairs.iter().collect::<Vec<_>>(),
+                message,
+                Hash::default(),
+            )
+        };
+        let max_signers = (1..)
+            .find(|&n| serialize(&signed_tx(n + 1)).unwrap().len() > PACKET_DATA_SIZE)
+            .unwrap();
+        assert!(max_signers < usize::from(u8::MAX));
+
+        let tx = signed_tx(max_signers);
+        let mut packet = Packet::from_data(None, tx).unwrap();
+        let packet_offsets = sigverify::get_packet_offsets(&mut packet, 0, false);
+        assert_eq!(packet_offsets.sig_len as usize, max_signers);
+        assert!(verify_packet(&mut packet, false));
+
+        // Tampering with the last signature of the packet is still caught
+        let last_sig_offset = SIG_OFFSET + (max_signers - 1) * size_of::<Signature>();
+        packet.buffer_mut()[last_sig_offset] ^= 0xff;
+        assert!(!verify_packet(&mut packet, false));
+    }
+
     #[test]
     fn test_verify_fuzz() {
         solana_logger::setup();
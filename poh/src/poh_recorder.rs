@@ -56,6 +56,9 @@ pub enum PohRecorderError {
     #[error("min height not reached")]
     MinHeightNotReached,
 
+    #[error("tick registered since the transactions were executed")]
+    TickRegisteredSinceExecution,
+
     #[error("send WorkingBankEntry error")]
     SendError(#[from] SendError<WorkingBankEntry>),
 }
@@ -95,6 +98,9 @@ pub struct Record {
     pub mixin: Hash,
     pub transactions: Vec<VersionedTransaction>,
     pub slot: Slot,
+    /// Tick height of the bank when `transactions` were executed, if they
+    /// read the tick hash sysvar
+    pub executed_tick_height: Option<u64>,
     pub sender: RecordResultSender,
 }
 impl Record {
@@ -102,12 +108,14 @@ impl Record {
         mixin: Hash,
         transactions: Vec<VersionedTransaction>,
         slot: Slot,
+        executed_tick_height: Option<u64>,
         sender: RecordResultSender,
     ) -> Self {
         Self {
             mixin,
             transactions,
             slot,
+            executed_tick_height,
             sender,
         }
     }
@@ -157,10 +165,14 @@ impl TransactionRecorder {
 
     /// Hashes `transactions` and sends to PoH service for recording. Waits for response up to 1s.
     /// Panics on unexpected (non-`MaxHeightReached`) errors.
+    ///
+    /// Transactions that read the tick hash sysvar pass the tick height of the bank they were
+    /// executed at as `executed_tick_height`, and fail to record if a tick was registered since.
     pub fn record_transactions(
         &self,
         bank_slot: Slot,
         transactions: Vec<VersionedTransaction>,
+        executed_tick_height: Option<u64>,
     ) -> RecordTransactionsSummary {
         let mut record_transactions_timings = RecordTransactionsTimings::default();
         let mut starting_transaction_index = None;
@@ -169,7 +181,8 @@ impl TransactionRecorder {
             let (hash, hash_us) = measure_us!(hash_transactions(&transactions));
             record_transactions_timings.hash_us = hash_us;
 
-            let (res, poh_record_us) = measure_us!(self.record(bank_slot, hash, transactions));
+            let (res, poh_record_us) =
+                measure_us!(self.record(bank_slot, hash, transactions, executed_tick_height));
             record_transactions_timings.poh_record_us = poh_record_us;
 
             match res {
@@ -190,6 +203,13 @@ impl TransactionRecorder {
                         starting_transaction_index: None,
                     };
                 }
+                Err(PohRecorderError::TickRegisteredSinceExecution) => {
+                    return RecordTransactionsSummary {
+                        record_transactions_timings,
+                        result: Err(PohRecorderError::TickRegisteredSinceExecution),
+                        starting_transaction_index: None,
+                    };
+                }
                 Err(e) => panic!("Poh recorder returned unexpected error: {e:?}"),
             }
         }
@@ -207,12 +227,17 @@ impl TransactionRecorder {
         bank_slot: Slot,
         mixin: Hash,
         transactions: Vec<VersionedTransaction>,
+        executed_tick_height: Option<u64>,
     ) -> Result<Option<usize>> {
         // create a new channel so that there is only 1 sender and when it goes out of scope, the receiver fails
         let (result_sender, result_receiver) = unbounded();
-        let res =
-            self.record_sender
-                .send(Record::new(mixin, transactions, bank_slot, result_sender));
+        let res = self.record_sender.send(Record::new(
+            mixin,
+            transactions,
+            bank_slot,
+            executed_tick_height,
+            result_sender,
+        ));
         if res.is_err() {
             // If the channel is dropped, then the validator is shutting down so return that we are hitting
             //  the max tick height to stop transaction processing and flush any transactions in the pipeline.
@@ -855,11 +880,16 @@ impl PohRecorder {
     }
 
     // Returns the index of `transactions.first()` in the slot, if being tracked by WorkingBank
+    //
+    // Transactions that read the tick hash sysvar observed the hash of the last tick registered
+    // when they were executed at `executed_tick_height`. Replay registers every tick before
+    // executing the entries that follow it, so they can't be recorded after another tick.
     pub fn record(
         &mut self,
         bank_slot: Slot,
         mixin: Hash,
         transactions: Vec<VersionedTransaction>,
+        executed_tick_height: Option<u64>,
     ) -> Result<Option<usize>> {
         // Entries without transactions are used to track real-time passing in the ledger and
         // cannot be generated by `record()`
@@ -881,6 +911,11 @@ impl PohRecorder {
             if bank_slot != working_bank.bank.slot() {
                 return Err(PohRecorderError::MaxHeightReached);
             }
+            if let Some(executed_tick_height) = executed_tick_height {
+                if executed_tick_height != working_bank.bank.tick_height() {
+                    return Err(PohRecorderError::TickRegisteredSinceExecution);
+                }
+            }
 
             let (mut poh_lock, poh_lock_time) = measure!(self.poh.lock().unwrap(), "poh_lock");
             self.record_lock_contention_us += poh_lock_time.as_us();
@@ -1374,7 +1409,7 @@ mod tests {
             // We haven't yet reached the minimum tick height for the working bank,
             // so record should fail
             assert_matches!(
-                poh_recorder.record(bank1.slot(), h1, vec![tx.into()]),
+                poh_recorder.record(bank1.slot(), h1, vec![tx.into()], None),
                 Err(PohRecorderError::MinHeightNotReached)
             );
             assert!(entry_receiver.try_recv().is_err());
@@ -1417,7 +1452,7 @@ mod tests {
             // However we hand over a bad slot so record fails
             let bad_slot = bank.slot() + 1;
             assert_matches!(
-                poh_recorder.record(bad_slot, h1, vec![tx.into()]),
+                poh_recorder.record(bad_slot, h1, vec![tx.into()], None),
                 Err(PohRecorderError::MaxHeightReached)
             );
         }
@@ -1464,7 +1499,7 @@ mod tests {
             let tx = test_tx();
             let h1 = hash(b"hello world!");
             assert!(poh_recorder
-                .record(bank1.slot(), h1, vec![tx.into()])
+                .record(bank1.slot(), h1, vec![tx.into()], None)
                 .is_ok());
             assert_eq!(poh_recorder.tick_cache.len(), 0);
 
@@ -1510,7 +1545,7 @@ mod tests {
             let tx = test_tx();
             let h1 = hash(b"hello world!");
             assert!(poh_recorder
-                .record(bank.slot(), h1, vec![tx.into()])
+                .record(bank.slot(), h1, vec![tx.into()], None)
                 .is_err());
             for _ in 0..num_ticks_to_max {
                 let (_bank, (entry, _tick_height)) = entry_receiver.recv().unwrap();
@@ -1558,7 +1593,7 @@ mod tests {
             let tx1 = test_tx();
             let h1 = hash(b"hello world!");
             let record_result = poh_recorder
-                .record(bank.slot(), h1, vec![tx0.into(), tx1.into()])
+                .record(bank.slot(), h1, vec![tx0.into(), tx1.into()], None)
                 .unwrap()
                 .unwrap();
             assert_eq!(record_result, 0);
@@ -1575,7 +1610,7 @@ mod tests {
             let tx = test_tx();
             let h2 = hash(b"foobar");
             let record_result = poh_recorder
-                .record(bank.slot(), h2, vec![tx.into()])
+                .record(bank.slot(), h2, vec![tx.into()], None)
                 .unwrap()
                 .unwrap();
             assert_eq!(record_result, 2);
@@ -1592,6 +1627,74 @@ mod tests {
         Blockstore::destroy(&ledger_path).unwrap();
     }
 
+    #[test]
+    fn test_poh_recorder_record_tick_since_execution_fails() {
+        let ledger_path = get_tmp_ledger_path!();
+        {
+            let blockstore = Blockstore::open(&ledger_path)
+                .expect("Expected to be able to open database ledger");
+            let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(2);
+            let bank = Arc::new(Bank::new_for_tests(&genesis_config));
+            let prev_hash = bank.last_blockhash();
+            let (mut poh_recorder, entry_receiver, _record_receiver) = PohRecorder::new(
+                0,
+                prev_hash,
+                bank.clone(),
+                Some((4, 4)),
+                bank.ticks_per_slot(),
+                &Pubkey::default(),
+                Arc::new(blockstore),
+                &Arc::new(LeaderScheduleCache::new_from_bank(&bank)),
+                &PohConfig::default(),
+                Arc::new(AtomicBool::default()),
+            );
+
+            poh_recorder.set_bank(bank.clone(), false);
+            poh_recorder.tick();
+            poh_recorder.tick();
+
+            // The transactions are executed, then a tick is registered before
+            // they are recorded
+            let executed_tick_height = bank.tick_height();
+            poh_recorder.tick();
+            let tx = test_tx();
+            let h1 = hash(b"hello world!");
+            assert_matches!(
+                poh_recorder.record(
+                    bank.slot(),
+                    h1,
+                    vec![tx.clone().into()],
+                    Some(executed_tick_height)
+                ),
+                Err(PohRecorderError::TickRegisteredSinceExecution)
+            );
+            for _ in 0..3 {
+                let (_bank, (entry, _tick_height)) = entry_receiver.recv().unwrap();
+                assert!(entry.is_tick());
+            }
+            assert!(entry_receiver.try_recv().is_err());
+
+            // Executing them again after the tick, or not reading the tick
+            // hash, records them
+            assert!(poh_recorder
+                .record(
+                    bank.slot(),
+                    h1,
+                    vec![tx.clone().into()],
+                    Some(bank.tick_height())
+                )
+                .is_ok());
+            assert!(poh_recorder
+                .record(bank.slot(), h1, vec![tx.into()], None)
+                .is_ok());
+            for _ in 0..2 {
+                let (_bank, (entry, _tick_height)) = entry_receiver.recv().unwrap();
+                assert!(!entry.is_tick());
+            }
+        }
+        Blockstore::destroy(&ledger_path).unwrap();
+    }
+
     #[test]
     fn test_poh_cache_on_disconnect() {
         let ledger_path = get_tmp_ledger_path!();
@@ -1842,7 +1945,7 @@ mod tests {
             let tx = test_tx();
             let h1 = hash(b"hello world!");
             assert!(poh_recorder
-                .record(bank.slot(), h1, vec![tx.into()])
+                .record(bank.slot(), h1, vec![tx.into()], None)
                 .is_err());
             assert!(poh_recorder.working_bank.is_none());
 
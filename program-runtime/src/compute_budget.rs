@@ -5,8 +5,8 @@ use {
         compute_budget::{self, ComputeBudgetInstruction},
         entrypoint::HEAP_LENGTH as MIN_HEAP_FRAME_BYTES,
         feature_set::{
-            add_set_tx_loaded_accounts_data_size_instruction, remove_deprecated_request_unit_ix,
-            FeatureSet,
+            add_set_tx_loaded_accounts_data_size_instruction, enable_transaction_guard_instruction,
+            remove_deprecated_request_unit_ix, FeatureSet,
         },
         fee::FeeBudgetLimits,
         instruction::{CompiledInstruction, InstructionError},
@@ -108,6 +108,10 @@ pub struct ComputeBudget {
     pub heap_cost: u64,
     /// Memory operation syscall base cost
     pub mem_op_base_cost: u64,
+    /// Number of compute units consumed by `sol_memcmp_ct` to compare up to 64
+    /// bytes, the length of a signature. Longer comparisons additionally cost
+    /// one unit per `cpi_bytes_per_unit` bytes beyond that length.
+    pub mem_cmp_ct_base_cost: u64,
     /// Number of compute units consumed to call alt_bn128_addition
     pub alt_bn128_addition_cost: u64,
     /// Number of compute units consumed to call alt_bn128_multiplication.
@@ -139,6 +143,21 @@ pub struct ComputeBudget {
     pub alt_bn128_g2_compress: u64,
     /// Number of compute units consumed to call alt_bn128_g2_decompress.
     pub alt_bn128_g2_decompress: u64,
+    /// Number of compute units consumed per input byte by the base58 encode
+    /// and decode syscalls, in addition to `syscall_base_cost`.
+    pub base58_byte_cost: u64,
+    /// Number of compute units consumed by `sol_ed25519_verify` to verify a
+    /// signature, in addition to hashing the message at the SHA-256 costs.
+    /// Signatures already verified by an ed25519 program instruction of the
+    /// transaction aren't charged this cost.
+    pub ed25519_verify_cost: u64,
+    /// Number of compute units consumed by a `sol_get_transaction_signature`
+    /// call, whether or not the requested signature exists.
+    pub transaction_signature_base_cost: u64,
+    /// Number of compute units consumed by `sol_get_transaction_signature` to
+    /// copy a signature into program memory, in addition to
+    /// `transaction_signature_base_cost`.
+    pub transaction_signature_copy_cost: u64,
 }
 
 impl Default for ComputeBudget {
@@ -182,6 +201,7 @@ impl ComputeBudget {
             heap_size: u32::try_from(solana_sdk::entrypoint::HEAP_LENGTH).unwrap(),
             heap_cost: 8,
             mem_op_base_cost: 10,
+            mem_cmp_ct_base_cost: 4,
             alt_bn128_addition_cost: 334,
             alt_bn128_multiplication_cost: 3_840,
             alt_bn128_pairing_one_pair_cost_first: 36_364,
@@ -195,6 +215,10 @@ impl ComputeBudget {
             alt_bn128_g1_decompress: 398,
             alt_bn128_g2_compress: 86,
             alt_bn128_g2_decompress: 13610,
+            base58_byte_cost: 4,
+            ed25519_verify_cost: 2_500,
+            transaction_signature_base_cost: 100,
+            transaction_signature_copy_cost: 10, // mem_op_base_cost of a 64 byte copy
         }
     }
 
@@ -203,6 +227,7 @@ impl ComputeBudget {
         instructions: impl Iterator<Item = (&'a Pubkey, &'a CompiledInstruction)>,
         support_request_units_deprecated: bool,
         support_set_loaded_accounts_data_size_limit_ix: bool,
+        support_transaction_guard_ix: bool,
     ) -> Result<PrioritizationFeeDetails, TransactionError> {
         let mut num_non_compute_budget_instructions: u32 = 0;
         let mut updated_compute_unit_limit = None;
@@ -260,6 +285,9 @@ impl ComputeBudget {
                         }
                         updated_loaded_accounts_data_size_limit = Some(bytes as usize);
                     }
+                    // Guards are checked against the message by the runtime
+                    Ok(ComputeBudgetInstruction::TransactionGuard(_))
+                        if support_transaction_guard_ix => {}
                     _ => return Err(invalid_instruction_data_error),
                 }
             } else {
@@ -310,6 +338,7 @@ impl ComputeBudget {
                 instructions,
                 !feature_set.is_active(&remove_deprecated_request_unit_ix::id()),
                 feature_set.is_active(&add_set_tx_loaded_accounts_data_size_instruction::id()),
+                feature_set.is_active(&enable_transaction_guard_instruction::id()),
             )
             .unwrap_or_default();
 
@@ -356,6 +385,7 @@ mod tests {
     use {
         super::*,
         solana_sdk::{
+            compute_budget::TransactionGuard,
             hash::Hash,
             instruction::Instruction,
             message::Message,
@@ -368,7 +398,7 @@ mod tests {
     };
 
     macro_rules! test {
-        ( $instructions: expr, $expected_result: expr, $expected_budget: expr, $support_set_loaded_accounts_data_size_limit_ix: expr ) => {
+        ( $instructions: expr, $expected_result: expr, $expected_budget: expr, $support_set_loaded_accounts_data_size_limit_ix: expr, $support_transaction_guard_ix: expr ) => {
             let payer_keypair = Keypair::new();
             let tx = SanitizedTransaction::from_transaction_for_tests(Transaction::new(
                 &[&payer_keypair],
@@ -380,10 +410,20 @@ mod tests {
                 tx.message().program_instructions_iter(),
                 false, /*not support request_units_deprecated*/
                 $support_set_loaded_accounts_data_size_limit_ix,
+                $support_transaction_guard_ix,
             );
             assert_eq!($expected_result, result);
             assert_eq!(compute_budget, $expected_budget);
         };
+        ( $instructions: expr, $expected_result: expr, $expected_budget: expr, $support_set_loaded_accounts_data_size_limit_ix: expr ) => {
+            test!(
+                $instructions,
+                $expected_result,
+                $expected_budget,
+                $support_set_loaded_accounts_data_size_limit_ix,
+                false
+            );
+        };
         ( $instructions: expr, $expected_result: expr, $expected_budget: expr) => {
             test!($instructions, $expected_result, $expected_budget, false);
         };
@@ -646,6 +686,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_transaction_guard_instruction() {
+        // Guards don't change the budget, and are only accepted when supported
+        let instructions = [
+            ComputeBudgetInstruction::transaction_guard(TransactionGuard {
+                max_signers: Some(1),
+                ..TransactionGuard::default()
+            }),
+            Instruction::new_with_bincode(Pubkey::new_unique(), &0_u8, vec![]),
+        ];
+        test!(
+            &instructions,
+            Ok(PrioritizationFeeDetails::default()),
+            ComputeBudget {
+                compute_unit_limit: DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT as u64,
+                ..ComputeBudget::default()
+            },
+            false,
+            true
+        );
+        test!(
+            &instructions,
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::InvalidInstructionData,
+            )),
+            ComputeBudget::default(),
+            false,
+            false
+        );
+    }
+
     #[test]
     fn test_process_loaded_accounts_data_size_limit_instruction() {
         // Assert for empty instructions, change value of support_set_loaded_accounts_data_size_limit_ix
@@ -821,6 +893,7 @@ mod tests {
             transaction.message().program_instructions_iter(),
             false, //not support request_units_deprecated
             true,  //support_set_loaded_accounts_data_size_limit_ix,
+            true,  //support_transaction_guard_ix,
         );
 
         // assert process_instructions will be successful with default,
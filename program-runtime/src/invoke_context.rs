@@ -22,15 +22,18 @@ use {
         bpf_loader_deprecated,
         feature_set::{
             check_slice_translation_size, enable_early_verification_of_account_modifications,
-            native_programs_consume_cu, FeatureSet,
+            native_programs_consume_cu, reject_cpi_to_sysvars, FeatureSet,
         },
         hash::Hash,
         instruction::{AccountMeta, InstructionError},
         native_loader,
+        nonce::TransactionNonceInfo,
         pubkey::Pubkey,
         rent::Rent,
         saturating_add_assign,
+        signature::Signature,
         stable_layout::stable_instruction::StableInstruction,
+        sysvar,
         transaction_context::{
             IndexOfAccount, InstructionAccount, TransactionAccount, TransactionContext,
         },
@@ -38,6 +41,7 @@ use {
     std::{
         alloc::Layout,
         cell::RefCell,
+        collections::{HashMap, HashSet},
         fmt::{self, Debug},
         rc::Rc,
         sync::{atomic::Ordering, Arc},
@@ -178,6 +182,11 @@ pub struct InvokeContext<'a> {
     pub lamports_per_signature: u64,
     pub syscall_context: Vec<Option<SyscallContext>>,
     traces: Vec<Vec<[u64; 12]>>,
+    transaction_signatures: &'a [Signature],
+    transaction_nonce_info: Option<TransactionNonceInfo>,
+    transaction_message_data: Vec<u8>,
+    tx_scratch: HashMap<Pubkey, Vec<u8>>,
+    verified_signatures: HashSet<Hash>,
 }
 
 impl<'a> InvokeContext<'a> {
@@ -215,6 +224,11 @@ impl<'a> InvokeContext<'a> {
             lamports_per_signature,
             syscall_context: Vec::new(),
             traces: Vec::new(),
+            transaction_signatures: &[],
+            transaction_nonce_info: None,
+            transaction_message_data: Vec::new(),
+            tx_scratch: HashMap::new(),
+            verified_signatures: HashSet::new(),
         }
     }
 
@@ -631,6 +645,12 @@ impl<'a> InvokeContext<'a> {
 
         // Find and validate executables / program accounts
         let callee_program_id = instruction.program_id;
+        if self.feature_set.is_active(&reject_cpi_to_sysvars::id())
+            && sysvar::is_sysvar_id(&callee_program_id)
+        {
+            ic_msg!(self, "Sysvar {} can't be invoked", callee_program_id);
+            return Err(InstructionError::UnsupportedSysvar);
+        }
         let program_account_index = instruction_context
             .find_index_of_instruction_account(self.transaction_context, &callee_program_id)
             .ok_or_else(|| {
@@ -853,6 +873,77 @@ impl<'a> InvokeContext<'a> {
         self.sysvar_cache
     }
 
+    /// Set the signatures of the transaction being processed
+    pub fn set_transaction_signatures(&mut self, signatures: &'a [Signature]) {
+        self.transaction_signatures = signatures;
+    }
+
+    /// Get the signatures of the transaction being processed
+    ///
+    /// Signatures are ordered like the signer account keys of the message.
+    /// Gives builtin programs direct access to the data of the signatures
+    /// sysvar without requiring the sysvar account to be passed in.
+    pub fn get_transaction_signatures(&self) -> &[Signature] {
+        self.transaction_signatures
+    }
+
+    /// Set the durable nonce consumed by the transaction being processed
+    pub fn set_transaction_nonce_info(&mut self, nonce_info: Option<TransactionNonceInfo>) {
+        self.transaction_nonce_info = nonce_info;
+    }
+
+    /// Get the durable nonce consumed by the transaction being processed
+    ///
+    /// `None` if the transaction used a recent blockhash.
+    pub fn get_transaction_nonce_info(&self) -> Option<&TransactionNonceInfo> {
+        self.transaction_nonce_info.as_ref()
+    }
+
+    /// Set the serialized message of the transaction being processed
+    pub fn set_transaction_message_data(&mut self, message_data: Vec<u8>) {
+        self.transaction_message_data = message_data;
+    }
+
+    /// Get the serialized message of the transaction being processed
+    ///
+    /// These are the bytes signed by the signers of the transaction. Empty
+    /// unless the `enable_get_serialized_message_syscall` feature is active.
+    pub fn get_transaction_message_data(&self) -> &[u8] {
+        &self.transaction_message_data
+    }
+
+    /// Replace the transaction scratch buffer of `program_id`
+    ///
+    /// Each program has its own buffer, which lives as long as the
+    /// transaction and can be read by any later instruction.
+    pub fn set_tx_scratch(&mut self, program_id: Pubkey, data: Vec<u8>) {
+        self.tx_scratch.insert(program_id, data);
+    }
+
+    /// Get the transaction scratch buffer of `program_id`, empty if the
+    /// program didn't write one
+    pub fn get_tx_scratch(&self, program_id: &Pubkey) -> &[u8] {
+        self.tx_scratch
+            .get(program_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Remember that a signature was verified by a precompile earlier in the
+    /// transaction
+    ///
+    /// `key` is derived by `precompiles::verified_signature_key` from the
+    /// precompile, signature, public key and message hash.
+    pub fn record_verified_signature(&mut self, key: Hash) {
+        self.verified_signatures.insert(key);
+    }
+
+    /// Whether the signature identified by `key` was verified earlier in the
+    /// transaction, so that verifying it again can be skipped
+    pub fn is_signature_verified(&self, key: &Hash) -> bool {
+        self.verified_signatures.contains(key)
+    }
+
     // Should alignment be enforced during user pointer translation
     pub fn get_check_aligned(&self) -> bool {
         self.transaction_context
@@ -1358,6 +1449,119 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prepare_instruction_sysvar_program_id() {
+        let sysvar_account = AccountSharedData::new(1, 0, &sysvar::id());
+        let loader_account = AccountSharedData::new(0, 0, &native_loader::id());
+        let transaction_accounts = vec![
+            (sysvar::signatures::id(), sysvar_account),
+            (solana_sdk::pubkey::new_rand(), loader_account),
+        ];
+        let instruction_accounts = vec![InstructionAccount {
+            index_in_transaction: 0,
+            index_in_caller: 0,
+            index_in_callee: 0,
+            is_signer: false,
+            is_writable: false,
+        }];
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        invoke_context
+            .transaction_context
+            .get_next_instruction_context()
+            .unwrap()
+            .configure(&[1], &instruction_accounts, &[]);
+        invoke_context.push().unwrap();
+        let inner_instruction = StableInstruction::from(Instruction::new_with_bytes(
+            sysvar::signatures::id(),
+            &[],
+            vec![],
+        ));
+
+        assert_eq!(
+            invoke_context.prepare_instruction(&inner_instruction, &[]),
+            Err(InstructionError::UnsupportedSysvar)
+        );
+
+        let mut feature_set = FeatureSet::all_enabled();
+        feature_set.deactivate(&reject_cpi_to_sysvars::id());
+        invoke_context.feature_set = Arc::new(feature_set);
+        assert_eq!(
+            invoke_context.prepare_instruction(&inner_instruction, &[]),
+            Err(InstructionError::AccountNotExecutable)
+        );
+    }
+
+    #[test]
+    fn test_scratch_sysvar_data_can_be_changed() {
+        let scratch_account = AccountSharedData::new(0, 4, &sysvar::id());
+        let other_sysvar_account = AccountSharedData::new(0, 4, &sysvar::id());
+        let mut program_account = AccountSharedData::new(1, 0, &native_loader::id());
+        program_account.set_executable(true);
+        let transaction_accounts = vec![
+            (sysvar::scratch::id(), scratch_account),
+            (sysvar::header::id(), other_sysvar_account),
+            (solana_sdk::pubkey::new_rand(), program_account),
+        ];
+        let instruction_accounts = (0..2)
+            .map(|index_in_transaction| InstructionAccount {
+                index_in_transaction,
+                index_in_caller: index_in_transaction,
+                index_in_callee: index_in_transaction,
+                is_signer: false,
+                is_writable: false,
+            })
+            .collect::<Vec<_>>();
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        invoke_context
+            .transaction_context
+            .get_next_instruction_context()
+            .unwrap()
+            .configure(&[2], &instruction_accounts, &[]);
+        invoke_context.push().unwrap();
+        let instruction_context = invoke_context
+            .transaction_context
+            .get_current_instruction_context()
+            .unwrap();
+
+        // Readonly like any other account until `scratch_sysvar` is active
+        let mut scratch = instruction_context
+            .try_borrow_instruction_account(invoke_context.transaction_context, 0)
+            .unwrap();
+        assert_eq!(
+            scratch.set_data_from_slice(&[1, 2, 3, 4]),
+            Err(InstructionError::ReadonlyDataModified)
+        );
+        drop(scratch);
+
+        invoke_context.transaction_context.enable_scratch_sysvar();
+        let instruction_context = invoke_context
+            .transaction_context
+            .get_current_instruction_context()
+            .unwrap();
+        let mut scratch = instruction_context
+            .try_borrow_instruction_account(invoke_context.transaction_context, 0)
+            .unwrap();
+        assert_eq!(scratch.set_data_from_slice(&[1, 2, 3, 4]), Ok(()));
+        assert_eq!(scratch.get_data(), &[1, 2, 3, 4]);
+        assert_eq!(
+            scratch.set_data_from_slice(&[1]),
+            Err(InstructionError::AccountDataSizeChanged)
+        );
+        assert_eq!(
+            scratch.checked_add_lamports(1),
+            Err(InstructionError::ReadonlyLamportChange)
+        );
+        drop(scratch);
+
+        let mut other_sysvar = instruction_context
+            .try_borrow_instruction_account(invoke_context.transaction_context, 1)
+            .unwrap();
+        assert_eq!(
+            other_sysvar.set_data_from_slice(&[1, 2, 3, 4]),
+            Err(InstructionError::ReadonlyDataModified)
+        );
+    }
+
     #[test]
     fn test_invoke_context_compute_budget() {
         let transaction_accounts =
@@ -1380,6 +1584,33 @@ mod tests {
         invoke_context.pop().unwrap();
     }
 
+    #[test]
+    fn test_get_transaction_signatures() {
+        let transaction_accounts =
+            vec![(solana_sdk::pubkey::new_rand(), AccountSharedData::default())];
+        let signatures = vec![Signature::new_unique(), Signature::new_unique()];
+
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        assert!(invoke_context.get_transaction_signatures().is_empty());
+
+        invoke_context.set_transaction_signatures(&signatures);
+        assert_eq!(invoke_context.get_transaction_signatures(), &signatures[..]);
+    }
+
+    #[test]
+    fn test_verified_signatures() {
+        let transaction_accounts =
+            vec![(solana_sdk::pubkey::new_rand(), AccountSharedData::default())];
+        let key = Hash::new_unique();
+
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        assert!(!invoke_context.is_signature_verified(&key));
+
+        invoke_context.record_verified_signature(key);
+        assert!(invoke_context.is_signature_verified(&key));
+        assert!(!invoke_context.is_signature_verified(&Hash::new_unique()));
+    }
+
     #[test]
     fn test_process_instruction_accounts_resize_delta() {
         let program_key = Pubkey::new_unique();
@@ -11,12 +11,16 @@ use {
     solana_measure::measure::Measure,
     solana_sdk::{
         account::WritableAccount,
-        feature_set::FeatureSet,
+        feature_set::{
+            enable_get_serialized_message_syscall, enable_sol_is_verified_syscall, FeatureSet,
+        },
         hash::Hash,
         message::SanitizedMessage,
-        precompiles::is_precompile,
+        nonce::TransactionNonceInfo,
+        precompiles::{is_precompile, signed_messages},
         rent::Rent,
         saturating_add_assign,
+        signature::Signature,
         sysvar::instructions,
         transaction::TransactionError,
         transaction_context::{IndexOfAccount, InstructionAccount, TransactionContext},
@@ -52,6 +56,8 @@ impl MessageProcessor {
     #[allow(clippy::too_many_arguments)]
     pub fn process_message(
         message: &SanitizedMessage,
+        signatures: &[Signature],
+        nonce_info: Option<TransactionNonceInfo>,
         program_indices: &[Vec<IndexOfAccount>],
         transaction_context: &mut TransactionContext,
         rent: Rent,
@@ -82,6 +88,17 @@ impl MessageProcessor {
             lamports_per_signature,
             current_accounts_data_len,
         );
+        invoke_context.set_transaction_signatures(signatures);
+        invoke_context.set_transaction_nonce_info(nonce_info);
+        if invoke_context
+            .feature_set
+            .is_active(&enable_get_serialized_message_syscall::id())
+        {
+            invoke_context.set_transaction_message_data(match message {
+                SanitizedMessage::Legacy(legacy_message) => legacy_message.message.serialize(),
+                SanitizedMessage::V0(loaded_message) => loaded_message.message.serialize(),
+            });
+        }
 
         debug_assert_eq!(program_indices.len(), message.instructions().len());
         for (instruction_index, ((program_id, instruction), program_indices)) in message
@@ -132,6 +149,27 @@ impl MessageProcessor {
             }
 
             let result = if is_precompile {
+                // Precompiles were verified before the message is processed,
+                // remember their signatures for the sol_is_verified syscall
+                if invoke_context
+                    .feature_set
+                    .is_active(&enable_sol_is_verified_syscall::id())
+                {
+                    let instruction_datas: Vec<_> = message
+                        .instructions()
+                        .iter()
+                        .map(|instruction| instruction.data.as_slice())
+                        .collect();
+                    if let Ok(signed_messages) =
+                        signed_messages(program_id, &instruction.data, &instruction_datas)
+                    {
+                        for signed_message in signed_messages {
+                            invoke_context.record_verified_signature(
+                                signed_message.verified_signature_key(program_id),
+                            );
+                        }
+                    }
+                }
                 invoke_context
                     .transaction_context
                     .get_next_instruction_context()
@@ -204,6 +242,21 @@ mod tests {
         },
     };
 
+    // Since libsecp256k1 is still using the old version of rand, this
+    // copies the `random` implementation at:
+    // https://docs.rs/libsecp256k1/latest/src/libsecp256k1/lib.rs.html#430
+    fn new_secp256k1_secret_key() -> libsecp256k1::SecretKey {
+        use rand::RngCore;
+        let mut rng = rand::thread_rng();
+        loop {
+            let mut ret = [0u8; libsecp256k1::util::SECRET_KEY_SIZE];
+            rng.fill_bytes(&mut ret);
+            if let Ok(key) = libsecp256k1::SecretKey::parse(&ret) {
+                break key;
+            }
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize)]
     enum MockInstruction {
         NoopSuccess,
@@ -308,6 +361,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &program_indices,
             &mut transaction_context,
             Rent::default(),
@@ -361,6 +416,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &program_indices,
             &mut transaction_context,
             Rent::default(),
@@ -404,6 +461,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &program_indices,
             &mut transaction_context,
             Rent::default(),
@@ -538,6 +597,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &program_indices,
             &mut transaction_context,
             Rent::default(),
@@ -575,6 +636,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &program_indices,
             &mut transaction_context,
             Rent::default(),
@@ -609,6 +672,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &program_indices,
             &mut transaction_context,
             Rent::default(),
@@ -670,20 +735,7 @@ mod tests {
         let mut transaction_context =
             TransactionContext::new(accounts, Some(Rent::default()), 1, 2);
 
-        // Since libsecp256k1 is still using the old version of rand, this test
-        // copies the `random` implementation at:
-        // https://docs.rs/libsecp256k1/latest/src/libsecp256k1/lib.rs.html#430
-        let secret_key = {
-            use rand::RngCore;
-            let mut rng = rand::thread_rng();
-            loop {
-                let mut ret = [0u8; libsecp256k1::util::SECRET_KEY_SIZE];
-                rng.fill_bytes(&mut ret);
-                if let Ok(key) = libsecp256k1::SecretKey::parse(&ret) {
-                    break key;
-                }
-            }
-        };
+        let secret_key = new_secp256k1_secret_key();
         let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(
             &[
                 new_secp256k1_instruction(&secret_key, b"hello"),
@@ -701,6 +753,8 @@ mod tests {
         let mut programs_updated_only_for_global_cache = LoadedProgramsForTxBatch::default();
         let result = MessageProcessor::process_message(
             &message,
+            &[],
+            None,
             &[vec![0], vec![1]],
             &mut transaction_context,
             Rent::default(),
@@ -727,4 +781,87 @@ mod tests {
         );
         assert_eq!(transaction_context.get_instruction_trace_length(), 2);
     }
+
+    #[test]
+    fn test_precompile_records_verified_signatures() {
+        let mock_program_id = Pubkey::new_unique();
+        // Succeeds if the key in its instruction data was recorded as verified
+        declare_process_instruction!(process_instruction, 1, |invoke_context| {
+            let transaction_context = &invoke_context.transaction_context;
+            let instruction_context = transaction_context.get_current_instruction_context()?;
+            let key = Hash::new(instruction_context.get_instruction_data());
+            if invoke_context.is_signature_verified(&key) {
+                Ok(())
+            } else {
+                Err(InstructionError::MissingRequiredSignature)
+            }
+        });
+
+        let secp256k1_instruction =
+            new_secp256k1_instruction(&new_secp256k1_secret_key(), b"hello");
+        let signed_message = signed_messages(
+            &secp256k1_program::id(),
+            &secp256k1_instruction.data,
+            &[&secp256k1_instruction.data],
+        )
+        .unwrap()[0];
+        let verified_key = signed_message.verified_signature_key(&secp256k1_program::id());
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(
+            &[
+                secp256k1_instruction.clone(),
+                Instruction::new_with_bytes(mock_program_id, verified_key.as_ref(), vec![]),
+            ],
+            None,
+        )));
+
+        let process_message = |feature_set: FeatureSet| {
+            let mut secp256k1_account = AccountSharedData::new(1, 0, &native_loader::id());
+            secp256k1_account.set_executable(true);
+            let mut mock_program_account = AccountSharedData::new(1, 0, &native_loader::id());
+            mock_program_account.set_executable(true);
+            let accounts = vec![
+                (secp256k1_program::id(), secp256k1_account),
+                (mock_program_id, mock_program_account),
+            ];
+            let mut transaction_context =
+                TransactionContext::new(accounts, Some(Rent::default()), 1, 2);
+            let mut programs_loaded_for_tx_batch = LoadedProgramsForTxBatch::default();
+            programs_loaded_for_tx_batch.replenish(
+                mock_program_id,
+                Arc::new(LoadedProgram::new_builtin(0, 0, process_instruction)),
+            );
+            MessageProcessor::process_message(
+                &message,
+                &[],
+                None,
+                &[vec![0], vec![1]],
+                &mut transaction_context,
+                Rent::default(),
+                None,
+                &programs_loaded_for_tx_batch,
+                &mut LoadedProgramsForTxBatch::default(),
+                &mut LoadedProgramsForTxBatch::default(),
+                Arc::new(feature_set),
+                ComputeBudget::default(),
+                &mut ExecuteTimings::default(),
+                &SysvarCache::default(),
+                Hash::default(),
+                0,
+                0,
+                &mut 0,
+            )
+        };
+
+        assert!(process_message(FeatureSet::all_enabled()).is_ok());
+
+        let mut feature_set = FeatureSet::all_enabled();
+        feature_set.deactivate(&enable_sol_is_verified_syscall::id());
+        assert_eq!(
+            process_message(feature_set),
+            Err(TransactionError::InstructionError(
+                1,
+                InstructionError::MissingRequiredSignature
+            ))
+        );
+    }
 }
@@ -8,9 +8,12 @@ use {
     base64::{prelude::BASE64_STANDARD, Engine},
     chrono_humanize::{Accuracy, HumanTime, Tense},
     log::*,
-    solana_accounts_db::epoch_accounts_hash::EpochAccountsHash,
+    recorded::{RecordedTransaction, RecordedTransactionError},
+    solana_accounts_db::{accounts::Accounts, epoch_accounts_hash::EpochAccountsHash},
     solana_banks_client::start_client,
-    solana_banks_server::banks_server::start_local_server,
+    solana_banks_server::banks_server::{
+        start_local_server, start_local_server_with_transaction_history, TransactionHistory,
+    },
     solana_bpf_loader_program::serialization::serialize_parameters,
     solana_program_runtime::{
         compute_budget::ComputeBudget, ic_msg, invoke_context::ProcessInstructionWithContext,
@@ -25,7 +28,9 @@ use {
         runtime_config::RuntimeConfig,
     },
     solana_sdk::{
-        account::{create_account_shared_data_for_test, Account, AccountSharedData},
+        account::{
+            create_account_shared_data_for_test, Account, AccountSharedData, ReadableAccount,
+        },
         account_info::AccountInfo,
         clock::Slot,
         entrypoint::{deserialize, ProgramResult, SUCCESS},
@@ -34,14 +39,16 @@ use {
         genesis_config::{ClusterType, GenesisConfig},
         hash::Hash,
         instruction::{Instruction, InstructionError},
+        native_loader,
         native_token::sol_to_lamports,
         poh_config::PohConfig,
         program_error::{ProgramError, UNSUPPORTED_SYSVAR},
         pubkey::Pubkey,
         rent::Rent,
-        signature::{Keypair, Signer},
+        signature::{Keypair, Signature, Signer},
         stable_layout::stable_instruction::StableInstruction,
-        sysvar::{Sysvar, SysvarId},
+        sysvar::{self, signatures::SysvarSerializeError, Sysvar, SysvarId},
+        transaction::{MessageHash, SanitizedTransaction},
     },
     solana_vote_program::vote_state::{self, VoteState, VoteStateVersions},
     std::{
@@ -70,6 +77,7 @@ pub use {
 };
 
 pub mod programs;
+pub mod recorded;
 
 /// Errors from the program test environment
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -406,10 +414,66 @@ impl solana_sdk::program_stubs::SyscallStubs for SyscallStubs {
             .unwrap();
     }
 
+    fn sol_tx_scratch_write(&self, data: &[u8]) {
+        let invoke_context = get_invoke_context();
+        let transaction_context = &invoke_context.transaction_context;
+        let instruction_context = transaction_context
+            .get_current_instruction_context()
+            .unwrap();
+        let caller = *instruction_context
+            .get_last_program_key(transaction_context)
+            .unwrap();
+        invoke_context.set_tx_scratch(caller, data.to_vec());
+    }
+
+    fn sol_tx_scratch_read(&self, program_id: &Pubkey) -> Option<Vec<u8>> {
+        let data = get_invoke_context().get_tx_scratch(program_id);
+        (!data.is_empty()).then(|| data.to_vec())
+    }
+
     fn sol_get_stack_height(&self) -> u64 {
         let invoke_context = get_invoke_context();
         invoke_context.get_stack_height().try_into().unwrap()
     }
+
+    fn sol_get_instructions_sysvar_slice(
+        &self,
+        offset: u64,
+        length: u64,
+        var_addr: *mut u8,
+    ) -> u64 {
+        let invoke_context = get_invoke_context();
+        let compute_budget = invoke_context.get_compute_budget();
+        let cost = compute_budget.sysvar_base_cost.saturating_add(
+            length
+                .checked_div(compute_budget.cpi_bytes_per_unit)
+                .unwrap_or(u64::MAX),
+        );
+        if invoke_context.consume_checked(cost).is_err() {
+            panic!("Exceeded compute budget");
+        }
+
+        let transaction_context = &invoke_context.transaction_context;
+        let Some(index_in_transaction) =
+            transaction_context.find_index_of_account(&sysvar::instructions::id())
+        else {
+            return sysvar::instructions::SYSVAR_NOT_FOUND;
+        };
+        let account = transaction_context
+            .get_account_at_index(index_in_transaction)
+            .unwrap()
+            .borrow();
+        let Some(slice) = offset
+            .checked_add(length)
+            .and_then(|end| account.data().get(offset as usize..end as usize))
+        else {
+            return sysvar::instructions::OFFSET_LENGTH_EXCEEDS_SYSVAR;
+        };
+        unsafe {
+            std::slice::from_raw_parts_mut(var_addr, slice.len()).copy_from_slice(slice);
+        }
+        SUCCESS
+    }
 }
 
 pub fn find_file(filename: &str) -> Option<PathBuf> {
@@ -455,6 +519,7 @@ pub struct ProgramTest {
     prefer_bpf: bool,
     deactivate_feature_set: HashSet<Pubkey>,
     transaction_account_lock_limit: Option<usize>,
+    recorded_transaction: Option<RecordedTransaction>,
 }
 
 impl Default for ProgramTest {
@@ -492,6 +557,7 @@ impl Default for ProgramTest {
             prefer_bpf,
             deactivate_feature_set,
             transaction_account_lock_limit: None,
+            recorded_transaction: None,
         }
     }
 }
@@ -514,6 +580,36 @@ impl ProgramTest {
         me
     }
 
+    /// Create a `ProgramTest` that replays a transaction recorded from a
+    /// cluster.
+    ///
+    /// `json` is a recording in the format described in the [`recorded`]
+    /// module. The recorded accounts are added to the test environment,
+    /// except for sysvars and builtin programs, which the test environment
+    /// provides itself. Execute the transaction with
+    /// [`ProgramTestContext::replay_recorded_transaction`]; it is loaded
+    /// with the per-transaction sysvars, such as the instructions and
+    /// signatures sysvars, it was originally executed with.
+    ///
+    /// All features are active by default, which may not match the cluster
+    /// the transaction was recorded from; use [`deactivate_feature`] to match
+    /// it.
+    ///
+    /// [`deactivate_feature`]: #method.deactivate_feature
+    pub fn from_recorded_transaction(json: &str) -> Result<Self, RecordedTransactionError> {
+        let recorded_transaction = RecordedTransaction::from_json(json)?;
+        let mut me = Self::default();
+        for (address, account) in &recorded_transaction.accounts {
+            if sysvar::check_id(&account.owner) || native_loader::check_id(&account.owner) {
+                debug!("Skipping recorded account {}", address);
+                continue;
+            }
+            me.add_account(*address, account.clone());
+        }
+        me.recorded_transaction = Some(recorded_transaction);
+        Ok(me)
+    }
+
     /// Override default SBF program selection
     pub fn prefer_bpf(&mut self, prefer_bpf: bool) {
         self.prefer_bpf = prefer_bpf;
@@ -895,10 +991,13 @@ impl ProgramTest {
     pub async fn start_with_context(mut self) -> ProgramTestContext {
         let (bank_forks, block_commitment_cache, last_blockhash, gci) = self.setup_bank();
         let target_tick_duration = gci.genesis_config.poh_config.target_tick_duration;
-        let transport = start_local_server(
+        let transaction_history = TransactionHistory::default();
+        let recorded_transaction = self.recorded_transaction.take();
+        let transport = start_local_server_with_transaction_history(
             bank_forks.clone(),
             block_commitment_cache.clone(),
             target_tick_duration,
+            transaction_history.clone(),
         )
         .await;
         let banks_client = start_client(transport)
@@ -911,6 +1010,8 @@ impl ProgramTest {
             banks_client,
             last_blockhash,
             gci,
+            transaction_history,
+            recorded_transaction,
         )
     }
 }
@@ -992,6 +1093,15 @@ impl<T> Drop for DroppableTask<T> {
     }
 }
 
+/// The per-transaction sysvar account data of a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionSysvars {
+    /// Instructions sysvar data, with the current instruction index set to 0
+    pub instructions: Vec<u8>,
+    /// Signatures sysvar data
+    pub signatures: Vec<u8>,
+}
+
 pub struct ProgramTestContext {
     pub banks_client: BanksClient,
     pub last_blockhash: Hash,
@@ -999,6 +1109,8 @@ pub struct ProgramTestContext {
     genesis_config: GenesisConfig,
     bank_forks: Arc<RwLock<BankForks>>,
     block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+    transaction_history: TransactionHistory,
+    recorded_transaction: Option<RecordedTransaction>,
     _bank_task: DroppableTask<()>,
 }
 
@@ -1009,6 +1121,8 @@ impl ProgramTestContext {
         banks_client: BanksClient,
         last_blockhash: Hash,
         genesis_config_info: GenesisConfigInfo,
+        transaction_history: TransactionHistory,
+        recorded_transaction: Option<RecordedTransaction>,
     ) -> Self {
         // Run a simulated PohService to provide the client with new blockhashes.  New blockhashes
         // are required when sending multiple otherwise identical transactions in series from a
@@ -1045,6 +1159,8 @@ impl ProgramTestContext {
             genesis_config: genesis_config_info.genesis_config,
             bank_forks,
             block_commitment_cache,
+            transaction_history,
+            recorded_transaction,
             _bank_task: bank_task,
         }
     }
@@ -1053,6 +1169,118 @@ impl ProgramTestContext {
         &self.genesis_config
     }
 
+    /// Get the instructions and signatures sysvar data of a transaction sent
+    /// through `banks_client`, identified by its first signature.
+    ///
+    /// The data is constructed the same way as when the transaction was
+    /// loaded for execution, using the current working bank to resolve
+    /// address table lookups. Returns `None` if no such transaction was sent,
+    /// or if it no longer sanitizes against the working bank.
+    pub fn get_transaction_sysvars(&self, signature: &Signature) -> Option<TransactionSysvars> {
+        let transaction = self
+            .transaction_history
+            .read()
+            .unwrap()
+            .get(signature)?
+            .clone();
+        let bank = self.bank_forks.read().unwrap().working_bank();
+        let mut transaction = SanitizedTransaction::try_create(
+            transaction,
+            MessageHash::Compute,
+            None,
+            bank.as_ref(),
+        )
+        .ok()?;
+        transaction.demote_account_keys(bank.demoted_sysvar_ids());
+        Some(TransactionSysvars {
+            instructions: Accounts::construct_instructions_account(
+                transaction.message(),
+                &bank.feature_set,
+            )
+            .data()
+            .to_vec(),
+            signatures: Accounts::construct_signatures_account(&transaction, &bank.feature_set)
+                .ok()?
+                .data()
+                .to_vec(),
+        })
+    }
+
+    /// Make programs read `signatures` from the signatures sysvar of every
+    /// transaction sent afterwards, instead of the transaction's own
+    /// signatures, or stop doing so if `signatures` is `None`.
+    ///
+    /// Each entry pairs a signer with its signature, so that edge cases that
+    /// can't be produced by signing a real transaction, such as the maximum
+    /// number of signatures or duplicate signers, can be crafted. The sysvar
+    /// uses the layout selected by the active features, with no address
+    /// table lookups. Transactions still need valid signatures to be
+    /// processed, and [`get_transaction_sysvars`] keeps returning the data
+    /// constructed from their own signatures.
+    ///
+    /// Fails without changing the sysvar if the number of signatures can't be
+    /// represented in it.
+    ///
+    /// [`get_transaction_sysvars`]: Self::get_transaction_sysvars
+    pub fn set_transaction_signatures(
+        &self,
+        signatures: Option<&[(Pubkey, Signature)]>,
+    ) -> Result<(), SysvarSerializeError> {
+        let bank = self.bank_forks.read().unwrap().working_bank();
+        let account = signatures
+            .map(|signatures| {
+                let signers: Vec<(&Pubkey, &Signature)> = signatures
+                    .iter()
+                    .map(|(signer, signature)| (signer, signature))
+                    .collect();
+                Accounts::construct_signatures_account_from_signers(
+                    &signers,
+                    &[],
+                    &bank.feature_set,
+                )
+            })
+            .transpose()?;
+        bank.set_signatures_sysvar_override_for_tests(account);
+        Ok(())
+    }
+
+    /// Execute the transaction this context was created from with
+    /// [`ProgramTest::from_recorded_transaction`].
+    ///
+    /// The working bank is first warped to the slot the transaction was
+    /// recorded in, so that the programs and address lookup table entries
+    /// the transaction uses are visible like they were on the cluster, and
+    /// the transaction's recent blockhash is registered with the bank. The
+    /// transaction is executed unchanged, so its signatures still verify.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context wasn't created from a recorded transaction.
+    pub async fn replay_recorded_transaction(
+        &mut self,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let RecordedTransaction {
+            slot, transaction, ..
+        } = self
+            .recorded_transaction
+            .clone()
+            .expect("ProgramTestContext was not created from a recorded transaction");
+
+        let working_slot = self.bank_forks.read().unwrap().working_bank().slot();
+        if slot > working_slot {
+            self.warp_to_slot(slot).unwrap();
+        }
+        self.bank_forks
+            .read()
+            .unwrap()
+            .working_bank()
+            .register_recent_blockhash(transaction.message.recent_blockhash());
+
+        self.banks_client
+            .process_transaction_with_metadata(transaction)
+            .await
+    }
+
     /// Manually increment vote credits for the current epoch in the specified vote account to simulate validator voting activity
     pub fn increment_vote_account_credits(
         &mut self,
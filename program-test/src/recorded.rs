@@ -0,0 +1,124 @@
+//! Transactions recorded from a cluster, with the states of the accounts they
+//! referenced, for replay in the test environment.
+//!
+//! A recording is a JSON object assembled from the output of RPC methods:
+//!
+//! ```json
+//! {
+//!   "slot": 226617155,
+//!   "transaction": ["<base64 encoded transaction>", "base64"],
+//!   "accounts": [
+//!     {
+//!       "pubkey": "<base58 address>",
+//!       "account": {
+//!         "lamports": 1000000,
+//!         "data": ["<base64 encoded data>", "base64"],
+//!         "owner": "11111111111111111111111111111111",
+//!         "executable": false,
+//!         "rentEpoch": 0
+//!       }
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! - `slot` and `transaction` are the fields of the same name returned by
+//!   `getTransaction` with the `base64` encoding,
+//! - every element of `accounts` is in the format of `solana account --output
+//!   json`, or of an element of `getMultipleAccounts` paired with its address,
+//!   and holds the state of an account before the transaction was executed.
+//!
+//! The accounts should include those of the programs the transaction invokes,
+//! their program data accounts and the address lookup tables it uses.
+
+use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    serde::Deserialize,
+    solana_account_decoder::UiAccount,
+    solana_sdk::{
+        account::Account, clock::Slot, pubkey::Pubkey, transaction::VersionedTransaction,
+    },
+    std::str::FromStr,
+    thiserror::Error,
+};
+
+/// Errors from parsing a recorded transaction
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum RecordedTransactionError {
+    #[error("invalid recording: {0}")]
+    InvalidJson(String),
+    #[error("unsupported transaction encoding `{0}`, expected `base64`")]
+    UnsupportedTransactionEncoding(String),
+    #[error("invalid transaction: {0}")]
+    InvalidTransaction(String),
+    #[error("invalid account address `{0}`")]
+    InvalidAccountAddress(String),
+    #[error("invalid state of account {0}")]
+    InvalidAccount(Pubkey),
+}
+
+/// A transaction recorded from a cluster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedTransaction {
+    /// The slot the transaction was executed in
+    pub slot: Slot,
+    pub transaction: VersionedTransaction,
+    /// The states of the accounts referenced by the transaction, before it was
+    /// executed
+    pub accounts: Vec<(Pubkey, Account)>,
+}
+
+#[derive(Deserialize)]
+struct UiRecordedTransaction {
+    slot: Slot,
+    transaction: (String, String),
+    accounts: Vec<UiKeyedAccount>,
+}
+
+#[derive(Deserialize)]
+struct UiKeyedAccount {
+    pubkey: String,
+    account: UiAccount,
+}
+
+impl RecordedTransaction {
+    /// Parse a recording in the format described in the [module
+    /// documentation](self)
+    pub fn from_json(json: &str) -> Result<Self, RecordedTransactionError> {
+        let UiRecordedTransaction {
+            slot,
+            transaction: (transaction, encoding),
+            accounts,
+        } = serde_json::from_str(json)
+            .map_err(|err| RecordedTransactionError::InvalidJson(err.to_string()))?;
+
+        if encoding != "base64" {
+            return Err(RecordedTransactionError::UnsupportedTransactionEncoding(
+                encoding,
+            ));
+        }
+        let transaction = BASE64_STANDARD
+            .decode(transaction)
+            .map_err(|err| RecordedTransactionError::InvalidTransaction(err.to_string()))?;
+        let transaction: VersionedTransaction = bincode::deserialize(&transaction)
+            .map_err(|err| RecordedTransactionError::InvalidTransaction(err.to_string()))?;
+
+        let accounts = accounts
+            .into_iter()
+            .map(|UiKeyedAccount { pubkey, account }| {
+                let address = Pubkey::from_str(&pubkey)
+                    .map_err(|_| RecordedTransactionError::InvalidAccountAddress(pubkey))?;
+                let account = account
+                    .decode::<Account>()
+                    .ok_or(RecordedTransactionError::InvalidAccount(address))?;
+                Ok((address, account))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            slot,
+            transaction,
+            accounts,
+        })
+    }
+}
@@ -0,0 +1,162 @@
+use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    solana_account_decoder::{UiAccount, UiAccountEncoding},
+    solana_program_test::{
+        processor,
+        recorded::{RecordedTransaction, RecordedTransactionError},
+        ProgramTest,
+    },
+    solana_sdk::{
+        account::Account,
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program,
+        sysvar::{self, signatures::SignaturesData},
+        transaction::{Transaction, VersionedTransaction},
+    },
+};
+
+// Records the first signature of the transaction in the second account
+fn record_signature_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _input: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signatures_info = next_account_info(account_info_iter)?;
+    let record_info = next_account_info(account_info_iter)?;
+
+    let signatures_data = SignaturesData::deserialize(&signatures_info.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let signature = signatures_data
+        .signatures
+        .first()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    record_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(signature.as_ref());
+    Ok(())
+}
+
+fn recording_json(
+    slot: u64,
+    transaction: &VersionedTransaction,
+    accounts: &[(Pubkey, Account)],
+) -> String {
+    let accounts: Vec<_> = accounts
+        .iter()
+        .map(|(pubkey, account)| {
+            serde_json::json!({
+                "pubkey": pubkey.to_string(),
+                "account": UiAccount::encode(pubkey, account, UiAccountEncoding::Base64, None, None),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "slot": slot,
+        "transaction": [
+            BASE64_STANDARD.encode(bincode::serialize(transaction).unwrap()),
+            "base64",
+        ],
+        "accounts": accounts,
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn replay_recorded_transaction() {
+    let program_id = Pubkey::new_unique();
+    let payer = Keypair::new();
+    let record = Pubkey::new_unique();
+    let recorded_slot = 1_000;
+
+    // A transaction signed against a blockhash the test environment has
+    // never seen
+    let transaction = Transaction::new_signed_with_payer(
+        &[Instruction::new_with_bytes(
+            program_id,
+            &[],
+            vec![
+                AccountMeta::new_readonly(sysvar::signatures::id(), false),
+                AccountMeta::new(record, false),
+            ],
+        )],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::new_unique(),
+    );
+    let accounts = vec![
+        (
+            payer.pubkey(),
+            Account::new(1_000_000_000, 0, &system_program::id()),
+        ),
+        (record, Account::new(1_000_000_000, 64, &program_id)),
+        // Sysvars are provided by the test environment
+        (sysvar::clock::id(), Account::new(1, 40, &sysvar::id())),
+    ];
+    let json = recording_json(recorded_slot, &transaction.clone().into(), &accounts);
+
+    let mut program_test = ProgramTest::from_recorded_transaction(&json).unwrap();
+    program_test.add_program(
+        "record_signature",
+        program_id,
+        processor!(record_signature_process_instruction),
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let result = context.replay_recorded_transaction().await.unwrap();
+    assert_eq!(result.result, Ok(()));
+
+    let record_account = context
+        .banks_client
+        .get_account(record)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(record_account.data, transaction.signatures[0].as_ref());
+    let clock = context
+        .banks_client
+        .get_sysvar::<sysvar::clock::Clock>()
+        .await
+        .unwrap();
+    assert_eq!(clock.slot, recorded_slot);
+}
+
+#[test]
+fn parse_recorded_transaction() {
+    let payer = Keypair::new();
+    let transaction: VersionedTransaction = Transaction::new_signed_with_payer(
+        &[],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::new_unique(),
+    )
+    .into();
+    let accounts = vec![(payer.pubkey(), Account::new(42, 0, &system_program::id()))];
+    let json = recording_json(7, &transaction, &accounts);
+
+    assert_eq!(
+        RecordedTransaction::from_json(&json),
+        Ok(RecordedTransaction {
+            slot: 7,
+            transaction,
+            accounts,
+        })
+    );
+
+    assert_eq!(
+        RecordedTransaction::from_json(&json.replace("\"base64\"]", "\"base58\"]")),
+        Err(RecordedTransactionError::UnsupportedTransactionEncoding(
+            "base58".to_string()
+        ))
+    );
+    assert!(matches!(
+        RecordedTransaction::from_json("{}"),
+        Err(RecordedTransactionError::InvalidJson(_))
+    ));
+}
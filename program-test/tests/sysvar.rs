@@ -1,9 +1,23 @@
 use {
-    solana_program_test::{processor, ProgramTest},
+    solana_program_test::{processor, ProgramTest, ProgramTestContext},
     solana_sdk::{
-        account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-        epoch_rewards::EpochRewards, epoch_schedule::EpochSchedule, instruction::Instruction, msg,
-        pubkey::Pubkey, rent::Rent, signature::Signer, sysvar::Sysvar, transaction::Transaction,
+        account_info::AccountInfo,
+        clock::Clock,
+        entrypoint::ProgramResult,
+        epoch_rewards::EpochRewards,
+        epoch_schedule::EpochSchedule,
+        instruction::{AccountMeta, Instruction},
+        msg,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+        signature::{Signature, Signer},
+        sysvar::{
+            instructions,
+            signatures::{self, SignaturesData, SysvarSerializeError, TxSignature},
+            Sysvar,
+        },
+        transaction::Transaction,
     },
 };
 
@@ -127,3 +141,127 @@ async fn get_epoch_rewards_sysvar() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn get_transaction_sysvars() {
+    let program_id = Pubkey::new_unique();
+    let program_test = ProgramTest::new(
+        "sysvar_getter",
+        program_id,
+        processor!(sysvar_getter_process_instruction),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    context.warp_to_slot(42).unwrap();
+    let instruction = Instruction::new_with_bincode(program_id, &(), vec![]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let signature = transaction.signatures[0];
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let sysvars = context.get_transaction_sysvars(&signature).unwrap();
+    #[allow(deprecated)]
+    let loaded_instruction = instructions::load_instruction_at(0, &sysvars.instructions).unwrap();
+    assert_eq!(loaded_instruction, instruction);
+    assert_eq!(
+        SignaturesData::deserialize(&sysvars.signatures)
+            .unwrap()
+            .signatures,
+        vec![TxSignature::from(signature)]
+    );
+
+    assert_eq!(
+        context.get_transaction_sysvars(&Signature::new_unique()),
+        None
+    );
+}
+
+// Check the number of signatures and that the last two signers are the same
+fn signatures_checker_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let num_signatures = signatures::load_num_signatures(&accounts[0])?;
+    if num_signatures != input[0] as usize {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if input[1] == 1 {
+        let (last_signer, _) =
+            signatures::load_signer_and_signature_at_checked(num_signatures - 1, &accounts[0])?;
+        let (previous_signer, _) =
+            signatures::load_signer_and_signature_at_checked(num_signatures - 2, &accounts[0])?;
+        if last_signer != previous_signer {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+    Ok(())
+}
+
+async fn process_signatures_checker(
+    context: &mut ProgramTestContext,
+    program_id: Pubkey,
+    num_signatures: u8,
+    duplicate_signers: bool,
+) {
+    let instruction = Instruction::new_with_bytes(
+        program_id,
+        &[num_signatures, duplicate_signers as u8],
+        vec![AccountMeta::new_readonly(signatures::id(), false)],
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_transaction_signatures() {
+    let program_id = Pubkey::new_unique();
+    let program_test = ProgramTest::new(
+        "signatures_checker",
+        program_id,
+        processor!(signatures_checker_process_instruction),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    process_signatures_checker(&mut context, program_id, 1, false).await;
+
+    // The maximum number of signatures, the last two by the same signer
+    let signer = Pubkey::new_unique();
+    let max_signatures = signatures::MAX_SIGNATURES as u8;
+    let mut crafted: Vec<_> = (1..max_signatures)
+        .map(|_| (Pubkey::new_unique(), Signature::new_unique()))
+        .collect();
+    crafted.last_mut().unwrap().0 = signer;
+    crafted.push((signer, Signature::new_unique()));
+    context.set_transaction_signatures(Some(&crafted)).unwrap();
+    process_signatures_checker(&mut context, program_id, max_signatures, true).await;
+
+    crafted.push((signer, Signature::new_unique()));
+    assert_eq!(
+        context.set_transaction_signatures(Some(&crafted)),
+        Err(SysvarSerializeError::TooManySignatures(crafted.len()))
+    );
+    process_signatures_checker(&mut context, program_id, max_signatures, true).await;
+
+    context.set_transaction_signatures(None).unwrap();
+    process_signatures_checker(&mut context, program_id, 1, false).await;
+}
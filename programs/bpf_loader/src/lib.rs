@@ -35,8 +35,9 @@ use {
             bpf_account_data_direct_mapping, cap_accounts_data_allocations_per_transaction,
             cap_bpf_program_instruction_accounts, delay_visibility_of_program_deployment,
             enable_bpf_loader_extend_program_ix, enable_bpf_loader_set_authority_checked_ix,
-            enable_program_redeployment_cooldown, limit_max_instruction_trace_length,
-            native_programs_consume_cu, remove_bpf_loader_incorrect_program_id,
+            enable_bpf_loader_set_sysvar_dependencies_ix, enable_program_redeployment_cooldown,
+            limit_max_instruction_trace_length, native_programs_consume_cu,
+            remove_bpf_loader_incorrect_program_id, sysvar_dependency_bits_v2,
         },
         instruction::{AccountMeta, InstructionError},
         loader_instruction::LoaderInstruction,
@@ -1430,6 +1431,168 @@ fn process_loader_upgradeable_instruction(
                 additional_bytes
             );
         }
+        UpgradeableLoaderInstruction::SetSysvarDependencies {
+            sysvar_dependencies,
+        } => {
+            if !invoke_context
+                .feature_set
+                .is_active(&enable_bpf_loader_set_sysvar_dependencies_ix::id())
+            {
+                return Err(InstructionError::InvalidInstructionData);
+            }
+
+            const PROGRAM_METADATA_ACCOUNT_INDEX: IndexOfAccount = 0;
+            const PROGRAM_ACCOUNT_INDEX: IndexOfAccount = 1;
+            const PROGRAM_DATA_ACCOUNT_INDEX: IndexOfAccount = 2;
+            const AUTHORITY_ACCOUNT_INDEX: IndexOfAccount = 3;
+            const OPTIONAL_PAYER_ACCOUNT_INDEX: IndexOfAccount = 4;
+            #[allow(dead_code)]
+            // System program is only required when a CPI is performed
+            const OPTIONAL_SYSTEM_PROGRAM_ACCOUNT_INDEX: IndexOfAccount = 5;
+
+            instruction_context.check_number_of_instruction_accounts(4)?;
+            let num_sysvar_dependency_bits = if invoke_context
+                .feature_set
+                .is_active(&sysvar_dependency_bits_v2::id())
+            {
+                bpf_loader_upgradeable::SYSVAR_DEPENDENCY_BITS_V2
+            } else {
+                bpf_loader_upgradeable::SYSVAR_DEPENDENCY_BITS_V1
+            };
+            if sysvar_dependencies.checked_shr(num_sysvar_dependency_bits) != Some(0) {
+                ic_logger_msg!(log_collector, "Sysvar dependencies contain unknown sysvars");
+                return Err(InstructionError::InvalidInstructionData);
+            }
+
+            // Verify Program account
+
+            let program_account = instruction_context
+                .try_borrow_instruction_account(transaction_context, PROGRAM_ACCOUNT_INDEX)?;
+            if program_account.get_owner() != program_id {
+                ic_logger_msg!(log_collector, "Program account not owned by loader");
+                return Err(InstructionError::InvalidAccountOwner);
+            }
+            let program_key = *program_account.get_key();
+            let programdata_address = if let UpgradeableLoaderState::Program {
+                programdata_address,
+            } = program_account.get_state()?
+            {
+                programdata_address
+            } else {
+                ic_logger_msg!(log_collector, "Invalid Program account");
+                return Err(InstructionError::InvalidAccountData);
+            };
+            drop(program_account);
+
+            // Verify ProgramData account and upgrade authority
+
+            let programdata_account = instruction_context
+                .try_borrow_instruction_account(transaction_context, PROGRAM_DATA_ACCOUNT_INDEX)?;
+            if *programdata_account.get_key() != programdata_address {
+                ic_logger_msg!(
+                    log_collector,
+                    "Program account does not match ProgramData account"
+                );
+                return Err(InstructionError::InvalidArgument);
+            }
+            let authority_key = transaction_context.get_key_of_account_at_index(
+                instruction_context
+                    .get_index_of_instruction_account_in_transaction(AUTHORITY_ACCOUNT_INDEX)?,
+            )?;
+            if let UpgradeableLoaderState::ProgramData {
+                slot: _,
+                upgrade_authority_address,
+            } = programdata_account.get_state()?
+            {
+                if upgrade_authority_address.is_none() {
+                    ic_logger_msg!(log_collector, "Program not upgradeable");
+                    return Err(InstructionError::Immutable);
+                }
+                if upgrade_authority_address != Some(*authority_key) {
+                    ic_logger_msg!(log_collector, "Incorrect upgrade authority provided");
+                    return Err(InstructionError::IncorrectAuthority);
+                }
+                if !instruction_context.is_instruction_account_signer(AUTHORITY_ACCOUNT_INDEX)? {
+                    ic_logger_msg!(log_collector, "Upgrade authority did not sign");
+                    return Err(InstructionError::MissingRequiredSignature);
+                }
+            } else {
+                ic_logger_msg!(log_collector, "Invalid ProgramData account");
+                return Err(InstructionError::InvalidAccountData);
+            }
+            drop(programdata_account);
+
+            // Verify ProgramMetadata account, creating it if necessary
+
+            let program_metadata_account = instruction_context.try_borrow_instruction_account(
+                transaction_context,
+                PROGRAM_METADATA_ACCOUNT_INDEX,
+            )?;
+            let (derived_address, bump_seed) = Pubkey::find_program_address(
+                &[
+                    program_key.as_ref(),
+                    bpf_loader_upgradeable::PROGRAM_METADATA_SEED,
+                ],
+                program_id,
+            );
+            if *program_metadata_account.get_key() != derived_address {
+                ic_logger_msg!(log_collector, "ProgramMetadata address is not derived");
+                return Err(InstructionError::InvalidArgument);
+            }
+            let is_initialized = program_metadata_account.get_owner() == program_id;
+            drop(program_metadata_account);
+
+            if !is_initialized {
+                let payer_key = *transaction_context.get_key_of_account_at_index(
+                    instruction_context.get_index_of_instruction_account_in_transaction(
+                        OPTIONAL_PAYER_ACCOUNT_INDEX,
+                    )?,
+                )?;
+                let program_metadata_len = UpgradeableLoaderState::size_of_program_metadata();
+                let rent = invoke_context.get_sysvar_cache().get_rent()?;
+                let instruction = system_instruction::create_account(
+                    &payer_key,
+                    &derived_address,
+                    1.max(rent.minimum_balance(program_metadata_len)),
+                    program_metadata_len as u64,
+                    program_id,
+                );
+                let signers = [[
+                    program_key.as_ref(),
+                    bpf_loader_upgradeable::PROGRAM_METADATA_SEED,
+                    &[bump_seed],
+                ]]
+                .iter()
+                .map(|seeds| Pubkey::create_program_address(seeds, program_id))
+                .collect::<Result<Vec<Pubkey>, solana_sdk::pubkey::PubkeyError>>()?;
+                invoke_context.native_invoke(instruction.into(), signers.as_slice())?;
+            }
+
+            let transaction_context = &invoke_context.transaction_context;
+            let instruction_context = transaction_context.get_current_instruction_context()?;
+            let mut program_metadata_account = instruction_context.try_borrow_instruction_account(
+                transaction_context,
+                PROGRAM_METADATA_ACCOUNT_INDEX,
+            )?;
+            match program_metadata_account.get_state()? {
+                UpgradeableLoaderState::Uninitialized
+                | UpgradeableLoaderState::ProgramMetadata { .. } => {}
+                _ => {
+                    ic_logger_msg!(log_collector, "Invalid ProgramMetadata account");
+                    return Err(InstructionError::InvalidAccountData);
+                }
+            }
+            program_metadata_account.set_state(&UpgradeableLoaderState::ProgramMetadata {
+                sysvar_dependencies,
+            })?;
+
+            ic_logger_msg!(
+                log_collector,
+                "Set sysvar dependencies of program {:?} to {:#x}",
+                program_key,
+                sysvar_dependencies
+            );
+        }
     }
 
     Ok(())
@@ -1766,6 +1929,7 @@ mod tests {
             },
             account_utils::StateMut,
             clock::Clock,
+            feature_set::FeatureSet,
             instruction::{AccountMeta, InstructionError},
             pubkey::Pubkey,
             rent::Rent,
@@ -4010,6 +4174,227 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bpf_loader_upgradeable_set_sysvar_dependencies() {
+        let loader_id = bpf_loader_upgradeable::id();
+        let sysvar_dependencies = bpf_loader_upgradeable::sysvar_dependencies_from_ids(&[
+            sysvar::instructions::id(),
+            sysvar::signatures::id(),
+        ])
+        .unwrap();
+        let instruction =
+            bincode::serialize(&UpgradeableLoaderInstruction::SetSysvarDependencies {
+                sysvar_dependencies,
+            })
+            .unwrap();
+        let upgrade_authority_address = Pubkey::new_unique();
+        let upgrade_authority_account = AccountSharedData::new(1, 0, &Pubkey::new_unique());
+        let program_address = Pubkey::new_unique();
+        let (programdata_address, _) =
+            Pubkey::find_program_address(&[program_address.as_ref()], &loader_id);
+        let (program_metadata_address, _) =
+            bpf_loader_upgradeable::find_program_metadata_address(&program_address);
+        let mut program_account =
+            AccountSharedData::new(1, UpgradeableLoaderState::size_of_program(), &loader_id);
+        program_account
+            .set_state(&UpgradeableLoaderState::Program {
+                programdata_address,
+            })
+            .unwrap();
+        let mut programdata_account = AccountSharedData::new(
+            1,
+            UpgradeableLoaderState::size_of_programdata(0),
+            &loader_id,
+        );
+        programdata_account
+            .set_state(&UpgradeableLoaderState::ProgramData {
+                slot: 0,
+                upgrade_authority_address: Some(upgrade_authority_address),
+            })
+            .unwrap();
+        let mut program_metadata_account = AccountSharedData::new(
+            1,
+            UpgradeableLoaderState::size_of_program_metadata(),
+            &loader_id,
+        );
+        program_metadata_account
+            .set_state(&UpgradeableLoaderState::ProgramMetadata {
+                sysvar_dependencies: 0,
+            })
+            .unwrap();
+        let transaction_accounts = vec![
+            (program_metadata_address, program_metadata_account),
+            (program_address, program_account),
+            (programdata_address, programdata_account.clone()),
+            (upgrade_authority_address, upgrade_authority_account),
+        ];
+        let instruction_accounts = vec![
+            AccountMeta::new(program_metadata_address, false),
+            AccountMeta::new_readonly(program_address, false),
+            AccountMeta::new_readonly(programdata_address, false),
+            AccountMeta::new_readonly(upgrade_authority_address, true),
+        ];
+
+        // Case: Success
+        let accounts = process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Ok(()),
+        );
+        let state: UpgradeableLoaderState = accounts.first().unwrap().state().unwrap();
+        assert_eq!(
+            state,
+            UpgradeableLoaderState::ProgramMetadata {
+                sysvar_dependencies
+            }
+        );
+
+        // Case: Unknown sysvar in dependencies
+        process_instruction(
+            &loader_id,
+            &[],
+            &bincode::serialize(&UpgradeableLoaderInstruction::SetSysvarDependencies {
+                sysvar_dependencies: u64::MAX,
+            })
+            .unwrap(),
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Err(InstructionError::InvalidInstructionData),
+        );
+
+        // Case: Sysvar added by the second version of the bit table
+        let v2_instruction =
+            bincode::serialize(&UpgradeableLoaderInstruction::SetSysvarDependencies {
+                sysvar_dependencies: bpf_loader_upgradeable::sysvar_dependencies_from_ids(&[
+                    sysvar::header::id(),
+                ])
+                .unwrap(),
+            })
+            .unwrap();
+        process_instruction(
+            &loader_id,
+            &[],
+            &v2_instruction,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Ok(()),
+        );
+        mock_process_instruction(
+            &loader_id,
+            vec![],
+            &v2_instruction,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Err(InstructionError::InvalidInstructionData),
+            super::process_instruction,
+            |invoke_context| {
+                let mut feature_set = FeatureSet::all_enabled();
+                feature_set.deactivate(&sysvar_dependency_bits_v2::id());
+                invoke_context.feature_set = Arc::new(feature_set);
+            },
+            |_invoke_context| {},
+        );
+
+        // Case: Authority did not sign
+        let mut unsigned_instruction_accounts = instruction_accounts.clone();
+        unsigned_instruction_accounts[3].is_signer = false;
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            transaction_accounts.clone(),
+            unsigned_instruction_accounts,
+            Err(InstructionError::MissingRequiredSignature),
+        );
+
+        // Case: Incorrect authority provided
+        let mut incorrect_transaction_accounts = transaction_accounts.clone();
+        incorrect_transaction_accounts[3].0 = Pubkey::new_unique();
+        let mut incorrect_instruction_accounts = instruction_accounts.clone();
+        incorrect_instruction_accounts[3].pubkey = incorrect_transaction_accounts[3].0;
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            incorrect_transaction_accounts,
+            incorrect_instruction_accounts,
+            Err(InstructionError::IncorrectAuthority),
+        );
+
+        // Case: Program not upgradeable
+        let mut immutable_transaction_accounts = transaction_accounts.clone();
+        immutable_transaction_accounts[2]
+            .1
+            .set_state(&UpgradeableLoaderState::ProgramData {
+                slot: 0,
+                upgrade_authority_address: None,
+            })
+            .unwrap();
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            immutable_transaction_accounts,
+            instruction_accounts.clone(),
+            Err(InstructionError::Immutable),
+        );
+
+        // Case: ProgramData account does not match the Program account
+        let mut mismatched_transaction_accounts = transaction_accounts.clone();
+        mismatched_transaction_accounts[2].0 = Pubkey::new_unique();
+        let mut mismatched_instruction_accounts = instruction_accounts.clone();
+        mismatched_instruction_accounts[2].pubkey = mismatched_transaction_accounts[2].0;
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            mismatched_transaction_accounts,
+            mismatched_instruction_accounts,
+            Err(InstructionError::InvalidArgument),
+        );
+
+        // Case: ProgramMetadata address is not derived
+        let mut underived_transaction_accounts = transaction_accounts.clone();
+        underived_transaction_accounts[0].0 = Pubkey::new_unique();
+        let mut underived_instruction_accounts = instruction_accounts.clone();
+        underived_instruction_accounts[0].pubkey = underived_transaction_accounts[0].0;
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            underived_transaction_accounts,
+            underived_instruction_accounts,
+            Err(InstructionError::InvalidArgument),
+        );
+
+        // Case: Uninitialized ProgramMetadata account without a payer
+        let mut uninitialized_transaction_accounts = transaction_accounts.clone();
+        uninitialized_transaction_accounts[0].1 = AccountSharedData::default();
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            uninitialized_transaction_accounts,
+            instruction_accounts.clone(),
+            Err(InstructionError::NotEnoughAccountKeys),
+        );
+
+        // Case: Not a ProgramMetadata account
+        let mut invalid_transaction_accounts = transaction_accounts;
+        invalid_transaction_accounts[0].1 = programdata_account;
+        process_instruction(
+            &loader_id,
+            &[],
+            &instruction,
+            invalid_transaction_accounts,
+            instruction_accounts,
+            Err(InstructionError::InvalidAccountData),
+        );
+    }
+
     #[test]
     #[ignore]
     fn test_fuzz() {
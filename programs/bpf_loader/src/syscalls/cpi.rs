@@ -956,6 +956,11 @@ where
                     serialized_metadata,
                 )?;
 
+            // The scratch sysvar is passed as readonly, but any program can
+            // change its data, so the changes the callee makes are copied
+            // back to the caller like for writable accounts.
+            let is_scratch_sysvar = callee_account.is_scratch_sysvar();
+
             // before initiating CPI, the caller may have modified the
             // account (caller_account). We need to update the corresponding
             // BorrowedAccount (callee_account) so the callee can see the
@@ -969,7 +974,7 @@ where
                 direct_mapping,
             )?;
 
-            let caller_account = if instruction_account.is_writable {
+            let caller_account = if instruction_account.is_writable || is_scratch_sysvar {
                 Some(caller_account)
             } else {
                 None
@@ -2647,6 +2652,64 @@ mod tests {
         assert_eq!(caller_account.original_data_len, original_data_len);
     }
 
+    #[test]
+    fn test_translate_accounts_scratch_sysvar() {
+        let mut transaction_accounts = transaction_with_one_readonly_instruction_account(vec![]);
+        transaction_accounts[1] = (
+            sysvar::scratch::id(),
+            AccountSharedData::new(1, 4, &sysvar::id()),
+            false,
+        );
+        let (key, account, _) = transaction_accounts[1].clone();
+
+        let vm_addr = MM_INPUT_START;
+        let (_mem, region, account_metadata) =
+            MockAccountInfo::new(key, &account).into_region(vm_addr);
+
+        let config = Config {
+            aligned_memory_mapping: false,
+            ..Config::default()
+        };
+        let memory_mapping = MemoryMapping::new(vec![region], &config, &SBPFVersion::V2).unwrap();
+
+        mock_invoke_context!(
+            invoke_context,
+            transaction_context,
+            b"instruction data",
+            transaction_accounts,
+            &[0],
+            &[1]
+        );
+
+        mock_create_vm!(_vm, Vec::new(), vec![account_metadata], &mut invoke_context);
+
+        // The callee's changes to the readonly scratch sysvar are only copied
+        // back to the caller once it can be changed
+        for is_scratch_sysvar_enabled in [false, true] {
+            if is_scratch_sysvar_enabled {
+                invoke_context.transaction_context.enable_scratch_sysvar();
+            }
+            let accounts = SyscallInvokeSignedRust::translate_accounts(
+                &[InstructionAccount {
+                    index_in_transaction: 1,
+                    index_in_caller: 0,
+                    index_in_callee: 0,
+                    is_signer: false,
+                    is_writable: false,
+                }],
+                &[0],
+                vm_addr,
+                1,
+                false,
+                &memory_mapping,
+                &mut invoke_context,
+            )
+            .unwrap();
+            assert_eq!(accounts.len(), 2);
+            assert_eq!(accounts[1].1.is_some(), is_scratch_sysvar_enabled);
+        }
+    }
+
     pub type TestTransactionAccount = (Pubkey, AccountSharedData, bool);
     struct MockCallerAccount {
         lamports: u64,
@@ -113,6 +113,57 @@ declare_syscall!(
     }
 );
 
+/// Number of bytes `sol_memcmp_ct` compares for `mem_cmp_ct_base_cost`, the
+/// length of a signature
+const MEM_CMP_CT_BASE_LEN: u64 = 64;
+
+declare_syscall!(
+    /// Constant-time memory equality
+    SyscallMemcmpCt,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        s1_addr: u64,
+        s2_addr: u64,
+        n: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let compute_budget = invoke_context.get_compute_budget();
+        let cost = compute_budget.mem_cmp_ct_base_cost.saturating_add(
+            n.saturating_sub(MEM_CMP_CT_BASE_LEN)
+                .checked_div(compute_budget.cpi_bytes_per_unit)
+                .unwrap_or(u64::MAX),
+        );
+        consume_compute_meter(invoke_context, cost)?;
+
+        let diff = if invoke_context
+            .feature_set
+            .is_active(&feature_set::bpf_account_data_direct_mapping::id())
+        {
+            memcmp_ct_non_contiguous(s1_addr, s2_addr, n, memory_mapping)?
+        } else {
+            let s1 = translate_slice::<u8>(
+                memory_mapping,
+                s1_addr,
+                n,
+                invoke_context.get_check_aligned(),
+                invoke_context.get_check_size(),
+            )?;
+            let s2 = translate_slice::<u8>(
+                memory_mapping,
+                s2_addr,
+                n,
+                invoke_context.get_check_aligned(),
+                invoke_context.get_check_size(),
+            )?;
+            memcmp_ct(s1, s2)
+        };
+
+        Ok(u64::from(diff != 0))
+    }
+);
+
 declare_syscall!(
     /// memset
     SyscallMemset,
@@ -216,6 +267,46 @@ unsafe fn memcmp(s1: &[u8], s2: &[u8], n: usize) -> i32 {
     0
 }
 
+/// OR of the XOR of every pair of bytes of `s1` and `s2`, zero if and only if
+/// they are equal. Every byte is visited, regardless of where the slices
+/// differ.
+fn memcmp_ct(s1: &[u8], s2: &[u8]) -> u8 {
+    let diff = s1.iter().zip(s2).fold(0u8, |diff, (a, b)| diff | (a ^ b));
+    std::hint::black_box(diff)
+}
+
+fn memcmp_ct_non_contiguous(
+    s1_addr: u64,
+    s2_addr: u64,
+    n: u64,
+    memory_mapping: &MemoryMapping,
+) -> Result<u8, Error> {
+    let mut diff = 0u8;
+    iter_memory_pair_chunks(
+        AccessType::Load,
+        s1_addr,
+        AccessType::Load,
+        s2_addr,
+        n,
+        memory_mapping,
+        false,
+        |s1_addr, s2_addr, chunk_len| {
+            // Safety:
+            // iter_memory_pair_chunks finds same length chunks across the two
+            // memory regions, so both chunks are exactly chunk_len long.
+            let (s1, s2) = unsafe {
+                (
+                    slice::from_raw_parts(s1_addr, chunk_len),
+                    slice::from_raw_parts(s2_addr, chunk_len),
+                )
+            };
+            diff |= memcmp_ct(s1, s2);
+            Ok(0)
+        },
+    )?;
+    Ok(diff)
+}
+
 fn memcmp_non_contiguous(
     src_addr: u64,
     dst_addr: u64,
@@ -924,4 +1015,52 @@ mod tests {
             unsafe { memcmp(b"oobar", b"obarb", 5) }
         );
     }
+
+    #[test]
+    fn test_memcmp_ct() {
+        assert_eq!(memcmp_ct(&[], &[]), 0);
+        assert_eq!(memcmp_ct(&[7; 64], &[7; 64]), 0);
+        for index in 0..64 {
+            let mut s2 = [7; 64];
+            s2[index] = 8;
+            assert_ne!(memcmp_ct(&[7; 64], &s2), 0);
+        }
+    }
+
+    #[test]
+    fn test_memcmp_ct_non_contiguous() {
+        let config = Config {
+            aligned_memory_mapping: false,
+            ..Config::default()
+        };
+        let mem1 = b"foo".to_vec();
+        let mem2 = b"barbad".to_vec();
+        let mem3 = b"foobarbad".to_vec();
+        let memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion::new_readonly(&mem1, MM_PROGRAM_START),
+                MemoryRegion::new_readonly(&mem2, MM_PROGRAM_START + 3),
+                MemoryRegion::new_readonly(&mem3, MM_PROGRAM_START + 9),
+            ],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        assert_eq!(
+            memcmp_ct_non_contiguous(MM_PROGRAM_START, MM_PROGRAM_START + 9, 9, &memory_mapping)
+                .unwrap(),
+            0
+        );
+        assert_ne!(
+            memcmp_ct_non_contiguous(
+                MM_PROGRAM_START + 1,
+                MM_PROGRAM_START + 11,
+                5,
+                &memory_mapping
+            )
+            .unwrap(),
+            0
+        );
+    }
 }
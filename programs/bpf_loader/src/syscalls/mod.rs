@@ -3,10 +3,11 @@ pub use self::{
     logging::{
         SyscallLog, SyscallLogBpfComputeUnits, SyscallLogData, SyscallLogPubkey, SyscallLogU64,
     },
-    mem_ops::{SyscallMemcmp, SyscallMemcpy, SyscallMemmove, SyscallMemset},
+    mem_ops::{SyscallMemcmp, SyscallMemcmpCt, SyscallMemcpy, SyscallMemmove, SyscallMemset},
     sysvar::{
         SyscallGetClockSysvar, SyscallGetEpochRewardsSysvar, SyscallGetEpochScheduleSysvar,
-        SyscallGetFeesSysvar, SyscallGetLastRestartSlotSysvar, SyscallGetRentSysvar,
+        SyscallGetFeesSysvar, SyscallGetInstructionsSysvarSlice, SyscallGetLastRestartSlotSysvar,
+        SyscallGetRentSysvar,
     },
 };
 #[allow(deprecated)]
@@ -28,8 +29,9 @@ use {
             ALT_BN128_ADDITION_OUTPUT_LEN, ALT_BN128_MULTIPLICATION_OUTPUT_LEN,
             ALT_BN128_PAIRING_ELEMENT_LEN, ALT_BN128_PAIRING_OUTPUT_LEN,
         },
+        base58::{self, Base58Error, MAX_DECODE_INPUT_LEN, MAX_ENCODE_INPUT_LEN},
         big_mod_exp::{big_mod_exp, BigModExpParams},
-        blake3, bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable,
+        blake3, bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, ed25519_program,
         entrypoint::{BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
         feature_set::bpf_account_data_direct_mapping,
         feature_set::FeatureSet,
@@ -37,27 +39,39 @@ use {
             self, blake3_syscall_enabled, curve25519_syscall_enabled,
             disable_cpi_setting_executable_and_rent_epoch, disable_deploy_of_alloc_free_syscall,
             disable_fees_sysvar, enable_alt_bn128_compression_syscall, enable_alt_bn128_syscall,
-            enable_big_mod_exp_syscall, enable_early_verification_of_account_modifications,
-            enable_partitioned_epoch_reward, enable_poseidon_syscall,
-            error_on_syscall_bpf_function_hash_collisions, last_restart_slot_sysvar,
+            enable_base58_syscalls, enable_big_mod_exp_syscall,
+            enable_early_verification_of_account_modifications,
+            enable_get_serialized_message_syscall, enable_get_transaction_nonce_info_syscall,
+            enable_get_transaction_signature_syscall, enable_partitioned_epoch_reward,
+            enable_poseidon_syscall, enable_signatures_sysvar, enable_sol_ed25519_verify_syscall,
+            enable_sol_is_verified_syscall, enable_sol_memcmp_ct_syscall,
+            enable_tx_scratch_syscalls, error_on_syscall_bpf_function_hash_collisions,
+            instructions_sysvar_slice_syscall_enabled, last_restart_slot_sysvar,
             libsecp256k1_0_5_upgrade_enabled, reject_callx_r10,
-            remaining_compute_units_syscall_enabled, stop_sibling_instruction_search_at_parent,
-            stop_truncating_strings_in_syscalls, switch_to_new_elf_parser,
+            remaining_compute_units_syscall_enabled,
+            sibling_instruction_provenance_syscall_enabled,
+            sibling_instruction_sysvar_access_syscall_enabled,
+            stop_sibling_instruction_search_at_parent, stop_truncating_strings_in_syscalls,
+            switch_to_new_elf_parser,
         },
-        hash::{Hasher, HASH_BYTES},
+        hash::{hash, Hash, Hasher, HASH_BYTES},
         instruction::{
             AccountMeta, InstructionError, ProcessedSiblingInstruction,
+            ProcessedSiblingInstructionProvenance, ProcessedSiblingInstructionSysvarAccess,
             TRANSACTION_LEVEL_STACK_HEIGHT,
         },
-        keccak, native_loader, poseidon,
-        precompiles::is_precompile,
-        program::MAX_RETURN_DATA,
+        keccak, native_loader,
+        nonce::TransactionNonceInfo,
+        poseidon,
+        precompiles::{is_precompile, verified_signature_key},
+        program::{MAX_RETURN_DATA, MAX_TX_SCRATCH_LEN},
         program_stubs::is_nonoverlapping,
         pubkey::{Pubkey, PubkeyError, MAX_SEEDS, MAX_SEED_LEN},
         secp256k1_recover::{
             Secp256k1RecoverError, SECP256K1_PUBLIC_KEY_LENGTH, SECP256K1_SIGNATURE_LENGTH,
         },
-        sysvar::{Sysvar, SysvarId},
+        signature::{Signature, SIGNATURE_BYTES},
+        sysvar::{self, instructions, Sysvar, SysvarId},
         transaction_context::{IndexOfAccount, InstructionAccount},
     },
     std::{
@@ -107,6 +121,8 @@ pub enum SyscallError {
     CopyOverlapping,
     #[error("Return data too large ({0} > {1})")]
     ReturnDataTooLarge(u64, u64),
+    #[error("Transaction scratch data too large ({0} > {1})")]
+    TxScratchTooLarge(u64, u64),
     #[error("Hashing too many sequences")]
     TooManySlices,
     #[error("InvalidLength")]
@@ -169,6 +185,26 @@ pub fn create_program_runtime_environment_v1<'a>(
     let enable_poseidon_syscall = feature_set.is_active(&enable_poseidon_syscall::id());
     let remaining_compute_units_syscall_enabled =
         feature_set.is_active(&remaining_compute_units_syscall_enabled::id());
+    let enable_base58_syscalls = feature_set.is_active(&enable_base58_syscalls::id());
+    let sibling_instruction_provenance_syscall_enabled =
+        feature_set.is_active(&sibling_instruction_provenance_syscall_enabled::id());
+    let sibling_instruction_sysvar_access_syscall_enabled =
+        feature_set.is_active(&sibling_instruction_sysvar_access_syscall_enabled::id());
+    let instructions_sysvar_slice_syscall_enabled =
+        feature_set.is_active(&instructions_sysvar_slice_syscall_enabled::id());
+    let enable_sol_memcmp_ct_syscall = feature_set.is_active(&enable_sol_memcmp_ct_syscall::id());
+    let enable_tx_scratch_syscalls = feature_set.is_active(&enable_tx_scratch_syscalls::id());
+    let enable_sol_is_verified_syscall =
+        feature_set.is_active(&enable_sol_is_verified_syscall::id());
+    let enable_sol_ed25519_verify_syscall =
+        feature_set.is_active(&enable_sol_ed25519_verify_syscall::id());
+    let enable_get_transaction_nonce_info_syscall =
+        feature_set.is_active(&enable_get_transaction_nonce_info_syscall::id());
+    let enable_get_serialized_message_syscall =
+        feature_set.is_active(&enable_get_serialized_message_syscall::id());
+    let enable_get_transaction_signature_syscall = feature_set
+        .is_active(&enable_get_transaction_signature_syscall::id())
+        && feature_set.is_active(&enable_signatures_sysvar::id());
     // !!! ATTENTION !!!
     // When adding new features for RBPF here,
     // also add them to `Bank::apply_builtin_program_feature_transitions()`.
@@ -285,17 +321,62 @@ pub fn create_program_runtime_environment_v1<'a>(
         SyscallGetEpochRewardsSysvar::call,
     )?;
 
+    register_feature_gated_function!(
+        result,
+        instructions_sysvar_slice_syscall_enabled,
+        *b"sol_get_instructions_sysvar_slice",
+        SyscallGetInstructionsSysvarSlice::call,
+    )?;
+
     // Memory ops
     result.register_function_hashed(*b"sol_memcpy_", SyscallMemcpy::call)?;
     result.register_function_hashed(*b"sol_memmove_", SyscallMemmove::call)?;
     result.register_function_hashed(*b"sol_memcmp_", SyscallMemcmp::call)?;
     result.register_function_hashed(*b"sol_memset_", SyscallMemset::call)?;
+    register_feature_gated_function!(
+        result,
+        enable_sol_memcmp_ct_syscall,
+        *b"sol_memcmp_ct_",
+        SyscallMemcmpCt::call,
+    )?;
 
     // Processed sibling instructions
     result.register_function_hashed(
         *b"sol_get_processed_sibling_instruction",
         SyscallGetProcessedSiblingInstruction::call,
     )?;
+    register_feature_gated_function!(
+        result,
+        sibling_instruction_provenance_syscall_enabled,
+        *b"sol_get_processed_sibling_instruction_provenance",
+        SyscallGetProcessedSiblingInstructionProvenance::call,
+    )?;
+    register_feature_gated_function!(
+        result,
+        sibling_instruction_sysvar_access_syscall_enabled,
+        *b"sol_get_processed_sibling_instruction_sysvar_access",
+        SyscallGetProcessedSiblingInstructionSysvarAccess::call,
+    )?;
+
+    // Transaction signatures
+    register_feature_gated_function!(
+        result,
+        enable_get_transaction_signature_syscall,
+        *b"sol_get_transaction_signature",
+        SyscallGetTransactionSignature::call,
+    )?;
+    register_feature_gated_function!(
+        result,
+        enable_get_transaction_nonce_info_syscall,
+        *b"sol_get_transaction_nonce_info",
+        SyscallGetTransactionNonceInfo::call,
+    )?;
+    register_feature_gated_function!(
+        result,
+        enable_get_serialized_message_syscall,
+        *b"sol_get_serialized_message",
+        SyscallGetSerializedMessage::call,
+    )?;
 
     // Stack height
     result.register_function_hashed(*b"sol_get_stack_height", SyscallGetStackHeight::call)?;
@@ -304,6 +385,36 @@ pub fn create_program_runtime_environment_v1<'a>(
     result.register_function_hashed(*b"sol_set_return_data", SyscallSetReturnData::call)?;
     result.register_function_hashed(*b"sol_get_return_data", SyscallGetReturnData::call)?;
 
+    // Transaction scratch
+    register_feature_gated_function!(
+        result,
+        enable_tx_scratch_syscalls,
+        *b"sol_tx_scratch_write",
+        SyscallTxScratchWrite::call,
+    )?;
+    register_feature_gated_function!(
+        result,
+        enable_tx_scratch_syscalls,
+        *b"sol_tx_scratch_read",
+        SyscallTxScratchRead::call,
+    )?;
+
+    // Precompile verification results
+    register_feature_gated_function!(
+        result,
+        enable_sol_is_verified_syscall,
+        *b"sol_is_verified",
+        SyscallIsVerified::call,
+    )?;
+
+    // Ed25519 signature verification
+    register_feature_gated_function!(
+        result,
+        enable_sol_ed25519_verify_syscall,
+        *b"sol_ed25519_verify",
+        SyscallEd25519Verify::call,
+    )?;
+
     // Cross-program invocation
     result.register_function_hashed(*b"sol_invoke_signed_c", SyscallInvokeSignedC::call)?;
     result.register_function_hashed(*b"sol_invoke_signed_rust", SyscallInvokeSignedRust::call)?;
@@ -356,6 +467,20 @@ pub fn create_program_runtime_environment_v1<'a>(
         SyscallAltBn128Compression::call,
     )?;
 
+    // Base58
+    register_feature_gated_function!(
+        result,
+        enable_base58_syscalls,
+        *b"sol_base58_encode",
+        SyscallBase58Encode::call,
+    )?;
+    register_feature_gated_function!(
+        result,
+        enable_base58_syscalls,
+        *b"sol_base58_decode",
+        SyscallBase58Decode::call,
+    )?;
+
     // Log data
     result.register_function_hashed(*b"sol_log_data", SyscallLogData::call)?;
 
@@ -1495,466 +1620,930 @@ declare_syscall!(
 );
 
 declare_syscall!(
-    /// Get a processed sigling instruction
-    SyscallGetProcessedSiblingInstruction,
+    /// Write the transaction scratch buffer of the current program
+    SyscallTxScratchWrite,
     fn inner_call(
         invoke_context: &mut InvokeContext,
-        index: u64,
-        meta_addr: u64,
-        program_id_addr: u64,
-        data_addr: u64,
-        accounts_addr: u64,
+        addr: u64,
+        len: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
         memory_mapping: &mut MemoryMapping,
     ) -> Result<u64, Error> {
         let budget = invoke_context.get_compute_budget();
 
-        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
-        let stop_sibling_instruction_search_at_parent = invoke_context
-            .feature_set
-            .is_active(&stop_sibling_instruction_search_at_parent::id());
+        let cost = len
+            .checked_div(budget.cpi_bytes_per_unit)
+            .unwrap_or(u64::MAX)
+            .saturating_add(budget.syscall_base_cost);
+        consume_compute_meter(invoke_context, cost)?;
 
-        // Reverse iterate through the instruction trace,
-        // ignoring anything except instructions on the same level
-        let stack_height = invoke_context.get_stack_height();
-        let instruction_trace_length = invoke_context
-            .transaction_context
-            .get_instruction_trace_length();
-        let mut reverse_index_at_stack_height = 0;
-        let mut found_instruction_context = None;
-        for index_in_trace in (0..instruction_trace_length).rev() {
-            let instruction_context = invoke_context
-                .transaction_context
-                .get_instruction_context_at_index_in_trace(index_in_trace)?;
-            if (stop_sibling_instruction_search_at_parent
-                || instruction_context.get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT)
-                && instruction_context.get_stack_height() < stack_height
-            {
-                break;
-            }
-            if instruction_context.get_stack_height() == stack_height {
-                if index.saturating_add(1) == reverse_index_at_stack_height {
-                    found_instruction_context = Some(instruction_context);
-                    break;
-                }
-                reverse_index_at_stack_height = reverse_index_at_stack_height.saturating_add(1);
-            }
+        if len > MAX_TX_SCRATCH_LEN as u64 {
+            return Err(SyscallError::TxScratchTooLarge(len, MAX_TX_SCRATCH_LEN as u64).into());
         }
 
-        if let Some(instruction_context) = found_instruction_context {
-            let result_header = translate_type_mut::<ProcessedSiblingInstruction>(
+        let data = if len == 0 {
+            Vec::new()
+        } else {
+            translate_slice::<u8>(
                 memory_mapping,
-                meta_addr,
+                addr,
+                len,
                 invoke_context.get_check_aligned(),
-            )?;
-
-            if result_header.data_len == (instruction_context.get_instruction_data().len() as u64)
-                && result_header.accounts_len
-                    == (instruction_context.get_number_of_instruction_accounts() as u64)
-            {
-                let program_id = translate_type_mut::<Pubkey>(
-                    memory_mapping,
-                    program_id_addr,
-                    invoke_context.get_check_aligned(),
-                )?;
-                let data = translate_slice_mut::<u8>(
-                    memory_mapping,
-                    data_addr,
-                    result_header.data_len,
-                    invoke_context.get_check_aligned(),
-                    invoke_context.get_check_size(),
-                )?;
-                let accounts = translate_slice_mut::<AccountMeta>(
-                    memory_mapping,
-                    accounts_addr,
-                    result_header.accounts_len,
-                    invoke_context.get_check_aligned(),
-                    invoke_context.get_check_size(),
-                )?;
+                invoke_context.get_check_size(),
+            )?
+            .to_vec()
+        };
+        let transaction_context = &invoke_context.transaction_context;
+        let program_id = *transaction_context
+            .get_current_instruction_context()
+            .and_then(|instruction_context| {
+                instruction_context.get_last_program_key(transaction_context)
+            })?;
 
-                if !is_nonoverlapping(
-                    result_header as *const _ as usize,
-                    std::mem::size_of::<ProcessedSiblingInstruction>(),
-                    program_id as *const _ as usize,
-                    std::mem::size_of::<Pubkey>(),
-                ) || !is_nonoverlapping(
-                    result_header as *const _ as usize,
-                    std::mem::size_of::<ProcessedSiblingInstruction>(),
-                    accounts.as_ptr() as usize,
-                    std::mem::size_of::<AccountMeta>()
-                        .saturating_mul(result_header.accounts_len as usize),
-                ) || !is_nonoverlapping(
-                    result_header as *const _ as usize,
-                    std::mem::size_of::<ProcessedSiblingInstruction>(),
-                    data.as_ptr() as usize,
-                    result_header.data_len as usize,
-                ) || !is_nonoverlapping(
-                    program_id as *const _ as usize,
-                    std::mem::size_of::<Pubkey>(),
-                    data.as_ptr() as usize,
-                    result_header.data_len as usize,
-                ) || !is_nonoverlapping(
-                    program_id as *const _ as usize,
-                    std::mem::size_of::<Pubkey>(),
-                    accounts.as_ptr() as usize,
-                    std::mem::size_of::<AccountMeta>()
-                        .saturating_mul(result_header.accounts_len as usize),
-                ) || !is_nonoverlapping(
-                    data.as_ptr() as usize,
-                    result_header.data_len as usize,
-                    accounts.as_ptr() as usize,
-                    std::mem::size_of::<AccountMeta>()
-                        .saturating_mul(result_header.accounts_len as usize),
-                ) {
-                    return Err(SyscallError::CopyOverlapping.into());
-                }
+        invoke_context.set_tx_scratch(program_id, data);
 
-                *program_id = *instruction_context
-                    .get_last_program_key(invoke_context.transaction_context)?;
-                data.clone_from_slice(instruction_context.get_instruction_data());
-                let account_metas = (0..instruction_context.get_number_of_instruction_accounts())
-                    .map(|instruction_account_index| {
-                        Ok(AccountMeta {
-                            pubkey: *invoke_context
-                                .transaction_context
-                                .get_key_of_account_at_index(
-                                    instruction_context
-                                        .get_index_of_instruction_account_in_transaction(
-                                            instruction_account_index,
-                                        )?,
-                                )?,
-                            is_signer: instruction_context
-                                .is_instruction_account_signer(instruction_account_index)?,
-                            is_writable: instruction_context
-                                .is_instruction_account_writable(instruction_account_index)?,
-                        })
-                    })
-                    .collect::<Result<Vec<_>, InstructionError>>()?;
-                accounts.clone_from_slice(account_metas.as_slice());
-            }
-            result_header.data_len = instruction_context.get_instruction_data().len() as u64;
-            result_header.accounts_len =
-                instruction_context.get_number_of_instruction_accounts() as u64;
-            return Ok(true as u64);
-        }
-        Ok(false as u64)
+        Ok(0)
     }
 );
 
 declare_syscall!(
-    /// Get current call stack height
-    SyscallGetStackHeight,
+    /// Read the transaction scratch buffer of a program
+    SyscallTxScratchRead,
     fn inner_call(
         invoke_context: &mut InvokeContext,
-        _arg1: u64,
-        _arg2: u64,
-        _arg3: u64,
+        program_id_addr: u64,
+        data_addr: u64,
+        length: u64,
         _arg4: u64,
         _arg5: u64,
-        _memory_mapping: &mut MemoryMapping,
+        memory_mapping: &mut MemoryMapping,
     ) -> Result<u64, Error> {
         let budget = invoke_context.get_compute_budget();
 
         consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
 
-        Ok(invoke_context.get_stack_height() as u64)
+        let program_id = *translate_type::<Pubkey>(
+            memory_mapping,
+            program_id_addr,
+            invoke_context.get_check_aligned(),
+        )?;
+        let scratch = invoke_context.get_tx_scratch(&program_id);
+        let scratch_len = scratch.len() as u64;
+        let length = length.min(scratch_len);
+        if length != 0 {
+            let cost = length
+                .checked_div(budget.cpi_bytes_per_unit)
+                .unwrap_or(u64::MAX);
+            consume_compute_meter(invoke_context, cost)?;
+
+            let to_slice = translate_slice_mut::<u8>(
+                memory_mapping,
+                data_addr,
+                length,
+                invoke_context.get_check_aligned(),
+                invoke_context.get_check_size(),
+            )?;
+            let from_slice = scratch
+                .get(..length as usize)
+                .ok_or(SyscallError::InvalidLength)?;
+            to_slice.copy_from_slice(from_slice);
+        }
+
+        // Return the actual length, rather than the length copied
+        Ok(scratch_len)
     }
 );
 
 declare_syscall!(
-    /// alt_bn128 group operations
-    SyscallAltBn128,
+    /// Get a signature of the transaction
+    SyscallGetTransactionSignature,
     fn inner_call(
         invoke_context: &mut InvokeContext,
-        group_op: u64,
-        input_addr: u64,
-        input_size: u64,
-        result_addr: u64,
+        index: u64,
+        signature_addr: u64,
+        _arg3: u64,
+        _arg4: u64,
         _arg5: u64,
         memory_mapping: &mut MemoryMapping,
     ) -> Result<u64, Error> {
-        use solana_sdk::alt_bn128::prelude::{ALT_BN128_ADD, ALT_BN128_MUL, ALT_BN128_PAIRING};
         let budget = invoke_context.get_compute_budget();
-        let (cost, output): (u64, usize) = match group_op {
-            ALT_BN128_ADD => (
-                budget.alt_bn128_addition_cost,
-                ALT_BN128_ADDITION_OUTPUT_LEN,
-            ),
-            ALT_BN128_MUL => (
-                budget.alt_bn128_multiplication_cost,
-                ALT_BN128_MULTIPLICATION_OUTPUT_LEN,
-            ),
-            ALT_BN128_PAIRING => {
-                let ele_len = input_size
-                    .checked_div(ALT_BN128_PAIRING_ELEMENT_LEN as u64)
-                    .expect("div by non-zero constant");
-                let cost = budget
-                    .alt_bn128_pairing_one_pair_cost_first
-                    .saturating_add(
-                        budget
-                            .alt_bn128_pairing_one_pair_cost_other
-                            .saturating_mul(ele_len.saturating_sub(1)),
-                    )
-                    .saturating_add(budget.sha256_base_cost)
-                    .saturating_add(input_size)
-                    .saturating_add(ALT_BN128_PAIRING_OUTPUT_LEN as u64);
-                (cost, ALT_BN128_PAIRING_OUTPUT_LEN)
-            }
-            _ => {
-                return Err(SyscallError::InvalidAttribute.into());
-            }
-        };
+        let copy_cost = budget.transaction_signature_copy_cost;
 
-        consume_compute_meter(invoke_context, cost)?;
+        consume_compute_meter(invoke_context, budget.transaction_signature_base_cost)?;
 
-        let input = translate_slice::<u8>(
+        let Some(signature) = usize::try_from(index)
+            .ok()
+            .and_then(|index| invoke_context.get_transaction_signatures().get(index))
+            .copied()
+        else {
+            return Ok(0);
+        };
+        consume_compute_meter(invoke_context, copy_cost)?;
+        let signature_slice = translate_slice_mut::<u8>(
             memory_mapping,
-            input_addr,
-            input_size,
+            signature_addr,
+            SIGNATURE_BYTES as u64,
             invoke_context.get_check_aligned(),
             invoke_context.get_check_size(),
         )?;
+        signature_slice.copy_from_slice(signature.as_ref());
+        Ok(1)
+    }
+);
 
-        let call_result = translate_slice_mut::<u8>(
-            memory_mapping,
-            result_addr,
-            output as u64,
-            invoke_context.get_check_aligned(),
-            invoke_context.get_check_size(),
-        )?;
-
-        let calculation = match group_op {
-            ALT_BN128_ADD => alt_bn128_addition,
-            ALT_BN128_MUL => alt_bn128_multiplication,
-            ALT_BN128_PAIRING => alt_bn128_pairing,
-            _ => {
-                return Err(SyscallError::InvalidAttribute.into());
-            }
-        };
-
-        let result_point = match calculation(input) {
-            Ok(result_point) => result_point,
-            Err(e) => {
-                return Ok(e.into());
-            }
-        };
+declare_syscall!(
+    /// Get the durable nonce consumed by the transaction
+    SyscallGetTransactionNonceInfo,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        nonce_info_addr: u64,
+        _arg2: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let budget = invoke_context.get_compute_budget();
 
-        if result_point.len() != output {
-            return Ok(AltBn128Error::SliceOutOfBounds.into());
-        }
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
 
-        call_result.copy_from_slice(&result_point);
-        Ok(SUCCESS)
+        let Some(nonce_info) = invoke_context.get_transaction_nonce_info().copied() else {
+            return Ok(0);
+        };
+        *translate_type_mut::<TransactionNonceInfo>(
+            memory_mapping,
+            nonce_info_addr,
+            invoke_context.get_check_aligned(),
+        )? = nonce_info;
+        Ok(1)
     }
 );
 
 declare_syscall!(
-    /// Big integer modular exponentiation
-    SyscallBigModExp,
+    /// Get the serialized message of the transaction
+    SyscallGetSerializedMessage,
     fn inner_call(
         invoke_context: &mut InvokeContext,
-        params: u64,
-        return_value: u64,
+        data_addr: u64,
+        length: u64,
         _arg3: u64,
         _arg4: u64,
         _arg5: u64,
         memory_mapping: &mut MemoryMapping,
     ) -> Result<u64, Error> {
-        let params = &translate_slice::<BigModExpParams>(
-            memory_mapping,
-            params,
-            1,
-            invoke_context.get_check_aligned(),
-            invoke_context.get_check_size(),
-        )?
-        .get(0)
-        .ok_or(SyscallError::InvalidLength)?;
+        let budget = invoke_context.get_compute_budget();
 
-        if params.base_len > 512 || params.exponent_len > 512 || params.modulus_len > 512 {
-            return Err(Box::new(SyscallError::InvalidLength));
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        let message_len = invoke_context.get_transaction_message_data().len() as u64;
+        let length = length.min(message_len);
+        if length != 0 {
+            let cost = length
+                .checked_div(budget.cpi_bytes_per_unit)
+                .unwrap_or(u64::MAX);
+            consume_compute_meter(invoke_context, cost)?;
+
+            let to_slice = translate_slice_mut::<u8>(
+                memory_mapping,
+                data_addr,
+                length,
+                invoke_context.get_check_aligned(),
+                invoke_context.get_check_size(),
+            )?;
+            let from_slice = invoke_context
+                .get_transaction_message_data()
+                .get(..length as usize)
+                .ok_or(SyscallError::InvalidLength)?;
+            to_slice.copy_from_slice(from_slice);
         }
 
-        let input_len: u64 = std::cmp::max(params.base_len, params.exponent_len);
-        let input_len: u64 = std::cmp::max(input_len, params.modulus_len);
+        // Return the actual length, rather than the length copied
+        Ok(message_len)
+    }
+);
 
+declare_syscall!(
+    /// Whether a precompile instruction of the transaction verified a signature
+    SyscallIsVerified,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        precompile_id_addr: u64,
+        signature_addr: u64,
+        pubkey_addr: u64,
+        pubkey_len: u64,
+        message_hash_addr: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
         let budget = invoke_context.get_compute_budget();
-        consume_compute_meter(
-            invoke_context,
-            budget.syscall_base_cost.saturating_add(
-                input_len
-                    .saturating_mul(input_len)
-                    .checked_div(budget.big_modular_exponentiation_cost)
-                    .unwrap_or(u64::MAX),
-            ),
-        )?;
 
-        let base = translate_slice::<u8>(
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        if pubkey_len > size_of::<Pubkey>() as u64 {
+            return Err(SyscallError::InvalidLength.into());
+        }
+        let precompile_id = translate_type::<Pubkey>(
             memory_mapping,
-            params.base as *const _ as u64,
-            params.base_len,
+            precompile_id_addr,
             invoke_context.get_check_aligned(),
-            invoke_context.get_check_size(),
         )?;
-
-        let exponent = translate_slice::<u8>(
+        let signature = translate_slice::<u8>(
             memory_mapping,
-            params.exponent as *const _ as u64,
-            params.exponent_len,
+            signature_addr,
+            SECP256K1_SIGNATURE_LENGTH as u64,
             invoke_context.get_check_aligned(),
             invoke_context.get_check_size(),
         )?;
-
-        let modulus = translate_slice::<u8>(
+        let pubkey = translate_slice::<u8>(
             memory_mapping,
-            params.modulus as *const _ as u64,
-            params.modulus_len,
+            pubkey_addr,
+            pubkey_len,
             invoke_context.get_check_aligned(),
             invoke_context.get_check_size(),
         )?;
-
-        let value = big_mod_exp(base, exponent, modulus);
-
-        let return_value = translate_slice_mut::<u8>(
+        let message_hash = translate_type::<Hash>(
             memory_mapping,
-            return_value,
-            params.modulus_len,
+            message_hash_addr,
             invoke_context.get_check_aligned(),
-            invoke_context.get_check_size(),
         )?;
-        return_value.copy_from_slice(value.as_slice());
 
-        Ok(0)
+        let key = verified_signature_key(precompile_id, signature, pubkey, message_hash);
+        Ok(u64::from(invoke_context.is_signature_verified(&key)))
     }
 );
 
 declare_syscall!(
-    // Poseidon
-    SyscallPoseidon,
+    /// Verify an ed25519 signature of an arbitrary message
+    SyscallEd25519Verify,
     fn inner_call(
         invoke_context: &mut InvokeContext,
-        parameters: u64,
-        endianness: u64,
-        vals_addr: u64,
-        vals_len: u64,
-        result_addr: u64,
+        pubkey_addr: u64,
+        message_addr: u64,
+        message_len: u64,
+        signature_addr: u64,
+        _arg5: u64,
         memory_mapping: &mut MemoryMapping,
     ) -> Result<u64, Error> {
-        let parameters: poseidon::Parameters = parameters.try_into()?;
-        let endianness: poseidon::Endianness = endianness.try_into()?;
-
-        if vals_len > 12 {
-            ic_msg!(
-                invoke_context,
-                "Poseidon hashing {} sequences is not supported",
-                vals_len,
-            );
-            return Err(SyscallError::InvalidLength.into());
-        }
-
         let budget = invoke_context.get_compute_budget();
-        let Some(cost) = budget.poseidon_cost(vals_len) else {
-            ic_msg!(
-                invoke_context,
-                "Overflow while calculating the compute cost"
-            );
-            return Err(SyscallError::ArithmeticOverflow.into());
-        };
-        consume_compute_meter(invoke_context, cost.to_owned())?;
+        let hash_cost = budget
+            .sha256_byte_cost
+            .saturating_mul(message_len)
+            .saturating_add(budget.sha256_base_cost);
+        let verify_cost = budget.ed25519_verify_cost;
 
-        let hash_result = translate_slice_mut::<u8>(
+        consume_compute_meter(invoke_context, hash_cost)?;
+
+        let pubkey = translate_type::<Pubkey>(
             memory_mapping,
-            result_addr,
-            poseidon::HASH_BYTES as u64,
+            pubkey_addr,
+            invoke_context.get_check_aligned(),
+        )?;
+        let message = translate_slice::<u8>(
+            memory_mapping,
+            message_addr,
+            message_len,
             invoke_context.get_check_aligned(),
             invoke_context.get_check_size(),
         )?;
-        let inputs = translate_slice::<&[u8]>(
+        let signature = translate_slice::<u8>(
             memory_mapping,
-            vals_addr,
-            vals_len,
+            signature_addr,
+            SIGNATURE_BYTES as u64,
             invoke_context.get_check_aligned(),
             invoke_context.get_check_size(),
         )?;
-        let inputs = inputs
-            .iter()
-            .map(|input| {
-                translate_slice::<u8>(
-                    memory_mapping,
-                    input.as_ptr() as *const _ as u64,
-                    input.len() as u64,
-                    invoke_context.get_check_aligned(),
-                    invoke_context.get_check_size(),
-                )
-            })
-            .collect::<Result<Vec<_>, Error>>()?;
-        let hash = match poseidon::hashv(parameters, endianness, inputs.as_slice()) {
-            Ok(hash) => hash,
-            Err(e) => {
-                return Ok(e.into());
-            }
-        };
-        hash_result.copy_from_slice(&hash.to_bytes());
 
-        Ok(SUCCESS)
-    }
-);
+        // Signatures verified by an ed25519 program instruction of the
+        // transaction, or by an earlier call, aren't verified again
+        let key = verified_signature_key(
+            &ed25519_program::id(),
+            signature,
+            pubkey.as_ref(),
+            &hash(message),
+        );
+        if invoke_context.is_signature_verified(&key) {
+            return Ok(1);
+        }
 
-declare_syscall!(
-    /// Read remaining compute units
-    SyscallRemainingComputeUnits,
-    fn inner_call(
-        invoke_context: &mut InvokeContext,
-        _arg1: u64,
-        _arg2: u64,
-        _arg3: u64,
-        _arg4: u64,
-        _arg5: u64,
-        _memory_mapping: &mut MemoryMapping,
-    ) -> Result<u64, Error> {
-        let budget = invoke_context.get_compute_budget();
-        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+        consume_compute_meter(invoke_context, verify_cost)?;
 
-        use solana_rbpf::vm::ContextObject;
-        Ok(invoke_context.get_remaining())
+        let signature = Signature::from(<[u8; SIGNATURE_BYTES]>::try_from(signature).unwrap());
+        if !signature.verify(pubkey.as_ref(), message) {
+            return Ok(0);
+        }
+        invoke_context.record_verified_signature(key);
+        Ok(1)
     }
 );
 
+/// Returns the index in the instruction trace of the processed sibling
+/// instruction at `index`, see [`get_processed_sibling_instruction`].
+///
+/// [`get_processed_sibling_instruction`]: solana_sdk::instruction::get_processed_sibling_instruction
+fn find_processed_sibling_instruction(
+    invoke_context: &InvokeContext,
+    index: u64,
+) -> Result<Option<usize>, Error> {
+    let stop_sibling_instruction_search_at_parent = invoke_context
+        .feature_set
+        .is_active(&stop_sibling_instruction_search_at_parent::id());
+
+    // Reverse iterate through the instruction trace,
+    // ignoring anything except instructions on the same level
+    let stack_height = invoke_context.get_stack_height();
+    let instruction_trace_length = invoke_context
+        .transaction_context
+        .get_instruction_trace_length();
+    let mut reverse_index_at_stack_height = 0;
+    for index_in_trace in (0..instruction_trace_length).rev() {
+        let instruction_context = invoke_context
+            .transaction_context
+            .get_instruction_context_at_index_in_trace(index_in_trace)?;
+        if (stop_sibling_instruction_search_at_parent
+            || instruction_context.get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT)
+            && instruction_context.get_stack_height() < stack_height
+        {
+            break;
+        }
+        if instruction_context.get_stack_height() == stack_height {
+            if index.saturating_add(1) == reverse_index_at_stack_height {
+                return Ok(Some(index_in_trace));
+            }
+            reverse_index_at_stack_height = reverse_index_at_stack_height.saturating_add(1);
+        }
+    }
+    Ok(None)
+}
+
 declare_syscall!(
-    /// alt_bn128 g1 and g2 compression and decompression
-    SyscallAltBn128Compression,
+    /// Get a processed sigling instruction
+    SyscallGetProcessedSiblingInstruction,
     fn inner_call(
         invoke_context: &mut InvokeContext,
-        op: u64,
-        input_addr: u64,
-        input_size: u64,
-        result_addr: u64,
-        _arg5: u64,
+        index: u64,
+        meta_addr: u64,
+        program_id_addr: u64,
+        data_addr: u64,
+        accounts_addr: u64,
         memory_mapping: &mut MemoryMapping,
     ) -> Result<u64, Error> {
-        use solana_sdk::alt_bn128::compression::prelude::{
-            alt_bn128_g1_compress, alt_bn128_g1_decompress, alt_bn128_g2_compress,
-            alt_bn128_g2_decompress, ALT_BN128_G1_COMPRESS, ALT_BN128_G1_DECOMPRESS,
-            ALT_BN128_G2_COMPRESS, ALT_BN128_G2_DECOMPRESS, G1, G1_COMPRESSED, G2, G2_COMPRESSED,
-        };
         let budget = invoke_context.get_compute_budget();
-        let base_cost = budget.syscall_base_cost;
-        let (cost, output): (u64, usize) = match op {
-            ALT_BN128_G1_COMPRESS => (
-                base_cost.saturating_add(budget.alt_bn128_g1_compress),
-                G1_COMPRESSED,
-            ),
-            ALT_BN128_G1_DECOMPRESS => {
-                (base_cost.saturating_add(budget.alt_bn128_g1_decompress), G1)
-            }
-            ALT_BN128_G2_COMPRESS => (
-                base_cost.saturating_add(budget.alt_bn128_g2_compress),
-                G2_COMPRESSED,
-            ),
-            ALT_BN128_G2_DECOMPRESS => {
-                (base_cost.saturating_add(budget.alt_bn128_g2_decompress), G2)
-            }
-            _ => {
-                return Err(SyscallError::InvalidAttribute.into());
-            }
+
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        let found_instruction_context = find_processed_sibling_instruction(invoke_context, index)?
+            .map(|index_in_trace| {
+                invoke_context
+                    .transaction_context
+                    .get_instruction_context_at_index_in_trace(index_in_trace)
+            })
+            .transpose()?;
+
+        if let Some(instruction_context) = found_instruction_context {
+            let result_header = translate_type_mut::<ProcessedSiblingInstruction>(
+                memory_mapping,
+                meta_addr,
+                invoke_context.get_check_aligned(),
+            )?;
+
+            if result_header.data_len == (instruction_context.get_instruction_data().len() as u64)
+                && result_header.accounts_len
+                    == (instruction_context.get_number_of_instruction_accounts() as u64)
+            {
+                let program_id = translate_type_mut::<Pubkey>(
+                    memory_mapping,
+                    program_id_addr,
+                    invoke_context.get_check_aligned(),
+                )?;
+                let data = translate_slice_mut::<u8>(
+                    memory_mapping,
+                    data_addr,
+                    result_header.data_len,
+                    invoke_context.get_check_aligned(),
+                    invoke_context.get_check_size(),
+                )?;
+                let accounts = translate_slice_mut::<AccountMeta>(
+                    memory_mapping,
+                    accounts_addr,
+                    result_header.accounts_len,
+                    invoke_context.get_check_aligned(),
+                    invoke_context.get_check_size(),
+                )?;
+
+                if !is_nonoverlapping(
+                    result_header as *const _ as usize,
+                    std::mem::size_of::<ProcessedSiblingInstruction>(),
+                    program_id as *const _ as usize,
+                    std::mem::size_of::<Pubkey>(),
+                ) || !is_nonoverlapping(
+                    result_header as *const _ as usize,
+                    std::mem::size_of::<ProcessedSiblingInstruction>(),
+                    accounts.as_ptr() as usize,
+                    std::mem::size_of::<AccountMeta>()
+                        .saturating_mul(result_header.accounts_len as usize),
+                ) || !is_nonoverlapping(
+                    result_header as *const _ as usize,
+                    std::mem::size_of::<ProcessedSiblingInstruction>(),
+                    data.as_ptr() as usize,
+                    result_header.data_len as usize,
+                ) || !is_nonoverlapping(
+                    program_id as *const _ as usize,
+                    std::mem::size_of::<Pubkey>(),
+                    data.as_ptr() as usize,
+                    result_header.data_len as usize,
+                ) || !is_nonoverlapping(
+                    program_id as *const _ as usize,
+                    std::mem::size_of::<Pubkey>(),
+                    accounts.as_ptr() as usize,
+                    std::mem::size_of::<AccountMeta>()
+                        .saturating_mul(result_header.accounts_len as usize),
+                ) || !is_nonoverlapping(
+                    data.as_ptr() as usize,
+                    result_header.data_len as usize,
+                    accounts.as_ptr() as usize,
+                    std::mem::size_of::<AccountMeta>()
+                        .saturating_mul(result_header.accounts_len as usize),
+                ) {
+                    return Err(SyscallError::CopyOverlapping.into());
+                }
+
+                *program_id = *instruction_context
+                    .get_last_program_key(invoke_context.transaction_context)?;
+                data.clone_from_slice(instruction_context.get_instruction_data());
+                let account_metas = (0..instruction_context.get_number_of_instruction_accounts())
+                    .map(|instruction_account_index| {
+                        Ok(AccountMeta {
+                            pubkey: *invoke_context
+                                .transaction_context
+                                .get_key_of_account_at_index(
+                                    instruction_context
+                                        .get_index_of_instruction_account_in_transaction(
+                                            instruction_account_index,
+                                        )?,
+                                )?,
+                            is_signer: instruction_context
+                                .is_instruction_account_signer(instruction_account_index)?,
+                            is_writable: instruction_context
+                                .is_instruction_account_writable(instruction_account_index)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, InstructionError>>()?;
+                accounts.clone_from_slice(account_metas.as_slice());
+            }
+            result_header.data_len = instruction_context.get_instruction_data().len() as u64;
+            result_header.accounts_len =
+                instruction_context.get_number_of_instruction_accounts() as u64;
+            return Ok(true as u64);
+        }
+        Ok(false as u64)
+    }
+);
+
+declare_syscall!(
+    /// Get the provenance of a processed sibling instruction
+    SyscallGetProcessedSiblingInstructionProvenance,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        index: u64,
+        provenance_addr: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let budget = invoke_context.get_compute_budget();
+
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        let Some(sibling_index_in_trace) = find_processed_sibling_instruction(invoke_context, index)?
+        else {
+            return Ok(false as u64);
+        };
+        let transaction_context = &invoke_context.transaction_context;
+        let sibling_instruction_context =
+            transaction_context.get_instruction_context_at_index_in_trace(sibling_index_in_trace)?;
+
+        // The top-level instruction the sibling was processed under is the
+        // last one at or before it in the trace
+        let mut top_level_instruction =
+            transaction_context.get_instruction_context_at_index_in_trace(0)?;
+        let mut top_level_instruction_index = 0u64;
+        for index_in_trace in 1..=sibling_index_in_trace {
+            let instruction_context =
+                transaction_context.get_instruction_context_at_index_in_trace(index_in_trace)?;
+            if instruction_context.get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT {
+                top_level_instruction = instruction_context;
+                top_level_instruction_index = top_level_instruction_index.saturating_add(1);
+            }
+        }
+
+        // Signers of a top-level instruction are transaction signers, whose
+        // account index is the index of their signature
+        let mut signers = 0u64;
+        for instruction_account_index in
+            0..top_level_instruction.get_number_of_instruction_accounts()
+        {
+            if top_level_instruction.is_instruction_account_signer(instruction_account_index)? {
+                let index_in_transaction = top_level_instruction
+                    .get_index_of_instruction_account_in_transaction(instruction_account_index)?;
+                signers |= 1u64.checked_shl(index_in_transaction as u32).unwrap_or(0);
+            }
+        }
+
+        let provenance = translate_type_mut::<ProcessedSiblingInstructionProvenance>(
+            memory_mapping,
+            provenance_addr,
+            invoke_context.get_check_aligned(),
+        )?;
+        *provenance = ProcessedSiblingInstructionProvenance {
+            stack_height: sibling_instruction_context.get_stack_height() as u64,
+            top_level_instruction_index,
+            signers,
+        };
+        Ok(true as u64)
+    }
+);
+
+declare_syscall!(
+    /// Get the per-transaction sysvars accessible to a processed sibling
+    /// instruction
+    SyscallGetProcessedSiblingInstructionSysvarAccess,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        index: u64,
+        access_addr: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let budget = invoke_context.get_compute_budget();
+
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        let Some(sibling_index_in_trace) = find_processed_sibling_instruction(invoke_context, index)?
+        else {
+            return Ok(false as u64);
+        };
+        let transaction_context = &invoke_context.transaction_context;
+        let sibling_stack_height = transaction_context
+            .get_instruction_context_at_index_in_trace(sibling_index_in_trace)?
+            .get_stack_height();
+
+        // The sibling instruction is followed in the trace by the
+        // instructions it invoked, which are deeper in the stack
+        let mut sysvars = 0u64;
+        for index_in_trace in
+            sibling_index_in_trace..transaction_context.get_instruction_trace_length()
+        {
+            let instruction_context =
+                transaction_context.get_instruction_context_at_index_in_trace(index_in_trace)?;
+            if index_in_trace != sibling_index_in_trace
+                && instruction_context.get_stack_height() <= sibling_stack_height
+            {
+                break;
+            }
+            for instruction_account_index in
+                0..instruction_context.get_number_of_instruction_accounts()
+            {
+                let index_in_transaction = instruction_context
+                    .get_index_of_instruction_account_in_transaction(instruction_account_index)?;
+                let key = transaction_context.get_key_of_account_at_index(index_in_transaction)?;
+                if let Some(position) = sysvar::PER_TRANSACTION_IDS
+                    .iter()
+                    .position(|sysvar_id| sysvar_id == key)
+                {
+                    sysvars |= 1u64.checked_shl(position as u32).unwrap_or(0);
+                }
+            }
+        }
+
+        let access = translate_type_mut::<ProcessedSiblingInstructionSysvarAccess>(
+            memory_mapping,
+            access_addr,
+            invoke_context.get_check_aligned(),
+        )?;
+        *access = ProcessedSiblingInstructionSysvarAccess { sysvars };
+        Ok(true as u64)
+    }
+);
+
+declare_syscall!(
+    /// Get current call stack height
+    SyscallGetStackHeight,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        _arg1: u64,
+        _arg2: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        _memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let budget = invoke_context.get_compute_budget();
+
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        Ok(invoke_context.get_stack_height() as u64)
+    }
+);
+
+declare_syscall!(
+    /// alt_bn128 group operations
+    SyscallAltBn128,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        group_op: u64,
+        input_addr: u64,
+        input_size: u64,
+        result_addr: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        use solana_sdk::alt_bn128::prelude::{ALT_BN128_ADD, ALT_BN128_MUL, ALT_BN128_PAIRING};
+        let budget = invoke_context.get_compute_budget();
+        let (cost, output): (u64, usize) = match group_op {
+            ALT_BN128_ADD => (
+                budget.alt_bn128_addition_cost,
+                ALT_BN128_ADDITION_OUTPUT_LEN,
+            ),
+            ALT_BN128_MUL => (
+                budget.alt_bn128_multiplication_cost,
+                ALT_BN128_MULTIPLICATION_OUTPUT_LEN,
+            ),
+            ALT_BN128_PAIRING => {
+                let ele_len = input_size
+                    .checked_div(ALT_BN128_PAIRING_ELEMENT_LEN as u64)
+                    .expect("div by non-zero constant");
+                let cost = budget
+                    .alt_bn128_pairing_one_pair_cost_first
+                    .saturating_add(
+                        budget
+                            .alt_bn128_pairing_one_pair_cost_other
+                            .saturating_mul(ele_len.saturating_sub(1)),
+                    )
+                    .saturating_add(budget.sha256_base_cost)
+                    .saturating_add(input_size)
+                    .saturating_add(ALT_BN128_PAIRING_OUTPUT_LEN as u64);
+                (cost, ALT_BN128_PAIRING_OUTPUT_LEN)
+            }
+            _ => {
+                return Err(SyscallError::InvalidAttribute.into());
+            }
+        };
+
+        consume_compute_meter(invoke_context, cost)?;
+
+        let input = translate_slice::<u8>(
+            memory_mapping,
+            input_addr,
+            input_size,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+
+        let call_result = translate_slice_mut::<u8>(
+            memory_mapping,
+            result_addr,
+            output as u64,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+
+        let calculation = match group_op {
+            ALT_BN128_ADD => alt_bn128_addition,
+            ALT_BN128_MUL => alt_bn128_multiplication,
+            ALT_BN128_PAIRING => alt_bn128_pairing,
+            _ => {
+                return Err(SyscallError::InvalidAttribute.into());
+            }
+        };
+
+        let result_point = match calculation(input) {
+            Ok(result_point) => result_point,
+            Err(e) => {
+                return Ok(e.into());
+            }
+        };
+
+        if result_point.len() != output {
+            return Ok(AltBn128Error::SliceOutOfBounds.into());
+        }
+
+        call_result.copy_from_slice(&result_point);
+        Ok(SUCCESS)
+    }
+);
+
+declare_syscall!(
+    /// Big integer modular exponentiation
+    SyscallBigModExp,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        params: u64,
+        return_value: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let params = &translate_slice::<BigModExpParams>(
+            memory_mapping,
+            params,
+            1,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?
+        .get(0)
+        .ok_or(SyscallError::InvalidLength)?;
+
+        if params.base_len > 512 || params.exponent_len > 512 || params.modulus_len > 512 {
+            return Err(Box::new(SyscallError::InvalidLength));
+        }
+
+        let input_len: u64 = std::cmp::max(params.base_len, params.exponent_len);
+        let input_len: u64 = std::cmp::max(input_len, params.modulus_len);
+
+        let budget = invoke_context.get_compute_budget();
+        consume_compute_meter(
+            invoke_context,
+            budget.syscall_base_cost.saturating_add(
+                input_len
+                    .saturating_mul(input_len)
+                    .checked_div(budget.big_modular_exponentiation_cost)
+                    .unwrap_or(u64::MAX),
+            ),
+        )?;
+
+        let base = translate_slice::<u8>(
+            memory_mapping,
+            params.base as *const _ as u64,
+            params.base_len,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+
+        let exponent = translate_slice::<u8>(
+            memory_mapping,
+            params.exponent as *const _ as u64,
+            params.exponent_len,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+
+        let modulus = translate_slice::<u8>(
+            memory_mapping,
+            params.modulus as *const _ as u64,
+            params.modulus_len,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+
+        let value = big_mod_exp(base, exponent, modulus);
+
+        let return_value = translate_slice_mut::<u8>(
+            memory_mapping,
+            return_value,
+            params.modulus_len,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+        return_value.copy_from_slice(value.as_slice());
+
+        Ok(0)
+    }
+);
+
+declare_syscall!(
+    // Poseidon
+    SyscallPoseidon,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        parameters: u64,
+        endianness: u64,
+        vals_addr: u64,
+        vals_len: u64,
+        result_addr: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let parameters: poseidon::Parameters = parameters.try_into()?;
+        let endianness: poseidon::Endianness = endianness.try_into()?;
+
+        if vals_len > 12 {
+            ic_msg!(
+                invoke_context,
+                "Poseidon hashing {} sequences is not supported",
+                vals_len,
+            );
+            return Err(SyscallError::InvalidLength.into());
+        }
+
+        let budget = invoke_context.get_compute_budget();
+        let Some(cost) = budget.poseidon_cost(vals_len) else {
+            ic_msg!(
+                invoke_context,
+                "Overflow while calculating the compute cost"
+            );
+            return Err(SyscallError::ArithmeticOverflow.into());
+        };
+        consume_compute_meter(invoke_context, cost.to_owned())?;
+
+        let hash_result = translate_slice_mut::<u8>(
+            memory_mapping,
+            result_addr,
+            poseidon::HASH_BYTES as u64,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+        let inputs = translate_slice::<&[u8]>(
+            memory_mapping,
+            vals_addr,
+            vals_len,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+        let inputs = inputs
+            .iter()
+            .map(|input| {
+                translate_slice::<u8>(
+                    memory_mapping,
+                    input.as_ptr() as *const _ as u64,
+                    input.len() as u64,
+                    invoke_context.get_check_aligned(),
+                    invoke_context.get_check_size(),
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let hash = match poseidon::hashv(parameters, endianness, inputs.as_slice()) {
+            Ok(hash) => hash,
+            Err(e) => {
+                return Ok(e.into());
+            }
+        };
+        hash_result.copy_from_slice(&hash.to_bytes());
+
+        Ok(SUCCESS)
+    }
+);
+
+declare_syscall!(
+    /// Read remaining compute units
+    SyscallRemainingComputeUnits,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        _arg1: u64,
+        _arg2: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        _memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let budget = invoke_context.get_compute_budget();
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+
+        use solana_rbpf::vm::ContextObject;
+        Ok(invoke_context.get_remaining())
+    }
+);
+
+declare_syscall!(
+    /// alt_bn128 g1 and g2 compression and decompression
+    SyscallAltBn128Compression,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        op: u64,
+        input_addr: u64,
+        input_size: u64,
+        result_addr: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        use solana_sdk::alt_bn128::compression::prelude::{
+            alt_bn128_g1_compress, alt_bn128_g1_decompress, alt_bn128_g2_compress,
+            alt_bn128_g2_decompress, ALT_BN128_G1_COMPRESS, ALT_BN128_G1_DECOMPRESS,
+            ALT_BN128_G2_COMPRESS, ALT_BN128_G2_DECOMPRESS, G1, G1_COMPRESSED, G2, G2_COMPRESSED,
+        };
+        let budget = invoke_context.get_compute_budget();
+        let base_cost = budget.syscall_base_cost;
+        let (cost, output): (u64, usize) = match op {
+            ALT_BN128_G1_COMPRESS => (
+                base_cost.saturating_add(budget.alt_bn128_g1_compress),
+                G1_COMPRESSED,
+            ),
+            ALT_BN128_G1_DECOMPRESS => {
+                (base_cost.saturating_add(budget.alt_bn128_g1_decompress), G1)
+            }
+            ALT_BN128_G2_COMPRESS => (
+                base_cost.saturating_add(budget.alt_bn128_g2_compress),
+                G2_COMPRESSED,
+            ),
+            ALT_BN128_G2_DECOMPRESS => {
+                (base_cost.saturating_add(budget.alt_bn128_g2_decompress), G2)
+            }
+            _ => {
+                return Err(SyscallError::InvalidAttribute.into());
+            }
         };
 
         consume_compute_meter(invoke_context, cost)?;
@@ -2021,6 +2610,109 @@ declare_syscall!(
     }
 );
 
+#[allow(clippy::too_many_arguments)]
+fn base58_convert(
+    invoke_context: &mut InvokeContext,
+    convert: fn(&[u8], &mut [u8]) -> Result<usize, Base58Error>,
+    max_input_len: usize,
+    input_addr: u64,
+    input_len: u64,
+    output_addr: u64,
+    output_len: u64,
+    written_addr: u64,
+    memory_mapping: &mut MemoryMapping,
+) -> Result<u64, Error> {
+    let budget = invoke_context.get_compute_budget();
+    if input_len > max_input_len as u64 {
+        consume_compute_meter(invoke_context, budget.syscall_base_cost)?;
+        return Ok(Base58Error::InputTooLong.into());
+    }
+    let cost = budget
+        .syscall_base_cost
+        .saturating_add(budget.base58_byte_cost.saturating_mul(input_len));
+    consume_compute_meter(invoke_context, cost)?;
+
+    let input = translate_slice::<u8>(
+        memory_mapping,
+        input_addr,
+        input_len,
+        invoke_context.get_check_aligned(),
+        invoke_context.get_check_size(),
+    )?;
+    let output = translate_slice_mut::<u8>(
+        memory_mapping,
+        output_addr,
+        output_len,
+        invoke_context.get_check_aligned(),
+        invoke_context.get_check_size(),
+    )?;
+    let written = translate_type_mut::<u64>(
+        memory_mapping,
+        written_addr,
+        invoke_context.get_check_aligned(),
+    )?;
+
+    match convert(input, output) {
+        Ok(len) => {
+            *written = len as u64;
+            Ok(SUCCESS)
+        }
+        Err(e) => Ok(e.into()),
+    }
+}
+
+declare_syscall!(
+    /// Base58 encode
+    SyscallBase58Encode,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        input_addr: u64,
+        input_len: u64,
+        output_addr: u64,
+        output_len: u64,
+        written_addr: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        base58_convert(
+            invoke_context,
+            base58::encode,
+            MAX_ENCODE_INPUT_LEN,
+            input_addr,
+            input_len,
+            output_addr,
+            output_len,
+            written_addr,
+            memory_mapping,
+        )
+    }
+);
+
+declare_syscall!(
+    /// Base58 decode
+    SyscallBase58Decode,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        input_addr: u64,
+        input_len: u64,
+        output_addr: u64,
+        output_len: u64,
+        written_addr: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        base58_convert(
+            invoke_context,
+            base58::decode,
+            MAX_DECODE_INPUT_LEN,
+            input_addr,
+            input_len,
+            output_addr,
+            output_len,
+            written_addr,
+            memory_mapping,
+        )
+    }
+);
+
 #[cfg(test)]
 #[allow(clippy::arithmetic_side_effects)]
 #[allow(clippy::indexing_slicing)]
@@ -2037,15 +2729,16 @@ mod tests {
             elf::SBPFVersion,
             error::EbpfError,
             memory_region::MemoryRegion,
-            vm::{BuiltinFunction, Config},
+            vm::{BuiltinFunction, Config, ContextObject},
         },
         solana_sdk::{
-            account::{create_account_shared_data_for_test, AccountSharedData},
+            account::{create_account_shared_data_for_test, Account, AccountSharedData},
             bpf_loader,
             fee_calculator::FeeCalculator,
             hash::hashv,
             instruction::Instruction,
             program::check_type_assumptions,
+            signature::Signature,
             stable_layout::stable_instruction::StableInstruction,
             sysvar::{
                 self, clock::Clock, epoch_rewards::EpochRewards, epoch_schedule::EpochSchedule,
@@ -3697,54 +4390,122 @@ mod tests {
         let mut memory_mapping = MemoryMapping::new(regions, &config, &SBPFVersion::V2).unwrap();
 
         let mut result = ProgramResult::Ok(0);
-        syscall(
-            invoke_context,
-            SEEDS_VA,
-            seeds.len() as u64,
+        syscall(
+            invoke_context,
+            SEEDS_VA,
+            seeds.len() as u64,
+            PROGRAM_ID_VA,
+            ADDRESS_VA,
+            if overlap_outputs {
+                ADDRESS_VA
+            } else {
+                BUMP_SEED_VA
+            },
+            &mut memory_mapping,
+            &mut result,
+        );
+        Result::<u64, Error>::from(result).map(|_| (address, bump_seed))
+    }
+
+    fn create_program_address(
+        invoke_context: &mut InvokeContext,
+        seeds: &[&[u8]],
+        address: &Pubkey,
+    ) -> Result<Pubkey, Error> {
+        let (address, _) = call_program_address_common(
+            invoke_context,
+            seeds,
+            address,
+            false,
+            SyscallCreateProgramAddress::call,
+        )?;
+        Ok(address)
+    }
+
+    fn try_find_program_address(
+        invoke_context: &mut InvokeContext,
+        seeds: &[&[u8]],
+        address: &Pubkey,
+    ) -> Result<(Pubkey, u8), Error> {
+        call_program_address_common(
+            invoke_context,
+            seeds,
+            address,
+            false,
+            SyscallTryFindProgramAddress::call,
+        )
+    }
+
+    #[test]
+    fn test_set_and_get_return_data() {
+        const SRC_VA: u64 = 0x100000000;
+        const DST_VA: u64 = 0x200000000;
+        const PROGRAM_ID_VA: u64 = 0x300000000;
+        let data = vec![42; 24];
+        let mut data_buffer = vec![0; 16];
+        let mut id_buffer = vec![0; 32];
+
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion::new_readonly(&data, SRC_VA),
+                MemoryRegion::new_writable(&mut data_buffer, DST_VA),
+                MemoryRegion::new_writable(&mut id_buffer, PROGRAM_ID_VA),
+            ],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        prepare_mockup!(invoke_context, program_id, bpf_loader::id());
+
+        let mut result = ProgramResult::Ok(0);
+        SyscallSetReturnData::call(
+            &mut invoke_context,
+            SRC_VA,
+            data.len() as u64,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), 0);
+
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetReturnData::call(
+            &mut invoke_context,
+            DST_VA,
+            data_buffer.len() as u64,
+            PROGRAM_ID_VA,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap() as usize, data.len());
+        assert_eq!(data.get(0..data_buffer.len()).unwrap(), data_buffer);
+        assert_eq!(id_buffer, program_id.to_bytes());
+
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetReturnData::call(
+            &mut invoke_context,
             PROGRAM_ID_VA,
-            ADDRESS_VA,
-            if overlap_outputs {
-                ADDRESS_VA
-            } else {
-                BUMP_SEED_VA
-            },
+            data_buffer.len() as u64,
+            PROGRAM_ID_VA,
+            0,
+            0,
             &mut memory_mapping,
             &mut result,
         );
-        Result::<u64, Error>::from(result).map(|_| (address, bump_seed))
-    }
-
-    fn create_program_address(
-        invoke_context: &mut InvokeContext,
-        seeds: &[&[u8]],
-        address: &Pubkey,
-    ) -> Result<Pubkey, Error> {
-        let (address, _) = call_program_address_common(
-            invoke_context,
-            seeds,
-            address,
-            false,
-            SyscallCreateProgramAddress::call,
-        )?;
-        Ok(address)
-    }
-
-    fn try_find_program_address(
-        invoke_context: &mut InvokeContext,
-        seeds: &[&[u8]],
-        address: &Pubkey,
-    ) -> Result<(Pubkey, u8), Error> {
-        call_program_address_common(
-            invoke_context,
-            seeds,
-            address,
-            false,
-            SyscallTryFindProgramAddress::call,
-        )
+        assert_matches!(
+            result,
+            ProgramResult::Err(error) if error.downcast_ref::<SyscallError>().unwrap() == &SyscallError::CopyOverlapping
+        );
     }
 
     #[test]
-    fn test_set_and_get_return_data() {
+    fn test_tx_scratch_write_and_read() {
         const SRC_VA: u64 = 0x100000000;
         const DST_VA: u64 = 0x200000000;
         const PROGRAM_ID_VA: u64 = 0x300000000;
@@ -3766,8 +4527,23 @@ mod tests {
 
         prepare_mockup!(invoke_context, program_id, bpf_loader::id());
 
+        // Nothing was written by the program yet
+        id_buffer.copy_from_slice(program_id.as_ref());
         let mut result = ProgramResult::Ok(0);
-        SyscallSetReturnData::call(
+        SyscallTxScratchRead::call(
+            &mut invoke_context,
+            PROGRAM_ID_VA,
+            DST_VA,
+            data_buffer.len() as u64,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), 0);
+
+        let mut result = ProgramResult::Ok(0);
+        SyscallTxScratchWrite::call(
             &mut invoke_context,
             SRC_VA,
             data.len() as u64,
@@ -3778,13 +4554,14 @@ mod tests {
             &mut result,
         );
         assert_eq!(result.unwrap(), 0);
+        assert_eq!(invoke_context.get_tx_scratch(&program_id), data);
 
         let mut result = ProgramResult::Ok(0);
-        SyscallGetReturnData::call(
+        SyscallTxScratchRead::call(
             &mut invoke_context,
+            PROGRAM_ID_VA,
             DST_VA,
             data_buffer.len() as u64,
-            PROGRAM_ID_VA,
             0,
             0,
             &mut memory_mapping,
@@ -3792,22 +4569,387 @@ mod tests {
         );
         assert_eq!(result.unwrap() as usize, data.len());
         assert_eq!(data.get(0..data_buffer.len()).unwrap(), data_buffer);
-        assert_eq!(id_buffer, program_id.to_bytes());
 
+        // Buffers are namespaced by program
+        id_buffer.copy_from_slice(Pubkey::new_unique().as_ref());
         let mut result = ProgramResult::Ok(0);
-        SyscallGetReturnData::call(
+        SyscallTxScratchRead::call(
             &mut invoke_context,
             PROGRAM_ID_VA,
+            DST_VA,
             data_buffer.len() as u64,
-            PROGRAM_ID_VA,
             0,
             0,
             &mut memory_mapping,
             &mut result,
         );
-        assert_matches!(
-            result,
-            ProgramResult::Err(error) if error.downcast_ref::<SyscallError>().unwrap() == &SyscallError::CopyOverlapping
+        assert_eq!(result.unwrap(), 0);
+
+        let mut result = ProgramResult::Ok(0);
+        SyscallTxScratchWrite::call(
+            &mut invoke_context,
+            SRC_VA,
+            MAX_TX_SCRATCH_LEN as u64 + 1,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_matches!(
+            result,
+            ProgramResult::Err(error) if error.downcast_ref::<SyscallError>().unwrap()
+                == &SyscallError::TxScratchTooLarge(MAX_TX_SCRATCH_LEN as u64 + 1, MAX_TX_SCRATCH_LEN as u64)
+        );
+    }
+
+    #[test]
+    fn test_syscall_sol_get_transaction_signature() {
+        const SIGNATURE_VA: u64 = 0x100000000;
+        let signatures = vec![Signature::new_unique(), Signature::new_unique()];
+        let mut signature_buffer = vec![0u8; SIGNATURE_BYTES];
+
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_writable(
+                &mut signature_buffer,
+                SIGNATURE_VA,
+            )],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        prepare_mockup!(invoke_context, _program_id, bpf_loader::id());
+        invoke_context.set_transaction_signatures(&signatures);
+        let budget = invoke_context.get_compute_budget();
+        let base_cost = budget.transaction_signature_base_cost;
+        let copy_cost = budget.transaction_signature_copy_cost;
+
+        for (index, signature) in signatures.iter().enumerate() {
+            invoke_context.mock_set_remaining(base_cost + copy_cost);
+            let mut result = ProgramResult::Ok(0);
+            SyscallGetTransactionSignature::call(
+                &mut invoke_context,
+                index as u64,
+                SIGNATURE_VA,
+                0,
+                0,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            assert_eq!(result.unwrap(), 1);
+            assert_eq!(signature_buffer, signature.as_ref());
+            assert_eq!(invoke_context.get_remaining(), 0);
+        }
+
+        // Missing signatures are only charged the base cost
+        signature_buffer.fill(0);
+        invoke_context.mock_set_remaining(base_cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetTransactionSignature::call(
+            &mut invoke_context,
+            signatures.len() as u64,
+            SIGNATURE_VA,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(signature_buffer, vec![0; SIGNATURE_BYTES]);
+
+        // Copying a signature without the units for it fails
+        invoke_context.mock_set_remaining(base_cost + copy_cost - 1);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetTransactionSignature::call(
+            &mut invoke_context,
+            0,
+            SIGNATURE_VA,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_matches!(
+            result,
+            ProgramResult::Err(error) if error.downcast_ref::<InstructionError>().unwrap()
+                == &InstructionError::ComputationalBudgetExceeded
+        );
+        assert_eq!(signature_buffer, vec![0; SIGNATURE_BYTES]);
+    }
+
+    #[test]
+    fn test_syscall_sol_get_serialized_message() {
+        const MESSAGE_VA: u64 = 0x100000000;
+        let message_data: Vec<u8> = (0..=255).collect();
+        let mut message_buffer = vec![0u8; message_data.len() + 8];
+
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_writable(&mut message_buffer, MESSAGE_VA)],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        prepare_mockup!(invoke_context, _program_id, bpf_loader::id());
+        let budget = invoke_context.get_compute_budget();
+        let base_cost = budget.syscall_base_cost;
+        let cpi_bytes_per_unit = budget.cpi_bytes_per_unit;
+
+        // Without a message, only the base cost is charged
+        invoke_context.mock_set_remaining(base_cost);
+        let mut result = ProgramResult::Ok(u64::MAX);
+        SyscallGetSerializedMessage::call(
+            &mut invoke_context,
+            MESSAGE_VA,
+            message_buffer.len() as u64,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(invoke_context.get_remaining(), 0);
+
+        invoke_context.set_transaction_message_data(message_data.clone());
+        let copy_cost = message_data.len() as u64 / cpi_bytes_per_unit;
+
+        // A buffer larger than the message only receives the message
+        invoke_context.mock_set_remaining(base_cost + copy_cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetSerializedMessage::call(
+            &mut invoke_context,
+            MESSAGE_VA,
+            message_buffer.len() as u64,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), message_data.len() as u64);
+        assert_eq!(&message_buffer[..message_data.len()], &message_data[..]);
+        assert_eq!(&message_buffer[message_data.len()..], &[0; 8]);
+        assert_eq!(invoke_context.get_remaining(), 0);
+
+        // A shorter buffer receives a prefix, and learns the full length
+        message_buffer.fill(0);
+        invoke_context.mock_set_remaining(base_cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetSerializedMessage::call(
+            &mut invoke_context,
+            MESSAGE_VA,
+            0,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), message_data.len() as u64);
+        assert_eq!(message_buffer, vec![0; message_data.len() + 8]);
+
+        // Copying the message without the units for it fails
+        invoke_context.mock_set_remaining(base_cost + copy_cost - 1);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetSerializedMessage::call(
+            &mut invoke_context,
+            MESSAGE_VA,
+            message_data.len() as u64,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_matches!(
+            result,
+            ProgramResult::Err(error) if error.downcast_ref::<InstructionError>().unwrap()
+                == &InstructionError::ComputationalBudgetExceeded
+        );
+        assert_eq!(message_buffer, vec![0; message_data.len() + 8]);
+    }
+
+    #[test]
+    fn test_syscall_sol_get_transaction_nonce_info() {
+        const NONCE_INFO_VA: u64 = 0x100000000;
+        let mut nonce_info_buffer = vec![0u8; size_of::<TransactionNonceInfo>()];
+
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_writable(
+                &mut nonce_info_buffer,
+                NONCE_INFO_VA,
+            )],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        prepare_mockup!(invoke_context, _program_id, bpf_loader::id());
+        let mut get_nonce_info = |invoke_context: &mut InvokeContext| {
+            let mut result = ProgramResult::Ok(0);
+            SyscallGetTransactionNonceInfo::call(
+                invoke_context,
+                NONCE_INFO_VA,
+                0,
+                0,
+                0,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            result.unwrap()
+        };
+
+        // The transaction used a recent blockhash
+        assert_eq!(get_nonce_info(&mut invoke_context), 0);
+
+        let nonce_info = TransactionNonceInfo {
+            nonce_account: Pubkey::new_unique(),
+            durable_nonce: Hash::new_unique(),
+        };
+        invoke_context.set_transaction_nonce_info(Some(nonce_info));
+        assert_eq!(get_nonce_info(&mut invoke_context), 1);
+        assert_eq!(
+            nonce_info_buffer,
+            [
+                nonce_info.nonce_account.as_ref(),
+                nonce_info.durable_nonce.as_ref()
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_syscall_sol_is_verified() {
+        const PRECOMPILE_ID_VA: u64 = 0x100000000;
+        const SIGNATURE_VA: u64 = 0x200000000;
+        const PUBKEY_VA: u64 = 0x300000000;
+        const MESSAGE_HASH_VA: u64 = 0x400000000;
+        let precompile_id = solana_sdk::ed25519_program::id();
+        let signature = [7u8; SIGNATURE_BYTES];
+        let pubkey = Pubkey::new_unique();
+        let message_hash = Hash::new_unique();
+
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion::new_readonly(precompile_id.as_ref(), PRECOMPILE_ID_VA),
+                MemoryRegion::new_readonly(&signature, SIGNATURE_VA),
+                MemoryRegion::new_readonly(pubkey.as_ref(), PUBKEY_VA),
+                MemoryRegion::new_readonly(message_hash.as_ref(), MESSAGE_HASH_VA),
+            ],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        prepare_mockup!(invoke_context, _program_id, bpf_loader::id());
+        let mut is_verified = |invoke_context: &mut InvokeContext, pubkey_len: u64| {
+            let mut result = ProgramResult::Ok(0);
+            SyscallIsVerified::call(
+                invoke_context,
+                PRECOMPILE_ID_VA,
+                SIGNATURE_VA,
+                PUBKEY_VA,
+                pubkey_len,
+                MESSAGE_HASH_VA,
+                &mut memory_mapping,
+                &mut result,
+            );
+            result
+        };
+
+        assert_eq!(is_verified(&mut invoke_context, 32).unwrap(), 0);
+
+        invoke_context.record_verified_signature(verified_signature_key(
+            &precompile_id,
+            &signature,
+            pubkey.as_ref(),
+            &message_hash,
+        ));
+        assert_eq!(is_verified(&mut invoke_context, 32).unwrap(), 1);
+        // The public key is part of the key
+        assert_eq!(is_verified(&mut invoke_context, 20).unwrap(), 0);
+        assert_matches!(
+            is_verified(&mut invoke_context, 33),
+            ProgramResult::Err(error) if error.downcast_ref::<SyscallError>().unwrap()
+                == &SyscallError::InvalidLength
+        );
+    }
+
+    #[test]
+    fn test_syscall_sol_ed25519_verify() {
+        const PUBKEY_VA: u64 = 0x100000000;
+        const MESSAGE_VA: u64 = 0x200000000;
+        const OTHER_MESSAGE_VA: u64 = 0x300000000;
+        const SIGNATURE_VA: u64 = 0x400000000;
+        let keypair = solana_sdk::signer::keypair::Keypair::new();
+        let pubkey = solana_sdk::signer::Signer::pubkey(&keypair);
+        let message = b"hello";
+        let other_message = b"world";
+        let signature = solana_sdk::signer::Signer::sign_message(&keypair, message);
+
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion::new_readonly(pubkey.as_ref(), PUBKEY_VA),
+                MemoryRegion::new_readonly(message, MESSAGE_VA),
+                MemoryRegion::new_readonly(other_message, OTHER_MESSAGE_VA),
+                MemoryRegion::new_readonly(signature.as_ref(), SIGNATURE_VA),
+            ],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        prepare_mockup!(invoke_context, _program_id, bpf_loader::id());
+        let budget = invoke_context.get_compute_budget().clone();
+        let hash_cost = budget.sha256_base_cost + budget.sha256_byte_cost * message.len() as u64;
+        let mut ed25519_verify = |invoke_context: &mut InvokeContext, message_va: u64| {
+            let mut result = ProgramResult::Ok(0);
+            SyscallEd25519Verify::call(
+                invoke_context,
+                PUBKEY_VA,
+                message_va,
+                message.len() as u64,
+                SIGNATURE_VA,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            result
+        };
+
+        invoke_context.mock_set_remaining(hash_cost + budget.ed25519_verify_cost);
+        assert_eq!(
+            ed25519_verify(&mut invoke_context, OTHER_MESSAGE_VA).unwrap(),
+            0
+        );
+        assert_eq!(invoke_context.get_remaining(), 0);
+
+        invoke_context.mock_set_remaining(hash_cost + budget.ed25519_verify_cost);
+        assert_eq!(ed25519_verify(&mut invoke_context, MESSAGE_VA).unwrap(), 1);
+        assert_eq!(invoke_context.get_remaining(), 0);
+
+        // The verified signature isn't verified again
+        invoke_context.mock_set_remaining(hash_cost);
+        assert_eq!(ed25519_verify(&mut invoke_context, MESSAGE_VA).unwrap(), 1);
+        assert_eq!(invoke_context.get_remaining(), 0);
+        assert!(
+            invoke_context.is_signature_verified(&verified_signature_key(
+                &ed25519_program::id(),
+                signature.as_ref(),
+                pubkey.as_ref(),
+                &hash(message),
+            ))
         );
     }
 
@@ -3964,6 +5106,348 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_syscall_sol_get_processed_sibling_instruction_provenance() {
+        let transaction_accounts = (0..4)
+            .map(|_| {
+                (
+                    Pubkey::new_unique(),
+                    AccountSharedData::new(0, 0, &bpf_loader::id()),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Instruction accounts of each instruction in the trace, as pairs of
+        // index in transaction and whether it signs
+        let instruction_trace: [(usize, &[(IndexOfAccount, bool)]); 5] = [
+            (1, &[(0, true)]),
+            (2, &[(0, false)]),
+            (1, &[(1, true), (2, false), (3, true)]),
+            (2, &[(1, false)]),
+            (1, &[]),
+        ];
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        for (index_in_trace, (stack_height, accounts)) in instruction_trace.into_iter().enumerate()
+        {
+            while stack_height
+                <= invoke_context
+                    .transaction_context
+                    .get_instruction_context_stack_height()
+            {
+                invoke_context.transaction_context.pop().unwrap();
+            }
+            let instruction_accounts = accounts
+                .iter()
+                .map(|(index_in_transaction, is_signer)| InstructionAccount {
+                    index_in_transaction: *index_in_transaction,
+                    index_in_caller: *index_in_transaction,
+                    index_in_callee: *index_in_transaction,
+                    is_signer: *is_signer,
+                    is_writable: false,
+                })
+                .collect::<Vec<_>>();
+            invoke_context
+                .transaction_context
+                .get_next_instruction_context()
+                .unwrap()
+                .configure(&[0], &instruction_accounts, &[index_in_trace as u8]);
+            invoke_context.transaction_context.push().unwrap();
+        }
+
+        let syscall_base_cost = invoke_context.get_compute_budget().syscall_base_cost;
+
+        const VM_BASE_ADDRESS: u64 = 0x100000000;
+        let mut provenance = ProcessedSiblingInstructionProvenance::default();
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_writable(
+                bytes_of_mut(&mut provenance),
+                VM_BASE_ADDRESS,
+            )],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        // The siblings of the last top-level instruction are the previous
+        // top-level instructions, most recent first
+        for (index, expected_provenance) in [
+            ProcessedSiblingInstructionProvenance {
+                stack_height: 1,
+                top_level_instruction_index: 1,
+                signers: 0b1010,
+            },
+            ProcessedSiblingInstructionProvenance {
+                stack_height: 1,
+                top_level_instruction_index: 0,
+                signers: 0b1,
+            },
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            invoke_context.mock_set_remaining(syscall_base_cost);
+            let mut result = ProgramResult::Ok(0);
+            SyscallGetProcessedSiblingInstructionProvenance::call(
+                &mut invoke_context,
+                index as u64,
+                VM_BASE_ADDRESS,
+                0,
+                0,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            assert_eq!(result.unwrap(), 1);
+            assert_eq!(provenance, expected_provenance);
+            assert!(provenance.is_top_level());
+            assert!(provenance.is_signed());
+        }
+        assert!(provenance.is_signed_by(0));
+        assert!(!provenance.is_signed_by(1));
+        assert!(!provenance.is_signed_by(64));
+
+        invoke_context.mock_set_remaining(syscall_base_cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetProcessedSiblingInstructionProvenance::call(
+            &mut invoke_context,
+            2,
+            VM_BASE_ADDRESS,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_syscall_sol_get_processed_sibling_instruction_sysvar_access() {
+        let transaction_accounts = vec![
+            (
+                instructions::id(),
+                AccountSharedData::new(0, 0, &sysvar::id()),
+            ),
+            (
+                sysvar::signatures::id(),
+                AccountSharedData::new(0, 0, &sysvar::id()),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountSharedData::new(0, 0, &bpf_loader::id()),
+            ),
+        ];
+        // Instruction accounts of each instruction in the trace, as indexes in
+        // transaction
+        let instruction_trace: [(usize, &[IndexOfAccount]); 4] =
+            [(1, &[2]), (2, &[1, 2]), (1, &[0]), (1, &[])];
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        for (index_in_trace, (stack_height, accounts)) in instruction_trace.into_iter().enumerate()
+        {
+            while stack_height
+                <= invoke_context
+                    .transaction_context
+                    .get_instruction_context_stack_height()
+            {
+                invoke_context.transaction_context.pop().unwrap();
+            }
+            let instruction_accounts = accounts
+                .iter()
+                .map(|index_in_transaction| InstructionAccount {
+                    index_in_transaction: *index_in_transaction,
+                    index_in_caller: *index_in_transaction,
+                    index_in_callee: *index_in_transaction,
+                    is_signer: false,
+                    is_writable: false,
+                })
+                .collect::<Vec<_>>();
+            invoke_context
+                .transaction_context
+                .get_next_instruction_context()
+                .unwrap()
+                .configure(&[2], &instruction_accounts, &[index_in_trace as u8]);
+            invoke_context.transaction_context.push().unwrap();
+        }
+
+        let syscall_base_cost = invoke_context.get_compute_budget().syscall_base_cost;
+
+        const VM_BASE_ADDRESS: u64 = 0x100000000;
+        let mut access = ProcessedSiblingInstructionSysvarAccess::default();
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_writable(
+                bytes_of_mut(&mut access),
+                VM_BASE_ADDRESS,
+            )],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        // The first top-level instruction was only given the signatures
+        // sysvar through the instruction it invoked
+        for (index, expected_sysvars) in [0b1, 0b10].into_iter().enumerate() {
+            invoke_context.mock_set_remaining(syscall_base_cost);
+            let mut result = ProgramResult::Ok(0);
+            SyscallGetProcessedSiblingInstructionSysvarAccess::call(
+                &mut invoke_context,
+                index as u64,
+                VM_BASE_ADDRESS,
+                0,
+                0,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            assert_eq!(result.unwrap(), 1);
+            assert_eq!(access.sysvars, expected_sysvars);
+        }
+        assert!(access.is_signatures_accessible());
+        assert!(!access.is_accessible(&instructions::id()));
+        assert!(!access.is_accessible(&Pubkey::new_unique()));
+
+        invoke_context.mock_set_remaining(syscall_base_cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetProcessedSiblingInstructionSysvarAccess::call(
+            &mut invoke_context,
+            2,
+            VM_BASE_ADDRESS,
+            0,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_syscall_sol_get_instructions_sysvar_slice() {
+        let instructions_data = (0..64).collect::<Vec<u8>>();
+        let transaction_accounts = vec![(
+            instructions::id(),
+            AccountSharedData::from(Account {
+                data: instructions_data.clone(),
+                owner: sysvar::id(),
+                ..Account::default()
+            }),
+        )];
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+
+        const VM_BASE_ADDRESS: u64 = 0x100000000;
+        let mut got = [0u8; 16];
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_writable(&mut got, VM_BASE_ADDRESS)],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        for (offset, expected_result) in [
+            (0, SUCCESS),
+            (48, SUCCESS),
+            (49, instructions::OFFSET_LENGTH_EXCEEDS_SYSVAR),
+            (u64::MAX, instructions::OFFSET_LENGTH_EXCEEDS_SYSVAR),
+        ] {
+            invoke_context.mock_set_remaining(u64::MAX);
+            let mut result = ProgramResult::Ok(0);
+            SyscallGetInstructionsSysvarSlice::call(
+                &mut invoke_context,
+                offset,
+                16,
+                VM_BASE_ADDRESS,
+                0,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            assert_eq!(result.unwrap(), expected_result);
+            if expected_result == SUCCESS {
+                let offset = offset as usize;
+                assert_eq!(got, instructions_data[offset..offset + 16]);
+            }
+        }
+
+        // Without the sysvar among the transaction accounts there is nothing
+        // to copy from
+        let transaction_accounts = vec![(
+            Pubkey::new_unique(),
+            AccountSharedData::new(0, 0, &bpf_loader::id()),
+        )];
+        with_mock_invoke_context!(invoke_context, transaction_context, transaction_accounts);
+        invoke_context.mock_set_remaining(u64::MAX);
+        let mut result = ProgramResult::Ok(0);
+        SyscallGetInstructionsSysvarSlice::call(
+            &mut invoke_context,
+            0,
+            16,
+            VM_BASE_ADDRESS,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), instructions::SYSVAR_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_syscall_sol_memcmp_ct() {
+        prepare_mockup!(invoke_context, program_id, bpf_loader::id());
+
+        const VM_BASE_ADDRESS: u64 = 0x100000000;
+        let mut values = [7u8; 512];
+        values[256 + 63] = 8;
+        let config = Config::default();
+        let mut memory_mapping = MemoryMapping::new(
+            vec![MemoryRegion::new_readonly(&values, VM_BASE_ADDRESS)],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        let budget = invoke_context.get_compute_budget();
+        let base_cost = budget.mem_cmp_ct_base_cost;
+        let cpi_bytes_per_unit = budget.cpi_bytes_per_unit;
+        for (s2_offset, n, expected_result, expected_cost) in [
+            (64, 64, 0, base_cost),
+            (256, 64, 1, base_cost),
+            (0, 0, 0, base_cost),
+            (0, 256, 0, base_cost + 192 / cpi_bytes_per_unit),
+            (256, 256, 1, base_cost + 192 / cpi_bytes_per_unit),
+        ] {
+            invoke_context.mock_set_remaining(expected_cost);
+            let mut result = ProgramResult::Ok(0);
+            SyscallMemcmpCt::call(
+                &mut invoke_context,
+                VM_BASE_ADDRESS,
+                VM_BASE_ADDRESS + s2_offset,
+                n,
+                0,
+                0,
+                &mut memory_mapping,
+                &mut result,
+            );
+            assert_eq!(result.unwrap(), expected_result);
+            assert_eq!(invoke_context.get_remaining(), 0);
+        }
+
+        // Reading past the mapped memory aborts
+        invoke_context.mock_set_remaining(u64::MAX);
+        let mut result = ProgramResult::Ok(0);
+        SyscallMemcmpCt::call(
+            &mut invoke_context,
+            VM_BASE_ADDRESS,
+            VM_BASE_ADDRESS + 256,
+            257,
+            0,
+            0,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_matches!(result, ProgramResult::Err(_));
+    }
+
     #[test]
     fn test_create_program_address() {
         // These tests duplicate the direct tests in solana_program::pubkey
@@ -4252,6 +5736,129 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_syscall_base58() {
+        let config = Config::default();
+        prepare_mockup!(invoke_context, program_id, bpf_loader::id());
+
+        const VADDR_INPUT: u64 = 0x100000000;
+        const VADDR_OUTPUT: u64 = 0x200000000;
+        const VADDR_WRITTEN: u64 = 0x300000000;
+        let signature = [7u8; 64];
+        let encoded = Signature::from(signature).to_string();
+
+        let mut output = [0u8; base58::max_encoded_len(64)];
+        let mut written = 0u64;
+        let mut memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion::new_readonly(&signature, VADDR_INPUT),
+                MemoryRegion::new_writable(&mut output, VADDR_OUTPUT),
+                MemoryRegion::new_writable(bytes_of_mut(&mut written), VADDR_WRITTEN),
+            ],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        let syscall_base_cost = invoke_context.get_compute_budget().syscall_base_cost;
+        let base58_byte_cost = invoke_context.get_compute_budget().base58_byte_cost;
+        let cost = syscall_base_cost + base58_byte_cost * 64;
+        invoke_context.mock_set_remaining(cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallBase58Encode::call(
+            &mut invoke_context,
+            VADDR_INPUT,
+            64,
+            VADDR_OUTPUT,
+            output.len() as u64,
+            VADDR_WRITTEN,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), SUCCESS);
+        assert_eq!(invoke_context.get_remaining(), 0);
+        assert_eq!(written, encoded.len() as u64);
+        assert_eq!(&output[..encoded.len()], encoded.as_bytes());
+
+        // Output buffer too small
+        invoke_context.mock_set_remaining(cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallBase58Encode::call(
+            &mut invoke_context,
+            VADDR_INPUT,
+            64,
+            VADDR_OUTPUT,
+            8,
+            VADDR_WRITTEN,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), u64::from(Base58Error::BufferTooSmall));
+
+        // Input too long, rejected before translating it
+        invoke_context.mock_set_remaining(syscall_base_cost);
+        let mut result = ProgramResult::Ok(0);
+        SyscallBase58Encode::call(
+            &mut invoke_context,
+            VADDR_INPUT,
+            MAX_ENCODE_INPUT_LEN as u64 + 1,
+            VADDR_OUTPUT,
+            output.len() as u64,
+            VADDR_WRITTEN,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), u64::from(Base58Error::InputTooLong));
+
+        // Decode the encoded signature back
+        let mut decoded = [0u8; 64];
+        let mut written = 0u64;
+        let mut memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion::new_readonly(encoded.as_bytes(), VADDR_INPUT),
+                MemoryRegion::new_writable(&mut decoded, VADDR_OUTPUT),
+                MemoryRegion::new_writable(bytes_of_mut(&mut written), VADDR_WRITTEN),
+            ],
+            &config,
+            &SBPFVersion::V2,
+        )
+        .unwrap();
+
+        invoke_context
+            .mock_set_remaining(syscall_base_cost + base58_byte_cost * encoded.len() as u64);
+        let mut result = ProgramResult::Ok(0);
+        SyscallBase58Decode::call(
+            &mut invoke_context,
+            VADDR_INPUT,
+            encoded.len() as u64,
+            VADDR_OUTPUT,
+            64,
+            VADDR_WRITTEN,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result.unwrap(), SUCCESS);
+        assert_eq!(written, 64);
+        assert_eq!(decoded, signature);
+
+        // Out of compute units
+        let mut result = ProgramResult::Ok(0);
+        SyscallBase58Decode::call(
+            &mut invoke_context,
+            VADDR_INPUT,
+            encoded.len() as u64,
+            VADDR_OUTPUT,
+            64,
+            VADDR_WRITTEN,
+            &mut memory_mapping,
+            &mut result,
+        );
+        assert_matches!(
+            result,
+            ProgramResult::Err(error) if error.downcast_ref::<InstructionError>().unwrap() == &InstructionError::ComputationalBudgetExceeded
+        );
+    }
+
     #[test]
     fn test_check_type_assumptions() {
         check_type_assumptions();
@@ -156,3 +156,55 @@ declare_syscall!(
         )
     }
 );
+
+declare_syscall!(
+    /// Copy a range of the instructions sysvar data
+    SyscallGetInstructionsSysvarSlice,
+    fn inner_call(
+        invoke_context: &mut InvokeContext,
+        offset: u64,
+        length: u64,
+        var_addr: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &mut MemoryMapping,
+    ) -> Result<u64, Error> {
+        let compute_budget = invoke_context.get_compute_budget();
+        let cost = compute_budget
+            .sysvar_base_cost
+            .saturating_add(
+                length
+                    .checked_div(compute_budget.cpi_bytes_per_unit)
+                    .unwrap_or(u64::MAX),
+            );
+        consume_compute_meter(invoke_context, cost)?;
+
+        let var = translate_slice_mut::<u8>(
+            memory_mapping,
+            var_addr,
+            length,
+            invoke_context.get_check_aligned(),
+            invoke_context.get_check_size(),
+        )?;
+
+        let transaction_context = &invoke_context.transaction_context;
+        let Some(index_in_transaction) =
+            transaction_context.find_index_of_account(&instructions::id())
+        else {
+            return Ok(instructions::SYSVAR_NOT_FOUND);
+        };
+        let account = transaction_context
+            .get_account_at_index(index_in_transaction)?
+            .try_borrow()
+            .map_err(|_| InstructionError::AccountBorrowFailed)?;
+        let Some(slice) = offset
+            .checked_add(length)
+            .and_then(|end| account.data().get(offset as usize..end as usize))
+        else {
+            return Ok(instructions::OFFSET_LENGTH_EXCEEDS_SYSVAR);
+        };
+        var.copy_from_slice(slice);
+
+        Ok(SUCCESS)
+    }
+);
@@ -341,6 +341,7 @@ pub fn process_instruction_truncate(
             state.slot = 0;
             state.status = LoaderV4Status::Retracted;
             state.authority_address = *authority_address;
+            state.min_transaction_signers = 0;
         }
     }
     Ok(())
@@ -433,9 +434,12 @@ pub fn process_instruction_deploy(
         source_program.checked_sub_lamports(transfer_lamports)?;
         program.checked_add_lamports(transfer_lamports)?;
     }
+    // Deploying from a source program keeps the requirement of the destination
+    let min_transaction_signers = state.min_transaction_signers;
     let state = get_state_mut(program.get_data_mut()?)?;
     state.slot = current_slot;
     state.status = LoaderV4Status::Deployed;
+    state.min_transaction_signers = min_transaction_signers;
 
     if let Some(old_entry) = invoke_context.find_program_in_cache(program.get_key()) {
         executor.tx_usage_counter.store(
@@ -523,6 +527,34 @@ pub fn process_instruction_transfer_authority(
     Ok(())
 }
 
+pub fn process_instruction_set_min_transaction_signers(
+    invoke_context: &mut InvokeContext,
+    min_transaction_signers: u32,
+) -> Result<(), InstructionError> {
+    if !invoke_context
+        .feature_set
+        .is_active(&feature_set::enable_loader_v4_min_transaction_signers::id())
+    {
+        return Err(InstructionError::InvalidInstructionData);
+    }
+    let log_collector = invoke_context.get_log_collector();
+    let transaction_context = &invoke_context.transaction_context;
+    let instruction_context = transaction_context.get_current_instruction_context()?;
+    let mut program = instruction_context.try_borrow_instruction_account(transaction_context, 0)?;
+    let authority_address = instruction_context
+        .get_index_of_instruction_account_in_transaction(1)
+        .and_then(|index| transaction_context.get_key_of_account_at_index(index))?;
+    let _state = check_program_account(
+        &log_collector,
+        instruction_context,
+        &program,
+        authority_address,
+    )?;
+    let state = get_state_mut(program.get_data_mut()?)?;
+    state.min_transaction_signers = min_transaction_signers;
+    Ok(())
+}
+
 pub fn process_instruction(
     invoke_context: &mut InvokeContext,
     _arg0: u64,
@@ -563,6 +595,12 @@ pub fn process_instruction_inner(
             LoaderV4Instruction::TransferAuthority => {
                 process_instruction_transfer_authority(invoke_context)
             }
+            LoaderV4Instruction::SetMinTransactionSigners {
+                min_transaction_signers,
+            } => process_instruction_set_min_transaction_signers(
+                invoke_context,
+                min_transaction_signers,
+            ),
         }
         .map_err(|err| Box::new(err) as Box<dyn std::error::Error>)
     } else {
@@ -580,6 +618,20 @@ pub fn process_instruction_inner(
             ic_logger_msg!(log_collector, "Program is not deployed");
             return Err(Box::new(InstructionError::InvalidArgument));
         }
+        let num_transaction_signers = invoke_context.get_transaction_signatures().len();
+        if invoke_context
+            .feature_set
+            .is_active(&feature_set::enable_loader_v4_min_transaction_signers::id())
+            && num_transaction_signers < state.min_transaction_signers as usize
+        {
+            ic_logger_msg!(
+                log_collector,
+                "Program requires {} transaction signers, but only {} signed",
+                state.min_transaction_signers,
+                num_transaction_signers
+            );
+            return Err(Box::new(InstructionError::MissingRequiredSignature));
+        }
         let mut get_or_create_executor_time = Measure::start("get_or_create_executor_time");
         let loaded_program = invoke_context
             .find_program_in_cache(program.get_key())
@@ -621,6 +673,7 @@ mod tests {
                 WritableAccount,
             },
             instruction::AccountMeta,
+            signature::Signature,
             slot_history::Slot,
             sysvar::{clock, rent},
             transaction_context::IndexOfAccount,
@@ -1525,6 +1578,161 @@ mod tests {
         test_loader_instruction_general_errors(LoaderV4Instruction::TransferAuthority);
     }
 
+    #[test]
+    fn test_loader_instruction_set_min_transaction_signers() {
+        let authority_address = Pubkey::new_unique();
+        let transaction_accounts = vec![
+            (
+                Pubkey::new_unique(),
+                load_program_account_from_elf(
+                    authority_address,
+                    LoaderV4Status::Deployed,
+                    "rodata_section",
+                ),
+            ),
+            (
+                Pubkey::new_unique(),
+                AccountSharedData::new(0, 0, &loader_v4::id()),
+            ),
+            (
+                authority_address,
+                AccountSharedData::new(0, 0, &Pubkey::new_unique()),
+            ),
+            (
+                clock::id(),
+                create_account_shared_data_for_test(&clock::Clock::default()),
+            ),
+            (
+                rent::id(),
+                create_account_shared_data_for_test(&rent::Rent::default()),
+            ),
+        ];
+        let instruction = bincode::serialize(&LoaderV4Instruction::SetMinTransactionSigners {
+            min_transaction_signers: 2,
+        })
+        .unwrap();
+
+        // Set the requirement
+        let accounts = process_instruction(
+            vec![],
+            &instruction,
+            transaction_accounts.clone(),
+            &[(0, false, true), (2, true, false)],
+            Ok(()),
+        );
+        let state = get_state(accounts[0].data()).unwrap();
+        assert_eq!(state.min_transaction_signers, 2);
+        assert_eq!(state.status, LoaderV4Status::Deployed);
+        assert_eq!(
+            accounts[0].data().len(),
+            transaction_accounts[0].1.data().len(),
+        );
+
+        // Error: Feature is inactive
+        mock_process_instruction(
+            &loader_v4::id(),
+            vec![],
+            &instruction,
+            transaction_accounts.clone(),
+            vec![
+                AccountMeta {
+                    pubkey: transaction_accounts[0].0,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: transaction_accounts[2].0,
+                    is_signer: true,
+                    is_writable: false,
+                },
+            ],
+            Err(InstructionError::InvalidInstructionData),
+            super::process_instruction,
+            |invoke_context| {
+                let mut feature_set = feature_set::FeatureSet::all_enabled();
+                feature_set
+                    .deactivate(&feature_set::enable_loader_v4_min_transaction_signers::id());
+                invoke_context.feature_set = Arc::new(feature_set);
+            },
+            |_invoke_context| {},
+        );
+
+        // Error: Program is uninitialized
+        process_instruction(
+            vec![],
+            &instruction,
+            transaction_accounts,
+            &[(1, false, true), (2, true, false)],
+            Err(InstructionError::InvalidAccountData),
+        );
+
+        test_loader_instruction_general_errors(LoaderV4Instruction::SetMinTransactionSigners {
+            min_transaction_signers: 2,
+        });
+    }
+
+    #[test]
+    fn test_execute_program_min_transaction_signers() {
+        let program_address = Pubkey::new_unique();
+        let mut program_account = load_program_account_from_elf(
+            Pubkey::new_unique(),
+            LoaderV4Status::Finalized,
+            "rodata_section",
+        );
+        get_state_mut(program_account.data_as_mut_slice())
+            .unwrap()
+            .min_transaction_signers = 2;
+        let transaction_accounts = vec![
+            (program_address, program_account),
+            (
+                Pubkey::new_unique(),
+                AccountSharedData::new(10000000, 32, &program_address),
+            ),
+        ];
+        let signatures: &'static [Signature] =
+            Box::leak(vec![Signature::new_unique(), Signature::new_unique()].into_boxed_slice());
+
+        for (is_feature_active, num_signatures, expected_result) in [
+            (true, 0, Err(InstructionError::MissingRequiredSignature)),
+            (true, 1, Err(InstructionError::MissingRequiredSignature)),
+            (true, 2, Err(InstructionError::Custom(42))),
+            (false, 0, Err(InstructionError::Custom(42))),
+        ] {
+            mock_process_instruction(
+                &loader_v4::id(),
+                vec![0],
+                &[0, 1, 2, 3],
+                transaction_accounts.clone(),
+                vec![AccountMeta {
+                    pubkey: transaction_accounts[1].0,
+                    is_signer: false,
+                    is_writable: true,
+                }],
+                expected_result,
+                super::process_instruction,
+                |invoke_context| {
+                    invoke_context
+                        .programs_modified_by_tx
+                        .environments
+                        .program_runtime_v2 = Arc::new(create_program_runtime_environment_v2(
+                        &ComputeBudget::default(),
+                        false,
+                    ));
+                    load_all_invoked_programs(invoke_context);
+                    invoke_context.set_transaction_signatures(&signatures[..num_signatures]);
+                    if !is_feature_active {
+                        let mut feature_set = feature_set::FeatureSet::all_enabled();
+                        feature_set.deactivate(
+                            &feature_set::enable_loader_v4_min_transaction_signers::id(),
+                        );
+                        invoke_context.feature_set = Arc::new(feature_set);
+                    }
+                },
+                |_invoke_context| {},
+            );
+        }
+    }
+
     #[test]
     fn test_execute_program() {
         let program_address = Pubkey::new_unique();
@@ -81,6 +81,7 @@ fn main() {
             "get_minimum_delegation",
             "inner_instruction_alignment_check",
             "instruction_introspection",
+            "introspection_no_alloc",
             "invoke",
             "invoke_and_error",
             "invoke_and_ok",
@@ -105,6 +106,7 @@ fn main() {
             "sha",
             "sibling_inner_instruction",
             "sibling_instruction",
+            "signatures_sysvar",
             "simulation",
             "spoof1",
             "spoof1_system",
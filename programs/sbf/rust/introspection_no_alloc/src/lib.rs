@@ -0,0 +1,193 @@
+//! Example Rust-based SBF program that checks the on-chain accessors of the
+//! transaction introspection sysvars don't allocate
+//!
+//! The program's allocator refuses to allocate while the accessors run, which
+//! aborts the program if any of them allocates. With [`ALLOCATE`] as
+//! instruction data, the program allocates while refusing to, to check that
+//! the guard is effective.
+//!
+//! The accessors are called with valid arguments only: their error paths may
+//! log with formatting, which allocates.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+use {
+    core::hint::black_box,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::Clock,
+        entrypoint::{BumpAllocator, ProgramResult, HEAP_LENGTH, HEAP_START_ADDRESS},
+        hash::Hash,
+        pubkey::Pubkey,
+        sysvar::{
+            epoch_stake, header, recent_signatures, scratch, signatures, tick_hash,
+            transaction_context, Sysvar,
+        },
+    },
+    std::{
+        alloc::{GlobalAlloc, Layout},
+        mem::size_of,
+        ptr::null_mut,
+    },
+};
+
+/// Instruction data making the program allocate while allocation is refused
+pub const ALLOCATE: u8 = 1;
+
+/// Set while allocation is refused, stored in the first word of the heap
+const REFUSE_ALLOCATION: *mut usize = HEAP_START_ADDRESS as *mut usize;
+
+/// The default allocator, on the heap after [`REFUSE_ALLOCATION`], failing
+/// every allocation while it is set
+struct GuardedAllocator(BumpAllocator);
+
+unsafe impl GlobalAlloc for GuardedAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if *REFUSE_ALLOCATION != 0 {
+            return null_mut();
+        }
+        self.0.alloc(layout)
+    }
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.0.dealloc(ptr, layout);
+    }
+}
+
+#[cfg(target_os = "solana")]
+#[global_allocator]
+static A: GuardedAllocator = GuardedAllocator(BumpAllocator {
+    start: HEAP_START_ADDRESS as usize + size_of::<usize>(),
+    len: HEAP_LENGTH - size_of::<usize>(),
+});
+
+/// Run `f`, failing any allocation it makes
+fn without_allocating<T>(f: impl FnOnce() -> T) -> T {
+    unsafe { *REFUSE_ALLOCATION = 1 };
+    let result = f();
+    unsafe { *REFUSE_ALLOCATION = 0 };
+    result
+}
+
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if instruction_data == [ALLOCATE] {
+        without_allocating(|| black_box(Box::new(black_box(0u64))));
+        return Ok(());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    let signatures_info = next_account_info(account_info_iter)?;
+    let header_info = next_account_info(account_info_iter)?;
+    let transaction_context_info = next_account_info(account_info_iter)?;
+    let recent_signatures_info = next_account_info(account_info_iter)?;
+    let scratch_info = next_account_info(account_info_iter)?;
+    let epoch_stake_info = next_account_info(account_info_iter)?;
+    let tick_hash_info = next_account_info(account_info_iter)?;
+    let slot = Clock::get()?.slot;
+
+    without_allocating(|| {
+        // Signatures
+        black_box(signatures::load_num_signatures(signatures_info));
+        black_box(signatures::load_layout_version(signatures_info));
+        black_box(signatures::load_signature_at_checked(0, signatures_info));
+        black_box(signatures::load_signature_at_compat(0, signatures_info));
+        black_box(signatures::load_signer_and_signature_at_checked(
+            0,
+            signatures_info,
+        ));
+        black_box(signatures::verify_signers(&[], 0, signatures_info));
+        black_box(signatures::load_num_address_table_lookups_checked(
+            signatures_info,
+        ));
+        black_box(signatures::verify_integrity(signatures_info));
+        if let Ok(data) = signatures_info.try_borrow_data() {
+            black_box(signatures::load_signature_at_unchecked(0, &data));
+            black_box(signatures::validate_signatures_data(&data));
+        }
+        if let Ok(view) = signatures::SignaturesView::new(signatures_info) {
+            black_box(view.get(0));
+            for signature in view.iter() {
+                black_box(signature);
+            }
+            for signature in view.sysvar().iter() {
+                black_box(signature);
+            }
+        }
+        black_box(signatures::get_transaction_signature(0));
+        black_box(signatures::find_program_address_from_transaction_signature(
+            0, program_id,
+        ));
+        let mut message = [0; 64];
+        black_box(signatures::get_serialized_message(&mut message));
+
+        // Header
+        black_box(header::load_transaction_version(header_info));
+        black_box(header::load_header_checked(header_info));
+        black_box(header::check_freshness(header_info, slot));
+        black_box(header::signer_account_info_at(0, accounts));
+        black_box(header::is_fee_payer(
+            scratch_info,
+            header_info,
+            signatures_info,
+        ));
+
+        // Transaction context
+        black_box(transaction_context::load_transaction_header_checked(
+            transaction_context_info,
+        ));
+        black_box(transaction_context::load_recent_blockhash_checked(
+            transaction_context_info,
+        ));
+        black_box(transaction_context::load_num_account_keys_checked(
+            transaction_context_info,
+        ));
+        black_box(transaction_context::load_account_key_at_checked(
+            0,
+            transaction_context_info,
+        ));
+        black_box(transaction_context::load_num_signatures_checked(
+            transaction_context_info,
+        ));
+        black_box(transaction_context::load_signature_hash_at_checked(
+            0,
+            transaction_context_info,
+        ));
+        black_box(transaction_context::hash_signature(&[0; 64]));
+
+        // Recent signatures
+        black_box(recent_signatures::load_root_checked(
+            slot,
+            recent_signatures_info,
+        ));
+        black_box(recent_signatures::verify_signature_proof(
+            &[0; 64],
+            0,
+            &[],
+            &Hash::default(),
+        ));
+
+        // Scratch
+        black_box(scratch::write_scratch_checked(scratch_info, 0, &[1; 8]));
+        let mut data = [0; 8];
+        black_box(scratch::read_scratch_checked(scratch_info, 0, &mut data));
+
+        // Epoch stake
+        black_box(epoch_stake::load_total_stake_checked(epoch_stake_info));
+        black_box(epoch_stake::load_vote_account_stake_checked(
+            program_id,
+            epoch_stake_info,
+        ));
+
+        // Tick hash
+        black_box(tick_hash::load_tick_hash_checked(tick_hash_info));
+    });
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Example Rust-based SBF program that echoes the transaction signatures read
+//! from the signatures sysvar as return data
+//!
+//! With [`PROFILE_LOADERS`] as instruction data, the program instead returns
+//! the compute units consumed reading every signature with each loader, as
+//! little-endian `u64`s in the order of [`Loader`].
+
+use {
+    core::hint::black_box,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        compute_units::sol_remaining_compute_units,
+        entrypoint::ProgramResult,
+        program::set_return_data,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvar::signatures::{
+            self, get_transaction_signature, load_num_signatures, load_signature_at_checked,
+            load_signature_at_unchecked, SignaturesView,
+        },
+    },
+};
+
+/// Instruction data requesting the compute unit profile of the loaders
+pub const PROFILE_LOADERS: u8 = 1;
+
+/// The ways to read the signatures that are profiled
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Loader {
+    /// `load_signature_at_checked`, once per signature
+    Checked,
+    /// Iterating over a `SignaturesView`, including its construction
+    View,
+    /// The `sol_get_transaction_signature` syscall, once per signature
+    Syscall,
+    /// `load_signature_at_unchecked`, once per signature, on data checked and
+    /// borrowed once
+    Unchecked,
+}
+
+impl Loader {
+    pub const ALL: [Loader; 4] = [
+        Loader::Checked,
+        Loader::View,
+        Loader::Syscall,
+        Loader::Unchecked,
+    ];
+}
+
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let signatures_account_info = next_account_info(account_info_iter)?;
+
+    if instruction_data == [PROFILE_LOADERS] {
+        return profile_loaders(signatures_account_info);
+    }
+
+    let signatures = SignaturesView::new(signatures_account_info)?;
+    let return_data: Vec<u8> = signatures
+        .iter()
+        .flat_map(|signature| signature.to_bytes())
+        .collect();
+    set_return_data(&return_data);
+
+    Ok(())
+}
+
+/// Compute units consumed by `f`, excluding those of measuring them
+fn consumed_units(f: impl FnOnce() -> ProgramResult) -> Result<u64, ProgramError> {
+    let before = sol_remaining_compute_units();
+    f()?;
+    Ok(before.saturating_sub(sol_remaining_compute_units()))
+}
+
+fn profile_loaders(signatures_account_info: &AccountInfo) -> ProgramResult {
+    let num_signatures = load_num_signatures(signatures_account_info)?;
+    let overhead = consumed_units(|| Ok(()))?;
+
+    let mut return_data = Vec::with_capacity(Loader::ALL.len() * 8);
+    for loader in Loader::ALL {
+        let units = consumed_units(|| {
+            match loader {
+                Loader::Checked => {
+                    for index in 0..num_signatures {
+                        black_box(load_signature_at_checked(index, signatures_account_info)?);
+                    }
+                }
+                Loader::View => {
+                    for signature in SignaturesView::new(signatures_account_info)?.iter() {
+                        black_box(signature);
+                    }
+                }
+                Loader::Syscall => {
+                    for index in 0..num_signatures {
+                        black_box(
+                            get_transaction_signature(index)
+                                .ok_or(ProgramError::InvalidArgument)?,
+                        );
+                    }
+                }
+                Loader::Unchecked => {
+                    if !signatures::check_id(signatures_account_info.key) {
+                        return Err(ProgramError::UnsupportedSysvar);
+                    }
+                    let data = signatures_account_info.try_borrow_data()?;
+                    for index in 0..num_signatures {
+                        black_box(
+                            load_signature_at_unchecked(index, &data)
+                                .map_err(|_| ProgramError::InvalidAccountData)?,
+                        );
+                    }
+                }
+            }
+            Ok(())
+        })?;
+        return_data.extend_from_slice(&units.saturating_sub(overhead).to_le_bytes());
+    }
+    set_return_data(&return_data);
+
+    Ok(())
+}
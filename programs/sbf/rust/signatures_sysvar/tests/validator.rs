@@ -0,0 +1,64 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        sysvar,
+    },
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+    solana_transaction_status::UiReturnDataEncoding,
+    solana_validator::test_validator::*,
+};
+
+#[test]
+fn echo_signatures_through_rpc() {
+    solana_logger::setup_with_default("solana_program_runtime=debug");
+    let program_id = Pubkey::new_unique();
+
+    let (test_validator, payer) = TestValidatorGenesis::default()
+        .add_program("solana_sbf_rust_signatures_sysvar", program_id)
+        .start();
+    let rpc_client = test_validator.get_rpc_client();
+    let blockhash = rpc_client.get_latest_blockhash().unwrap();
+
+    let signers = [Keypair::new(), Keypair::new()];
+    let mut accounts = vec![AccountMeta::new_readonly(sysvar::signatures::id(), false)];
+    accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)),
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id,
+            accounts,
+            data: vec![],
+        }],
+        Some(&payer.pubkey()),
+        &[&payer, &signers[0], &signers[1]],
+        blockhash,
+    );
+    assert_eq!(transaction.signatures.len(), 3);
+    let expected_return_data: Vec<u8> = transaction
+        .signatures
+        .iter()
+        .flat_map(|signature| signature.as_ref().to_vec())
+        .collect();
+
+    let result = rpc_client.simulate_transaction(&transaction).unwrap().value;
+    assert_eq!(result.err, None);
+    let return_data = result.return_data.unwrap();
+    assert_eq!(return_data.program_id, program_id.to_string());
+    let (data, encoding) = return_data.data;
+    assert_eq!(encoding, UiReturnDataEncoding::Base64);
+    assert_eq!(BASE64_STANDARD.decode(data).unwrap(), expected_return_data);
+
+    rpc_client
+        .send_and_confirm_transaction(&transaction)
+        .unwrap();
+}
@@ -1529,6 +1529,127 @@ fn test_program_sbf_instruction_introspection() {
     assert!(bank.get_account(&sysvar::instructions::id()).is_none());
 }
 
+#[test]
+#[cfg(feature = "sbf_rust")]
+fn test_program_sbf_signatures_sysvar_compute_units() {
+    solana_logger::setup();
+
+    // Bounds on the compute units consumed reading one signature, in the order
+    // the program reports them. The syscall is charged at least its metered
+    // cost, so that looping over it isn't cheaper than its execution.
+    let budget = ComputeBudget::default();
+    let syscall_cost =
+        budget.transaction_signature_base_cost + budget.transaction_signature_copy_cost;
+    let loaders = [
+        ("load_signature_at_checked", 0, 200),
+        ("SignaturesView::iter", 0, 100),
+        ("sol_get_transaction_signature", syscall_cost, 200),
+        ("load_signature_at_unchecked", 0, 100),
+    ];
+
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config(50_000);
+    let bank = Bank::new_for_tests(&genesis_config);
+    let bank = Arc::new(bank);
+    let mut bank_client = BankClient::new_shared(bank.clone());
+
+    let (bank, program_id) = load_program_and_advance_slot(
+        &mut bank_client,
+        &bpf_loader::id(),
+        &mint_keypair,
+        "solana_sbf_rust_signatures_sysvar",
+    );
+    bank.freeze();
+
+    let signers = [Keypair::new(), Keypair::new()];
+    let mut account_metas = vec![AccountMeta::new_readonly(sysvar::signatures::id(), false)];
+    account_metas.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)),
+    );
+    // Profile the loaders rather than echo the signatures
+    let instruction = Instruction::new_with_bytes(program_id, &[1], account_metas);
+    let message = Message::new(&[instruction], Some(&mint_keypair.pubkey()));
+    let transaction = Transaction::new(
+        &[&mint_keypair, &signers[0], &signers[1]],
+        message,
+        bank.last_blockhash(),
+    );
+    let num_signatures = transaction.signatures.len() as u64;
+    let sanitized_tx = SanitizedTransaction::from_transaction_for_tests(transaction);
+
+    let result = bank.simulate_transaction(sanitized_tx);
+    assert_eq!(result.result, Ok(()));
+    let return_data = result.return_data.unwrap().data;
+    assert_eq!(return_data.len(), loaders.len() * 8);
+
+    let units_per_signature: Vec<_> = return_data
+        .chunks_exact(8)
+        .map(|units| u64::from_le_bytes(units.try_into().unwrap()) / num_signatures)
+        .collect();
+    println!("\n  {:30} per signature  bounds", "Loader");
+    for ((loader, lower, upper), &units_per_signature) in
+        loaders.iter().zip(units_per_signature.iter())
+    {
+        println!(
+            "  {:30} {:13} {:>6}..={}",
+            loader, units_per_signature, lower, upper
+        );
+        assert!(
+            (*lower..=*upper).contains(&units_per_signature),
+            "{loader} consumed {units_per_signature} compute units per signature, outside of {lower}..={upper}"
+        );
+    }
+    // Skipping the account check and borrow on every lookup saves units
+    assert!(units_per_signature[3] < units_per_signature[0]);
+}
+
+#[test]
+#[cfg(feature = "sbf_rust")]
+fn test_program_sbf_introspection_no_alloc() {
+    solana_logger::setup();
+
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config(50_000);
+    let bank = Bank::new_for_tests(&genesis_config);
+    let bank = Arc::new(bank);
+    let mut bank_client = BankClient::new_shared(bank);
+
+    let (_bank, program_id) = load_program_and_advance_slot(
+        &mut bank_client,
+        &bpf_loader::id(),
+        &mint_keypair,
+        "solana_sbf_rust_introspection_no_alloc",
+    );
+
+    let account_metas = vec![
+        AccountMeta::new_readonly(sysvar::signatures::id(), false),
+        AccountMeta::new_readonly(sysvar::header::id(), false),
+        AccountMeta::new_readonly(sysvar::transaction_context::id(), false),
+        AccountMeta::new_readonly(sysvar::recent_signatures::id(), false),
+        AccountMeta::new_readonly(sysvar::scratch::id(), false),
+        AccountMeta::new_readonly(sysvar::epoch_stake::id(), false),
+        AccountMeta::new_readonly(sysvar::tick_hash::id(), false),
+    ];
+
+    // None of the accessors allocates
+    let instruction = Instruction::new_with_bytes(program_id, &[0], account_metas.clone());
+    let result = bank_client.send_and_confirm_instruction(&mint_keypair, instruction);
+    assert!(result.is_ok());
+
+    // Allocating while the program refuses to aborts it
+    let instruction = Instruction::new_with_bytes(program_id, &[1], account_metas);
+    let result = bank_client.send_and_confirm_instruction(&mint_keypair, instruction);
+    assert!(result.is_err());
+}
+
 #[test]
 #[cfg(feature = "sbf_rust")]
 fn test_program_sbf_test_use_latest_executor() {
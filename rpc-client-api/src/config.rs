@@ -44,6 +44,10 @@ pub struct RpcSimulateTransactionConfig {
     pub encoding: Option<UiTransactionEncoding>,
     pub accounts: Option<RpcSimulateTransactionAccountsConfig>,
     pub min_context_slot: Option<Slot>,
+    /// Construct the signatures sysvar from the submitted signatures, which
+    /// may be placeholders, even before the signatures sysvar is enabled
+    #[serde(default)]
+    pub populate_signatures_sysvar: bool,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -73,6 +73,8 @@ pub enum RpcRequest {
     GetMinimumBalanceForRentExemption,
     GetMultipleAccounts,
     GetProgramAccounts,
+    GetProgramAddressFromSignature,
+    GetProgramSysvarDependencies,
     #[deprecated(
         since = "1.9.0",
         note = "Please use RpcRequest::GetLatestBlockhash instead"
@@ -80,12 +82,14 @@ pub enum RpcRequest {
     GetRecentBlockhash,
     GetRecentPerformanceSamples,
     GetRecentPrioritizationFees,
+    GetRequiredSigners,
     GetHighestSnapshotSlot,
     #[deprecated(
         since = "1.9.0",
         note = "Please use RpcRequest::GetHighestSnapshotSlot instead"
     )]
     GetSnapshotSlot,
+    GetSignatureMerkleProof,
     GetSignaturesForAddress,
     GetSignatureStatuses,
     GetSlot,
@@ -104,7 +108,9 @@ pub enum RpcRequest {
     GetTokenLargestAccounts,
     GetTokenSupply,
     GetTransaction,
+    GetTransactionByAnySignature,
     GetTransactionCount,
+    GetTransactionShredProof,
     GetVersion,
     GetVoteAccounts,
     IsBlockhashValid,
@@ -157,11 +163,15 @@ impl fmt::Display for RpcRequest {
             RpcRequest::GetMinimumBalanceForRentExemption => "getMinimumBalanceForRentExemption",
             RpcRequest::GetMultipleAccounts => "getMultipleAccounts",
             RpcRequest::GetProgramAccounts => "getProgramAccounts",
+            RpcRequest::GetProgramAddressFromSignature => "getProgramAddressFromSignature",
+            RpcRequest::GetProgramSysvarDependencies => "getProgramSysvarDependencies",
             RpcRequest::GetRecentBlockhash => "getRecentBlockhash",
             RpcRequest::GetRecentPerformanceSamples => "getRecentPerformanceSamples",
             RpcRequest::GetRecentPrioritizationFees => "getRecentPrioritizationFees",
+            RpcRequest::GetRequiredSigners => "getRequiredSigners",
             RpcRequest::GetHighestSnapshotSlot => "getHighestSnapshotSlot",
             RpcRequest::GetSnapshotSlot => "getSnapshotSlot",
+            RpcRequest::GetSignatureMerkleProof => "getSignatureMerkleProof",
             RpcRequest::GetSignaturesForAddress => "getSignaturesForAddress",
             RpcRequest::GetSignatureStatuses => "getSignatureStatuses",
             RpcRequest::GetSlot => "getSlot",
@@ -180,7 +190,9 @@ impl fmt::Display for RpcRequest {
             RpcRequest::GetTokenSupply => "getTokenSupply",
             RpcRequest::GetTokenLargestAccounts => "getTokenLargestAccounts",
             RpcRequest::GetTransaction => "getTransaction",
+            RpcRequest::GetTransactionByAnySignature => "getTransactionByAnySignature",
             RpcRequest::GetTransactionCount => "getTransactionCount",
+            RpcRequest::GetTransactionShredProof => "getTransactionShredProof",
             RpcRequest::GetVersion => "getVersion",
             RpcRequest::GetVoteAccounts => "getVoteAccounts",
             RpcRequest::IsBlockhashValid => "isBlockhashValid",
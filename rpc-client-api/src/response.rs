@@ -13,7 +13,12 @@ use {
         ConfirmedTransactionStatusWithSignature, TransactionConfirmationStatus, UiConfirmedBlock,
         UiTransactionReturnData,
     },
-    std::{collections::HashMap, fmt, net::SocketAddr, str::FromStr},
+    std::{
+        collections::{BTreeMap, HashMap},
+        fmt,
+        net::SocketAddr,
+        str::FromStr,
+    },
     thiserror::Error,
 };
 
@@ -423,6 +428,35 @@ pub struct RpcSimulateTransactionResult {
     pub accounts: Option<Vec<Option<UiAccount>>>,
     pub units_consumed: Option<u64>,
     pub return_data: Option<UiTransactionReturnData>,
+    /// Introspection sysvars the transaction exposed to the programs it
+    /// invokes, omitted if none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub introspection_accessed: Option<Vec<String>>,
+    /// What the introspection sysvars exposed to the transaction, omitted
+    /// unless the transaction accesses them and introspection features are
+    /// active
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub introspection: Option<RpcSimulateIntrospection>,
+}
+
+/// Version of the [`RpcSimulateIntrospection`] schema.
+pub const RPC_SIMULATE_INTROSPECTION_VERSION: u8 = 1;
+
+/// Summary of the transaction as seen through the introspection sysvars.
+///
+/// Fields are only added or changed along with an increment of `version`, so
+/// that clients can check the version before relying on the schema.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSimulateIntrospection {
+    /// Schema version, see [`RPC_SIMULATE_INTROSPECTION_VERSION`]
+    pub version: u8,
+    pub num_instructions: u64,
+    pub num_signatures: u64,
+    /// Layout version of each introspection sysvar available to the
+    /// transaction, keyed by base-58 encoded sysvar address. Ordered by
+    /// address so the serialized output is deterministic
+    pub sysvar_versions: BTreeMap<String, u8>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -490,6 +524,10 @@ pub struct RpcPerfSample {
     pub slot: Slot,
     pub num_transactions: u64,
     pub num_non_vote_transactions: Option<u64>,
+    /// Transactions referencing per-transaction sysvars, such as the
+    /// signatures sysvar, `None` in samples recorded before they were counted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_introspection_transactions: Option<u64>,
     pub num_slots: u64,
     pub sample_period_secs: u16,
 }
@@ -554,6 +592,54 @@ pub struct RpcPrioritizationFee {
     pub prioritization_fee: u64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcProgramAddress {
+    pub address: String,
+    pub bump_seed: u8,
+}
+
+/// Data shreds carrying a rooted transaction.
+///
+/// `shreds` are the base64 encoded payloads of consecutive data shreds signed
+/// by `leader`. The bincode serialized transaction spans
+/// `transactionOffset..transactionOffset + transactionLen` of the concatenated
+/// data sections of the shreds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionShredProof {
+    pub slot: Slot,
+    pub leader: Option<String>,
+    pub shreds: Vec<String>,
+    pub transaction_offset: usize,
+    pub transaction_len: usize,
+}
+
+/// Proof that a transaction signature is part of the root of its slot in the
+/// recent signatures sysvar.
+///
+/// `root` and the hashes of `proof` are base58 encoded. `index` is the
+/// position of the signature among the sorted signatures of the slot.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSignatureMerkleProof {
+    pub slot: Slot,
+    pub root: String,
+    pub index: usize,
+    pub proof: Vec<String>,
+}
+
+/// Signers required by a message.
+///
+/// `signers` are the base58 encoded pubkeys that must sign the message, in the
+/// order their signatures appear in the transaction.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcRequiredSigners {
+    pub signers: Vec<String>,
+    pub num_required_signatures: usize,
+}
+
 #[cfg(test)]
 pub mod tests {
 
@@ -582,6 +668,7 @@ pub mod tests {
             slot,
             num_transactions,
             num_non_vote_transactions: None,
+            num_introspection_transactions: None,
             num_slots,
             sample_period_secs,
         };
@@ -602,6 +689,7 @@ pub mod tests {
             slot,
             num_transactions,
             num_non_vote_transactions,
+            num_introspection_transactions: None,
             num_slots,
             sample_period_secs,
         };
@@ -617,4 +705,33 @@ pub mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    // Make sure that `RpcPerfSample` serializes the `num_introspection_transactions` field only
+    // when it is known.
+    #[test]
+    fn rpc_perf_sample_serializes_num_introspection_transactions() {
+        let input = RpcPerfSample {
+            slot: 2047,
+            num_transactions: 1523,
+            num_non_vote_transactions: Some(904),
+            num_introspection_transactions: Some(61),
+            num_slots: 150,
+            sample_period_secs: 60,
+        };
+        let actual = serde_json::to_value(input.clone())
+            .expect("Can convert RpcPerfSample into a JSON value");
+        let expected = json!({
+            "slot": 2047,
+            "numTransactions": 1523,
+            "numNonVoteTransactions": 904,
+            "numIntrospectionTransactions": 61,
+            "numSlots": 150,
+            "samplePeriodSecs": 60,
+        });
+        assert_eq!(actual, expected);
+
+        let deserialized: RpcPerfSample =
+            serde_json::from_value(actual).expect("Can parse RpcPerfSample from JSON");
+        assert_eq!(deserialized, input);
+    }
 }
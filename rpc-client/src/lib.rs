@@ -6,6 +6,7 @@ pub mod nonblocking;
 pub mod rpc_client;
 pub mod rpc_sender;
 pub mod spinner;
+pub mod sysvar;
 
 pub mod mock_sender_for_cli {
     /// Magic `SIGNATURE` value used by `solana-cli` unit tests.
@@ -350,6 +350,8 @@ impl RpcSender for MockSender {
                     accounts: None,
                     units_consumed: None,
                     return_data: None,
+                    introspection_accessed: None,
+                    introspection: None,
                 },
             })?,
             "getMinimumBalanceForRentExemption" => json![20],
@@ -420,6 +422,7 @@ impl RpcSender for MockSender {
                 slot: 347873,
                 num_transactions: 125,
                 num_non_vote_transactions: Some(1),
+                num_introspection_transactions: None,
                 num_slots: 123,
                 sample_period_secs: 60,
             }])?,
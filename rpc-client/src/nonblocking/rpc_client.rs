@@ -23,6 +23,7 @@ use {
             SerializableTransaction,
         },
         rpc_sender::*,
+        sysvar::DecodeSysvar,
     },
     base64::{prelude::BASE64_STANDARD, Engine},
     bincode::serialize,
@@ -3976,6 +3977,60 @@ impl RpcClient {
             })?
     }
 
+    /// Returns the sysvar `T`, decoded from the data of its account.
+    ///
+    /// This method uses the configured [commitment level][cl].
+    ///
+    /// [cl]: https://docs.solana.com/developing/clients/jsonrpc-api#configuring-state-commitment
+    ///
+    /// Any type implementing [`DecodeSysvar`] can be fetched, including the
+    /// bincode-encoded sysvars such as [`Clock`] and [`SlotHashes`], and the
+    /// [`SignaturesData`] and [`HeaderData`] layouts of the per-transaction
+    /// signatures and header sysvars.
+    ///
+    /// [`Clock`]: solana_sdk::sysvar::clock::Clock
+    /// [`SlotHashes`]: solana_sdk::sysvar::slot_hashes::SlotHashes
+    /// [`SignaturesData`]: solana_sdk::sysvar::signatures::SignaturesData
+    /// [`HeaderData`]: solana_sdk::sysvar::header::HeaderData
+    ///
+    /// # Errors
+    ///
+    /// If the sysvar account does not exist, this method returns
+    /// [`RpcError::ForUser`]. If its data cannot be decoded as `T`, this
+    /// method returns [`RpcError::ParseError`].
+    ///
+    /// # RPC Reference
+    ///
+    /// This method is built on the [`getAccountInfo`] RPC method.
+    ///
+    /// [`getAccountInfo`]: https://docs.solana.com/developing/clients/jsonrpc-api#getaccountinfo
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use solana_rpc_client_api::client_error::Error;
+    /// # use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+    /// # use solana_sdk::sysvar::clock::Clock;
+    /// # futures::executor::block_on(async {
+    /// #     let rpc_client = RpcClient::new_mock("succeeds".to_string());
+    /// let clock = rpc_client.get_parsed_sysvar::<Clock>().await?;
+    /// println!("unix timestamp: {}", clock.unix_timestamp);
+    /// #     Ok::<(), Error>(())
+    /// # })?;
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub async fn get_parsed_sysvar<T: DecodeSysvar>(&self) -> ClientResult<T> {
+        let sysvar_id = T::sysvar_id();
+        let account = self.get_account(&sysvar_id).await?;
+        T::decode(&account.data)
+            .map_err(|err| RpcError::ParseError(format!("sysvar {sysvar_id}: {err}")).into())
+    }
+
+    #[deprecated(since = "1.18.0", note = "Please use `get_parsed_sysvar` instead")]
+    pub async fn get_account_with_sysvar_decoding<T: DecodeSysvar>(&self) -> ClientResult<T> {
+        self.get_parsed_sysvar().await
+    }
+
     /// Get the max slot seen from retransmit stage.
     ///
     /// # RPC Reference
@@ -20,6 +20,7 @@ use {
         mock_sender::MockSender,
         nonblocking::{self, rpc_client::get_rpc_request_str},
         rpc_sender::*,
+        sysvar::DecodeSysvar,
     },
     serde::Serialize,
     serde_json::Value,
@@ -3155,6 +3156,57 @@ impl RpcClient {
         self.invoke((self.rpc_client.as_ref()).get_account(pubkey))
     }
 
+    /// Returns the sysvar `T`, decoded from the data of its account.
+    ///
+    /// This method uses the configured [commitment level][cl].
+    ///
+    /// [cl]: https://docs.solana.com/developing/clients/jsonrpc-api#configuring-state-commitment
+    ///
+    /// Any type implementing [`DecodeSysvar`] can be fetched, including the
+    /// bincode-encoded sysvars such as [`Clock`] and [`SlotHashes`], and the
+    /// [`SignaturesData`] and [`HeaderData`] layouts of the per-transaction
+    /// signatures and header sysvars.
+    ///
+    /// [`Clock`]: solana_sdk::sysvar::clock::Clock
+    /// [`SlotHashes`]: solana_sdk::sysvar::slot_hashes::SlotHashes
+    /// [`SignaturesData`]: solana_sdk::sysvar::signatures::SignaturesData
+    /// [`HeaderData`]: solana_sdk::sysvar::header::HeaderData
+    ///
+    /// # Errors
+    ///
+    /// If the sysvar account does not exist, this method returns
+    /// [`RpcError::ForUser`]. If its data cannot be decoded as `T`, this
+    /// method returns [`RpcError::ParseError`].
+    ///
+    /// [`RpcError::ForUser`]: solana_rpc_client_api::request::RpcError::ForUser
+    /// [`RpcError::ParseError`]: solana_rpc_client_api::request::RpcError::ParseError
+    ///
+    /// # RPC Reference
+    ///
+    /// This method is built on the [`getAccountInfo`] RPC method.
+    ///
+    /// [`getAccountInfo`]: https://docs.solana.com/developing/clients/jsonrpc-api#getaccountinfo
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use solana_rpc_client_api::client_error::Error;
+    /// # use solana_rpc_client::rpc_client::RpcClient;
+    /// # use solana_sdk::sysvar::clock::Clock;
+    /// # let rpc_client = RpcClient::new_mock("succeeds".to_string());
+    /// let clock = rpc_client.get_parsed_sysvar::<Clock>()?;
+    /// println!("unix timestamp: {}", clock.unix_timestamp);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn get_parsed_sysvar<T: DecodeSysvar>(&self) -> ClientResult<T> {
+        self.invoke((self.rpc_client.as_ref()).get_parsed_sysvar())
+    }
+
+    #[deprecated(since = "1.18.0", note = "Please use `get_parsed_sysvar` instead")]
+    pub fn get_account_with_sysvar_decoding<T: DecodeSysvar>(&self) -> ClientResult<T> {
+        self.get_parsed_sysvar()
+    }
+
     /// Returns all information associated with the account of the provided pubkey.
     ///
     /// If the account does not exist, this method returns `Ok(None)`.
@@ -4103,11 +4155,12 @@ mod tests {
         jsonrpc_core::{futures::prelude::*, Error, IoHandler, Params},
         jsonrpc_http_server::{AccessControlAllowOrigin, DomainsValidation, ServerBuilder},
         serde_json::{json, Number},
-        solana_rpc_client_api::client_error::ErrorKind,
+        solana_rpc_client_api::{client_error::ErrorKind, request::RpcError},
         solana_sdk::{
             instruction::InstructionError,
             signature::{Keypair, Signer},
             system_transaction,
+            sysvar::{self, clock::Clock},
             transaction::TransactionError,
         },
         std::{io, thread},
@@ -4347,6 +4400,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_parsed_sysvar() {
+        let clock = Clock {
+            slot: 1,
+            epoch_start_timestamp: 2,
+            epoch: 3,
+            leader_schedule_epoch: 4,
+            unix_timestamp: 5,
+        };
+        let mock_account = |data: Vec<u8>| {
+            let account = Account {
+                lamports: 1_000_000,
+                data,
+                owner: sysvar::id(),
+                executable: false,
+                rent_epoch: 0,
+            };
+            let mocks: Mocks = [(
+                RpcRequest::GetAccountInfo,
+                serde_json::to_value(Response {
+                    context: RpcResponseContext {
+                        slot: 1,
+                        api_version: None,
+                    },
+                    value: UiAccount::encode(
+                        &sysvar::clock::id(),
+                        &account,
+                        UiAccountEncoding::Base64,
+                        None,
+                        None,
+                    ),
+                })
+                .unwrap(),
+            )]
+            .into_iter()
+            .collect();
+            RpcClient::new_mock_with_mocks("mock_client".to_string(), mocks)
+        };
+
+        let rpc_client = mock_account(bincode::serialize(&clock).unwrap());
+        assert_eq!(rpc_client.get_parsed_sysvar::<Clock>().unwrap(), clock);
+
+        let rpc_client = mock_account(vec![0; 8]);
+        assert_matches!(
+            rpc_client.get_parsed_sysvar::<Clock>().unwrap_err().kind(),
+            ErrorKind::RpcError(RpcError::ParseError(_))
+        );
+    }
+
     #[test]
     fn test_get_program_accounts_with_config() {
         let program_id = Pubkey::new_unique();
@@ -0,0 +1,131 @@
+//! Decoding of sysvar accounts fetched over RPC.
+//!
+//! See [`RpcClient::get_parsed_sysvar`].
+//!
+//! [`RpcClient::get_parsed_sysvar`]: crate::rpc_client::RpcClient::get_parsed_sysvar
+
+use solana_sdk::{
+    pubkey::Pubkey,
+    sysvar::{
+        self, clock::Clock, epoch_rewards::EpochRewards, epoch_schedule::EpochSchedule,
+        header::HeaderData, last_restart_slot::LastRestartSlot,
+        recent_signatures::RecentSignatures, rent::Rent, signatures::SignaturesData,
+        slot_hashes::SlotHashes, slot_history::SlotHistory, stake_history::StakeHistory, SysvarId,
+    },
+};
+
+/// A sysvar that can be decoded from the data of its account.
+pub trait DecodeSysvar: Sized {
+    /// The address of the sysvar account.
+    fn sysvar_id() -> Pubkey;
+
+    /// Decode the sysvar from the data of its account.
+    fn decode(data: &[u8]) -> Result<Self, String>;
+}
+
+macro_rules! impl_decode_sysvar_with_bincode {
+    ($($sysvar:ty),* $(,)?) => {
+        $(
+            impl DecodeSysvar for $sysvar {
+                fn sysvar_id() -> Pubkey {
+                    <$sysvar as SysvarId>::id()
+                }
+
+                fn decode(data: &[u8]) -> Result<Self, String> {
+                    bincode::deserialize(data).map_err(|err| err.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_decode_sysvar_with_bincode!(
+    Clock,
+    EpochRewards,
+    EpochSchedule,
+    LastRestartSlot,
+    RecentSignatures,
+    Rent,
+    SlotHashes,
+    SlotHistory,
+    StakeHistory,
+);
+
+impl DecodeSysvar for SignaturesData {
+    fn sysvar_id() -> Pubkey {
+        sysvar::signatures::id()
+    }
+
+    fn decode(data: &[u8]) -> Result<Self, String> {
+        SignaturesData::deserialize(data).map_err(|err| err.to_string())
+    }
+}
+
+impl DecodeSysvar for HeaderData {
+    fn sysvar_id() -> Pubkey {
+        sysvar::header::id()
+    }
+
+    fn decode(data: &[u8]) -> Result<Self, String> {
+        HeaderData::deserialize(data).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            hash::Hash,
+            message::{LegacyMessage, Message, SanitizedMessage},
+            sysvar::{
+                header::{construct_header_data, FreshnessTag, TransactionVersion},
+                signatures::{construct_signatures_data, TxSignature},
+            },
+        },
+    };
+
+    #[test]
+    fn test_decode_sysvar() {
+        let clock = Clock {
+            slot: 1,
+            epoch_start_timestamp: 2,
+            epoch: 3,
+            leader_schedule_epoch: 4,
+            unix_timestamp: 5,
+        };
+        assert_eq!(<Clock as DecodeSysvar>::sysvar_id(), sysvar::clock::id());
+        assert_eq!(
+            Clock::decode(&bincode::serialize(&clock).unwrap()),
+            Ok(clock)
+        );
+        assert!(Clock::decode(&[0; 8]).is_err());
+
+        let signatures = vec![TxSignature::from([7; 64])];
+        assert_eq!(SignaturesData::sysvar_id(), sysvar::signatures::id());
+        assert_eq!(
+            SignaturesData::decode(&construct_signatures_data(&signatures).unwrap())
+                .unwrap()
+                .signatures,
+            signatures
+        );
+        assert!(SignaturesData::decode(&[]).is_err());
+
+        let payer = Pubkey::new_unique();
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(&[], Some(&payer))));
+        let freshness_tag = FreshnessTag {
+            slot: 1,
+            message_hash: Hash::new_unique(),
+        };
+        assert_eq!(HeaderData::sysvar_id(), sysvar::header::id());
+        assert_eq!(
+            HeaderData::decode(&construct_header_data(&message, &freshness_tag)),
+            Ok(HeaderData {
+                version: TransactionVersion::Legacy,
+                freshness_tag,
+                message_header: None,
+            })
+        );
+        assert!(HeaderData::decode(&[0; 2]).is_err());
+    }
+}
@@ -26,7 +26,7 @@ use {
     solana_ledger::{
         blockstore::{Blockstore, SignatureInfosForAddress},
         blockstore_db::BlockstoreError,
-        blockstore_meta::{PerfSample, PerfSampleV1, PerfSampleV2},
+        blockstore_meta::{PerfSample, PerfSampleV1, PerfSampleV2, PerfSampleV3},
         get_tmp_ledger_path,
         leader_schedule_cache::LeaderScheduleCache,
     },
@@ -49,6 +49,7 @@ use {
     solana_runtime::{
         bank::{Bank, TransactionSimulationResult},
         bank_forks::BankForks,
+        bank_utils::verify_transaction_signatures_batch,
         commitment::{BlockCommitmentArray, BlockCommitmentCache, CommitmentSlots},
         non_circulating_supply::calculate_non_circulating_supply,
         prioritization_fee_cache::PrioritizationFeeCache,
@@ -58,6 +59,7 @@ use {
     solana_sdk::{
         account::{AccountSharedData, ReadableAccount},
         account_utils::StateMut,
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
         clock::{Slot, UnixTimestamp, MAX_RECENT_BLOCKHASHES},
         commitment_config::{CommitmentConfig, CommitmentLevel},
         epoch_info::EpochInfo,
@@ -66,13 +68,13 @@ use {
         feature_set,
         fee_calculator::FeeCalculator,
         hash::Hash,
-        message::SanitizedMessage,
+        message::{SanitizedMessage, SanitizedVersionedMessage, VersionedMessage},
         pubkey::{Pubkey, PUBKEY_BYTES},
         signature::{Keypair, Signature, Signer},
         stake::state::{StakeActivationStatus, StakeStateV2},
         stake_history::StakeHistory,
         system_instruction,
-        sysvar::stake_history,
+        sysvar::{self, signatures, stake_history},
         transaction::{
             self, AddressLoader, MessageHash, SanitizedTransaction, TransactionError,
             VersionedTransaction, MAX_TX_ACCOUNT_LOCKS,
@@ -1539,6 +1541,102 @@ impl JsonRpcRequestProcessor {
         Ok(None)
     }
 
+    /// Returns the transaction carrying `signature`, which may be any of its
+    /// signatures rather than only the first
+    ///
+    /// Signatures other than the first are only indexed in the blockstore, so
+    /// transactions that are only available from BigTable can't be found by
+    /// them.
+    pub async fn get_transaction_by_any_signature(
+        &self,
+        signature: Signature,
+        config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+    ) -> Result<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        if !self.config.enable_rpc_transaction_history {
+            return Err(RpcCustomError::TransactionHistoryNotAvailable.into());
+        }
+        let signature = self
+            .blockstore
+            .get_first_signature(signature)
+            .unwrap_or(signature);
+        self.get_transaction(signature, config).await
+    }
+
+    /// Returns the data shreds carrying a finalized transaction, which a
+    /// client can check against the slot leader's signature instead of
+    /// trusting this node
+    pub fn get_transaction_shred_proof(
+        &self,
+        signature: Signature,
+    ) -> Result<Option<RpcTransactionShredProof>> {
+        if !self.config.enable_rpc_transaction_history {
+            return Err(RpcCustomError::TransactionHistoryNotAvailable.into());
+        }
+        let Some(transaction_shreds) = self
+            .blockstore
+            .get_rooted_transaction_shreds(signature)
+            .unwrap_or(None)
+        else {
+            return Ok(None);
+        };
+        let slot = transaction_shreds.slot;
+        if slot
+            > self
+                .block_commitment_cache
+                .read()
+                .unwrap()
+                .highest_super_majority_root()
+        {
+            return Ok(None);
+        }
+
+        let transaction_range = transaction_shreds.transaction_range;
+        Ok(Some(RpcTransactionShredProof {
+            slot,
+            leader: self
+                .leader_schedule_cache
+                .slot_leader_at(slot, None)
+                .map(|leader| leader.to_string()),
+            shreds: transaction_shreds
+                .shreds
+                .iter()
+                .map(|payload| BASE64_STANDARD.encode(payload))
+                .collect(),
+            transaction_offset: transaction_range.start,
+            transaction_len: transaction_range.len(),
+        }))
+    }
+
+    /// Returns the proof that a transaction is part of the root of a
+    /// finalized slot in the recent signatures sysvar
+    pub fn get_signature_merkle_proof(
+        &self,
+        slot: Slot,
+        signature: Signature,
+    ) -> Result<Option<RpcSignatureMerkleProof>> {
+        if slot
+            > self
+                .block_commitment_cache
+                .read()
+                .unwrap()
+                .highest_super_majority_root()
+            || !self.blockstore.is_root(slot)
+        {
+            return Ok(None);
+        }
+        let result = self.blockstore.get_signature_merkle_proof(slot, signature);
+        self.check_slot_cleaned_up(&result, slot)?;
+        let Some(proof) = result.ok().flatten() else {
+            return Ok(None);
+        };
+        Ok(Some(RpcSignatureMerkleProof {
+            slot: proof.slot,
+            root: proof.root.to_string(),
+            index: proof.index,
+            proof: proof.proof.iter().map(Hash::to_string).collect(),
+        }))
+    }
+
     pub fn get_confirmed_signatures_for_address(
         &self,
         pubkey: Pubkey,
@@ -2178,6 +2276,125 @@ impl JsonRpcRequestProcessor {
             })
             .collect())
     }
+
+    fn get_program_sysvar_dependencies(
+        &self,
+        program_id: &Pubkey,
+        config: RpcContextConfig,
+    ) -> Result<RpcResponse<Option<Vec<String>>>> {
+        let bank = self.get_bank_with_config(config)?;
+        let (program_metadata_address, _) =
+            bpf_loader_upgradeable::find_program_metadata_address(program_id);
+        let sysvar_dependencies = bank
+            .get_account(&program_metadata_address)
+            .filter(|account| bpf_loader_upgradeable::check_id(account.owner()))
+            .and_then(|account| match account.state() {
+                Ok(UpgradeableLoaderState::ProgramMetadata {
+                    sysvar_dependencies,
+                }) => Some(
+                    bpf_loader_upgradeable::sysvar_dependencies_to_ids(sysvar_dependencies)
+                        .iter()
+                        .map(|sysvar_id| sysvar_id.to_string())
+                        .collect(),
+                ),
+                _ => None,
+            });
+        Ok(new_response(&bank, sysvar_dependencies))
+    }
+}
+
+/// Returns the introspection sysvars passed to any instruction of the
+/// message, and thus readable by the programs the transaction invokes.
+///
+/// Accounts can only be passed on through CPI if the caller was given them,
+/// so checking the top-level instructions is sufficient.
+fn get_introspection_accessed(message: &SanitizedMessage) -> Vec<Pubkey> {
+    let account_keys = message.account_keys();
+    sysvar::PER_TRANSACTION_IDS
+        .iter()
+        .filter(|sysvar_id| {
+            message.instructions().iter().any(|instruction| {
+                instruction
+                    .accounts
+                    .iter()
+                    .any(|index| account_keys.get(*index as usize) == Some(*sysvar_id))
+            })
+        })
+        .copied()
+        .collect()
+}
+
+/// Returns the summary of the transaction exposed through the introspection
+/// sysvars, or `None` if no transaction introspection feature is active.
+///
+/// `populate_signatures_sysvar` reports the signatures sysvar as available
+/// whether or not it's enabled, as requested by the simulation config.
+fn get_simulate_introspection(
+    transaction: &SanitizedTransaction,
+    feature_set: &feature_set::FeatureSet,
+    populate_signatures_sysvar: bool,
+) -> Option<RpcSimulateIntrospection> {
+    let introspection_features = feature_set::FEATURE_GROUPS.get("transaction-introspection")?;
+    if !introspection_features
+        .iter()
+        .any(|feature_id| feature_set.is_active(feature_id))
+    {
+        return None;
+    }
+
+    let signatures_version = if feature_set
+        .is_active(&feature_set::signer_pubkeys_in_signatures_sysvar::id())
+    {
+        signatures::SIGNER_PAIRS_LAYOUT_VERSION
+    } else if feature_set.is_active(&feature_set::u16_signature_count_in_signatures_sysvar::id()) {
+        signatures::WIDE_COUNT_LAYOUT_VERSION
+    } else {
+        0
+    };
+    let sysvar_versions = [
+        (sysvar::instructions::id(), Some(0)),
+        (
+            signatures::id(),
+            (populate_signatures_sysvar
+                || feature_set.is_active(&feature_set::enable_signatures_sysvar::id()))
+            .then_some(signatures_version),
+        ),
+        (
+            sysvar::header::id(),
+            feature_set
+                .is_active(&feature_set::header_sysvar::id())
+                .then_some(0),
+        ),
+        (
+            sysvar::scratch::id(),
+            feature_set
+                .is_active(&feature_set::scratch_sysvar::id())
+                .then_some(0),
+        ),
+        (
+            sysvar::tick_hash::id(),
+            feature_set
+                .is_active(&feature_set::tick_hash_sysvar::id())
+                .then_some(0),
+        ),
+        (
+            sysvar::transaction_context::id(),
+            feature_set
+                .is_active(&feature_set::transaction_context_sysvar::id())
+                .then_some(0),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(sysvar_id, version)| Some((sysvar_id.to_string(), version?)))
+    .collect();
+
+    let message = transaction.message();
+    Some(RpcSimulateIntrospection {
+        version: RPC_SIMULATE_INTROSPECTION_VERSION,
+        num_instructions: message.instructions().len() as u64,
+        num_signatures: transaction.signatures().len() as u64,
+        sysvar_versions,
+    })
 }
 
 fn optimize_filters(filters: &mut [RpcFilterType]) {
@@ -2195,8 +2412,11 @@ fn verify_transaction(
     transaction: &SanitizedTransaction,
     feature_set: &Arc<feature_set::FeatureSet>,
 ) -> Result<()> {
-    #[allow(clippy::question_mark)]
-    if transaction.verify().is_err() {
+    let signatures_verified =
+        verify_transaction_signatures_batch(std::slice::from_ref(transaction))
+            .into_iter()
+            .all(|result| result.is_ok());
+    if !signatures_verified {
         return Err(RpcCustomError::TransactionSignatureVerificationFailure.into());
     }
 
@@ -3259,10 +3479,7 @@ pub mod rpc_accounts_scan {
 // Full RPC interface that an API node is expected to provide
 // (rpc_minimal should also be provided by an API node)
 pub mod rpc_full {
-    use {
-        super::*,
-        solana_sdk::message::{SanitizedVersionedMessage, VersionedMessage},
-    };
+    use super::*;
     #[rpc]
     pub trait Full {
         type Metadata;
@@ -3368,6 +3585,14 @@ pub mod rpc_full {
             config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
         ) -> BoxFuture<Result<Option<EncodedConfirmedTransactionWithStatusMeta>>>;
 
+        #[rpc(meta, name = "getTransactionByAnySignature")]
+        fn get_transaction_by_any_signature(
+            &self,
+            meta: Self::Metadata,
+            signature_str: String,
+            config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+        ) -> BoxFuture<Result<Option<EncodedConfirmedTransactionWithStatusMeta>>>;
+
         #[rpc(meta, name = "getSignaturesForAddress")]
         fn get_signatures_for_address(
             &self,
@@ -3415,6 +3640,45 @@ pub mod rpc_full {
             meta: Self::Metadata,
             pubkey_strs: Option<Vec<String>>,
         ) -> Result<Vec<RpcPrioritizationFee>>;
+
+        #[rpc(meta, name = "getProgramAddressFromSignature")]
+        fn get_program_address_from_signature(
+            &self,
+            meta: Self::Metadata,
+            signature_str: String,
+            program_id_str: String,
+        ) -> Result<RpcProgramAddress>;
+
+        #[rpc(meta, name = "getProgramSysvarDependencies")]
+        fn get_program_sysvar_dependencies(
+            &self,
+            meta: Self::Metadata,
+            program_id_str: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<Option<Vec<String>>>>;
+
+        #[rpc(meta, name = "getTransactionShredProof")]
+        fn get_transaction_shred_proof(
+            &self,
+            meta: Self::Metadata,
+            signature_str: String,
+        ) -> Result<Option<RpcTransactionShredProof>>;
+
+        #[rpc(meta, name = "getSignatureMerkleProof")]
+        fn get_signature_merkle_proof(
+            &self,
+            meta: Self::Metadata,
+            slot: Slot,
+            signature_str: String,
+        ) -> Result<Option<RpcSignatureMerkleProof>>;
+
+        #[rpc(meta, name = "getRequiredSigners")]
+        fn get_required_signers(
+            &self,
+            meta: Self::Metadata,
+            data: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<RpcRequiredSigners>>;
     }
 
     pub struct FullImpl;
@@ -3626,7 +3890,8 @@ pub mod rpc_full {
                 min_context_slot,
             })?;
 
-            let transaction = sanitize_transaction(unsanitized_tx, preflight_bank)?;
+            let mut transaction = sanitize_transaction(unsanitized_tx, preflight_bank)?;
+            transaction.demote_account_keys(preflight_bank.demoted_sysvar_ids());
             let signature = *transaction.signature();
 
             let mut last_valid_block_height = preflight_bank
@@ -3690,6 +3955,8 @@ pub mod rpc_full {
                             accounts: None,
                             units_consumed: Some(units_consumed),
                             return_data: return_data.map(|return_data| return_data.into()),
+                            introspection_accessed: None,
+                            introspection: None,
                         },
                     }
                     .into());
@@ -3720,6 +3987,7 @@ pub mod rpc_full {
                 encoding,
                 accounts: config_accounts,
                 min_context_slot,
+                populate_signatures_sysvar,
             } = config.unwrap_or_default();
             let tx_encoding = encoding.unwrap_or(UiTransactionEncoding::Base58);
             let binary_encoding = tx_encoding.into_binary_encoding().ok_or_else(|| {
@@ -3745,11 +4013,22 @@ pub mod rpc_full {
                     .set_recent_blockhash(bank.last_blockhash());
             }
 
-            let transaction = sanitize_transaction(unsanitized_tx, bank)?;
+            let mut transaction = sanitize_transaction(unsanitized_tx, bank)?;
+            transaction.demote_account_keys(bank.demoted_sysvar_ids());
             if sig_verify {
                 verify_transaction(&transaction, &bank.feature_set)?;
             }
             let number_of_accounts = transaction.message().account_keys().len();
+            let introspection_accessed = get_introspection_accessed(transaction.message());
+            let introspection = (!introspection_accessed.is_empty())
+                .then(|| {
+                    get_simulate_introspection(
+                        &transaction,
+                        &bank.feature_set,
+                        populate_signatures_sysvar,
+                    )
+                })
+                .flatten();
 
             let TransactionSimulationResult {
                 result,
@@ -3757,7 +4036,11 @@ pub mod rpc_full {
                 post_simulation_accounts,
                 units_consumed,
                 return_data,
-            } = bank.simulate_transaction(transaction);
+            } = if populate_signatures_sysvar {
+                bank.simulate_transaction_with_signatures_sysvar(transaction)
+            } else {
+                bank.simulate_transaction(transaction)
+            };
 
             let accounts = if let Some(config_accounts) = config_accounts {
                 let accounts_encoding = config_accounts
@@ -3808,6 +4091,13 @@ pub mod rpc_full {
                     accounts,
                     units_consumed: Some(units_consumed),
                     return_data: return_data.map(|return_data| return_data.into()),
+                    introspection_accessed: (!introspection_accessed.is_empty()).then(|| {
+                        introspection_accessed
+                            .iter()
+                            .map(|sysvar_id| sysvar_id.to_string())
+                            .collect()
+                    }),
+                    introspection,
                 },
             ))
         }
@@ -3885,6 +4175,26 @@ pub mod rpc_full {
             Box::pin(async move { meta.get_transaction(signature.unwrap(), config).await })
         }
 
+        fn get_transaction_by_any_signature(
+            &self,
+            meta: Self::Metadata,
+            signature_str: String,
+            config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+        ) -> BoxFuture<Result<Option<EncodedConfirmedTransactionWithStatusMeta>>> {
+            debug!(
+                "get_transaction_by_any_signature rpc request received: {:?}",
+                signature_str
+            );
+            let signature = verify_signature(&signature_str);
+            if let Err(err) = signature {
+                return Box::pin(future::err(err));
+            }
+            Box::pin(async move {
+                meta.get_transaction_by_any_signature(signature.unwrap(), config)
+                    .await
+            })
+        }
+
         fn get_signatures_for_address(
             &self,
             meta: Self::Metadata,
@@ -3980,14 +4290,7 @@ pub mod rpc_full {
                 TransactionBinaryEncoding::Base64,
             )?;
             let bank = &*meta.get_bank_with_config(config.unwrap_or_default())?;
-            let sanitized_versioned_message = SanitizedVersionedMessage::try_from(message)
-                .map_err(|err| {
-                    Error::invalid_params(format!("invalid transaction message: {err}"))
-                })?;
-            let sanitized_message = SanitizedMessage::try_new(sanitized_versioned_message, bank)
-                .map_err(|err| {
-                    Error::invalid_params(format!("invalid transaction message: {err}"))
-                })?;
+            let sanitized_message = sanitize_message(message, bank)?;
             let fee = bank.get_fee_for_message(&sanitized_message);
             Ok(new_response(bank, fee))
         }
@@ -4022,6 +4325,100 @@ pub mod rpc_full {
                 .collect::<Result<Vec<_>>>()?;
             meta.get_recent_prioritization_fees(pubkeys)
         }
+
+        fn get_program_address_from_signature(
+            &self,
+            _meta: Self::Metadata,
+            signature_str: String,
+            program_id_str: String,
+        ) -> Result<RpcProgramAddress> {
+            debug!(
+                "get_program_address_from_signature rpc request received: {:?} {:?}",
+                signature_str, program_id_str
+            );
+            let signature = verify_signature(&signature_str)?;
+            let program_id = verify_pubkey(&program_id_str)?;
+            let (address, bump_seed) =
+                Pubkey::find_program_address_from_signature(&signature.into(), &program_id);
+            Ok(RpcProgramAddress {
+                address: address.to_string(),
+                bump_seed,
+            })
+        }
+
+        fn get_program_sysvar_dependencies(
+            &self,
+            meta: Self::Metadata,
+            program_id_str: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<Option<Vec<String>>>> {
+            debug!(
+                "get_program_sysvar_dependencies rpc request received: {:?}",
+                program_id_str
+            );
+            let program_id = verify_pubkey(&program_id_str)?;
+            meta.get_program_sysvar_dependencies(&program_id, config.unwrap_or_default())
+        }
+
+        fn get_transaction_shred_proof(
+            &self,
+            meta: Self::Metadata,
+            signature_str: String,
+        ) -> Result<Option<RpcTransactionShredProof>> {
+            debug!(
+                "get_transaction_shred_proof rpc request received: {:?}",
+                signature_str
+            );
+            let signature = verify_signature(&signature_str)?;
+            meta.get_transaction_shred_proof(signature)
+        }
+
+        fn get_signature_merkle_proof(
+            &self,
+            meta: Self::Metadata,
+            slot: Slot,
+            signature_str: String,
+        ) -> Result<Option<RpcSignatureMerkleProof>> {
+            debug!(
+                "get_signature_merkle_proof rpc request received: {:?} {:?}",
+                slot, signature_str
+            );
+            let signature = verify_signature(&signature_str)?;
+            meta.get_signature_merkle_proof(slot, signature)
+        }
+
+        fn get_required_signers(
+            &self,
+            meta: Self::Metadata,
+            data: String,
+            config: Option<RpcContextConfig>,
+        ) -> Result<RpcResponse<RpcRequiredSigners>> {
+            debug!("get_required_signers rpc request received");
+            let (_, message) = decode_and_deserialize::<VersionedMessage>(
+                data,
+                TransactionBinaryEncoding::Base64,
+            )?;
+            let bank = &*meta.get_bank_with_config(config.unwrap_or_default())?;
+            let sanitized_message = sanitize_message(message, bank)?;
+            let num_required_signatures =
+                usize::from(sanitized_message.header().num_required_signatures);
+            // Lookup tables only load non-signer accounts, so the signers are always the
+            // leading static keys, but the lookups are resolved to reject messages that
+            // couldn't be executed.
+            let signers = sanitized_message
+                .account_keys()
+                .iter()
+                .take(num_required_signatures)
+                .map(|pubkey| pubkey.to_string())
+                .collect();
+            Ok(new_response(
+                bank,
+                RpcRequiredSigners {
+                    signers,
+                    num_required_signatures,
+                },
+            ))
+        }
     }
 }
 
@@ -4035,6 +4432,7 @@ fn rpc_perf_sample_from_perf_sample(slot: u64, sample: PerfSample) -> RpcPerfSam
             slot,
             num_transactions,
             num_non_vote_transactions: None,
+            num_introspection_transactions: None,
             num_slots,
             sample_period_secs,
         },
@@ -4047,6 +4445,21 @@ fn rpc_perf_sample_from_perf_sample(slot: u64, sample: PerfSample) -> RpcPerfSam
             slot,
             num_transactions,
             num_non_vote_transactions: Some(num_non_vote_transactions),
+            num_introspection_transactions: None,
+            num_slots,
+            sample_period_secs,
+        },
+        PerfSample::V3(PerfSampleV3 {
+            num_transactions,
+            num_non_vote_transactions,
+            num_introspection_transactions,
+            num_slots,
+            sample_period_secs,
+        }) => RpcPerfSample {
+            slot,
+            num_transactions,
+            num_non_vote_transactions: Some(num_non_vote_transactions),
+            num_introspection_transactions: Some(num_introspection_transactions),
             num_slots,
             sample_period_secs,
         },
@@ -4513,6 +4926,13 @@ where
         .map(|output| (wire_output, output))
 }
 
+fn sanitize_message(message: VersionedMessage, bank: &Bank) -> Result<SanitizedMessage> {
+    let sanitized_versioned_message = SanitizedVersionedMessage::try_from(message)
+        .map_err(|err| Error::invalid_params(format!("invalid transaction message: {err}")))?;
+    SanitizedMessage::try_new(sanitized_versioned_message, bank)
+        .map_err(|err| Error::invalid_params(format!("invalid transaction message: {err}")))
+}
+
 fn sanitize_transaction(
     transaction: VersionedTransaction,
     address_loader: impl AddressLoader,
@@ -4636,6 +5056,7 @@ pub mod tests {
             blockstore_meta::PerfSampleV2,
             blockstore_processor::fill_blockstore_slot_with_ticks,
             genesis_utils::{create_genesis_config, GenesisConfigInfo},
+            shred::Shred,
         },
         solana_rpc_client_api::{
             custom_error::{
@@ -4659,7 +5080,7 @@ pub mod tests {
             compute_budget::ComputeBudgetInstruction,
             fee_calculator::{FeeRateGovernor, DEFAULT_BURN_PERCENT},
             hash::{hash, Hash},
-            instruction::InstructionError,
+            instruction::{AccountMeta, Instruction, InstructionError},
             message::{
                 v0::{self, MessageAddressTableLookup},
                 Message, MessageHeader, VersionedMessage,
@@ -4668,7 +5089,7 @@ pub mod tests {
             rpc_port,
             signature::{Keypair, Signer},
             slot_hashes::SlotHashes,
-            system_program, system_transaction,
+            system_program, system_transaction, sysvar,
             timing::slot_duration_from_slots_per_year,
             transaction::{
                 self, SimpleAddressLoader, Transaction, TransactionError, TransactionVersion,
@@ -5166,14 +5587,16 @@ pub mod tests {
         let num_slots = 1;
         let num_transactions = 4;
         let num_non_vote_transactions = 1;
+        let num_introspection_transactions = 1;
         let sample_period_secs = 60;
         rpc.blockstore
             .write_perf_sample(
                 slot,
-                &PerfSampleV2 {
+                &PerfSampleV3 {
                     num_slots,
                     num_transactions,
                     num_non_vote_transactions,
+                    num_introspection_transactions,
                     sample_period_secs,
                 },
             )
@@ -5186,6 +5609,7 @@ pub mod tests {
             "numSlots": num_slots,
             "numTransactions": num_transactions,
             "numNonVoteTransactions": num_non_vote_transactions,
+            "numIntrospectionTransactions": num_introspection_transactions,
             "samplePeriodSecs": sample_period_secs,
         }]);
         assert_eq!(result, expected);
@@ -6106,6 +6530,89 @@ pub mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_rpc_simulate_transaction_introspection_accessed() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        let recent_blockhash = bank.confirmed_last_blockhash();
+        bank.freeze();
+
+        let bob_pubkey = Pubkey::new_unique();
+        let mut transfer_instruction =
+            system_instruction::transfer(&rpc.mint_keypair.pubkey(), &bob_pubkey, 1234);
+        let simulate = |instruction: &Instruction| {
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction.clone()],
+                Some(&rpc.mint_keypair.pubkey()),
+                &[&rpc.mint_keypair],
+                recent_blockhash,
+            );
+            let request = create_test_request(
+                "simulateTransaction",
+                Some(json!([
+                    BASE64_STANDARD.encode(serialize(&tx).unwrap()),
+                    { "encoding": "base64" }
+                ])),
+            );
+            let response: RpcResponse<RpcSimulateTransactionResult> =
+                parse_success_result(rpc.handle_request_sync(request));
+            response.value
+        };
+
+        // No introspection sysvar passed to the program
+        let result = simulate(&transfer_instruction);
+        assert_eq!(result.introspection_accessed, None);
+        assert_eq!(result.introspection, None);
+
+        // Introspection sysvars passed to the program, the system program
+        // ignores the extra accounts
+        transfer_instruction
+            .accounts
+            .push(AccountMeta::new_readonly(signatures::id(), false));
+        transfer_instruction
+            .accounts
+            .push(AccountMeta::new_readonly(sysvar::instructions::id(), false));
+        let result = simulate(&transfer_instruction);
+        assert_eq!(
+            result.introspection_accessed,
+            Some(vec![
+                sysvar::instructions::id().to_string(),
+                signatures::id().to_string()
+            ])
+        );
+
+        // Every introspection sysvar is available to the transaction on a
+        // cluster with all features active
+        let introspection = result.introspection.unwrap();
+        assert_eq!(
+            serde_json::to_value(&introspection).unwrap(),
+            json!({
+                "version": RPC_SIMULATE_INTROSPECTION_VERSION,
+                "numInstructions": 1,
+                "numSignatures": 1,
+                "sysvarVersions": {
+                    (sysvar::header::id().to_string()): 0,
+                    (sysvar::instructions::id().to_string()): 0,
+                    (sysvar::scratch::id().to_string()): 0,
+                    (signatures::id().to_string()): signatures::SIGNER_PAIRS_LAYOUT_VERSION,
+                    (sysvar::tick_hash::id().to_string()): 0,
+                    (sysvar::transaction_context::id().to_string()): 0,
+                },
+            })
+        );
+        assert_eq!(
+            get_simulate_introspection(
+                &SanitizedTransaction::from_transaction_for_tests(Transaction::new_with_payer(
+                    &[transfer_instruction],
+                    Some(&rpc.mint_keypair.pubkey()),
+                )),
+                &feature_set::FeatureSet::default(),
+                false,
+            ),
+            None
+        );
+    }
+
     #[test]
     #[should_panic(expected = "simulation bank must be frozen")]
     fn test_rpc_simulate_transaction_panic_on_unfrozen_bank() {
@@ -8597,6 +9104,216 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_rpc_get_program_address_from_signature() {
+        let rpc = RpcHandler::start();
+        let signature = Signature::from([7; 64]);
+        let program_id = Pubkey::new_unique();
+        let (expected_address, expected_bump_seed) =
+            Pubkey::find_program_address(&[&[7; 32], &[7; 32]], &program_id);
+
+        let request = create_test_request(
+            "getProgramAddressFromSignature",
+            Some(json!([signature.to_string(), program_id.to_string()])),
+        );
+        let response: RpcProgramAddress = parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(
+            response,
+            RpcProgramAddress {
+                address: expected_address.to_string(),
+                bump_seed: expected_bump_seed,
+            }
+        );
+
+        let request = create_test_request(
+            "getProgramAddressFromSignature",
+            Some(json!(["not a signature", program_id.to_string()])),
+        );
+        let response = parse_failure_response(rpc.handle_request_sync(request));
+        assert_eq!(response.0, ErrorCode::InvalidParams.code());
+    }
+
+    #[test]
+    fn test_rpc_get_program_sysvar_dependencies() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        let program_id = Pubkey::new_unique();
+
+        let request = create_test_request(
+            "getProgramSysvarDependencies",
+            Some(json!([program_id.to_string()])),
+        );
+        let response: RpcResponse<Option<Vec<String>>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(response.value, None);
+
+        let sysvar_dependencies = bpf_loader_upgradeable::sysvar_dependencies_from_ids(&[
+            sysvar::instructions::id(),
+            sysvar::signatures::id(),
+        ])
+        .unwrap();
+        let (program_metadata_address, _) =
+            bpf_loader_upgradeable::find_program_metadata_address(&program_id);
+        let program_metadata_account = AccountSharedData::new_data(
+            1,
+            &UpgradeableLoaderState::ProgramMetadata {
+                sysvar_dependencies,
+            },
+            &bpf_loader_upgradeable::id(),
+        )
+        .unwrap();
+        bank.store_account(&program_metadata_address, &program_metadata_account);
+
+        let request = create_test_request(
+            "getProgramSysvarDependencies",
+            Some(json!([program_id.to_string()])),
+        );
+        let response: RpcResponse<Option<Vec<String>>> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(
+            response.value,
+            Some(vec![
+                sysvar::instructions::id().to_string(),
+                sysvar::signatures::id().to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rpc_get_transaction_by_any_signature() {
+        let rpc = RpcHandler::start();
+        let confirmed_block_signatures = rpc.create_test_transactions_and_populate_blockstore();
+        let signature = confirmed_block_signatures[0];
+        let secondary_signature = Signature::new_unique();
+        rpc.blockstore
+            .write_secondary_signatures(0, signature, &[secondary_signature])
+            .unwrap();
+
+        for any_signature in [signature, secondary_signature] {
+            let request = create_test_request(
+                "getTransactionByAnySignature",
+                Some(json!([any_signature.to_string(), "json"])),
+            );
+            let response: Option<EncodedConfirmedTransactionWithStatusMeta> =
+                parse_success_result(rpc.handle_request_sync(request));
+            let response = response.unwrap();
+            assert_eq!(response.slot, 0);
+            let EncodedTransaction::Json(transaction) = response.transaction.transaction else {
+                panic!("unexpected transaction encoding");
+            };
+            assert_eq!(transaction.signatures, vec![signature.to_string()]);
+        }
+
+        let request = create_test_request(
+            "getTransactionByAnySignature",
+            Some(json!([Signature::new_unique().to_string()])),
+        );
+        let response: Option<EncodedConfirmedTransactionWithStatusMeta> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(response, None);
+    }
+
+    #[test]
+    fn test_rpc_get_transaction_shred_proof() {
+        let rpc = RpcHandler::start();
+        let confirmed_block_signatures = rpc.create_test_transactions_and_populate_blockstore();
+
+        for signature in &confirmed_block_signatures {
+            let request = create_test_request(
+                "getTransactionShredProof",
+                Some(json!([signature.to_string()])),
+            );
+            let response: Option<RpcTransactionShredProof> =
+                parse_success_result(rpc.handle_request_sync(request));
+            let response = response.unwrap();
+            assert_eq!(response.slot, 0);
+            assert!(response.leader.is_some());
+
+            let data: Vec<u8> = response
+                .shreds
+                .iter()
+                .flat_map(|shred| {
+                    let payload = BASE64_STANDARD.decode(shred).unwrap();
+                    Shred::new_from_serialized_shred(payload)
+                        .unwrap()
+                        .data()
+                        .unwrap()
+                        .to_vec()
+                })
+                .collect();
+            let transaction: VersionedTransaction = deserialize(
+                &data[response.transaction_offset
+                    ..response.transaction_offset + response.transaction_len],
+            )
+            .unwrap();
+            assert_eq!(&transaction.signatures[0], signature);
+        }
+
+        let request = create_test_request(
+            "getTransactionShredProof",
+            Some(json!([Signature::new_unique().to_string()])),
+        );
+        let response: Option<RpcTransactionShredProof> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(response, None);
+    }
+
+    #[test]
+    fn test_rpc_get_signature_merkle_proof() {
+        let rpc = RpcHandler::start();
+        let confirmed_block_signatures = rpc.create_test_transactions_and_populate_blockstore();
+        let mut slot_signatures: Vec<Signature> = rpc
+            .blockstore
+            .get_slot_entries(0, 0)
+            .unwrap()
+            .iter()
+            .flat_map(|entry| &entry.transactions)
+            .map(|transaction| transaction.signatures[0])
+            .collect();
+        slot_signatures.sort_unstable();
+        let root = sysvar::recent_signatures::compute_signature_root(&slot_signatures);
+
+        for signature in &confirmed_block_signatures {
+            let request = create_test_request(
+                "getSignatureMerkleProof",
+                Some(json!([0, signature.to_string()])),
+            );
+            let response: Option<RpcSignatureMerkleProof> =
+                parse_success_result(rpc.handle_request_sync(request));
+            let response = response.unwrap();
+            assert_eq!(response.slot, 0);
+            assert_eq!(response.root, root.to_string());
+            let proof: Vec<Hash> = response
+                .proof
+                .iter()
+                .map(|hash| Hash::from_str(hash).unwrap())
+                .collect();
+            assert!(sysvar::recent_signatures::verify_signature_proof(
+                signature.as_ref(),
+                response.index,
+                &proof,
+                &root
+            ));
+        }
+
+        let request = create_test_request(
+            "getSignatureMerkleProof",
+            Some(json!([0, Signature::new_unique().to_string()])),
+        );
+        let response: Option<RpcSignatureMerkleProof> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(response, None);
+
+        // Slots that are not finalized have no proofs
+        let request = create_test_request(
+            "getSignatureMerkleProof",
+            Some(json!([1, confirmed_block_signatures[0].to_string()])),
+        );
+        let response: Option<RpcSignatureMerkleProof> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(response, None);
+    }
+
     #[test]
     fn test_get_fee_for_message() {
         let rpc = RpcHandler::start();
@@ -8647,6 +9364,65 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_get_required_signers() {
+        let rpc = RpcHandler::start();
+        let bank = rpc.working_bank();
+        // Slot hashes is necessary for processing versioned txs.
+        bank.set_sysvar_for_tests(&SlotHashes::default());
+        let recent_blockhash = bank.last_blockhash();
+
+        let fee_payer = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let legacy_msg = VersionedMessage::Legacy(Message {
+            header: MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 1,
+                ..MessageHeader::default()
+            },
+            recent_blockhash,
+            account_keys: vec![fee_payer, signer, Pubkey::new_unique()],
+            ..Message::default()
+        });
+        let request = create_test_request(
+            "getRequiredSigners",
+            Some(json!([
+                BASE64_STANDARD.encode(serialize(&legacy_msg).unwrap())
+            ])),
+        );
+        let response: RpcResponse<RpcRequiredSigners> =
+            parse_success_result(rpc.handle_request_sync(request));
+        assert_eq!(
+            response.value,
+            RpcRequiredSigners {
+                signers: vec![fee_payer.to_string(), signer.to_string()],
+                num_required_signatures: 2,
+            }
+        );
+
+        // Lookups into a missing table can't be resolved
+        let v0_msg = VersionedMessage::V0(v0::Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                ..MessageHeader::default()
+            },
+            recent_blockhash,
+            account_keys: vec![fee_payer],
+            address_table_lookups: vec![MessageAddressTableLookup {
+                account_key: Pubkey::new_unique(),
+                writable_indexes: vec![0],
+                readonly_indexes: vec![],
+            }],
+            ..v0::Message::default()
+        });
+        let request = create_test_request(
+            "getRequiredSigners",
+            Some(json!([BASE64_STANDARD.encode(serialize(&v0_msg).unwrap())])),
+        );
+        let response = parse_failure_response(rpc.handle_request_sync(request));
+        assert_eq!(response.0, ErrorCode::InvalidParams.code());
+    }
+
     #[test]
     fn test_rpc_get_recent_prioritization_fees() {
         fn wait_for_cache_blocks(cache: &PrioritizationFeeCache, num_blocks: usize) {
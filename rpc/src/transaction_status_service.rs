@@ -201,6 +201,21 @@ impl TransactionStatusService {
                                     transaction_status_meta,
                                 )
                                 .expect("Expect database write to succeed: TransactionStatus");
+
+                            if let [signature, secondary_signatures @ ..] = transaction.signatures()
+                            {
+                                if !secondary_signatures.is_empty() {
+                                    blockstore
+                                        .write_secondary_signatures(
+                                            slot,
+                                            *signature,
+                                            secondary_signatures,
+                                        )
+                                        .expect(
+                                            "Expect database write to succeed: SecondarySignatures",
+                                        );
+                                }
+                            }
                         }
                     }
                 }
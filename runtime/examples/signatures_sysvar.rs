@@ -0,0 +1,97 @@
+//! A minimal transaction processor that executes an introspecting program.
+//!
+//! This tree has no standalone SVM crate, so the example drives a single
+//! [`Bank`] directly, without a validator, blockstore or banking stage around
+//! it. The bank constructs the per-transaction sysvars while loading each
+//! transaction, which makes this a starting point for rollups that want to
+//! offer signature introspection to their programs.
+//!
+//! The introspecting program is a builtin that copies the first signature of
+//! the transaction out of the signatures sysvar into an account it owns.
+//!
+//! Run with `cargo run --example signatures_sysvar -p solana-runtime`.
+
+use {
+    solana_program_runtime::{declare_process_instruction, ic_msg},
+    solana_runtime::{
+        bank::Bank,
+        genesis_utils::{create_genesis_config, GenesisConfigInfo},
+    },
+    solana_sdk::{
+        account::{AccountSharedData, ReadableAccount},
+        instruction::{AccountMeta, Instruction, InstructionError},
+        message::Message,
+        pubkey::Pubkey,
+        signature::Signer,
+        sysvar::signatures::{self, SignaturesData},
+        transaction::Transaction,
+    },
+};
+
+declare_process_instruction!(process_instruction, 1, |invoke_context| {
+    let transaction_context = &invoke_context.transaction_context;
+    let instruction_context = transaction_context.get_current_instruction_context()?;
+
+    let signatures_sysvar =
+        instruction_context.try_borrow_instruction_account(transaction_context, 0)?;
+    if !signatures::check_id(signatures_sysvar.get_key()) {
+        return Err(InstructionError::UnsupportedSysvar);
+    }
+    let signatures_data = SignaturesData::deserialize(signatures_sysvar.get_data())
+        .map_err(|_| InstructionError::InvalidAccountData)?;
+    drop(signatures_sysvar);
+    let first_signature = signatures_data
+        .signatures
+        .first()
+        .ok_or(InstructionError::InvalidAccountData)?;
+    ic_msg!(
+        invoke_context,
+        "transaction carries {} signature(s)",
+        signatures_data.signatures.len()
+    );
+
+    instruction_context
+        .try_borrow_instruction_account(transaction_context, 1)?
+        .set_data_from_slice(first_signature.as_ref())?;
+    Ok(())
+});
+
+fn main() {
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config(1_000_000_000);
+    let mut bank = Bank::new_for_tests(&genesis_config);
+
+    let program_id = Pubkey::new_unique();
+    bank.add_mockup_builtin(program_id, process_instruction);
+
+    // The account the program records the introspected signature in
+    let record_pubkey = Pubkey::new_unique();
+    let rent_exempt_lamports = bank.get_minimum_balance_for_rent_exemption(64);
+    bank.store_account(
+        &record_pubkey,
+        &AccountSharedData::new(rent_exempt_lamports, 64, &program_id),
+    );
+
+    let instruction = Instruction::new_with_bytes(
+        program_id,
+        &[],
+        vec![
+            AccountMeta::new_readonly(signatures::id(), false),
+            AccountMeta::new(record_pubkey, false),
+        ],
+    );
+    let message = Message::new(&[instruction], Some(&mint_keypair.pubkey()));
+    let transaction = Transaction::new(&[&mint_keypair], message, bank.last_blockhash());
+    bank.process_transaction(&transaction)
+        .expect("introspecting transaction failed");
+
+    let record = bank.get_account(&record_pubkey).unwrap();
+    assert_eq!(record.data(), transaction.signatures[0].as_ref());
+    println!(
+        "program observed signature {} through the signatures sysvar",
+        transaction.signatures[0]
+    );
+}
@@ -43,6 +43,7 @@ use {
         epoch_rewards_hasher::hash_rewards_into_partitions,
         epoch_stakes::{EpochStakes, NodeVoteAccounts},
         inline_feature_gate_program,
+        recent_signatures::{RecentSignatures, SignaturePosition},
         runtime_config::RuntimeConfig,
         serde_snapshot::BankIncrementalSnapshotPersistence,
         snapshot_hash::SnapshotHash,
@@ -130,12 +131,14 @@ use {
             MAX_TRANSACTION_FORWARDING_DELAY, MAX_TRANSACTION_FORWARDING_DELAY_GPU,
             SECONDS_PER_DAY,
         },
+        compute_budget::check_transaction_guards,
         epoch_info::EpochInfo,
         epoch_schedule::EpochSchedule,
         feature,
         feature_set::{
             self, add_set_tx_loaded_accounts_data_size_instruction,
             enable_early_verification_of_account_modifications,
+            enable_transaction_guard_instruction,
             include_loaded_accounts_data_size_in_fee_calculation,
             remove_congestion_multiplier_from_fee_calculation, remove_deprecated_request_unit_ix,
             FeatureSet,
@@ -153,7 +156,7 @@ use {
         message::{AccountKeys, SanitizedMessage},
         native_loader,
         native_token::LAMPORTS_PER_SOL,
-        nonce::{self, state::DurableNonce, NONCED_TX_MARKER_IX_INDEX},
+        nonce::{self, state::DurableNonce, TransactionNonceInfo, NONCED_TX_MARKER_IX_INDEX},
         nonce_account,
         packet::PACKET_DATA_SIZE,
         precompiles::get_precompiles,
@@ -195,7 +198,7 @@ use {
                 AtomicBool, AtomicI64, AtomicU64, AtomicUsize,
                 Ordering::{self, AcqRel, Acquire, Relaxed},
             },
-            Arc, LockResult, RwLock, RwLockReadGuard, RwLockWriteGuard,
+            Arc, LockResult, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard,
         },
         thread::Builder,
         time::{Duration, Instant},
@@ -509,6 +512,8 @@ impl PartialEq for Bank {
         let Self {
             rc: _,
             status_cache: _,
+            recent_signatures: _,
+            committed_transaction_signatures: _,
             blockhash_queue,
             ancestors,
             hash,
@@ -517,6 +522,7 @@ impl PartialEq for Bank {
             hard_forks,
             transaction_count,
             non_vote_transaction_count_since_restart: _,
+            introspection_transaction_count_since_restart: _,
             transaction_error_count: _,
             transaction_entries_count: _,
             transactions_per_entry_max: _,
@@ -567,6 +573,9 @@ impl PartialEq for Bank {
             loaded_programs_cache: _,
             check_program_modification_slot: _,
             epoch_reward_status: _,
+            last_tick_hash: _,
+            signatures_sysvar_override: _,
+            demoted_sysvar_ids: _,
             // Ignore new fields explicitly if they do not impact PartialEq.
             // Adding ".." will remove compile-time checks that if a new field
             // is added to the struct, this PartialEq is accordingly updated.
@@ -666,6 +675,14 @@ pub struct Bank {
     /// A cache of signature statuses
     pub status_cache: Arc<RwLock<BankStatusCache>>,
 
+    /// Positions of the transaction signatures processed in recent slots,
+    /// `None` unless enabled with `RuntimeConfig::recent_signatures_max_slots`
+    recent_signatures: Option<Arc<RwLock<RecentSignatures>>>,
+
+    /// First signature of every transaction committed in this slot, from
+    /// which the slot's root in the recent signatures sysvar is computed
+    committed_transaction_signatures: Mutex<Vec<Signature>>,
+
     /// FIFO queue of `recent_blockhash` items
     blockhash_queue: RwLock<BlockhashQueue>,
 
@@ -692,6 +709,12 @@ pub struct Bank {
     /// snapshots, but is preserved in `Bank::new_from_parent`.
     non_vote_transaction_count_since_restart: AtomicU64,
 
+    /// The number of transactions referencing per-transaction sysvars, such as
+    /// the signatures sysvar, processed without error since the most recent boot from
+    /// snapshot or genesis. Like `non_vote_transaction_count_since_restart`,
+    /// this value is local to the node but preserved in `Bank::new_from_parent`.
+    introspection_transaction_count_since_restart: AtomicU64,
+
     /// The number of transaction errors in this slot
     transaction_error_count: AtomicU64,
 
@@ -825,6 +848,18 @@ pub struct Bank {
     pub check_program_modification_slot: bool,
 
     epoch_reward_status: EpochRewardStatus,
+
+    /// Hash of the most recent tick registered in this slot, `None` until the
+    /// first tick is registered
+    last_tick_hash: RwLock<Option<Hash>>,
+
+    /// Signatures sysvar account loaded for every transaction instead of the
+    /// one constructed from its signatures, set by tests to craft edge cases
+    signatures_sysvar_override: RwLock<Option<AccountSharedData>>,
+
+    /// The [`sysvar::FEATURE_GATED_IDS`] whose feature is active, demoted to
+    /// readonly in the transactions sanitized for this bank
+    demoted_sysvar_ids: Vec<Pubkey>,
 }
 
 struct VoteWithStakeDelegations {
@@ -1012,6 +1047,8 @@ impl Bank {
             incremental_snapshot_persistence: None,
             rc: BankRc::new(accounts, Slot::default()),
             status_cache: Arc::<RwLock<BankStatusCache>>::default(),
+            recent_signatures: None,
+            committed_transaction_signatures: Mutex::default(),
             blockhash_queue: RwLock::<BlockhashQueue>::default(),
             ancestors: Ancestors::default(),
             hash: RwLock::<Hash>::default(),
@@ -1020,6 +1057,7 @@ impl Bank {
             hard_forks: Arc::<RwLock<HardForks>>::default(),
             transaction_count: AtomicU64::default(),
             non_vote_transaction_count_since_restart: AtomicU64::default(),
+            introspection_transaction_count_since_restart: AtomicU64::default(),
             transaction_error_count: AtomicU64::default(),
             transaction_entries_count: AtomicU64::default(),
             transactions_per_entry_max: AtomicU64::default(),
@@ -1069,6 +1107,9 @@ impl Bank {
             loaded_programs_cache: Arc::<RwLock<LoadedPrograms>>::default(),
             check_program_modification_slot: false,
             epoch_reward_status: EpochRewardStatus::default(),
+            last_tick_hash: RwLock::default(),
+            signatures_sysvar_override: RwLock::default(),
+            demoted_sysvar_ids: Vec::new(),
         };
 
         let accounts_data_size_initial = bank.get_total_accounts_stats().unwrap().data_len as u64;
@@ -1143,6 +1184,7 @@ impl Bank {
         let mut bank = Self::default_with_accounts(accounts);
         bank.ancestors = Ancestors::from(vec![bank.slot()]);
         bank.transaction_debug_keys = debug_keys;
+        bank.recent_signatures = Self::new_recent_signatures(&runtime_config);
         bank.runtime_config = runtime_config;
         bank.cluster_type = Some(genesis_config.cluster_type);
 
@@ -1310,6 +1352,7 @@ impl Bank {
         });
 
         let (status_cache, status_cache_time_us) = measure_us!(Arc::clone(&parent.status_cache));
+        let recent_signatures = parent.recent_signatures.clone();
 
         let (fee_rate_governor, fee_components_time_us) = measure_us!(
             FeeRateGovernor::new_derived(&parent.fee_rate_governor, parent.signature_count())
@@ -1343,6 +1386,8 @@ impl Bank {
             incremental_snapshot_persistence: None,
             rc,
             status_cache,
+            recent_signatures,
+            committed_transaction_signatures: Mutex::default(),
             slot,
             bank_id,
             epoch,
@@ -1367,6 +1412,9 @@ impl Bank {
             non_vote_transaction_count_since_restart: AtomicU64::new(
                 parent.non_vote_transaction_count_since_restart(),
             ),
+            introspection_transaction_count_since_restart: AtomicU64::new(
+                parent.introspection_transaction_count_since_restart(),
+            ),
             transaction_error_count: AtomicU64::new(0),
             transaction_entries_count: AtomicU64::new(0),
             transactions_per_entry_max: AtomicU64::new(0),
@@ -1420,6 +1468,11 @@ impl Bank {
             loaded_programs_cache: parent.loaded_programs_cache.clone(),
             check_program_modification_slot: false,
             epoch_reward_status: parent.epoch_reward_status.clone(),
+            last_tick_hash: RwLock::default(),
+            signatures_sysvar_override: RwLock::new(
+                parent.signatures_sysvar_override.read().unwrap().clone(),
+            ),
+            demoted_sysvar_ids: parent.demoted_sysvar_ids.clone(),
         };
 
         let (_, ancestors_time_us) = measure_us!({
@@ -1457,7 +1510,8 @@ impl Bank {
             new.update_stake_history(Some(parent_epoch));
             new.update_clock(Some(parent_epoch));
             new.update_fees();
-            new.update_last_restart_slot()
+            new.update_last_restart_slot();
+            new.update_epoch_stake(Some(parent_epoch))
         });
 
         let (_, fill_sysvar_cache_time_us) = measure_us!(new.fill_missing_sysvar_cache_entries());
@@ -1759,6 +1813,9 @@ impl Bank {
                 new.inherit_specially_retained_account_fields(account),
             )
         });
+        // The cache was filled by new_from_parent(), before the clock was rewritten and the
+        // feature set recomputed, so re-prime it from the warped accounts.
+        new.reset_sysvar_cache();
         new.fill_missing_sysvar_cache_entries();
         new.freeze();
         new
@@ -1801,6 +1858,8 @@ impl Bank {
             incremental_snapshot_persistence: fields.incremental_snapshot_persistence,
             rc: bank_rc,
             status_cache: new(),
+            recent_signatures: Self::new_recent_signatures(&runtime_config),
+            committed_transaction_signatures: Mutex::default(),
             blockhash_queue: RwLock::new(fields.blockhash_queue),
             ancestors,
             hash: RwLock::new(fields.hash),
@@ -1809,6 +1868,7 @@ impl Bank {
             hard_forks: Arc::new(RwLock::new(fields.hard_forks)),
             transaction_count: AtomicU64::new(fields.transaction_count),
             non_vote_transaction_count_since_restart: new(),
+            introspection_transaction_count_since_restart: new(),
             transaction_error_count: new(),
             transaction_entries_count: new(),
             transactions_per_entry_max: new(),
@@ -1858,6 +1918,9 @@ impl Bank {
             loaded_programs_cache: Arc::<RwLock<LoadedPrograms>>::default(),
             check_program_modification_slot: false,
             epoch_reward_status: EpochRewardStatus::default(),
+            last_tick_hash: RwLock::default(),
+            signatures_sysvar_override: RwLock::default(),
+            demoted_sysvar_ids: new(),
         };
         bank.finish_init(
             genesis_config,
@@ -2154,6 +2217,55 @@ impl Bank {
         }
     }
 
+    /// Update the epoch stake sysvar with the activated stake of the current
+    /// epoch. Only the first bank of an epoch writes the account, unless the
+    /// account is missing because the feature was just activated.
+    fn update_epoch_stake(&self, parent_epoch: Option<Epoch>) {
+        if !self
+            .feature_set
+            .is_active(&feature_set::epoch_stake_sysvar::id())
+        {
+            return;
+        }
+        if parent_epoch == Some(self.epoch())
+            && self.get_account(&sysvar::epoch_stake::id()).is_some()
+        {
+            return;
+        }
+        let Some(epoch_stakes) = self.epoch_stakes(self.epoch()) else {
+            return;
+        };
+        let vote_account_stakes: Vec<(Pubkey, u64)> = epoch_stakes
+            .stakes()
+            .vote_accounts()
+            .as_ref()
+            .iter()
+            .filter(|(_, (stake, _))| *stake > 0)
+            .map(|(vote_pubkey, (stake, _))| (*vote_pubkey, *stake))
+            .collect();
+        let data = sysvar::epoch_stake::construct_epoch_stake_data(
+            epoch_stakes.total_stake(),
+            &vote_account_stakes,
+        );
+        self.update_sysvar_account(&sysvar::epoch_stake::id(), |account| {
+            let (lamports, rent_epoch) = self.inherit_specially_retained_account_fields(account);
+            let mut account = AccountSharedData::new(lamports, data.len(), &sysvar::id());
+            account.set_data_from_slice(&data);
+            account.set_rent_epoch(rent_epoch);
+            account
+        });
+    }
+
+    /// Load `account` as the signatures sysvar of every transaction executed
+    /// by this bank and its descendants, instead of constructing it from the
+    /// transaction's signatures, or stop doing so if `account` is `None`.
+    ///
+    /// The signatures of the transactions are still verified as usual; only
+    /// what programs read from the sysvar changes.
+    pub fn set_signatures_sysvar_override_for_tests(&self, account: Option<AccountSharedData>) {
+        *self.signatures_sysvar_override.write().unwrap() = account;
+    }
+
     pub fn set_sysvar_for_tests<T>(&self, sysvar: &T)
     where
         T: Sysvar + SysvarId,
@@ -2198,6 +2310,36 @@ impl Bank {
         });
     }
 
+    /// Record the signature root of this slot in the recent signatures
+    /// sysvar. Called when the bank is frozen, once every transaction of the
+    /// slot has been committed.
+    fn update_recent_signatures(&self) {
+        if !self
+            .feature_set
+            .is_active(&feature_set::recent_signatures_sysvar::id())
+        {
+            return;
+        }
+        // Transactions are committed in a different order on every validator,
+        // sort the signatures so that the root is deterministic
+        let mut signatures = mem::take(&mut *self.committed_transaction_signatures.lock().unwrap());
+        signatures.sort_unstable();
+        let root = sysvar::recent_signatures::compute_signature_root(&signatures);
+        self.update_sysvar_account(&sysvar::recent_signatures::id(), |account| {
+            let mut recent_signatures = account
+                .as_ref()
+                .map(|account| {
+                    from_account::<sysvar::recent_signatures::RecentSignatures, _>(account).unwrap()
+                })
+                .unwrap_or_default();
+            recent_signatures.add(self.slot(), root);
+            create_account(
+                &recent_signatures,
+                self.inherit_specially_retained_account_fields(account),
+            )
+        });
+    }
+
     pub fn get_slot_history(&self) -> SlotHistory {
         from_account(&self.get_account(&sysvar::slot_history::id()).unwrap()).unwrap()
     }
@@ -3757,6 +3899,7 @@ impl Bank {
             self.collect_fees();
             self.distribute_rent();
             self.update_slot_history();
+            self.update_recent_signatures();
             self.run_incinerator();
 
             // freeze is a one-way trip, idempotent
@@ -4139,10 +4282,50 @@ impl Bank {
     /// Forget all signatures. Useful for benchmarking.
     pub fn clear_signatures(&self) {
         self.status_cache.write().unwrap().clear();
+        if let Some(recent_signatures) = &self.recent_signatures {
+            recent_signatures.write().unwrap().clear();
+        }
     }
 
     pub fn clear_slot_signatures(&self, slot: Slot) {
         self.status_cache.write().unwrap().clear_slot_entries(slot);
+        if let Some(recent_signatures) = &self.recent_signatures {
+            recent_signatures.write().unwrap().remove_slot(slot);
+        }
+    }
+
+    fn new_recent_signatures(
+        runtime_config: &RuntimeConfig,
+    ) -> Option<Arc<RwLock<RecentSignatures>>> {
+        runtime_config
+            .recent_signatures_max_slots
+            .filter(|max_slots| *max_slots > 0)
+            .map(|max_slots| Arc::new(RwLock::new(RecentSignatures::new(max_slots))))
+    }
+
+    /// Position of a transaction signature processed in this bank or one of
+    /// its ancestors, if recent signatures are retained and it is recent enough
+    /// to still be.
+    ///
+    /// The position is specific to this validator, see [`SignaturePosition`].
+    pub fn get_recent_signature_position(
+        &self,
+        signature: &Signature,
+    ) -> Option<SignaturePosition> {
+        self.recent_signatures
+            .as_ref()?
+            .read()
+            .unwrap()
+            .find(signature, |slot| self.ancestors.contains_key(&slot))
+    }
+
+    /// Approximate number of bytes used to retain recent signatures
+    pub fn recent_signatures_memory_usage(&self) -> usize {
+        self.recent_signatures
+            .as_ref()
+            .map_or(0, |recent_signatures| {
+                recent_signatures.read().unwrap().memory_usage()
+            })
     }
 
     fn update_transaction_statuses(
@@ -4151,9 +4334,25 @@ impl Bank {
         execution_results: &[TransactionExecutionResult],
     ) {
         let mut status_cache = self.status_cache.write().unwrap();
+        let mut recent_signatures = self
+            .recent_signatures
+            .as_ref()
+            .map(|recent_signatures| recent_signatures.write().unwrap());
+        let mut committed_transaction_signatures = self
+            .feature_set
+            .is_active(&feature_set::recent_signatures_sysvar::id())
+            .then(|| self.committed_transaction_signatures.lock().unwrap());
         assert_eq!(sanitized_txs.len(), execution_results.len());
         for (tx, execution_result) in sanitized_txs.iter().zip(execution_results) {
             if let Some(details) = execution_result.details() {
+                if let Some(recent_signatures) = recent_signatures.as_mut() {
+                    recent_signatures.insert(self.slot(), tx.signatures());
+                }
+                if let Some(committed_transaction_signatures) =
+                    committed_transaction_signatures.as_mut()
+                {
+                    committed_transaction_signatures.push(*tx.signature());
+                }
                 // Add the message hash to the status cache to ensure that this message
                 // won't be processed again with a different signature.
                 status_cache.insert(
@@ -4202,6 +4401,7 @@ impl Bank {
         if self.is_block_boundary(self.tick_height.load(Relaxed) + 1) {
             self.register_recent_blockhash(hash);
         }
+        *self.last_tick_hash.write().unwrap() = Some(*hash);
 
         // ReplayStage will start computing the accounts delta hash when it
         // detects the tick height has reached the boundary, so the system
@@ -4211,6 +4411,20 @@ impl Bank {
         self.tick_height.fetch_add(1, Relaxed);
     }
 
+    /// Hash of the most recent tick registered in this slot, or of the last
+    /// tick of the parent slot if no tick was registered yet
+    ///
+    /// This is the data of the tick hash sysvar of the transactions the bank
+    /// executes. Replay registers each tick before executing the entries that
+    /// follow it, so the PoH recorder refuses to record transactions reading
+    /// the sysvar once another tick was registered since they executed.
+    pub fn tick_hash(&self) -> Hash {
+        self.last_tick_hash
+            .read()
+            .unwrap()
+            .unwrap_or_else(|| self.last_blockhash())
+    }
+
     pub fn is_complete(&self) -> bool {
         self.tick_height() == self.max_tick_height()
     }
@@ -4235,12 +4449,47 @@ impl Bank {
         }
     }
 
+    /// The [`sysvar::FEATURE_GATED_IDS`] whose feature is active, which are
+    /// demoted to readonly in the transactions sanitized for this bank with
+    /// [`SanitizedTransaction::demote_account_keys`].
+    pub fn demoted_sysvar_ids(&self) -> &[Pubkey] {
+        &self.demoted_sysvar_ids
+    }
+
+    fn compute_demoted_sysvar_ids(&self) -> Vec<Pubkey> {
+        [
+            (
+                sysvar::epoch_stake::id(),
+                feature_set::epoch_stake_sysvar::id(),
+            ),
+            (
+                sysvar::recent_signatures::id(),
+                feature_set::recent_signatures_sysvar::id(),
+            ),
+            (sysvar::header::id(), feature_set::header_sysvar::id()),
+            (sysvar::scratch::id(), feature_set::scratch_sysvar::id()),
+            (sysvar::tick_hash::id(), feature_set::tick_hash_sysvar::id()),
+            (
+                sysvar::transaction_context::id(),
+                feature_set::transaction_context_sysvar::id(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, feature_id)| self.feature_set.is_active(feature_id))
+        .map(|(sysvar_id, _)| sysvar_id)
+        .collect()
+    }
+
     /// Prepare a transaction batch from a list of legacy transactions. Used for tests only.
     pub fn prepare_batch_for_tests(&self, txs: Vec<Transaction>) -> TransactionBatch {
         let transaction_account_lock_limit = self.get_transaction_account_lock_limit();
         let sanitized_txs = txs
             .into_iter()
-            .map(SanitizedTransaction::from_transaction_for_tests)
+            .map(|tx| {
+                let mut tx = SanitizedTransaction::from_transaction_for_tests(tx);
+                tx.demote_account_keys(self.demoted_sysvar_ids());
+                tx
+            })
             .collect::<Vec<_>>();
         let lock_results = self
             .rc
@@ -4254,7 +4503,12 @@ impl Bank {
     pub fn prepare_entry_batch(&self, txs: Vec<VersionedTransaction>) -> Result<TransactionBatch> {
         let sanitized_txs = txs
             .into_iter()
-            .map(|tx| SanitizedTransaction::try_create(tx, MessageHash::Compute, None, self))
+            .map(|tx| {
+                let mut tx =
+                    SanitizedTransaction::try_create(tx, MessageHash::Compute, None, self)?;
+                tx.demote_account_keys(self.demoted_sysvar_ids());
+                Ok(tx)
+            })
             .collect::<Result<Vec<_>>>()?;
         let tx_account_lock_limit = self.get_transaction_account_lock_limit();
         let lock_results = self
@@ -4326,15 +4580,44 @@ impl Bank {
         self.simulate_transaction_unchecked(transaction)
     }
 
+    /// Run a transaction against a frozen bank without committing the results, with the
+    /// signatures sysvar constructed from the transaction's signatures, as submitted, even if the
+    /// `enable_signatures_sysvar` feature is not active
+    pub fn simulate_transaction_with_signatures_sysvar(
+        &self,
+        transaction: SanitizedTransaction,
+    ) -> TransactionSimulationResult {
+        assert!(self.is_frozen(), "simulation bank must be frozen");
+
+        self.simulate_transaction_with_options(transaction, true)
+    }
+
     /// Run transactions against a bank without committing the results; does not check if the bank
     /// is frozen, enabling use in single-Bank test frameworks
     pub fn simulate_transaction_unchecked(
         &self,
         transaction: SanitizedTransaction,
+    ) -> TransactionSimulationResult {
+        self.simulate_transaction_with_options(transaction, false)
+    }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: SanitizedTransaction,
+        populate_signatures_sysvar: bool,
     ) -> TransactionSimulationResult {
         let account_keys = transaction.message().account_keys();
         let number_of_accounts = account_keys.len();
-        let account_overrides = self.get_account_overrides_for_simulation(&account_keys);
+        let mut account_overrides = self.get_account_overrides_for_simulation(&account_keys);
+        if populate_signatures_sysvar && account_keys.iter().any(sysvar::signatures::check_id) {
+            // Transactions that can't be represented in the sysvar fail to load
+            // without the override
+            if let Ok(signatures_sysvar) =
+                Accounts::construct_signatures_account(&transaction, &self.feature_set)
+            {
+                account_overrides.set_signatures_sysvar(Some(signatures_sysvar));
+            }
+        }
         let batch = self.prepare_unlocked_batch_from_single_tx(&transaction);
         let mut timings = ExecuteTimings::default();
 
@@ -4568,7 +4851,36 @@ impl Bank {
     ) -> Vec<TransactionCheckResult> {
         let age_results =
             self.check_age(sanitized_txs.iter(), lock_results, max_age, error_counters);
-        self.check_status_cache(sanitized_txs, age_results, error_counters)
+        let cache_results = self.check_status_cache(sanitized_txs, age_results, error_counters);
+        self.check_guards(sanitized_txs, cache_results)
+    }
+
+    /// Reject the transactions violating their transaction guard, which only
+    /// depends on the message, before their accounts are loaded
+    fn check_guards(
+        &self,
+        sanitized_txs: &[SanitizedTransaction],
+        check_results: Vec<TransactionCheckResult>,
+    ) -> Vec<TransactionCheckResult> {
+        if !self
+            .feature_set
+            .is_active(&enable_transaction_guard_instruction::id())
+        {
+            return check_results;
+        }
+        sanitized_txs
+            .iter()
+            .zip(check_results)
+            .map(|(sanitized_tx, (check_result, nonce))| {
+                if check_result.is_ok() {
+                    if let Err(err) = check_transaction_guards(sanitized_tx.message()) {
+                        return (Err(err), None);
+                    }
+                }
+
+                (check_result, nonce)
+            })
+            .collect()
     }
 
     pub fn collect_balances(&self, batch: &TransactionBatch) -> TransactionBalances {
@@ -4852,6 +5164,12 @@ impl Bank {
         {
             transaction_context.enable_cap_accounts_data_allocations_per_transaction();
         }
+        if self
+            .feature_set
+            .is_active(&feature_set::scratch_sysvar::id())
+        {
+            transaction_context.enable_scratch_sysvar();
+        }
         #[cfg(debug_assertions)]
         transaction_context.set_signature(tx.signature());
 
@@ -4880,9 +5198,21 @@ impl Bank {
             self.slot,
             programs_loaded_for_tx_batch.environments.clone(),
         );
+        // A transaction only consumes the nonce of its message when its
+        // blockhash isn't recent, in which case the nonce fee is charged
+        let nonce_info = durable_nonce_fee
+            .is_some()
+            .then(|| tx.message().get_durable_nonce())
+            .flatten()
+            .map(|nonce_account| TransactionNonceInfo {
+                nonce_account: *nonce_account,
+                durable_nonce: *tx.message().recent_blockhash(),
+            });
         let mut process_message_time = Measure::start("process_message_time");
         let process_result = MessageProcessor::process_message(
             tx.message(),
+            tx.signatures(),
+            nonce_info,
             &loaded_transaction.program_indices,
             &mut transaction_context,
             self.rent_collector.rent,
@@ -5168,9 +5498,23 @@ impl Bank {
             self.replenish_program_cache(&program_accounts_map),
         ));
 
+        let signatures_sysvar_override = self.signatures_sysvar_override.read().unwrap().clone();
+        let account_overrides_with_signatures;
+        let account_overrides = match signatures_sysvar_override {
+            Some(signatures_sysvar) => {
+                let mut overrides = account_overrides.cloned().unwrap_or_default();
+                overrides.set_signatures_sysvar(Some(signatures_sysvar));
+                account_overrides_with_signatures = overrides;
+                Some(&account_overrides_with_signatures)
+            }
+            None => account_overrides,
+        };
+
         let mut load_time = Measure::start("accounts_load");
         let mut loaded_transactions = self.rc.accounts.load_accounts(
             &self.ancestors,
+            self.slot(),
+            &self.tick_hash(),
             sanitized_txs,
             check_results,
             &self.blockhash_queue.read().unwrap(),
@@ -5211,6 +5555,8 @@ impl Bank {
                                 .is_active(&remove_deprecated_request_unit_ix::id()),
                             self.feature_set
                                 .is_active(&add_set_tx_loaded_accounts_data_size_instruction::id()),
+                            self.feature_set
+                                .is_active(&enable_transaction_guard_instruction::id()),
                         );
                         compute_budget_process_transaction_time.stop();
                         saturating_add_assign!(
@@ -5571,6 +5917,16 @@ impl Bank {
         self.increment_non_vote_transaction_count_since_restart(
             committed_non_vote_transactions_count,
         );
+        let committed_introspection_transactions_count = sanitized_txs
+            .iter()
+            .zip(&execution_results)
+            .filter(|(tx, execution_result)| {
+                execution_result.was_executed_successfully()
+                    && tx.message().references_per_transaction_sysvar()
+            })
+            .count();
+        self.introspection_transaction_count_since_restart
+            .fetch_add(committed_introspection_transactions_count as u64, Relaxed);
         self.increment_signature_count(signature_count);
 
         if committed_with_failure_result_count > 0 {
@@ -7018,6 +7374,15 @@ impl Bank {
         self.non_vote_transaction_count_since_restart.load(Relaxed)
     }
 
+    /// Returns the number of transactions referencing per-transaction sysvars
+    /// processed without error since the most recent boot from snapshot or
+    /// genesis, see
+    /// [`SanitizedMessage::references_per_transaction_sysvar`].
+    pub fn introspection_transaction_count_since_restart(&self) -> u64 {
+        self.introspection_transaction_count_since_restart
+            .load(Relaxed)
+    }
+
     /// Return the transaction count executed only in this bank
     pub fn executed_transaction_count(&self) -> u64 {
         self.transaction_count()
@@ -7388,8 +7753,10 @@ impl Bank {
                 tx.message.hash()
             };
 
-            SanitizedTransaction::try_create(tx, message_hash, None, self)
-        }?;
+            let mut sanitized_tx = SanitizedTransaction::try_create(tx, message_hash, None, self)?;
+            sanitized_tx.demote_account_keys(self.demoted_sysvar_ids());
+            sanitized_tx
+        };
 
         if verification_mode == TransactionVerificationMode::HashAndVerifyPrecompiles
             || verification_mode == TransactionVerificationMode::FullVerification
@@ -7991,6 +8358,7 @@ impl Bank {
         feature_set.active.remove(id);
         feature_set.inactive.insert(*id);
         self.feature_set = Arc::new(feature_set);
+        self.demoted_sysvar_ids = self.compute_demoted_sysvar_ids();
     }
 
     pub fn activate_feature(&mut self, id: &Pubkey) {
@@ -7998,6 +8366,7 @@ impl Bank {
         feature_set.inactive.remove(id);
         feature_set.active.insert(*id, 0);
         self.feature_set = Arc::new(feature_set);
+        self.demoted_sysvar_ids = self.compute_demoted_sysvar_ids();
     }
 
     pub fn fill_bank_with_ticks_for_tests(&self) {
@@ -8027,6 +8396,7 @@ impl Bank {
         let (feature_set, new_feature_activations) =
             self.compute_active_feature_set(allow_new_activations);
         self.feature_set = Arc::new(feature_set);
+        self.demoted_sysvar_ids = self.compute_demoted_sysvar_ids();
 
         if new_feature_activations.contains(&feature_set::pico_inflation::id()) {
             *self.inflation.write().unwrap() = Inflation::pico();
@@ -8160,6 +8530,18 @@ impl Bank {
             feature_set::last_restart_slot_sysvar::id(),
             feature_set::delay_visibility_of_program_deployment::id(),
             feature_set::remaining_compute_units_syscall_enabled::id(),
+            feature_set::enable_base58_syscalls::id(),
+            feature_set::sibling_instruction_provenance_syscall_enabled::id(),
+            feature_set::instructions_sysvar_slice_syscall_enabled::id(),
+            feature_set::enable_sol_memcmp_ct_syscall::id(),
+            feature_set::enable_tx_scratch_syscalls::id(),
+            feature_set::sibling_instruction_sysvar_access_syscall_enabled::id(),
+            feature_set::enable_sol_is_verified_syscall::id(),
+            feature_set::enable_get_transaction_signature_syscall::id(),
+            feature_set::enable_signatures_sysvar::id(),
+            feature_set::enable_sol_ed25519_verify_syscall::id(),
+            feature_set::enable_get_transaction_nonce_info_syscall::id(),
+            feature_set::enable_get_serialized_message_syscall::id(),
         ];
         if !only_apply_transitions_for_new_features
             || FEATURES_AFFECTING_RBPF
@@ -63,7 +63,7 @@ use {
             BankId, Epoch, Slot, UnixTimestamp, DEFAULT_HASHES_PER_TICK, DEFAULT_SLOTS_PER_EPOCH,
             DEFAULT_TICKS_PER_SLOT, INITIAL_RENT_EPOCH, MAX_PROCESSING_AGE, MAX_RECENT_BLOCKHASHES,
         },
-        compute_budget::ComputeBudgetInstruction,
+        compute_budget::{ComputeBudgetInstruction, TransactionGuard},
         entrypoint::MAX_PERMITTED_DATA_INCREASE,
         epoch_schedule::{EpochSchedule, MINIMUM_SLOTS_PER_EPOCH},
         feature::{self, Feature},
@@ -10251,6 +10251,88 @@ fn test_failed_compute_request_instruction() {
     assert_eq!(bank.signature_count(), 3);
 }
 
+#[test]
+fn test_transaction_guard_instruction() {
+    solana_logger::setup();
+    let GenesisConfigInfo {
+        mut genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(
+        1_000_000_000_000_000,
+        &Pubkey::new_unique(),
+        bootstrap_validator_stake_lamports(),
+    );
+    let bank = Bank::new_for_tests(&genesis_config);
+
+    let payer_keypair = Keypair::new();
+    let payer = payer_keypair.pubkey();
+    bank.transfer(1_000_000, &mint_keypair, &payer).unwrap();
+
+    let guarded_transaction = |guard: TransactionGuard, bank: &Bank| {
+        let message = Message::new(
+            &[
+                ComputeBudgetInstruction::transaction_guard(guard),
+                system_instruction::transfer(&payer, &Pubkey::new_unique(), 1),
+            ],
+            Some(&payer),
+        );
+        Transaction::new(&[&payer_keypair], message, bank.last_blockhash())
+    };
+
+    let satisfied_guard = TransactionGuard {
+        max_signers: Some(1),
+        required_signer: Some(payer),
+        required_program_id: Some(system_program::id()),
+    };
+    assert_eq!(
+        bank.process_transaction(&guarded_transaction(satisfied_guard.clone(), &bank)),
+        Ok(())
+    );
+
+    // Violated guards reject the transaction before it is executed or charged
+    let balance = bank.get_balance(&payer);
+    let violated_guard = TransactionGuard {
+        required_signer: Some(mint_keypair.pubkey()),
+        ..satisfied_guard.clone()
+    };
+    let violating_transaction = guarded_transaction(violated_guard, &bank);
+    assert_eq!(
+        bank.process_transaction(&violating_transaction),
+        Err(TransactionError::TransactionGuardViolated(0))
+    );
+    assert_eq!(bank.get_balance(&payer), balance);
+
+    // ... and they are rejected when checked, before their accounts are loaded
+    let sanitized_txs = [SanitizedTransaction::from_transaction_for_tests(
+        violating_transaction,
+    )];
+    let check_results = bank.check_transactions(
+        &sanitized_txs,
+        &[Ok(())],
+        MAX_PROCESSING_AGE,
+        &mut TransactionErrorMetrics::default(),
+    );
+    assert_eq!(
+        check_results,
+        vec![(Err(TransactionError::TransactionGuardViolated(0)), None)]
+    );
+
+    // Guards are invalid compute budget instructions before the feature is active
+    genesis_config
+        .accounts
+        .remove(&feature_set::enable_transaction_guard_instruction::id());
+    let bank = Bank::new_for_tests(&genesis_config);
+    bank.transfer(1_000_000, &mint_keypair, &payer).unwrap();
+    assert_eq!(
+        bank.process_transaction(&guarded_transaction(satisfied_guard, &bank)),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidInstructionData
+        ))
+    );
+}
+
 #[test]
 fn test_verify_and_hash_transaction_sig_len() {
     let GenesisConfigInfo {
@@ -10314,6 +10396,306 @@ fn test_verify_and_hash_transaction_sig_len() {
     }
 }
 
+#[test]
+fn test_signatures_sysvar_requires_verified_signatures() {
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(42, &solana_sdk::pubkey::new_rand(), 42);
+    let bank = Bank::new_for_tests(&genesis_config);
+
+    let cosigner = Keypair::new();
+    let mut instruction =
+        system_instruction::transfer(&mint_keypair.pubkey(), &Pubkey::new_unique(), 1);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(cosigner.pubkey(), true));
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(sysvar::signatures::id(), false));
+    let message = Message::new(&[instruction], Some(&mint_keypair.pubkey()));
+    assert_eq!(message.header.num_required_signatures, 2);
+
+    // Partially signed: the cosigner's signature is left as the default
+    // signature, which must fail verification before any sysvar is built
+    let mut tx = Transaction::new_unsigned(message);
+    tx.partial_sign(&[&mint_keypair], bank.last_blockhash());
+    assert!(!tx.is_signed());
+    assert_eq!(
+        bank.verify_transaction(
+            tx.clone().into(),
+            TransactionVerificationMode::FullVerification
+        )
+        .err(),
+        Some(TransactionError::SignatureFailure),
+    );
+
+    // Required signature absent: rejected by sanitization regardless of the
+    // verification mode
+    let mut unsanitary_tx = tx.clone();
+    unsanitary_tx.signatures.pop();
+    for verification_mode in [
+        TransactionVerificationMode::HashOnly,
+        TransactionVerificationMode::FullVerification,
+    ] {
+        assert_eq!(
+            bank.verify_transaction(unsanitary_tx.clone().into(), verification_mode)
+                .err(),
+            Some(TransactionError::SanitizeFailure),
+        );
+    }
+
+    // Once fully signed, the sanitized transaction carries every signature,
+    // in signer order, for the runtime to serialize into the sysvar
+    tx.partial_sign(&[&cosigner], bank.last_blockhash());
+    let sanitized_tx = bank
+        .verify_transaction(
+            tx.clone().into(),
+            TransactionVerificationMode::FullVerification,
+        )
+        .unwrap();
+    assert_eq!(sanitized_tx.signatures(), &tx.signatures[..]);
+    assert_eq!(bank.process_transaction(&tx), Ok(()));
+}
+
+#[test]
+fn test_signatures_sysvar_unverified_signatures() {
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(sol_to_lamports(1.), &Pubkey::new_unique(), 42);
+    let bank = Bank::new_for_tests(&genesis_config);
+    bank.freeze();
+
+    let cosigner = Keypair::new();
+    let mut instruction =
+        system_instruction::transfer(&mint_keypair.pubkey(), &Pubkey::new_unique(), 1);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(cosigner.pubkey(), true));
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(sysvar::signatures::id(), false));
+    let message = Message::new(&[instruction], Some(&mint_keypair.pubkey()));
+    let mut tx = Transaction::new_unsigned(message);
+    tx.partial_sign(&[&mint_keypair], bank.last_blockhash());
+    assert_eq!(tx.signatures[1], Signature::default());
+
+    // Simulating without verification exposes the signature bytes as they
+    // are, including the cosigner's default signature
+    let simulation_result = bank.simulate_transaction_unchecked(
+        SanitizedTransaction::from_transaction_for_tests(tx.clone()),
+    );
+    assert_eq!(simulation_result.result, Ok(()));
+    let (_, signatures_account) = simulation_result
+        .post_simulation_accounts
+        .iter()
+        .find(|(key, _)| key == &sysvar::signatures::id())
+        .unwrap();
+    let signatures_data =
+        sysvar::signatures::SignaturesData::deserialize(signatures_account.data()).unwrap();
+    assert!(signatures_data
+        .signatures
+        .iter()
+        .map(|signature| &signature.0)
+        .eq(tx.signatures.iter().map(Signature::as_bytes)));
+
+    // Once fully signed, the data only differs by the cosigner's signature
+    tx.partial_sign(&[&cosigner], bank.last_blockhash());
+    let simulation_result =
+        bank.simulate_transaction(SanitizedTransaction::from_transaction_for_tests(tx.clone()));
+    let (_, signed_signatures_account) = simulation_result
+        .post_simulation_accounts
+        .iter()
+        .find(|(key, _)| key == &sysvar::signatures::id())
+        .unwrap();
+    let signed_signatures_data =
+        sysvar::signatures::SignaturesData::deserialize(signed_signatures_account.data()).unwrap();
+    assert_eq!(
+        signed_signatures_data.signatures[0],
+        signatures_data.signatures[0]
+    );
+    assert_eq!(signed_signatures_data.signers, signatures_data.signers);
+    assert_eq!(
+        &signed_signatures_data.signatures[1].0,
+        tx.signatures[1].as_bytes()
+    );
+}
+
+#[test]
+fn test_signatures_sysvar_populated_for_execution() {
+    declare_process_instruction!(process_instruction, 1, |invoke_context| {
+        let transaction_context = &invoke_context.transaction_context;
+        let instruction_context = transaction_context.get_current_instruction_context()?;
+        let sysvar_account =
+            instruction_context.try_borrow_instruction_account(transaction_context, 0)?;
+        if sysvar_account.get_key() != &sysvar::signatures::id()
+            || sysvar_account.get_owner() != &sysvar::id()
+            || sysvar_account.is_writable()
+        {
+            return Err(InstructionError::InvalidArgument);
+        }
+        let signatures_data =
+            sysvar::signatures::SignaturesData::deserialize(sysvar_account.get_data())
+                .map_err(|_| InstructionError::InvalidAccountData)?;
+        let cosigner = transaction_context.get_key_of_account_at_index(
+            instruction_context.get_index_of_instruction_account_in_transaction(1)?,
+        )?;
+        let signers = signatures_data
+            .signers
+            .ok_or(InstructionError::InvalidAccountData)?;
+        if signatures_data.signatures.len() != 2 || signers.get(1) != Some(cosigner) {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        Ok(())
+    });
+
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(sol_to_lamports(1.), &Pubkey::new_unique(), 42);
+    let mut bank = Bank::new_for_tests(&genesis_config);
+    let mock_program_id = Pubkey::new_unique();
+    bank.add_mockup_builtin(mock_program_id, process_instruction);
+
+    // The sysvar is requested as writable, which the message demotes
+    let cosigner = Keypair::new();
+    let instruction = Instruction::new_with_bytes(
+        mock_program_id,
+        &[],
+        vec![
+            AccountMeta::new(sysvar::signatures::id(), false),
+            AccountMeta::new_readonly(cosigner.pubkey(), true),
+        ],
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&mint_keypair.pubkey()),
+        &[&mint_keypair, &cosigner],
+        bank.last_blockhash(),
+    );
+    assert_eq!(bank.process_transaction(&tx), Ok(()));
+
+    // The account is built for each transaction and never stored
+    assert_eq!(bank.get_account(&sysvar::signatures::id()), None);
+}
+
+#[test]
+fn test_simulate_transaction_with_signatures_sysvar() {
+    // Succeeds if the signatures sysvar holds the placeholder signature
+    declare_process_instruction!(process_instruction, 1, |invoke_context| {
+        let transaction_context = &invoke_context.transaction_context;
+        let instruction_context = transaction_context.get_current_instruction_context()?;
+        let sysvar_account =
+            instruction_context.try_borrow_instruction_account(transaction_context, 0)?;
+        let signatures_data =
+            sysvar::signatures::SignaturesData::deserialize(sysvar_account.get_data())
+                .map_err(|_| InstructionError::InvalidAccountData)?;
+        if signatures_data.signatures != [[0; 64]] {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        Ok(())
+    });
+
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(sol_to_lamports(1.), &Pubkey::new_unique(), 42);
+    let mut bank = Bank::new_for_tests(&genesis_config);
+    bank.deactivate_feature(&feature_set::enable_signatures_sysvar::id());
+    let mock_program_id = Pubkey::new_unique();
+    bank.add_mockup_builtin(mock_program_id, process_instruction);
+    bank.freeze();
+
+    let instruction = Instruction::new_with_bytes(
+        mock_program_id,
+        &[],
+        vec![AccountMeta::new_readonly(sysvar::signatures::id(), false)],
+    );
+    let mut tx =
+        Transaction::new_unsigned(Message::new(&[instruction], Some(&mint_keypair.pubkey())));
+    tx.message.recent_blockhash = bank.last_blockhash();
+    let tx = SanitizedTransaction::from_transaction_for_tests(tx);
+
+    assert_eq!(
+        bank.simulate_transaction(tx.clone()).result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidAccountData
+        ))
+    );
+    assert_eq!(
+        bank.simulate_transaction_with_signatures_sysvar(tx).result,
+        Ok(())
+    );
+}
+
+#[test]
+fn test_signatures_sysvar_feature_gate() {
+    // Succeeds if the signatures sysvar is populated exactly when the first
+    // byte of the instruction data is non-zero
+    declare_process_instruction!(process_instruction, 1, |invoke_context| {
+        let transaction_context = &invoke_context.transaction_context;
+        let instruction_context = transaction_context.get_current_instruction_context()?;
+        let expect_populated = instruction_context.get_instruction_data()[0] != 0;
+        let sysvar_account =
+            instruction_context.try_borrow_instruction_account(transaction_context, 0)?;
+        let populated = sysvar_account.get_owner() == &sysvar::id()
+            && sysvar::signatures::SignaturesData::deserialize(sysvar_account.get_data()).is_ok();
+        if populated != expect_populated {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        if !populated && !sysvar_account.get_data().is_empty() {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        Ok(())
+    });
+
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(sol_to_lamports(1.), &Pubkey::new_unique(), 42);
+    let mut bank = Bank::new_for_tests(&genesis_config);
+    bank.deactivate_feature(&feature_set::enable_signatures_sysvar::id());
+    let mock_program_id = Pubkey::new_unique();
+    bank.add_mockup_builtin(mock_program_id, process_instruction);
+
+    let process = |bank: &Bank, expect_populated: bool| {
+        let instruction = Instruction::new_with_bytes(
+            mock_program_id,
+            &[expect_populated as u8],
+            vec![AccountMeta::new_readonly(sysvar::signatures::id(), false)],
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&mint_keypair.pubkey()),
+            &[&mint_keypair],
+            bank.last_blockhash(),
+        );
+        bank.process_transaction(&tx)
+    };
+
+    // Before activation the sysvar address loads as a nonexistent account
+    assert_eq!(process(&bank, false), Ok(()));
+    assert_eq!(
+        process(&bank, true),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::InvalidAccountData
+        ))
+    );
+
+    bank.activate_feature(&feature_set::enable_signatures_sysvar::id());
+    bank.register_recent_blockhash(&Hash::new_unique());
+    assert_eq!(process(&bank, true), Ok(()));
+    assert_eq!(bank.get_account(&sysvar::signatures::id()), None);
+}
+
 #[test]
 fn test_verify_transactions_packet_data_size() {
     let GenesisConfigInfo { genesis_config, .. } =
@@ -11457,6 +11839,8 @@ fn test_rent_state_list_len() {
     let mut error_counters = TransactionErrorMetrics::default();
     let loaded_txs = bank.rc.accounts.load_accounts(
         &bank.ancestors,
+        bank.slot(),
+        &bank.tick_hash(),
         &[sanitized_tx.clone()],
         vec![(Ok(()), None)],
         &bank.blockhash_queue.read().unwrap(),
@@ -14048,3 +14432,356 @@ fn test_last_restart_slot() {
     assert!(!last_restart_slot_dirty(&bank7));
     assert_eq!(get_last_restart_slot(&bank7), Some(6));
 }
+
+#[test]
+fn test_epoch_stake_sysvar() {
+    let leader_pubkey = solana_sdk::pubkey::new_rand();
+    let GenesisConfigInfo {
+        mut genesis_config,
+        voting_keypair,
+        ..
+    } = create_genesis_config_with_leader(100, &leader_pubkey, 10);
+    // Remove the feature so we can simulate its activation
+    genesis_config
+        .accounts
+        .remove(&feature_set::epoch_stake_sysvar::id())
+        .unwrap();
+
+    let bank0 = Arc::new(Bank::new_for_tests(&genesis_config));
+    assert!(bank0.get_account(&sysvar::epoch_stake::id()).is_none());
+
+    let mut bank1 = Arc::new(Bank::new_from_parent(bank0, &Pubkey::default(), 1));
+    assert!(bank1.get_account(&sysvar::epoch_stake::id()).is_none());
+
+    // Activate the feature in slot 1, it will get initialized in slot 1's children
+    Arc::get_mut(&mut bank1)
+        .unwrap()
+        .activate_feature(&feature_set::epoch_stake_sysvar::id());
+    let bank2 = Bank::new_from_parent(bank1, &Pubkey::default(), 2);
+    let account = bank2.get_account(&sysvar::epoch_stake::id()).unwrap();
+    assert_eq!(account.owner(), &sysvar::id());
+
+    let key = sysvar::epoch_stake::id();
+    let mut lamports = account.lamports();
+    let mut data = account.data().to_vec();
+    let owner = *account.owner();
+    let account_info = solana_sdk::account_info::AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        Epoch::default(),
+    );
+    assert_eq!(
+        sysvar::epoch_stake::load_total_stake_checked(&account_info),
+        Ok(bank2.total_epoch_stake())
+    );
+    let vote_pubkey = voting_keypair.pubkey();
+    assert_eq!(
+        sysvar::epoch_stake::load_vote_account_stake_checked(&vote_pubkey, &account_info),
+        Ok(bank2.epoch_vote_account_stake(&vote_pubkey))
+    );
+}
+
+#[test]
+fn test_recent_signatures_sysvar() {
+    let pubkey = solana_sdk::pubkey::new_rand();
+    let GenesisConfigInfo {
+        mut genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(sol_to_lamports(1.), &pubkey, 0);
+    let amount = genesis_config.rent.minimum_balance(0);
+
+    let bank0 = Arc::new(Bank::new_for_tests(&genesis_config));
+    let signatures = [
+        bank0.transfer(amount, &mint_keypair, &pubkey).unwrap(),
+        bank0.transfer(amount + 1, &mint_keypair, &pubkey).unwrap(),
+        bank0.transfer(amount + 2, &mint_keypair, &pubkey).unwrap(),
+    ];
+    // The root is added when the bank is frozen
+    assert!(bank0
+        .get_account(&sysvar::recent_signatures::id())
+        .is_none());
+    bank0.freeze();
+
+    let account = bank0.get_account(&sysvar::recent_signatures::id()).unwrap();
+    assert_eq!(account.owner(), &sysvar::id());
+    let recent_signatures: sysvar::recent_signatures::RecentSignatures =
+        from_account(&account).unwrap();
+    let mut sorted_signatures = signatures;
+    sorted_signatures.sort_unstable();
+    let root = *recent_signatures.get(0).unwrap();
+    assert_eq!(
+        root,
+        sysvar::recent_signatures::compute_signature_root(&sorted_signatures)
+    );
+    for (index, signature) in sorted_signatures.iter().enumerate() {
+        let proof =
+            sysvar::recent_signatures::compute_signature_proof(&sorted_signatures, index).unwrap();
+        assert!(sysvar::recent_signatures::verify_signature_proof(
+            signature.as_ref(),
+            index,
+            &proof,
+            &root
+        ));
+    }
+
+    // A slot without transactions has the default root
+    let bank1 = Bank::new_from_parent(bank0, &Pubkey::default(), 1);
+    bank1.freeze();
+    let recent_signatures: sysvar::recent_signatures::RecentSignatures =
+        from_account(&bank1.get_account(&sysvar::recent_signatures::id()).unwrap()).unwrap();
+    assert_eq!(
+        recent_signatures.roots(),
+        &[(1, Hash::default()), (0, root)]
+    );
+
+    // Nothing is recorded until the feature is activated
+    genesis_config
+        .accounts
+        .remove(&feature_set::recent_signatures_sysvar::id())
+        .unwrap();
+    let bank0 = Bank::new_for_tests(&genesis_config);
+    bank0.transfer(amount, &mint_keypair, &pubkey).unwrap();
+    bank0.freeze();
+    assert!(bank0
+        .get_account(&sysvar::recent_signatures::id())
+        .is_none());
+}
+
+#[test]
+fn test_recent_signature_positions() {
+    let (genesis_config, mint_keypair) = create_genesis_config(sol_to_lamports(1.));
+    let pubkey = solana_sdk::pubkey::new_rand();
+    let bank0 = Arc::new(Bank::new_with_runtime_config_for_tests(
+        &genesis_config,
+        Arc::new(RuntimeConfig {
+            recent_signatures_max_slots: Some(2),
+            ..RuntimeConfig::default()
+        }),
+    ));
+    let amount = genesis_config.rent.minimum_balance(0);
+
+    // Not retained unless configured
+    let disabled_bank = Bank::new_for_tests(&genesis_config);
+    let signature = disabled_bank
+        .transfer(amount, &mint_keypair, &pubkey)
+        .unwrap();
+    assert_eq!(
+        disabled_bank.get_recent_signature_position(&signature),
+        None
+    );
+    assert_eq!(disabled_bank.recent_signatures_memory_usage(), 0);
+
+    let signature0 = bank0.transfer(amount, &mint_keypair, &pubkey).unwrap();
+    let signature1 = bank0.transfer(amount + 1, &mint_keypair, &pubkey).unwrap();
+    assert_eq!(
+        bank0.get_recent_signature_position(&signature0),
+        Some(SignaturePosition {
+            slot: 0,
+            commit_index: 0
+        })
+    );
+    assert_eq!(
+        bank0.get_recent_signature_position(&signature1),
+        Some(SignaturePosition {
+            slot: 0,
+            commit_index: 1
+        })
+    );
+    assert!(bank0.recent_signatures_memory_usage() > 0);
+
+    // Signatures processed on a sibling fork are not visible
+    let bank1 = Arc::new(Bank::new_from_parent(bank0.clone(), &Pubkey::default(), 1));
+    let bank2 = Arc::new(Bank::new_from_parent(bank0, &Pubkey::default(), 2));
+    let signature2 = bank1.transfer(amount + 2, &mint_keypair, &pubkey).unwrap();
+    assert_eq!(
+        bank1.get_recent_signature_position(&signature2),
+        Some(SignaturePosition {
+            slot: 1,
+            commit_index: 0
+        })
+    );
+    assert_eq!(bank2.get_recent_signature_position(&signature2), None);
+
+    // Slot 0 is evicted once a third slot records a signature
+    let signature3 = bank2.transfer(amount + 3, &mint_keypair, &pubkey).unwrap();
+    assert_eq!(
+        bank2.get_recent_signature_position(&signature3),
+        Some(SignaturePosition {
+            slot: 2,
+            commit_index: 0
+        })
+    );
+    assert_eq!(bank2.get_recent_signature_position(&signature0), None);
+}
+
+#[test]
+fn test_tick_hash() {
+    let (genesis_config, _mint_keypair) = create_genesis_config(sol_to_lamports(1.));
+    let bank0 = Arc::new(Bank::new_for_tests(&genesis_config));
+    assert_eq!(bank0.tick_hash(), bank0.last_blockhash());
+    let tick_hash = Hash::new_unique();
+    bank0.register_tick(&tick_hash);
+    assert_eq!(bank0.tick_hash(), tick_hash);
+
+    // Before its first tick, a bank reports the last tick of its parent
+    bank0.fill_bank_with_ticks_for_tests();
+    let bank1 = Bank::new_from_parent(bank0.clone(), &Pubkey::default(), 1);
+    assert_eq!(bank1.tick_hash(), bank0.last_blockhash());
+
+    let tick_hash = Hash::new_unique();
+    bank1.register_tick(&tick_hash);
+    // Ticks other than the last of the slot don't change the blockhash
+    assert_eq!(bank1.tick_hash(), tick_hash);
+    assert_eq!(bank1.last_blockhash(), bank0.last_blockhash());
+}
+
+#[test]
+fn test_introspection_transaction_count_since_restart() {
+    let (genesis_config, mint_keypair) = create_genesis_config(sol_to_lamports(1.));
+    let bank0 = Arc::new(Bank::new_for_tests(&genesis_config));
+    let pubkey = solana_sdk::pubkey::new_rand();
+    let amount = genesis_config.rent.minimum_balance(0);
+    bank0.transfer(amount, &mint_keypair, &pubkey).unwrap();
+    assert_eq!(bank0.introspection_transaction_count_since_restart(), 0);
+
+    // A transfer that also passes the instructions sysvar
+    let mut instruction = system_instruction::transfer(&mint_keypair.pubkey(), &pubkey, amount);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(sysvar::instructions::id(), false));
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&mint_keypair.pubkey()),
+        &[&mint_keypair],
+        bank0.last_blockhash(),
+    );
+    bank0.process_transaction(&tx).unwrap();
+    assert_eq!(bank0.introspection_transaction_count_since_restart(), 1);
+    assert_eq!(bank0.non_vote_transaction_count_since_restart(), 2);
+
+    // Failed transactions aren't counted
+    let mut instruction =
+        system_instruction::transfer(&mint_keypair.pubkey(), &pubkey, sol_to_lamports(2.));
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(sysvar::instructions::id(), false));
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&mint_keypair.pubkey()),
+        &[&mint_keypair],
+        bank0.last_blockhash(),
+    );
+    assert!(bank0.process_transaction(&tx).is_err());
+    assert_eq!(bank0.introspection_transaction_count_since_restart(), 1);
+
+    let bank1 = Bank::new_from_parent(bank0, &Pubkey::default(), 1);
+    assert_eq!(bank1.introspection_transaction_count_since_restart(), 1);
+}
+
+#[test]
+fn test_warp_from_parent_preserves_introspection_features() {
+    let (mut genesis_config, _mint_keypair) = create_genesis_config(LAMPORTS_PER_SOL);
+    activate_all_features(&mut genesis_config);
+    // Deactivated on this cluster, must stay inactive across the warp
+    genesis_config
+        .accounts
+        .remove(&feature_set::integrity_checksum_in_sysvars::id());
+    // Requested but not yet activated, activates at the first epoch boundary
+    let feature_account_balance =
+        std::cmp::max(genesis_config.rent.minimum_balance(Feature::size_of()), 1);
+    genesis_config.accounts.insert(
+        feature_set::enable_get_transaction_nonce_info_syscall::id(),
+        Account::from(feature::create_account(
+            &Feature { activated_at: None },
+            feature_account_balance,
+        )),
+    );
+    let bank = Arc::new(Bank::new_for_tests(&genesis_config));
+    assert!(!bank
+        .feature_set
+        .is_active(&feature_set::enable_get_transaction_nonce_info_syscall::id()));
+
+    let warp_slot = genesis_config.epoch_schedule.get_first_slot_in_epoch(2) + 1;
+    let bank = Bank::warp_from_parent(
+        bank,
+        &Pubkey::default(),
+        warp_slot,
+        CalcAccountsHashDataSource::IndexForTests,
+    );
+    assert_eq!(bank.slot(), warp_slot);
+    assert!(bank
+        .feature_set
+        .is_active(&feature_set::enable_signatures_sysvar::id()));
+    assert!(bank
+        .feature_set
+        .is_active(&feature_set::enable_get_transaction_nonce_info_syscall::id()));
+    assert!(!bank
+        .feature_set
+        .is_active(&feature_set::integrity_checksum_in_sysvars::id()));
+
+    // The sysvar cache matches the warped clock
+    assert_eq!(
+        *bank.get_sysvar_cache_for_tests().get_clock().unwrap(),
+        bank.clock()
+    );
+}
+
+#[test]
+fn test_feature_gated_sysvars_demoted_once_active() {
+    let GenesisConfigInfo {
+        genesis_config,
+        mint_keypair,
+        ..
+    } = create_genesis_config_with_leader(sol_to_lamports(1.), &Pubkey::new_unique(), 42);
+    let mut bank = Bank::new_for_tests(&genesis_config);
+    bank.deactivate_feature(&feature_set::epoch_stake_sysvar::id());
+
+    let instruction = Instruction::new_with_bytes(
+        Pubkey::new_unique(),
+        &[],
+        vec![AccountMeta::new(sysvar::epoch_stake::id(), false)],
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&mint_keypair.pubkey()),
+        &[&mint_keypair],
+        bank.last_blockhash(),
+    );
+    let sysvar_index = tx
+        .message
+        .account_keys
+        .iter()
+        .position(sysvar::epoch_stake::check_id)
+        .unwrap();
+
+    // Upgrading doesn't change which accounts transactions write
+    assert!(bank.demoted_sysvar_ids().is_empty());
+    let sanitized_tx = bank
+        .verify_transaction(
+            tx.clone().into(),
+            TransactionVerificationMode::FullVerification,
+        )
+        .unwrap();
+    assert!(sanitized_tx.message().is_writable(sysvar_index));
+
+    // Transactions are demoted once, when they are sanitized for the bank
+    bank.activate_feature(&feature_set::epoch_stake_sysvar::id());
+    assert_eq!(bank.demoted_sysvar_ids(), [sysvar::epoch_stake::id()]);
+    let demoted_tx = bank
+        .verify_transaction(
+            tx.clone().into(),
+            TransactionVerificationMode::FullVerification,
+        )
+        .unwrap();
+    assert!(!demoted_tx.message().is_writable(sysvar_index));
+    let batch = bank.prepare_batch_for_tests(vec![tx]);
+    assert!(!batch.sanitized_transactions()[0]
+        .message()
+        .is_writable(sysvar_index));
+}
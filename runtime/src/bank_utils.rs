@@ -3,8 +3,13 @@ use {
         bank::Bank,
         genesis_utils::{self, GenesisConfigInfo, ValidatorVoteKeypairs},
     },
+    rayon::prelude::*,
     solana_accounts_db::transaction_results::TransactionResults,
-    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::SanitizedTransaction},
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Signature, Signer},
+        transaction::{self, SanitizedTransaction, TransactionError},
+    },
     solana_vote::{vote_parser, vote_sender_types::ReplayVoteSender},
 };
 
@@ -54,3 +59,83 @@ pub fn find_and_send_votes(
             });
     }
 }
+
+/// Verify the signatures of a batch of transactions in parallel.
+///
+/// Work is split per signature rather than per transaction, so a batch with
+/// a few heavily co-signed transactions still spreads across the rayon thread
+/// pool. Each signature is checked exactly as [`SanitizedTransaction::verify`]
+/// does; randomized ed25519 batch equations are deliberately not used, since
+/// their cofactored check accepts signatures that the runtime rejects.
+///
+/// Returns one result per transaction, in order.
+pub fn verify_transaction_signatures_batch(
+    txs: &[SanitizedTransaction],
+) -> Vec<transaction::Result<()>> {
+    let messages: Vec<Vec<u8>> = txs.par_iter().map(|tx| tx.message_data()).collect();
+    let signatures: Vec<(usize, &Signature, &Pubkey)> = txs
+        .iter()
+        .enumerate()
+        .flat_map(|(index, tx)| {
+            tx.signatures()
+                .iter()
+                .zip(tx.message().account_keys().iter())
+                .map(move |(signature, pubkey)| (index, signature, pubkey))
+        })
+        .collect();
+    let failed: Vec<usize> = signatures
+        .into_par_iter()
+        .filter(|(index, signature, pubkey)| !signature.verify(pubkey.as_ref(), &messages[*index]))
+        .map(|(index, _, _)| index)
+        .collect();
+
+    let mut results = vec![Ok(()); txs.len()];
+    for index in failed {
+        results[index] = Err(TransactionError::SignatureFailure);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            hash::Hash, signature::Keypair, system_transaction, transaction::Transaction,
+        },
+    };
+
+    #[test]
+    fn test_verify_transaction_signatures_batch() {
+        let keypair = Keypair::new();
+        let mut txs: Vec<Transaction> = (0..64)
+            .map(|lamports| {
+                system_transaction::transfer(
+                    &keypair,
+                    &Pubkey::new_unique(),
+                    lamports,
+                    Hash::default(),
+                )
+            })
+            .collect();
+        txs[7].signatures[0] = Signature::new_unique();
+        txs[42].message.recent_blockhash = Hash::new_unique();
+        let txs: Vec<SanitizedTransaction> = txs
+            .into_iter()
+            .map(SanitizedTransaction::from_transaction_for_tests)
+            .collect();
+
+        let results = verify_transaction_signatures_batch(&txs);
+        assert_eq!(results.len(), txs.len());
+        for (tx, result) in txs.iter().zip(&results) {
+            assert_eq!(result, &tx.verify());
+        }
+        assert_eq!(results[7], Err(TransactionError::SignatureFailure));
+        assert_eq!(results[42], Err(TransactionError::SignatureFailure));
+        assert_eq!(
+            results.iter().filter(|result| result.is_ok()).count(),
+            txs.len() - 2
+        );
+        assert!(verify_transaction_signatures_batch(&[]).is_empty());
+    }
+}
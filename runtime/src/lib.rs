@@ -20,6 +20,7 @@ pub mod loader_utils;
 pub mod non_circulating_supply;
 pub mod prioritization_fee;
 pub mod prioritization_fee_cache;
+pub mod recent_signatures;
 pub mod root_bank_cache;
 pub mod runtime_config;
 pub mod serde_snapshot;
@@ -0,0 +1,310 @@
+//! A bounded record of the transaction signatures processed in recent slots.
+//!
+//! For each of the last `max_slots` slots, keeps the position,
+//! `(slot, commit_index)`, of every signature of every transaction committed in
+//! that slot. This answers "was this signature processed recently, and where"
+//! without going to the blockstore, e.g. for RPC.
+//!
+//! The record is local to the validator and not part of consensus: banking
+//! threads commit transactions in parallel, so the commit index of a
+//! transaction differs between validators, and must not be exposed to
+//! programs. The recent signatures sysvar commits to the sorted signatures of
+//! each slot instead.
+//!
+//! Since the record is shared across forks, a signature may have a position in
+//! more than one slot. Callers filter positions by their own ancestors.
+
+use {
+    solana_sdk::{clock::Slot, signature::Signature},
+    std::{
+        collections::{BTreeMap, HashMap},
+        mem::size_of,
+    },
+};
+
+/// Suggested number of slots whose signatures are retained, about as long as
+/// a transaction's blockhash is valid.
+pub const DEFAULT_MAX_RECENT_SIGNATURE_SLOTS: usize = 150;
+
+/// Position of a transaction signature within the ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignaturePosition {
+    pub slot: Slot,
+    /// Order in which this validator committed the transaction among those
+    /// committed in `slot`, which differs between validators
+    pub commit_index: usize,
+}
+
+/// Signatures recorded in a retained slot.
+#[derive(Debug, Default)]
+struct SlotSignatures {
+    num_transactions: usize,
+    /// Signatures of every transaction, in commit order
+    signatures: Vec<Signature>,
+}
+
+#[derive(Debug)]
+pub struct RecentSignatures {
+    max_slots: usize,
+    slots: BTreeMap<Slot, SlotSignatures>,
+    positions: HashMap<Signature, Vec<SignaturePosition>>,
+    num_positions: usize,
+}
+
+impl Default for RecentSignatures {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RECENT_SIGNATURE_SLOTS)
+    }
+}
+
+impl RecentSignatures {
+    pub fn new(max_slots: usize) -> Self {
+        Self {
+            max_slots,
+            slots: BTreeMap::new(),
+            positions: HashMap::new(),
+            num_positions: 0,
+        }
+    }
+
+    pub fn max_slots(&self) -> usize {
+        self.max_slots
+    }
+
+    /// Number of slots currently retained
+    pub fn num_slots(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Number of signature positions currently retained
+    pub fn len(&self) -> usize {
+        self.num_positions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_positions == 0
+    }
+
+    /// Record the signatures of a transaction committed in `slot`.
+    ///
+    /// Returns the commit index of the transaction within `slot`, or `None` if
+    /// the slot is older than every retained slot and the record is full.
+    pub fn insert(&mut self, slot: Slot, signatures: &[Signature]) -> Option<usize> {
+        if self.max_slots == 0 {
+            return None;
+        }
+        if !self.slots.contains_key(&slot) && self.slots.len() >= self.max_slots {
+            let oldest_slot = *self.slots.keys().next()?;
+            if slot < oldest_slot {
+                return None;
+            }
+            self.remove_slot(oldest_slot);
+        }
+
+        let slot_signatures = self.slots.entry(slot).or_default();
+        let commit_index = slot_signatures.num_transactions;
+        slot_signatures.num_transactions = commit_index.saturating_add(1);
+        slot_signatures.signatures.extend_from_slice(signatures);
+        for signature in signatures {
+            self.positions
+                .entry(*signature)
+                .or_default()
+                .push(SignaturePosition { slot, commit_index });
+            self.num_positions = self.num_positions.saturating_add(1);
+        }
+        Some(commit_index)
+    }
+
+    /// All retained positions of `signature`, across forks.
+    pub fn get(&self, signature: &Signature) -> &[SignaturePosition] {
+        self.positions
+            .get(signature)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The retained position of `signature` in a slot accepted by `filter`.
+    pub fn find<F: Fn(Slot) -> bool>(
+        &self,
+        signature: &Signature,
+        filter: F,
+    ) -> Option<SignaturePosition> {
+        self.get(signature)
+            .iter()
+            .find(|position| filter(position.slot))
+            .copied()
+    }
+
+    /// Forget every signature recorded in `slot`, e.g. when the slot is
+    /// evicted or its bank is dumped.
+    pub fn remove_slot(&mut self, slot: Slot) {
+        let Some(slot_signatures) = self.slots.remove(&slot) else {
+            return;
+        };
+        for signature in &slot_signatures.signatures {
+            if let Some(positions) = self.positions.get_mut(signature) {
+                let len = positions.len();
+                positions.retain(|position| position.slot != slot);
+                self.num_positions = self
+                    .num_positions
+                    .saturating_sub(len.saturating_sub(positions.len()));
+                if positions.is_empty() {
+                    self.positions.remove(signature);
+                }
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.positions.clear();
+        self.num_positions = 0;
+    }
+
+    /// Approximate number of heap bytes used by the record
+    pub fn memory_usage(&self) -> usize {
+        let slots_bytes = self
+            .slots
+            .values()
+            .map(|slot_signatures| {
+                size_of::<Slot>()
+                    .saturating_add(size_of::<SlotSignatures>())
+                    .saturating_add(
+                        slot_signatures
+                            .signatures
+                            .capacity()
+                            .saturating_mul(size_of::<Signature>()),
+                    )
+            })
+            .sum::<usize>();
+        let positions_bytes = self
+            .positions
+            .len()
+            .saturating_mul(
+                size_of::<Signature>().saturating_add(size_of::<Vec<SignaturePosition>>()),
+            )
+            .saturating_add(
+                self.num_positions
+                    .saturating_mul(size_of::<SignaturePosition>()),
+            );
+        slots_bytes.saturating_add(positions_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut recent_signatures = RecentSignatures::new(2);
+        let signature0 = Signature::new_unique();
+        let signature1 = Signature::new_unique();
+        let signature2 = Signature::new_unique();
+
+        assert_eq!(
+            recent_signatures.insert(1, &[signature0, signature1]),
+            Some(0)
+        );
+        assert_eq!(recent_signatures.insert(1, &[signature2]), Some(1));
+        assert_eq!(recent_signatures.len(), 3);
+        assert_eq!(recent_signatures.num_slots(), 1);
+
+        assert_eq!(
+            recent_signatures.get(&signature1),
+            &[SignaturePosition {
+                slot: 1,
+                commit_index: 0
+            }]
+        );
+        assert_eq!(
+            recent_signatures.get(&signature2),
+            &[SignaturePosition {
+                slot: 1,
+                commit_index: 1
+            }]
+        );
+        assert!(recent_signatures.get(&Signature::new_unique()).is_empty());
+    }
+
+    #[test]
+    fn test_forks() {
+        let mut recent_signatures = RecentSignatures::new(4);
+        let signature = Signature::new_unique();
+
+        // The same transaction landed on two forks
+        recent_signatures.insert(2, &[signature]);
+        recent_signatures.insert(3, &[Signature::new_unique()]);
+        recent_signatures.insert(3, &[signature]);
+
+        assert_eq!(recent_signatures.get(&signature).len(), 2);
+        assert_eq!(
+            recent_signatures.find(&signature, |slot| slot == 3),
+            Some(SignaturePosition {
+                slot: 3,
+                commit_index: 1
+            })
+        );
+        assert_eq!(recent_signatures.find(&signature, |slot| slot == 4), None);
+
+        recent_signatures.remove_slot(2);
+        assert_eq!(
+            recent_signatures.get(&signature),
+            &[SignaturePosition {
+                slot: 3,
+                commit_index: 1
+            }]
+        );
+        assert_eq!(recent_signatures.len(), 2);
+    }
+
+    #[test]
+    fn test_eviction() {
+        let mut recent_signatures = RecentSignatures::new(2);
+        let signatures: Vec<_> = (0..4).map(|_| Signature::new_unique()).collect();
+
+        for (slot, signature) in signatures.iter().enumerate() {
+            assert_eq!(
+                recent_signatures.insert(slot as Slot, &[*signature]),
+                Some(0)
+            );
+            assert!(recent_signatures.num_slots() <= 2);
+        }
+
+        // Only the two most recent slots are retained
+        assert!(recent_signatures.get(&signatures[0]).is_empty());
+        assert!(recent_signatures.get(&signatures[1]).is_empty());
+        assert_eq!(recent_signatures.get(&signatures[2]).len(), 1);
+        assert_eq!(recent_signatures.get(&signatures[3]).len(), 1);
+        assert_eq!(recent_signatures.len(), 2);
+
+        // Slots older than every retained slot are not recorded once full
+        assert_eq!(recent_signatures.insert(0, &[signatures[0]]), None);
+        assert!(recent_signatures.get(&signatures[0]).is_empty());
+
+        recent_signatures.clear();
+        assert!(recent_signatures.is_empty());
+        assert_eq!(recent_signatures.num_slots(), 0);
+
+        let mut disabled = RecentSignatures::new(0);
+        assert_eq!(disabled.insert(0, &[signatures[0]]), None);
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_memory_usage() {
+        let mut recent_signatures = RecentSignatures::new(2);
+        assert_eq!(recent_signatures.memory_usage(), 0);
+
+        recent_signatures.insert(0, &[Signature::new_unique()]);
+        let one_slot = recent_signatures.memory_usage();
+        assert!(one_slot > 2 * size_of::<Signature>());
+
+        recent_signatures.insert(1, &[Signature::new_unique()]);
+        assert_eq!(recent_signatures.memory_usage(), 2 * one_slot);
+
+        // Evicting slot 0 keeps usage bounded
+        recent_signatures.insert(2, &[Signature::new_unique()]);
+        assert_eq!(recent_signatures.memory_usage(), 2 * one_slot);
+    }
+}
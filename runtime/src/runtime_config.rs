@@ -6,4 +6,9 @@ pub struct RuntimeConfig {
     pub compute_budget: Option<ComputeBudget>,
     pub log_messages_bytes_limit: Option<usize>,
     pub transaction_account_lock_limit: Option<usize>,
+    /// Number of slots whose transaction signatures the bank retains, for
+    /// lookups that aren't part of consensus. Recording them takes a lock on
+    /// the commit path, so it is disabled unless set, see
+    /// `DEFAULT_MAX_RECENT_SIGNATURE_SLOTS` for a suggested value
+    pub recent_signatures_max_slots: Option<usize>,
 }
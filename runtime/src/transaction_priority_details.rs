@@ -29,6 +29,7 @@ pub trait GetTransactionPriorityDetails {
                 instructions,
                 true, // supports prioritization by request_units_deprecated instruction
                 true, // enable support set accounts data size instruction
+                true, // enable support transaction guard instruction
                       // TODO: round_compute_unit_price_enabled: bool
             )
             .ok()?;
@@ -0,0 +1,39 @@
+#![feature(test)]
+
+extern crate test;
+use {
+    solana_sdk::program_memory::{sol_memcmp, sol_memcmp_ct},
+    test::Bencher,
+};
+
+const SIGNATURE_LEN: usize = 64;
+
+#[bench]
+fn bench_sol_memcmp_signature_equal(b: &mut Bencher) {
+    let s1 = [7u8; SIGNATURE_LEN];
+    let s2 = [7u8; SIGNATURE_LEN];
+    b.iter(|| sol_memcmp(test::black_box(&s1), test::black_box(&s2), SIGNATURE_LEN));
+}
+
+#[bench]
+fn bench_sol_memcmp_signature_differ_first(b: &mut Bencher) {
+    let s1 = [7u8; SIGNATURE_LEN];
+    let mut s2 = [7u8; SIGNATURE_LEN];
+    s2[0] = 8;
+    b.iter(|| sol_memcmp(test::black_box(&s1), test::black_box(&s2), SIGNATURE_LEN));
+}
+
+#[bench]
+fn bench_sol_memcmp_ct_signature_equal(b: &mut Bencher) {
+    let s1 = [7u8; SIGNATURE_LEN];
+    let s2 = [7u8; SIGNATURE_LEN];
+    b.iter(|| sol_memcmp_ct(test::black_box(&s1), test::black_box(&s2), SIGNATURE_LEN));
+}
+
+#[bench]
+fn bench_sol_memcmp_ct_signature_differ_first(b: &mut Bencher) {
+    let s1 = [7u8; SIGNATURE_LEN];
+    let mut s2 = [7u8; SIGNATURE_LEN];
+    s2[0] = 8;
+    b.iter(|| sol_memcmp_ct(test::black_box(&s1), test::black_box(&s2), SIGNATURE_LEN));
+}
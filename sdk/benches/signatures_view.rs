@@ -0,0 +1,86 @@
+//! Compares looking up every signature of the signatures sysvar through
+//! `load_signature_at_checked`, which checks the account and parses the
+//! number of signatures on each call, with a `SignaturesView` that does so
+//! once, and with `load_signature_at_unchecked` on data borrowed once.
+//!
+//! This measures native execution. The work saved per lookup, an account ID
+//! comparison, a data borrow and a length check, is the same in SBF, where it
+//! is paid in compute units on every iteration of a loop over signatures.
+
+use {
+    criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion},
+    solana_sdk::{
+        account_info::AccountInfo,
+        clock::Epoch,
+        sysvar::{
+            self,
+            signatures::{
+                construct_signatures_data, load_signature_at_checked, load_signature_at_unchecked,
+                SignaturesView, TxSignature, SIGNATURE_LEN,
+            },
+        },
+    },
+};
+
+fn bench_signature_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signatures_sysvar_lookups");
+    for num_signatures in [1, 8, 64] {
+        let signatures: Vec<_> = (0..num_signatures)
+            .map(|index| TxSignature::from([index as u8; SIGNATURE_LEN]))
+            .collect();
+        let mut data = construct_signatures_data(&signatures).unwrap();
+        let key = sysvar::signatures::id();
+        let owner = sysvar::id();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("load_signature_at_checked", num_signatures),
+            &account_info,
+            |b, account_info| {
+                b.iter(|| {
+                    for index in 0..num_signatures {
+                        black_box(load_signature_at_checked(index, account_info).unwrap());
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("signatures_view", num_signatures),
+            &account_info,
+            |b, account_info| {
+                b.iter(|| {
+                    let view = SignaturesView::new(account_info).unwrap();
+                    for index in 0..view.len() {
+                        black_box(view.get(index).unwrap());
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("load_signature_at_unchecked", num_signatures),
+            &account_info,
+            |b, account_info| {
+                b.iter(|| {
+                    let data = account_info.try_borrow_data().unwrap();
+                    for index in 0..num_signatures {
+                        black_box(load_signature_at_unchecked(index, &data).unwrap());
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_signature_lookups);
+criterion_main!(benches);
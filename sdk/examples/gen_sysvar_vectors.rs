@@ -0,0 +1,268 @@
+//! Emits JSON test vectors for parsers of the signatures sysvar written in
+//! other languages.
+//!
+//! Each vector holds the inputs of a sysvar, the account data the runtime
+//! constructs from them, and what the loaders of
+//! `solana_sdk::sysvar::signatures` read back from that data, including the
+//! errors of out of bounds lookups. Vectors without inputs hold malformed
+//! data. Client test suites check their parsers against the output of
+//!
+//! ```text
+//! cargo run -p solana-sdk --example gen_sysvar_vectors > signatures_sysvar_vectors.json
+//! ```
+
+use {
+    serde::Serialize,
+    serde_json::{json, Value},
+    solana_sdk::{
+        account_info::AccountInfo,
+        clock::Epoch,
+        program_error::ProgramError,
+        program_stubs::{self, SyscallStubs},
+        pubkey::Pubkey,
+        sysvar::{
+            self,
+            signatures::{
+                construct_signatures_data_from_iter,
+                construct_signatures_data_with_address_table_lookups,
+                construct_signer_signatures_data, construct_wide_count_signatures_data,
+                load_address_table_lookup_at_checked, load_layout_version,
+                load_num_address_table_lookups_checked, load_num_signatures,
+                load_signature_at_checked, load_signer_and_signature_at_checked,
+                AddressTableLookupDigest, SignaturesLayoutVersion, SIGNATURE_LEN,
+                VERSIONED_LAYOUT_MARKER,
+            },
+        },
+    },
+};
+
+/// Drops the logs of out of bounds lookups, which would otherwise be printed
+/// among the vectors.
+struct QuietStubs;
+
+impl SyscallStubs for QuietStubs {
+    fn sol_log(&self, _message: &str) {}
+}
+
+fn signatures(count: usize) -> Vec<[u8; SIGNATURE_LEN]> {
+    (0..count)
+        .map(|index| {
+            let mut signature = [0; SIGNATURE_LEN];
+            signature[..2].copy_from_slice(&(index as u16).to_le_bytes());
+            signature[2..].fill(0xa5);
+            signature
+        })
+        .collect()
+}
+
+fn signers(count: usize) -> Vec<Pubkey> {
+    (0..count)
+        .map(|index| Pubkey::new_from_array([index as u8 + 1; 32]))
+        .collect()
+}
+
+fn lookups() -> Vec<AddressTableLookupDigest> {
+    vec![
+        AddressTableLookupDigest {
+            account_key: Pubkey::new_from_array([0x11; 32]),
+            writable_start: 4,
+            writable_len: 2,
+            readonly_start: 7,
+            readonly_len: 1,
+        },
+        AddressTableLookupDigest {
+            account_key: Pubkey::new_from_array([0x22; 32]),
+            writable_start: 6,
+            writable_len: 1,
+            readonly_start: 8,
+            readonly_len: 3,
+        },
+    ]
+}
+
+fn lookup_json(lookup: &AddressTableLookupDigest) -> Value {
+    json!({
+        "account_key": lookup.account_key.to_string(),
+        "writable_start": lookup.writable_start,
+        "writable_len": lookup.writable_len,
+        "readonly_start": lookup.readonly_start,
+        "readonly_len": lookup.readonly_len,
+    })
+}
+
+fn result_json<T: Serialize>(result: Result<T, ProgramError>) -> Value {
+    match result {
+        Ok(value) => json!({ "ok": value }),
+        Err(err) => json!({ "err": format!("{err:?}") }),
+    }
+}
+
+/// What the loaders read from `data`, one lookup past the end of each list
+fn loader_outputs(mut data: Vec<u8>) -> Value {
+    let key = sysvar::signatures::id();
+    let owner = sysvar::id();
+    let mut lamports = 0;
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        Epoch::default(),
+    );
+
+    let layout_version = load_layout_version(&account_info)
+        .map(|version| format!("{version:?}"))
+        .map_err(ProgramError::from);
+    let num_signatures = load_num_signatures(&account_info);
+    let num_lookups = load_num_address_table_lookups_checked(&account_info);
+    let (signatures, signers): (Vec<_>, Vec<_>) = (0..=*num_signatures.as_ref().unwrap_or(&0))
+        .map(|index| {
+            (
+                result_json(load_signature_at_checked(index, &account_info).map(hex::encode)),
+                result_json(
+                    load_signer_and_signature_at_checked(index, &account_info)
+                        .map(|(signer, _)| signer.to_string()),
+                ),
+            )
+        })
+        .unzip();
+    let lookups: Vec<_> = (0..=*num_lookups.as_ref().unwrap_or(&0))
+        .map(|index| {
+            result_json(
+                load_address_table_lookup_at_checked(index, &account_info)
+                    .map(|lookup| lookup_json(&lookup)),
+            )
+        })
+        .collect();
+
+    json!({
+        "layout_version": result_json(layout_version),
+        "num_signatures": result_json(num_signatures),
+        "signatures": signatures,
+        "signers": signers,
+        "num_address_table_lookups": result_json(num_lookups),
+        "address_table_lookups": lookups,
+    })
+}
+
+fn vector(
+    name: &str,
+    version: SignaturesLayoutVersion,
+    signatures: &[[u8; SIGNATURE_LEN]],
+    lookups: Option<&[AddressTableLookupDigest]>,
+) -> Value {
+    let signers = signers(signatures.len());
+    let data = match version {
+        SignaturesLayoutVersion::Legacy => match lookups {
+            Some(lookups) => {
+                construct_signatures_data_with_address_table_lookups(signatures.iter(), lookups)
+            }
+            None => construct_signatures_data_from_iter(signatures.iter()),
+        },
+        SignaturesLayoutVersion::SignerPairs => {
+            construct_signer_signatures_data(signers.iter().zip(signatures), lookups)
+        }
+        SignaturesLayoutVersion::WideCount => {
+            construct_wide_count_signatures_data(signatures.iter(), lookups)
+        }
+    }
+    .unwrap();
+
+    json!({
+        "name": name,
+        "inputs": {
+            "layout_version": format!("{version:?}"),
+            "signatures": signatures.iter().map(hex::encode).collect::<Vec<_>>(),
+            "signers": version.has_signers().then(|| {
+                signers.iter().map(Pubkey::to_string).collect::<Vec<_>>()
+            }),
+            "address_table_lookups": lookups.map(|lookups| {
+                lookups.iter().map(lookup_json).collect::<Vec<_>>()
+            }),
+        },
+        "data": hex::encode(&data),
+        "outputs": loader_outputs(data),
+    })
+}
+
+fn malformed_vector(name: &str, data: Vec<u8>) -> Value {
+    json!({
+        "name": name,
+        "inputs": null,
+        "data": hex::encode(&data),
+        "outputs": loader_outputs(data),
+    })
+}
+
+fn main() {
+    program_stubs::set_syscall_stubs(Box::new(QuietStubs));
+
+    let lookups = lookups();
+    let mut truncated = construct_signatures_data_from_iter(signatures(2).iter()).unwrap();
+    truncated.truncate(truncated.len() - 1);
+
+    let vectors = vec![
+        vector(
+            "legacy_one_signature",
+            SignaturesLayoutVersion::Legacy,
+            &signatures(1),
+            None,
+        ),
+        vector(
+            "legacy_three_signatures",
+            SignaturesLayoutVersion::Legacy,
+            &signatures(3),
+            None,
+        ),
+        vector(
+            "legacy_with_address_table_lookups",
+            SignaturesLayoutVersion::Legacy,
+            &signatures(2),
+            Some(&lookups),
+        ),
+        vector(
+            "signer_pairs",
+            SignaturesLayoutVersion::SignerPairs,
+            &signatures(2),
+            None,
+        ),
+        vector(
+            "signer_pairs_with_address_table_lookups",
+            SignaturesLayoutVersion::SignerPairs,
+            &signatures(2),
+            Some(&lookups),
+        ),
+        vector(
+            "wide_count",
+            SignaturesLayoutVersion::WideCount,
+            &signatures(2),
+            None,
+        ),
+        vector(
+            "wide_count_over_one_byte",
+            SignaturesLayoutVersion::WideCount,
+            &signatures(300),
+            None,
+        ),
+        vector(
+            "wide_count_with_address_table_lookups",
+            SignaturesLayoutVersion::WideCount,
+            &signatures(1),
+            Some(&lookups),
+        ),
+        malformed_vector("empty", vec![]),
+        malformed_vector(
+            "unknown_layout_version",
+            vec![VERSIONED_LAYOUT_MARKER, u8::MAX, 0],
+        ),
+        malformed_vector("truncated_signature", truncated),
+    ];
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&json!({ "vectors": vectors })).unwrap()
+    );
+}
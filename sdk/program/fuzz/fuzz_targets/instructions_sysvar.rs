@@ -0,0 +1,62 @@
+//! Feeds arbitrary account data to the instructions sysvar loaders, which
+//! must never panic.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target,
+    solana_program::{
+        account_info::AccountInfo,
+        program_stubs::{self, SyscallStubs},
+        sysvar::{self, instructions::*},
+    },
+    std::sync::Once,
+};
+
+/// Drops the logs of out of bounds lookups
+struct QuietStubs;
+
+impl SyscallStubs for QuietStubs {
+    fn sol_log(&self, _message: &str) {}
+}
+
+fuzz_target!(|data: &[u8]| {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(QuietStubs));
+    });
+
+    let num_instructions = data
+        .get(..2)
+        .map_or(0, |count| u16::from_le_bytes([count[0], count[1]]) as usize);
+
+    let key = id();
+    let owner = sysvar::id();
+    let mut lamports = 0;
+    let mut data = data.to_vec();
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let _ = verify_integrity(&account_info);
+    for index in 0..=num_instructions {
+        let _ = load_instruction_at_checked(index, &account_info);
+    }
+    for offset in [i64::MIN, -1, 1, i64::MAX] {
+        let _ = get_instruction_relative(offset, &account_info);
+    }
+    let current = get_instruction_relative(0, &account_info);
+    if let Ok(current_index) = load_current_index_checked(&account_info) {
+        assert_eq!(
+            current,
+            load_instruction_at_checked(current_index as usize, &account_info)
+        );
+    }
+});
@@ -0,0 +1,75 @@
+//! Feeds arbitrary account data to the signatures sysvar loaders.
+//!
+//! The loaders must never panic, and data accepted by
+//! `validate_signatures_data` must decode in full.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target,
+    solana_program::{
+        account_info::AccountInfo,
+        program_stubs::{self, SyscallStubs},
+        sysvar::{self, signatures::*},
+    },
+    std::sync::Once,
+};
+
+/// Drops the logs of out of bounds lookups
+struct QuietStubs;
+
+impl SyscallStubs for QuietStubs {
+    fn sol_log(&self, _message: &str) {}
+}
+
+fuzz_target!(|data: &[u8]| {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(QuietStubs));
+    });
+
+    let valid = validate_signatures_data(data).is_ok();
+    let decoded = SignaturesData::deserialize(data);
+    assert_eq!(valid, decoded.is_ok());
+
+    let key = id();
+    let owner = sysvar::id();
+    let mut lamports = 0;
+    let mut data = data.to_vec();
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let _ = load_layout_version(&account_info);
+    let _ = verify_integrity(&account_info);
+    let _ = load_all_signatures(&account_info);
+    let num_signatures = load_num_signatures(&account_info).unwrap_or(0);
+    let num_lookups = load_num_address_table_lookups_checked(&account_info).unwrap_or(0);
+    for index in 0..=num_signatures {
+        let signature = load_signature_at_checked(index, &account_info);
+        let signer = load_signer_and_signature_at_checked(index, &account_info);
+        if let Ok(decoded) = &decoded {
+            assert_eq!(signature.ok().as_ref(), decoded.signatures.get(index));
+            if let Some(signers) = &decoded.signers {
+                assert_eq!(
+                    signer.ok().map(|(signer, _)| signer),
+                    signers.get(index).copied()
+                );
+            }
+        }
+    }
+    for index in 0..=num_lookups {
+        let lookup = load_address_table_lookup_at_checked(index, &account_info);
+        if let Ok(decoded) = &decoded {
+            let lookups = decoded.address_table_lookups.as_deref().unwrap_or_default();
+            assert_eq!(lookup.ok().as_ref(), lookups.get(index));
+        }
+    }
+});
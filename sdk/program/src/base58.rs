@@ -0,0 +1,212 @@
+//! Base58 encoding and decoding.
+//!
+//! Uses the Bitcoin alphabet, as used for the human-readable form of
+//! addresses, hashes and transaction signatures.
+//!
+//! Base58 conversion is quadratic in the length of the input and costly to
+//! run in a program, so on-chain these functions call the `sol_base58_encode`
+//! and `sol_base58_decode` syscalls. Neither allocates: the caller provides
+//! the output buffer and gets back the number of bytes written.
+
+use thiserror::Error;
+
+/// Maximum number of bytes that can be encoded in one call.
+pub const MAX_ENCODE_INPUT_LEN: usize = 128;
+
+/// Maximum number of base58 characters that can be decoded in one call.
+pub const MAX_DECODE_INPUT_LEN: usize = max_encoded_len(MAX_ENCODE_INPUT_LEN);
+
+/// Upper bound of the length of the base58 encoding of `len` bytes.
+///
+/// Useful for sizing output buffers, e.g. a 64-byte signature encodes to at
+/// most `max_encoded_len(64)` characters.
+pub const fn max_encoded_len(len: usize) -> usize {
+    // log(256) / log(58) ≈ 1.366
+    len.saturating_mul(138) / 100 + 1
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base58Error {
+    #[error("Input is too long.")]
+    InputTooLong,
+    #[error("Output buffer is too small.")]
+    BufferTooSmall,
+    #[error("Input contains a character outside of the base58 alphabet.")]
+    InvalidCharacter,
+    #[error("Unexpected error")]
+    Unexpected,
+}
+
+impl From<u64> for Base58Error {
+    fn from(error: u64) -> Self {
+        match error {
+            1 => Base58Error::InputTooLong,
+            2 => Base58Error::BufferTooSmall,
+            3 => Base58Error::InvalidCharacter,
+            _ => Base58Error::Unexpected,
+        }
+    }
+}
+
+impl From<Base58Error> for u64 {
+    fn from(error: Base58Error) -> Self {
+        match error {
+            Base58Error::InputTooLong => 1,
+            Base58Error::BufferTooSmall => 2,
+            Base58Error::InvalidCharacter => 3,
+            Base58Error::Unexpected => 4,
+        }
+    }
+}
+
+/// Encode `input` as base58 into `output`, returning the number of characters
+/// written.
+///
+/// # Errors
+///
+/// Returns [`Base58Error::InputTooLong`] if `input` is longer than
+/// [`MAX_ENCODE_INPUT_LEN`], or [`Base58Error::BufferTooSmall`] if the
+/// encoding does not fit in `output`.
+///
+/// # Examples
+///
+/// ```rust
+/// use solana_program::base58::{encode, max_encoded_len};
+///
+/// let mut output = [0; max_encoded_len(4)];
+/// let len = encode(&[0, 0, 1, 2], &mut output).unwrap();
+/// assert_eq!(&output[..len], b"115T");
+/// ```
+pub fn encode(input: &[u8], output: &mut [u8]) -> Result<usize, Base58Error> {
+    #[cfg(not(target_os = "solana"))]
+    {
+        if input.len() > MAX_ENCODE_INPUT_LEN {
+            return Err(Base58Error::InputTooLong);
+        }
+        bs58::encode(input)
+            .into(output)
+            .map_err(|_| Base58Error::BufferTooSmall)
+    }
+    #[cfg(target_os = "solana")]
+    {
+        let mut written = 0u64;
+        let result = unsafe {
+            crate::syscalls::sol_base58_encode(
+                input as *const _ as *const u8,
+                input.len() as u64,
+                output as *mut _ as *mut u8,
+                output.len() as u64,
+                &mut written as *mut u64,
+            )
+        };
+
+        match result {
+            0 => Ok(written as usize),
+            e => Err(Base58Error::from(e)),
+        }
+    }
+}
+
+/// Decode the base58 characters of `input` into `output`, returning the
+/// number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`Base58Error::InputTooLong`] if `input` is longer than
+/// [`MAX_DECODE_INPUT_LEN`], [`Base58Error::InvalidCharacter`] if `input` is
+/// not valid base58, or [`Base58Error::BufferTooSmall`] if the decoded bytes
+/// do not fit in `output`.
+pub fn decode(input: &[u8], output: &mut [u8]) -> Result<usize, Base58Error> {
+    #[cfg(not(target_os = "solana"))]
+    {
+        if input.len() > MAX_DECODE_INPUT_LEN {
+            return Err(Base58Error::InputTooLong);
+        }
+        bs58::decode(input).into(output).map_err(|err| match err {
+            bs58::decode::Error::BufferTooSmall => Base58Error::BufferTooSmall,
+            bs58::decode::Error::InvalidCharacter { .. }
+            | bs58::decode::Error::NonAsciiCharacter { .. } => Base58Error::InvalidCharacter,
+            _ => Base58Error::Unexpected,
+        })
+    }
+    #[cfg(target_os = "solana")]
+    {
+        let mut written = 0u64;
+        let result = unsafe {
+            crate::syscalls::sol_base58_decode(
+                input as *const _ as *const u8,
+                input.len() as u64,
+                output as *mut _ as *mut u8,
+                output.len() as u64,
+                &mut written as *mut u64,
+            )
+        };
+
+        match result {
+            0 => Ok(written as usize),
+            e => Err(Base58Error::from(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let signature = [7; 64];
+        let mut encoded = [0; max_encoded_len(64)];
+        let len = encode(&signature, &mut encoded).unwrap();
+        assert_eq!(
+            &encoded[..len],
+            bs58::encode(signature).into_string().as_bytes()
+        );
+
+        let mut decoded = [0; 64];
+        assert_eq!(decode(&encoded[..len], &mut decoded), Ok(64));
+        assert_eq!(decoded, signature);
+    }
+
+    #[test]
+    fn test_max_encoded_len() {
+        for len in 0..=MAX_ENCODE_INPUT_LEN {
+            let input = vec![u8::MAX; len];
+            assert!(bs58::encode(&input).into_string().len() <= max_encoded_len(len));
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut output = [0; 2];
+        assert_eq!(
+            encode(&[0xff; 4], &mut output),
+            Err(Base58Error::BufferTooSmall)
+        );
+        assert_eq!(
+            encode(&[0; MAX_ENCODE_INPUT_LEN + 1], &mut output),
+            Err(Base58Error::InputTooLong)
+        );
+        assert_eq!(
+            decode(b"0OIl", &mut output),
+            Err(Base58Error::InvalidCharacter)
+        );
+        assert_eq!(
+            decode(b"zzzzzz", &mut output),
+            Err(Base58Error::BufferTooSmall)
+        );
+        assert_eq!(
+            decode(&[b'1'; MAX_DECODE_INPUT_LEN + 1], &mut output),
+            Err(Base58Error::InputTooLong)
+        );
+
+        for error in [
+            Base58Error::InputTooLong,
+            Base58Error::BufferTooSmall,
+            Base58Error::InvalidCharacter,
+            Base58Error::Unexpected,
+        ] {
+            assert_eq!(Base58Error::from(u64::from(error)), error);
+        }
+    }
+}
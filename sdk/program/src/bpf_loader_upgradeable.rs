@@ -50,6 +50,13 @@ pub enum UpgradeableLoaderState {
         // The raw program data follows this serialized structure in the
         // account's data.
     },
+    /// A ProgramMetadata account.
+    ProgramMetadata {
+        /// Bitmask of the sysvars the program declares it may access.
+        ///
+        /// See [`SYSVAR_DEPENDENCY_IDS`] for the meaning of each bit.
+        sysvar_dependencies: u64,
+    },
 }
 impl UpgradeableLoaderState {
     /// Size of a serialized program account.
@@ -72,6 +79,11 @@ impl UpgradeableLoaderState {
         36 // see test_state_size_of_program
     }
 
+    /// Size of a serialized program metadata account.
+    pub const fn size_of_program_metadata() -> usize {
+        12 // see test_state_size_of_program_metadata
+    }
+
     /// Size of a serialized buffer account.
     pub const fn size_of_buffer(program_len: usize) -> usize {
         Self::size_of_buffer_metadata().saturating_add(program_len)
@@ -119,6 +131,76 @@ impl UpgradeableLoaderState {
     }
 }
 
+/// Seed appended to the program address to derive its ProgramMetadata address.
+pub const PROGRAM_METADATA_SEED: &[u8] = b"program_metadata";
+
+/// Returns the address and bump seed of a program's ProgramMetadata account.
+pub fn find_program_metadata_address(program_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[program_address.as_ref(), PROGRAM_METADATA_SEED], &id())
+}
+
+/// Sysvars that can be declared as dependencies, in the order of their bits.
+///
+/// Bit `i` of a sysvar dependencies bitmask stands for the sysvar at position
+/// `i`. Positions never change: sysvars are only appended, as a new version of
+/// the table which the loader accepts once the feature introducing it is
+/// active. See [`SYSVAR_DEPENDENCY_BITS_V1`] and [`SYSVAR_DEPENDENCY_BITS_V2`].
+pub const SYSVAR_DEPENDENCY_IDS: [Pubkey; 19] = [
+    sysvar::CLOCK_ID,
+    sysvar::EPOCH_SCHEDULE_ID,
+    // The deprecated sysvars' ids are statics
+    crate::pubkey!("SysvarFees111111111111111111111111111111111"),
+    crate::pubkey!("SysvarRecentB1ockHashes11111111111111111111"),
+    sysvar::RENT_ID,
+    sysvar::REWARDS_ID,
+    sysvar::SLOT_HASHES_ID,
+    sysvar::SLOT_HISTORY_ID,
+    sysvar::STAKE_HISTORY_ID,
+    sysvar::INSTRUCTIONS_ID,
+    sysvar::SIGNATURES_ID,
+    sysvar::EPOCH_REWARDS_ID,
+    sysvar::LAST_RESTART_SLOT_ID,
+    sysvar::EPOCH_STAKE_ID,
+    sysvar::RECENT_SIGNATURES_ID,
+    sysvar::HEADER_ID,
+    sysvar::SCRATCH_ID,
+    sysvar::TICK_HASH_ID,
+    sysvar::TRANSACTION_CONTEXT_ID,
+];
+
+/// Number of bits of [`SYSVAR_DEPENDENCY_IDS`] the loader accepts initially.
+pub const SYSVAR_DEPENDENCY_BITS_V1: u32 = 14;
+
+/// Number of bits of [`SYSVAR_DEPENDENCY_IDS`] the loader accepts once the
+/// `sysvar_dependency_bits_v2` feature is active.
+pub const SYSVAR_DEPENDENCY_BITS_V2: u32 = 19;
+
+/// Returns the sysvar dependencies bitmask of the given sysvars.
+///
+/// Bit `i` of the bitmask is set if the sysvar at position `i` of
+/// [`SYSVAR_DEPENDENCY_IDS`] is a dependency. Returns `None` if any of the
+/// given addresses is not in the table.
+pub fn sysvar_dependencies_from_ids(sysvar_ids: &[Pubkey]) -> Option<u64> {
+    sysvar_ids.iter().try_fold(0u64, |mask, sysvar_id| {
+        SYSVAR_DEPENDENCY_IDS
+            .iter()
+            .position(|id| id == sysvar_id)
+            .map(|position| mask | 1u64.wrapping_shl(position as u32))
+    })
+}
+
+/// Returns the sysvars in the given sysvar dependencies bitmask.
+///
+/// Bits that do not correspond to a known sysvar are ignored.
+pub fn sysvar_dependencies_to_ids(sysvar_dependencies: u64) -> Vec<Pubkey> {
+    SYSVAR_DEPENDENCY_IDS
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| sysvar_dependencies & 1u64.wrapping_shl(*position as u32) != 0)
+        .map(|(_, id)| *id)
+        .collect()
+}
+
 /// Returns the instructions required to initialize a Buffer account.
 pub fn create_buffer(
     payer_address: &Pubkey,
@@ -240,6 +322,10 @@ pub fn is_set_authority_checked_instruction(instruction_data: &[u8]) -> bool {
     !instruction_data.is_empty() && 7 == instruction_data[0]
 }
 
+pub fn is_set_sysvar_dependencies_instruction(instruction_data: &[u8]) -> bool {
+    !instruction_data.is_empty() && 8 == instruction_data[0]
+}
+
 /// Returns the instructions required to set a buffers's authority.
 pub fn set_buffer_authority(
     buffer_address: &Pubkey,
@@ -375,6 +461,39 @@ pub fn extend_program(
     )
 }
 
+/// Returns the instruction required to declare the sysvars a program may
+/// access. The ProgramMetadata account is created on first use, in which case
+/// a payer is required.
+pub fn set_sysvar_dependencies(
+    program_address: &Pubkey,
+    authority_address: &Pubkey,
+    payer_address: Option<&Pubkey>,
+    sysvar_dependencies: u64,
+) -> Instruction {
+    let (programdata_address, _) = Pubkey::find_program_address(&[program_address.as_ref()], &id());
+    let (program_metadata_address, _) = find_program_metadata_address(program_address);
+    let mut metas = vec![
+        AccountMeta::new(program_metadata_address, false),
+        AccountMeta::new_readonly(*program_address, false),
+        AccountMeta::new_readonly(programdata_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+    ];
+    if let Some(payer_address) = payer_address {
+        metas.push(AccountMeta::new(*payer_address, true));
+        metas.push(AccountMeta::new_readonly(
+            crate::system_program::id(),
+            false,
+        ));
+    }
+    Instruction::new_with_bincode(
+        id(),
+        &UpgradeableLoaderInstruction::SetSysvarDependencies {
+            sysvar_dependencies,
+        },
+        metas,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, bincode::serialized_size};
@@ -420,6 +539,67 @@ mod tests {
         assert_eq!(UpgradeableLoaderState::size_of_program() as u64, size);
     }
 
+    #[test]
+    fn test_state_size_of_program_metadata() {
+        let program_metadata_state = UpgradeableLoaderState::ProgramMetadata {
+            sysvar_dependencies: u64::MAX,
+        };
+        let size = serialized_size(&program_metadata_state).unwrap();
+        assert_eq!(
+            UpgradeableLoaderState::size_of_program_metadata() as u64,
+            size
+        );
+    }
+
+    #[test]
+    fn test_sysvar_dependencies() {
+        assert_eq!(sysvar_dependencies_from_ids(&[]), Some(0));
+        assert_eq!(
+            sysvar_dependencies_from_ids(&[sysvar::clock::id(), sysvar::clock::id()]),
+            Some(1)
+        );
+        assert_eq!(
+            sysvar_dependencies_from_ids(&[sysvar::clock::id(), Pubkey::new_unique()]),
+            None
+        );
+
+        let sysvar_ids = [sysvar::signatures::id(), sysvar::instructions::id()];
+        let sysvar_dependencies = sysvar_dependencies_from_ids(&sysvar_ids).unwrap();
+        assert_eq!(sysvar_dependencies.count_ones(), 2);
+        assert_eq!(
+            sysvar_dependencies_to_ids(sysvar_dependencies),
+            vec![sysvar::instructions::id(), sysvar::signatures::id()]
+        );
+        assert_eq!(sysvar_dependencies_to_ids(u64::MAX), SYSVAR_DEPENDENCY_IDS);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_sysvar_dependency_ids() {
+        // The bit of each sysvar is part of the on-chain format and must not change
+        for (sysvar_id, bit) in [
+            (sysvar::clock::id(), 0),
+            (sysvar::fees::id(), 2),
+            (sysvar::recent_blockhashes::id(), 3),
+            (sysvar::instructions::id(), 9),
+            (sysvar::signatures::id(), 10),
+            (sysvar::epoch_stake::id(), 13),
+            (sysvar::recent_signatures::id(), 14),
+            (sysvar::transaction_context::id(), 18),
+        ] {
+            assert_eq!(sysvar_dependencies_from_ids(&[sysvar_id]), Some(1 << bit));
+        }
+        assert_eq!(
+            SYSVAR_DEPENDENCY_IDS.len(),
+            SYSVAR_DEPENDENCY_BITS_V2 as usize
+        );
+        assert!(SYSVAR_DEPENDENCY_BITS_V1 < SYSVAR_DEPENDENCY_BITS_V2);
+        for (position, sysvar_id) in SYSVAR_DEPENDENCY_IDS.iter().enumerate() {
+            assert!(sysvar::is_sysvar_id(sysvar_id));
+            assert!(!SYSVAR_DEPENDENCY_IDS[..position].contains(sysvar_id));
+        }
+    }
+
     #[test]
     #[allow(deprecated)]
     fn test_account_lengths() {
@@ -499,6 +679,18 @@ mod tests {
             is_instruction_fn(&bincode::serialize(&UpgradeableLoaderInstruction::Close).unwrap());
         let expected_result = matches!(expected_instruction, UpgradeableLoaderInstruction::Close);
         assert_eq!(expected_result, result);
+
+        let result = is_instruction_fn(
+            &bincode::serialize(&UpgradeableLoaderInstruction::SetSysvarDependencies {
+                sysvar_dependencies: 0,
+            })
+            .unwrap(),
+        );
+        let expected_result = matches!(
+            expected_instruction,
+            UpgradeableLoaderInstruction::SetSysvarDependencies { .. }
+        );
+        assert_eq!(expected_result, result);
     }
 
     #[test]
@@ -519,6 +711,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_set_sysvar_dependencies_instruction() {
+        assert!(!is_set_sysvar_dependencies_instruction(&[]));
+        assert_is_instruction(
+            is_set_sysvar_dependencies_instruction,
+            UpgradeableLoaderInstruction::SetSysvarDependencies {
+                sysvar_dependencies: 0,
+            },
+        );
+    }
+
     #[test]
     fn test_is_upgrade_instruction() {
         assert!(!is_upgrade_instruction(&[]));
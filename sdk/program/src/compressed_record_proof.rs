@@ -0,0 +1,314 @@
+//! Proofs that a transaction wrote a compressed record.
+//!
+//! Programs that compress their state store, instead of the records
+//! themselves, a _signature pointer_: the signature of the transaction that
+//! wrote a record, from which an indexer can recover the record. A
+//! [`CompressedRecordProof`] is the bundle an indexer hands to applications
+//! resolving such a pointer: the transaction signature, the slot in which it
+//! was committed, the index of the instruction that wrote the record, the
+//! account data it wrote, and a merkle proof that the signature is part of
+//! the slot's signature root.
+//!
+//! [`CompressedRecordProof::verify`] checks the merkle proof against a root
+//! supplied by the caller, and
+//! [`CompressedRecordProof::verify_with_sysvar`] against the root of the
+//! [recent signatures sysvar], so that programs can check proofs on-chain for
+//! as long as the slot is retained. The proof serializes with `bincode`, and
+//! fits in instruction data as long as the account diffs are small: each
+//! level of the merkle tree adds 32 bytes.
+//!
+//! The merkle proof only attests that the signature was committed in the
+//! slot. The instruction index and account diffs are what the indexer claims
+//! the transaction wrote, and must be checked against the transaction itself,
+//! or against a commitment the program stored, before being trusted.
+//!
+//! [recent signatures sysvar]: crate::sysvar::recent_signatures
+
+use crate::{
+    account_info::AccountInfo,
+    clock::Slot,
+    hash::Hash,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{
+        recent_signatures::{self, RecentSignatures},
+        signatures::TxSignature,
+    },
+};
+
+/// Error returned when a [`CompressedRecordProof`] doesn't verify.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressedRecordProofError {
+    /// The recent signatures sysvar no longer, or doesn't yet, hold the root
+    /// of the proof's slot
+    #[error("slot is not retained in the recent signatures sysvar")]
+    SlotNotRetained,
+    /// The signature doesn't hash up to the slot's signature root
+    #[error("invalid merkle proof")]
+    InvalidMerkleProof,
+}
+
+impl From<CompressedRecordProofError> for ProgramError {
+    fn from(_: CompressedRecordProofError) -> Self {
+        ProgramError::InvalidArgument
+    }
+}
+
+/// Account data written by the instruction that wrote a record.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AccountDiff {
+    pub pubkey: Pubkey,
+    /// Offset in the account data at which `data` was written
+    pub offset: u64,
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+}
+
+/// Proof that a signature is a leaf of a slot's signature root.
+///
+/// See the [recent signatures sysvar](crate::sysvar::recent_signatures) for
+/// how the tree is built.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignatureMerkleProof {
+    /// Index of the signature among the sorted signatures of the slot
+    pub leaf_index: u64,
+    /// Sibling hashes from the leaf up to the root
+    pub siblings: Vec<Hash>,
+}
+
+/// A compressed record, and the proof that the transaction that wrote it was
+/// committed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CompressedRecordProof {
+    #[serde(with = "serde_tx_signature")]
+    pub tx_signature: TxSignature,
+    pub slot: Slot,
+    /// Index of the top-level instruction that wrote the record
+    pub ix_index: u16,
+    pub account_diffs: Vec<AccountDiff>,
+    pub merkle_proof: SignatureMerkleProof,
+}
+
+impl CompressedRecordProof {
+    /// Build the proof for the transaction `tx_signature`, given every
+    /// signature of `slot`, sorted by their bytes.
+    ///
+    /// Returns `None` if `tx_signature` is not one of `slot_signatures`.
+    ///
+    /// This function is used by indexers and not available to Solana
+    /// programs.
+    #[cfg(not(target_os = "solana"))]
+    pub fn new(
+        tx_signature: TxSignature,
+        slot: Slot,
+        ix_index: u16,
+        account_diffs: Vec<AccountDiff>,
+        slot_signatures: &[TxSignature],
+    ) -> Option<Self> {
+        let leaf_index = slot_signatures
+            .binary_search_by(|signature| signature.0.cmp(&tx_signature.0))
+            .ok()?;
+        let siblings = recent_signatures::compute_signature_proof(slot_signatures, leaf_index)?;
+        Some(Self {
+            tx_signature,
+            slot,
+            ix_index,
+            account_diffs,
+            merkle_proof: SignatureMerkleProof {
+                leaf_index: leaf_index as u64,
+                siblings,
+            },
+        })
+    }
+
+    /// Check the merkle proof against the signature root of the proof's
+    /// slot.
+    pub fn verify(&self, signature_root: &Hash) -> Result<(), CompressedRecordProofError> {
+        let leaf_index = usize::try_from(self.merkle_proof.leaf_index)
+            .map_err(|_| CompressedRecordProofError::InvalidMerkleProof)?;
+        recent_signatures::verify_signature_proof(
+            self.tx_signature.as_ref(),
+            leaf_index,
+            &self.merkle_proof.siblings,
+            signature_root,
+        )
+        .then_some(())
+        .ok_or(CompressedRecordProofError::InvalidMerkleProof)
+    }
+
+    /// Check the merkle proof against the signature roots of a deserialized
+    /// recent signatures sysvar.
+    pub fn verify_with_recent_signatures(
+        &self,
+        recent_signatures: &RecentSignatures,
+    ) -> Result<(), CompressedRecordProofError> {
+        let signature_root = recent_signatures
+            .get(self.slot)
+            .ok_or(CompressedRecordProofError::SlotNotRetained)?;
+        self.verify(signature_root)
+    }
+
+    /// Check the merkle proof against the recent signatures sysvar account,
+    /// without deserializing the whole sysvar.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to the
+    /// recent signatures sysvar ID.
+    /// Returns [`ProgramError::InvalidArgument`] if the slot is not retained or the proof doesn't
+    /// verify.
+    pub fn verify_with_sysvar(
+        &self,
+        recent_signatures_sysvar_account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let signature_root =
+            recent_signatures::load_root_checked(self.slot, recent_signatures_sysvar_account_info)?
+                .ok_or(CompressedRecordProofError::SlotNotRetained)?;
+        Ok(self.verify(&signature_root)?)
+    }
+}
+
+/// Serialize a [`TxSignature`] as bytes, since serde doesn't implement its
+/// traits for 64-byte arrays.
+mod serde_tx_signature {
+    use {
+        crate::sysvar::signatures::{TxSignature, SIGNATURE_LEN},
+        serde::{de::Error, Deserialize, Deserializer, Serializer},
+    };
+
+    pub fn serialize<S: Serializer>(
+        signature: &TxSignature,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde_bytes::serialize(signature.as_ref(), serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TxSignature, D::Error> {
+        let bytes = serde_bytes::ByteBuf::deserialize(deserializer)?;
+        <[u8; SIGNATURE_LEN]>::try_from(bytes.as_slice())
+            .map(TxSignature)
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"64 bytes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::clock::Epoch};
+
+    fn slot_signatures() -> Vec<TxSignature> {
+        (0..5u8).map(|i| TxSignature([i; 64])).collect()
+    }
+
+    fn new_proof() -> CompressedRecordProof {
+        CompressedRecordProof::new(
+            TxSignature([3; 64]),
+            7,
+            1,
+            vec![AccountDiff {
+                pubkey: Pubkey::new_unique(),
+                offset: 8,
+                data: vec![1, 2, 3],
+            }],
+            &slot_signatures(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify() {
+        let root = recent_signatures::compute_signature_root(&slot_signatures());
+        let proof = new_proof();
+        assert_eq!(proof.merkle_proof.leaf_index, 3);
+        assert_eq!(proof.verify(&root), Ok(()));
+        assert_eq!(
+            proof.verify(&Hash::new_unique()),
+            Err(CompressedRecordProofError::InvalidMerkleProof)
+        );
+
+        let mut wrong_index = proof.clone();
+        wrong_index.merkle_proof.leaf_index = 2;
+        assert_eq!(
+            wrong_index.verify(&root),
+            Err(CompressedRecordProofError::InvalidMerkleProof)
+        );
+
+        let mut wrong_signature = proof;
+        wrong_signature.tx_signature = TxSignature([9; 64]);
+        assert_eq!(
+            wrong_signature.verify(&root),
+            Err(CompressedRecordProofError::InvalidMerkleProof)
+        );
+
+        assert_eq!(
+            CompressedRecordProof::new(TxSignature([9; 64]), 7, 1, vec![], &slot_signatures()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let proof = new_proof();
+        let data = bincode::serialize(&proof).unwrap();
+        assert_eq!(
+            bincode::deserialize::<CompressedRecordProof>(&data).unwrap(),
+            proof
+        );
+
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CompressedRecordProof>(&json).unwrap(),
+            proof
+        );
+    }
+
+    #[test]
+    fn test_verify_with_sysvar() {
+        let proof = new_proof();
+        let mut recent_signatures = RecentSignatures::default();
+        recent_signatures.add(6, Hash::new_unique());
+        assert_eq!(
+            proof.verify_with_recent_signatures(&recent_signatures),
+            Err(CompressedRecordProofError::SlotNotRetained)
+        );
+        recent_signatures.add(
+            proof.slot,
+            recent_signatures::compute_signature_root(&slot_signatures()),
+        );
+        assert_eq!(
+            proof.verify_with_recent_signatures(&recent_signatures),
+            Ok(())
+        );
+
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let mut data = bincode::serialize(&recent_signatures).unwrap();
+        let key = recent_signatures::id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(proof.verify_with_sysvar(&account_info), Ok(()));
+
+        let mut expired = proof.clone();
+        expired.slot = 5;
+        assert_eq!(
+            expired.verify_with_sysvar(&account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            proof.verify_with_sysvar(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+}
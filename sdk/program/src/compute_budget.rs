@@ -0,0 +1,226 @@
+//! Introspection of the compute budget requested by the current transaction.
+//!
+//! The instructions of the compute budget program are defined in
+//! `solana_sdk::compute_budget`, which is not available to programs. This
+//! module decodes them from the [instructions sysvar] instead, so that
+//! programs adjusting their behavior to the budget of the transaction don't
+//! have to parse instruction data by hand.
+//!
+//! [instructions sysvar]: crate::sysvar::instructions
+
+use crate::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, sysvar::instructions,
+};
+
+crate::declare_id!("ComputeBudget111111111111111111111111111111");
+
+// Variant indexes of `ComputeBudgetInstruction`, which is borsh serialized
+const REQUEST_UNITS_DEPRECATED: u8 = 0;
+const REQUEST_HEAP_FRAME: u8 = 1;
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+const SET_LOADED_ACCOUNTS_DATA_SIZE_LIMIT: u8 = 4;
+const TRANSACTION_GUARD: u8 = 5;
+
+/// The compute budget requested by the compute budget instructions of a
+/// transaction.
+///
+/// A field is `None` if the transaction doesn't request it, in which case the
+/// runtime applies its default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeBudgetLimits {
+    /// Compute units the transaction may consume
+    pub compute_unit_limit: Option<u32>,
+    /// Price of a compute unit, in micro-lamports
+    pub compute_unit_price: Option<u64>,
+    /// Size in bytes of the heap region of each program
+    pub heap_frame_bytes: Option<u32>,
+    /// Bytes of account data the transaction may load
+    pub loaded_accounts_data_size_limit: Option<u32>,
+}
+
+impl ComputeBudgetLimits {
+    /// Decode the compute budget instructions among the given program ids and
+    /// instruction data, ignoring the instructions of other programs and
+    /// transaction guards, which don't affect the budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidInstructionData`] if a compute budget instruction can't be
+    /// decoded, or if a limit is requested more than once, which the runtime also rejects.
+    pub fn try_from_instruction_data<'a>(
+        instructions: impl IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+    ) -> Result<Self, ProgramError> {
+        let mut limits = Self::default();
+        for (program_id, data) in instructions {
+            if check_id(program_id) {
+                limits.apply(data)?;
+            }
+        }
+        Ok(limits)
+    }
+
+    fn apply(&mut self, data: &[u8]) -> Result<(), ProgramError> {
+        fn set<T>(limit: &mut Option<T>, value: Option<T>) -> Result<(), ProgramError> {
+            let value = value.ok_or(ProgramError::InvalidInstructionData)?;
+            if limit.replace(value).is_some() {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            Ok(())
+        }
+
+        let (tag, rest) = data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let read_u32 = |offset: usize| {
+            rest.get(offset..offset.saturating_add(4))
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u32::from_le_bytes)
+        };
+        match *tag {
+            REQUEST_UNITS_DEPRECATED => set(&mut self.compute_unit_limit, read_u32(0)),
+            REQUEST_HEAP_FRAME => set(&mut self.heap_frame_bytes, read_u32(0)),
+            SET_COMPUTE_UNIT_LIMIT => set(&mut self.compute_unit_limit, read_u32(0)),
+            SET_COMPUTE_UNIT_PRICE => set(
+                &mut self.compute_unit_price,
+                rest.get(..8)
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .map(u64::from_le_bytes),
+            ),
+            SET_LOADED_ACCOUNTS_DATA_SIZE_LIMIT => {
+                set(&mut self.loaded_accounts_data_size_limit, read_u32(0))
+            }
+            TRANSACTION_GUARD => Ok(()),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Decode the compute budget requested by the currently executing transaction
+/// from the instructions sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to
+/// [`instructions::ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is too short to hold the
+/// number of instructions.
+/// Returns [`ProgramError::InvalidInstructionData`] if a compute budget instruction can't be
+/// decoded.
+pub fn try_from_instructions(
+    instruction_sysvar_account_info: &AccountInfo,
+) -> Result<ComputeBudgetLimits, ProgramError> {
+    if !instructions::check_id(instruction_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let num_instructions = instruction_sysvar_account_info
+        .try_borrow_data()?
+        .get(..2)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u16::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let instructions = (0..usize::from(num_instructions))
+        .map(|index| {
+            instructions::load_instruction_at_checked(index, instruction_sysvar_account_info)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    ComputeBudgetLimits::try_from_instruction_data(
+        instructions
+            .iter()
+            .map(|instruction| (&instruction.program_id, instruction.data.as_slice())),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::Epoch,
+            instruction::Instruction,
+            message::{Message, SanitizedMessage},
+            sysvar::instructions::construct_instructions_data,
+        },
+    };
+
+    #[test]
+    fn test_try_from_instructions() {
+        let mut price_data = vec![SET_COMPUTE_UNIT_PRICE];
+        price_data.extend_from_slice(&7u64.to_le_bytes());
+        let mut limit_data = vec![SET_COMPUTE_UNIT_LIMIT];
+        limit_data.extend_from_slice(&300_000u32.to_le_bytes());
+        let instructions = vec![
+            Instruction::new_with_bytes(id(), &price_data, vec![]),
+            Instruction::new_with_bytes(Pubkey::new_unique(), &[SET_COMPUTE_UNIT_LIMIT], vec![]),
+            Instruction::new_with_bytes(id(), &limit_data, vec![]),
+        ];
+        let payer = Pubkey::new_unique();
+        let message =
+            SanitizedMessage::try_from(Message::new(&instructions, Some(&payer))).unwrap();
+        let mut data = construct_instructions_data(&message.decompile_instructions());
+
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = instructions::id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            try_from_instructions(&account_info),
+            Ok(ComputeBudgetLimits {
+                compute_unit_limit: Some(300_000),
+                compute_unit_price: Some(7),
+                ..ComputeBudgetLimits::default()
+            })
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            try_from_instructions(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_try_from_instruction_data() {
+        let program_id = id();
+        let mut heap_data = vec![REQUEST_HEAP_FRAME];
+        heap_data.extend_from_slice(&(64 * 1024u32).to_le_bytes());
+        assert_eq!(
+            ComputeBudgetLimits::try_from_instruction_data([(&program_id, heap_data.as_slice())]),
+            Ok(ComputeBudgetLimits {
+                heap_frame_bytes: Some(64 * 1024),
+                ..ComputeBudgetLimits::default()
+            })
+        );
+
+        // Requesting the same limit twice
+        assert_eq!(
+            ComputeBudgetLimits::try_from_instruction_data([
+                (&program_id, heap_data.as_slice()),
+                (&program_id, heap_data.as_slice()),
+            ]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        // Truncated and unknown instructions
+        for data in [&heap_data[..4], &[][..], &[TRANSACTION_GUARD + 1][..]] {
+            assert_eq!(
+                ComputeBudgetLimits::try_from_instruction_data([(&program_id, data)]),
+                Err(ProgramError::InvalidInstructionData)
+            );
+        }
+        assert_eq!(
+            ComputeBudgetLimits::try_from_instruction_data([]),
+            Ok(ComputeBudgetLimits::default())
+        );
+    }
+}
@@ -1,5 +1,512 @@
 //! The [ed25519 native program][np].
 //!
 //! [np]: https://docs.solana.com/developing/runtime-facilities/programs#ed25519-program
+//!
+//! Besides the program ID, this module describes the layout of the program's
+//! instruction data, and provides helpers for programs that introspect an
+//! ed25519 program instruction through the [instructions sysvar] to learn
+//! which public key signed which message, without repeating the offset
+//! arithmetic by hand.
+//!
+//! [instructions sysvar]: crate::sysvar::instructions
+//!
+//! The instruction data starts with the number of signatures as a `u8` and a
+//! padding byte, followed by an [`Ed25519SignatureOffsets`] entry per
+//! signature. Each entry locates the signature, public key and message of a
+//! signature, either within the same instruction, when the instruction index
+//! is `u16::MAX`, or within another instruction of the transaction.
+//!
+//! Programs that only need to check a signature of a message they hold can
+//! call [`verify_signature`] instead of requiring an ed25519 program
+//! instruction in the transaction.
+
+use {
+    crate::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+        sysvar::instructions,
+    },
+    bytemuck::{Pod, Zeroable},
+};
 
 crate::declare_id!("Ed25519SigVerify111111111111111111111111111");
+
+pub const PUBKEY_SERIALIZED_SIZE: usize = 32;
+pub const SIGNATURE_SERIALIZED_SIZE: usize = 64;
+pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+// bytemuck requires structures to be aligned
+pub const SIGNATURE_OFFSETS_START: usize = 2;
+pub const DATA_START: usize = SIGNATURE_OFFSETS_SERIALIZED_SIZE + SIGNATURE_OFFSETS_START;
+
+/// Instruction index meaning "the ed25519 program instruction itself".
+pub const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+#[derive(Default, Debug, Copy, Clone, Zeroable, Pod, Eq, PartialEq)]
+#[repr(C)]
+pub struct Ed25519SignatureOffsets {
+    pub signature_offset: u16, // offset to ed25519 signature of 64 bytes
+    pub signature_instruction_index: u16, // instruction index to find signature
+    pub public_key_offset: u16, // offset to public key of 32 bytes
+    pub public_key_instruction_index: u16, // instruction index to find public key
+    pub message_data_offset: u16, // offset to start of message data
+    pub message_data_size: u16, // size of message data
+    pub message_instruction_index: u16, // index of instruction data to get message data
+}
+
+impl Ed25519SignatureOffsets {
+    /// Decode the offsets of the signature at `signature_index` of an ed25519
+    /// program instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if `signature_index` is out
+    /// of bounds, and [`ProgramError::InvalidInstructionData`] if the data is
+    /// malformed.
+    pub fn parse(instruction_data: &[u8], signature_index: u8) -> Result<Self, ProgramError> {
+        if signature_index >= num_signatures(instruction_data)? {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let start = usize::from(signature_index)
+            .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .saturating_add(SIGNATURE_OFFSETS_START);
+        let end = start.saturating_add(SIGNATURE_OFFSETS_SERIALIZED_SIZE);
+        let offsets = instruction_data
+            .get(start..end)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(bytemuck::pod_read_unaligned(offsets))
+    }
+}
+
+/// A signature verified by an ed25519 program instruction, with every
+/// instruction index resolved to the index of an instruction in the
+/// transaction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Ed25519VerifiedSignature {
+    /// The public key the signature was verified against
+    pub pubkey: Pubkey,
+    /// Index of the instruction holding the signed message
+    pub message_instruction_index: u16,
+    /// Offset of the signed message within that instruction's data
+    pub message_data_offset: u16,
+    /// Size of the signed message
+    pub message_data_size: u16,
+}
+
+/// Returns the number of signatures verified by an ed25519 program
+/// instruction.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidInstructionData`] if the data is too short
+/// to hold the offsets of every signature.
+pub fn num_signatures(instruction_data: &[u8]) -> Result<u8, ProgramError> {
+    let num_signatures = *instruction_data
+        .first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let offsets_end = usize::from(num_signatures)
+        .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+        .saturating_add(SIGNATURE_OFFSETS_START);
+    if instruction_data.len() < offsets_end {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(num_signatures)
+}
+
+/// Returns the offsets of the signature at `signature_index` of an ed25519
+/// program instruction.
+///
+/// See [`Ed25519SignatureOffsets::parse`].
+pub fn get_signature_offsets(
+    instruction_data: &[u8],
+    signature_index: u8,
+) -> Result<Ed25519SignatureOffsets, ProgramError> {
+    Ed25519SignatureOffsets::parse(instruction_data, signature_index)
+}
+
+/// Returns the public key the signature at `signature_index` of an ed25519
+/// program instruction was verified against, when the public key is stored in
+/// the instruction itself, as done by `new_ed25519_instruction`.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidInstructionData`] if the public key is
+/// stored in another instruction, use [`load_verified_signature_checked`]
+/// instead in that case.
+pub fn get_verified_pubkey(
+    instruction_data: &[u8],
+    signature_index: u8,
+) -> Result<Pubkey, ProgramError> {
+    let offsets = get_signature_offsets(instruction_data, signature_index)?;
+    if offsets.public_key_instruction_index != CURRENT_INSTRUCTION_INDEX {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    read_pubkey(instruction_data, offsets.public_key_offset)
+}
+
+/// Load the signature at `signature_index` verified by the ed25519 program
+/// instruction at `instruction_index` of the currently executing transaction.
+///
+/// Since the ed25519 program fails the transaction if any signature does not
+/// verify, a program that finds this instruction in the transaction knows the
+/// returned public key signed the located message.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`instructions::ID`].
+/// Returns [`ProgramError::IncorrectProgramId`] if the instruction at
+/// `instruction_index` is not an ed25519 program instruction.
+/// Returns [`ProgramError::InvalidArgument`] if either index is out of bounds.
+pub fn load_verified_signature_checked(
+    instruction_index: u16,
+    signature_index: u8,
+    instruction_sysvar_account_info: &AccountInfo,
+) -> Result<Ed25519VerifiedSignature, ProgramError> {
+    let instruction = instructions::load_instruction_at_checked(
+        usize::from(instruction_index),
+        instruction_sysvar_account_info,
+    )?;
+    if !check_id(&instruction.program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let offsets = get_signature_offsets(&instruction.data, signature_index)?;
+    let resolve = |index| {
+        if index == CURRENT_INSTRUCTION_INDEX {
+            instruction_index
+        } else {
+            index
+        }
+    };
+
+    let public_key_instruction_index = resolve(offsets.public_key_instruction_index);
+    let pubkey = if public_key_instruction_index == instruction_index {
+        read_pubkey(&instruction.data, offsets.public_key_offset)?
+    } else {
+        let public_key_instruction = instructions::load_instruction_at_checked(
+            usize::from(public_key_instruction_index),
+            instruction_sysvar_account_info,
+        )?;
+        read_pubkey(&public_key_instruction.data, offsets.public_key_offset)?
+    };
+
+    Ok(Ed25519VerifiedSignature {
+        pubkey,
+        message_instruction_index: resolve(offsets.message_instruction_index),
+        message_data_offset: offsets.message_data_offset,
+        message_data_size: offsets.message_data_size,
+    })
+}
+
+/// Whether the ed25519 program instruction at `instruction_index` of the
+/// currently executing transaction verified `signature` of `message` by
+/// `pubkey`.
+///
+/// Each signature of the instruction is located, resolving the instructions
+/// holding its public key, signature and message, and compared to the
+/// expected ones. Since the ed25519 program fails the transaction if any
+/// signature does not verify, a match means the signature is valid.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`instructions::ID`].
+/// Returns [`ProgramError::IncorrectProgramId`] if the instruction at
+/// `instruction_index` is not an ed25519 program instruction.
+/// Returns [`ProgramError::InvalidArgument`] if an instruction index is out of
+/// bounds, and [`ProgramError::InvalidInstructionData`] if the offsets point
+/// past the end of an instruction's data.
+pub fn verify_ed25519_ix_matches(
+    message: &[u8],
+    pubkey: &Pubkey,
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    instruction_index: u16,
+    instruction_sysvar_account_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    let instruction = instructions::load_instruction_at_checked(
+        usize::from(instruction_index),
+        instruction_sysvar_account_info,
+    )?;
+    if !check_id(&instruction.program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let matches = |index: u16, offset: u16, expected: &[u8]| -> Result<bool, ProgramError> {
+        if index == CURRENT_INSTRUCTION_INDEX || index == instruction_index {
+            Ok(read_bytes(&instruction.data, offset, expected.len())? == expected)
+        } else {
+            let other_instruction = instructions::load_instruction_at_checked(
+                usize::from(index),
+                instruction_sysvar_account_info,
+            )?;
+            Ok(read_bytes(&other_instruction.data, offset, expected.len())? == expected)
+        }
+    };
+
+    for signature_index in 0..num_signatures(&instruction.data)? {
+        let offsets = Ed25519SignatureOffsets::parse(&instruction.data, signature_index)?;
+        if usize::from(offsets.message_data_size) == message.len()
+            && matches(
+                offsets.public_key_instruction_index,
+                offsets.public_key_offset,
+                pubkey.as_ref(),
+            )?
+            && matches(
+                offsets.signature_instruction_index,
+                offsets.signature_offset,
+                signature,
+            )?
+            && matches(
+                offsets.message_instruction_index,
+                offsets.message_data_offset,
+                message,
+            )?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Whether `signature` is a valid ed25519 signature of `message` by `pubkey`.
+///
+/// The signature is verified strictly, rejecting small order public keys and
+/// non-canonical encodings. A signature already verified by an ed25519
+/// program instruction of the current transaction, or by an earlier call, is
+/// not verified again, and only the message hashing cost is charged. Note
+/// that the ed25519 program does not verify strictly, so a signature it
+/// accepted is accepted here too.
+pub fn verify_signature(
+    pubkey: &Pubkey,
+    message: &[u8],
+    signature: &[u8; SIGNATURE_SERIALIZED_SIZE],
+) -> bool {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        crate::syscalls::sol_ed25519_verify(
+            pubkey.as_ref().as_ptr(),
+            message.as_ptr(),
+            message.len() as u64,
+            signature.as_ptr(),
+        ) == 1
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_ed25519_verify(pubkey, message, signature)
+}
+
+fn read_bytes(data: &[u8], offset: u16, len: usize) -> Result<&[u8], ProgramError> {
+    let start = usize::from(offset);
+    data.get(start..start.saturating_add(len))
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_pubkey(data: &[u8], offset: u16) -> Result<Pubkey, ProgramError> {
+    read_bytes(data, offset, PUBKEY_SERIALIZED_SIZE).map(|bytes| Pubkey::try_from(bytes).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            instruction::Instruction,
+            message::{Message as LegacyMessage, SanitizedMessage},
+        },
+        bytemuck::bytes_of,
+    };
+
+    fn instruction_data(offsets: &[Ed25519SignatureOffsets], payload: &[u8]) -> Vec<u8> {
+        let mut data = vec![offsets.len() as u8, 0];
+        for offsets in offsets {
+            data.extend_from_slice(bytes_of(offsets));
+        }
+        data.extend_from_slice(payload);
+        data
+    }
+
+    #[test]
+    fn test_get_verified_pubkey() {
+        let pubkey = Pubkey::new_unique();
+        let offsets = Ed25519SignatureOffsets {
+            signature_offset: (DATA_START + PUBKEY_SERIALIZED_SIZE) as u16,
+            signature_instruction_index: CURRENT_INSTRUCTION_INDEX,
+            public_key_offset: DATA_START as u16,
+            public_key_instruction_index: CURRENT_INSTRUCTION_INDEX,
+            message_data_offset: (DATA_START + PUBKEY_SERIALIZED_SIZE + SIGNATURE_SERIALIZED_SIZE)
+                as u16,
+            message_data_size: 5,
+            message_instruction_index: CURRENT_INSTRUCTION_INDEX,
+        };
+        let mut payload = pubkey.to_bytes().to_vec();
+        payload.extend_from_slice(&[0; SIGNATURE_SERIALIZED_SIZE]);
+        payload.extend_from_slice(b"hello");
+        let data = instruction_data(&[offsets], &payload);
+
+        assert_eq!(num_signatures(&data), Ok(1));
+        assert_eq!(get_signature_offsets(&data, 0), Ok(offsets));
+        assert_eq!(
+            get_signature_offsets(&data, 1),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(get_verified_pubkey(&data, 0), Ok(pubkey));
+
+        // The public key lives in another instruction
+        let data = instruction_data(
+            &[Ed25519SignatureOffsets {
+                public_key_instruction_index: 0,
+                ..offsets
+            }],
+            &payload,
+        );
+        assert_eq!(
+            get_verified_pubkey(&data, 0),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Offsets pointing past the end of the data
+        let data = instruction_data(&[offsets], &payload[..16]);
+        assert_eq!(
+            get_verified_pubkey(&data, 0),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            num_signatures(&[2, 0, 0]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            num_signatures(&[]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_load_verified_signature_checked() {
+        // The public key and message live in the first instruction, the
+        // signature in the ed25519 program instruction
+        let pubkey = Pubkey::new_unique();
+        let mut payload = pubkey.to_bytes().to_vec();
+        payload.extend_from_slice(b"message");
+        let other_instruction = Instruction::new_with_bytes(Pubkey::new_unique(), &payload, vec![]);
+        let offsets = Ed25519SignatureOffsets {
+            signature_offset: DATA_START as u16,
+            signature_instruction_index: CURRENT_INSTRUCTION_INDEX,
+            public_key_offset: 0,
+            public_key_instruction_index: 0,
+            message_data_offset: PUBKEY_SERIALIZED_SIZE as u16,
+            message_data_size: 7,
+            message_instruction_index: 0,
+        };
+        let ed25519_instruction = Instruction::new_with_bytes(
+            id(),
+            &instruction_data(&[offsets], &[0; SIGNATURE_SERIALIZED_SIZE]),
+            vec![],
+        );
+        let sanitized_message = SanitizedMessage::try_from(LegacyMessage::new(
+            &[other_instruction, ed25519_instruction],
+            Some(&Pubkey::new_unique()),
+        ))
+        .unwrap();
+
+        let key = instructions::id();
+        let mut lamports = 0;
+        let mut data =
+            instructions::construct_instructions_data(&sanitized_message.decompile_instructions());
+        let owner = crate::sysvar::id();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            load_verified_signature_checked(1, 0, &account_info),
+            Ok(Ed25519VerifiedSignature {
+                pubkey,
+                message_instruction_index: 0,
+                message_data_offset: PUBKEY_SERIALIZED_SIZE as u16,
+                message_data_size: 7,
+            })
+        );
+        assert_eq!(
+            load_verified_signature_checked(0, 0, &account_info),
+            Err(ProgramError::IncorrectProgramId)
+        );
+        assert_eq!(
+            load_verified_signature_checked(1, 1, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            load_verified_signature_checked(2, 0, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+    #[test]
+    fn test_verify_ed25519_ix_matches() {
+        // The public key and signature live in the ed25519 program
+        // instruction, the message in the first instruction
+        let pubkey = Pubkey::new_unique();
+        let signature = [7; SIGNATURE_SERIALIZED_SIZE];
+        let message = b"message";
+        let other_instruction = Instruction::new_with_bytes(Pubkey::new_unique(), message, vec![]);
+        let offsets = Ed25519SignatureOffsets {
+            signature_offset: (DATA_START + PUBKEY_SERIALIZED_SIZE) as u16,
+            signature_instruction_index: CURRENT_INSTRUCTION_INDEX,
+            public_key_offset: DATA_START as u16,
+            public_key_instruction_index: CURRENT_INSTRUCTION_INDEX,
+            message_data_offset: 0,
+            message_data_size: message.len() as u16,
+            message_instruction_index: 0,
+        };
+        let mut payload = pubkey.to_bytes().to_vec();
+        payload.extend_from_slice(&signature);
+        let ed25519_instruction =
+            Instruction::new_with_bytes(id(), &instruction_data(&[offsets], &payload), vec![]);
+        let sanitized_message = SanitizedMessage::try_from(LegacyMessage::new(
+            &[other_instruction, ed25519_instruction],
+            Some(&Pubkey::new_unique()),
+        ))
+        .unwrap();
+
+        let key = instructions::id();
+        let mut lamports = 0;
+        let mut data =
+            instructions::construct_instructions_data(&sanitized_message.decompile_instructions());
+        let owner = crate::sysvar::id();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            verify_ed25519_ix_matches(message, &pubkey, &signature, 1, &account_info),
+            Ok(true)
+        );
+        assert_eq!(
+            verify_ed25519_ix_matches(b"massage", &pubkey, &signature, 1, &account_info),
+            Ok(false)
+        );
+        assert_eq!(
+            verify_ed25519_ix_matches(b"mess", &pubkey, &signature, 1, &account_info),
+            Ok(false)
+        );
+        assert_eq!(
+            verify_ed25519_ix_matches(message, &Pubkey::new_unique(), &signature, 1, &account_info),
+            Ok(false)
+        );
+        assert_eq!(
+            verify_ed25519_ix_matches(message, &pubkey, &[8; 64], 1, &account_info),
+            Ok(false)
+        );
+        assert_eq!(
+            verify_ed25519_ix_matches(message, &pubkey, &signature, 0, &account_info),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -6,7 +6,10 @@
 
 extern crate alloc;
 use {
-    crate::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    crate::{
+        account_info::AccountInfo, introspection::TransactionIntrospection,
+        program_error::ProgramError, pubkey::Pubkey,
+    },
     alloc::vec::Vec,
     std::{
         alloc::Layout,
@@ -28,6 +31,20 @@ pub type ProgramResult = ResultGeneric<(), ProgramError>;
 pub type ProcessInstruction =
     fn(program_id: &Pubkey, accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult;
 
+/// User implemented function to process an instruction, declared with
+/// [`entrypoint_with_introspection!`]
+///
+/// Receives the per-transaction sysvar accounts found among `accounts` in
+/// addition to the arguments of [`ProcessInstruction`].
+///
+/// [`entrypoint_with_introspection!`]: crate::entrypoint_with_introspection
+pub type ProcessInstructionWithIntrospection = fn(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    introspection: &TransactionIntrospection,
+    instruction_data: &[u8],
+) -> ProgramResult;
+
 /// Programs indicate success with a return value of 0
 pub const SUCCESS: u64 = 0;
 
@@ -139,6 +156,86 @@ macro_rules! entrypoint {
     };
 }
 
+/// Declare the program entrypoint of a program that inspects the transaction
+/// it executes in.
+///
+/// This macro is like [`entrypoint!`], except that it locates the
+/// instructions and signatures sysvar accounts among the accounts passed to
+/// the instruction, and passes them to the provided function as a
+/// [`TransactionIntrospection`]. A sysvar that isn't passed is only an error
+/// once the function accesses it.
+///
+/// [`entrypoint!`]: crate::entrypoint!
+/// [`TransactionIntrospection`]: crate::introspection::TransactionIntrospection
+///
+/// The argument is the name of a function with this type signature:
+///
+/// ```ignore
+/// fn process_instruction(
+///     program_id: &Pubkey,                         // Public key of the account the program was loaded into
+///     accounts: &[AccountInfo],                    // All accounts required to process the instruction
+///     introspection: &TransactionIntrospection,    // The sysvar accounts among `accounts`
+///     instruction_data: &[u8],                     // Serialized instruction-specific data
+/// ) -> ProgramResult;
+/// ```
+///
+/// The same [Cargo features] as for [`entrypoint!`] apply.
+///
+/// [Cargo features]: https://doc.rust-lang.org/cargo/reference/features.html
+///
+/// # Examples
+///
+/// Rejecting instructions that aren't the first of their transaction:
+///
+/// ```no_run
+/// #[cfg(not(feature = "no-entrypoint"))]
+/// pub mod entrypoint {
+///
+///     use solana_program::{
+///         account_info::AccountInfo,
+///         entrypoint::ProgramResult,
+///         entrypoint_with_introspection,
+///         introspection::TransactionIntrospection,
+///         program_error::ProgramError,
+///         pubkey::Pubkey,
+///     };
+///
+///     entrypoint_with_introspection!(process_instruction);
+///
+///     pub fn process_instruction(
+///         program_id: &Pubkey,
+///         accounts: &[AccountInfo],
+///         introspection: &TransactionIntrospection,
+///         instruction_data: &[u8],
+///     ) -> ProgramResult {
+///         if introspection.current_instruction_index()? != 0 {
+///             return Err(ProgramError::InvalidInstructionData);
+///         }
+///
+///         Ok(())
+///     }
+///
+/// }
+/// ```
+#[macro_export]
+macro_rules! entrypoint_with_introspection {
+    ($process_instruction:ident) => {
+        /// # Safety
+        #[no_mangle]
+        pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
+            let (program_id, accounts, instruction_data) =
+                unsafe { $crate::entrypoint::deserialize(input) };
+            let introspection = $crate::introspection::TransactionIntrospection::new(&accounts);
+            match $process_instruction(&program_id, &accounts, &introspection, &instruction_data) {
+                Ok(()) => $crate::entrypoint::SUCCESS,
+                Err(error) => error.into(),
+            }
+        }
+        $crate::custom_heap_default!();
+        $crate::custom_panic_default!();
+    };
+}
+
 /// Define the default global allocator.
 ///
 /// The default global allocator is enabled only if the calling crate has not
@@ -727,6 +727,141 @@ pub fn get_processed_sibling_instruction(index: usize) -> Option<Instruction> {
     crate::program_stubs::sol_get_processed_sibling_instruction(index)
 }
 
+/// Where a processed sibling instruction came from, as returned by the
+/// `sol_get_processed_sibling_instruction_provenance` syscall.
+///
+/// Ties a sibling instruction to the top-level instruction it was processed
+/// under, and to the transaction signatures of that instruction's signers.
+/// This allows programs to check that a sibling was called directly by the
+/// user, rather than through CPI, and which signatures authorized it.
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ProcessedSiblingInstructionProvenance {
+    /// Stack height the sibling instruction was processed at
+    pub stack_height: u64,
+    /// Index within the transaction message of the top-level instruction the
+    /// sibling instruction is, or was invoked under
+    pub top_level_instruction_index: u64,
+    /// Signers of the top-level instruction, as a bitmask of transaction
+    /// signature indexes: bit `i` is set if the signer of the signature at
+    /// index `i` is an account of the top-level instruction
+    pub signers: u64,
+}
+
+impl ProcessedSiblingInstructionProvenance {
+    /// Whether the sibling instruction is itself a top-level instruction of
+    /// the transaction
+    pub fn is_top_level(&self) -> bool {
+        self.stack_height == TRANSACTION_LEVEL_STACK_HEIGHT as u64
+    }
+
+    /// Whether the top-level instruction has at least one signer
+    pub fn is_signed(&self) -> bool {
+        self.signers != 0
+    }
+
+    /// Whether the signer of the transaction signature at `signature_index`
+    /// is an account of the top-level instruction
+    pub fn is_signed_by(&self, signature_index: usize) -> bool {
+        u32::try_from(signature_index)
+            .ok()
+            .and_then(|shift| 1u64.checked_shl(shift))
+            .map(|bit| self.signers & bit != 0)
+            .unwrap_or(false)
+    }
+}
+
+/// Returns the provenance of a sibling instruction from the processed sibling
+/// instruction list.
+///
+/// `index` refers to the same sibling instruction as in
+/// [`get_processed_sibling_instruction`].
+pub fn get_processed_sibling_instruction_provenance(
+    index: usize,
+) -> Option<ProcessedSiblingInstructionProvenance> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut provenance = ProcessedSiblingInstructionProvenance::default();
+        if 1 == unsafe {
+            crate::syscalls::sol_get_processed_sibling_instruction_provenance(
+                index as u64,
+                &mut provenance,
+            )
+        } {
+            Some(provenance)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_get_processed_sibling_instruction_provenance(index)
+}
+
+/// The per-transaction sysvars a processed sibling instruction was given, as
+/// returned by the `sol_get_processed_sibling_instruction_sysvar_access`
+/// syscall.
+///
+/// The runtime can't observe which account data a program reads, but a
+/// program can only read a sysvar account it was passed. This allows wrapper
+/// programs to check that a sibling instruction was able to verify the
+/// transaction signatures, e.g. that it was given the signatures sysvar.
+#[repr(C)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ProcessedSiblingInstructionSysvarAccess {
+    /// Per-transaction sysvars passed to the sibling instruction or to any
+    /// instruction it invoked, as a bitmask of indexes into
+    /// [`sysvar::PER_TRANSACTION_IDS`]
+    ///
+    /// [`sysvar::PER_TRANSACTION_IDS`]: crate::sysvar::PER_TRANSACTION_IDS
+    pub sysvars: u64,
+}
+
+impl ProcessedSiblingInstructionSysvarAccess {
+    /// Whether the per-transaction sysvar `sysvar_id` was passed to the
+    /// sibling instruction or to an instruction it invoked
+    pub fn is_accessible(&self, sysvar_id: &Pubkey) -> bool {
+        crate::sysvar::PER_TRANSACTION_IDS
+            .iter()
+            .position(|id| id == sysvar_id)
+            .map(|index| self.sysvars & (1u64 << index) != 0)
+            .unwrap_or(false)
+    }
+
+    /// Whether the signatures sysvar was passed to the sibling instruction or
+    /// to an instruction it invoked
+    pub fn is_signatures_accessible(&self) -> bool {
+        self.is_accessible(&crate::sysvar::signatures::id())
+    }
+}
+
+/// Returns the per-transaction sysvars accessible to a sibling instruction
+/// from the processed sibling instruction list.
+///
+/// `index` refers to the same sibling instruction as in
+/// [`get_processed_sibling_instruction`].
+pub fn get_processed_sibling_instruction_sysvar_access(
+    index: usize,
+) -> Option<ProcessedSiblingInstructionSysvarAccess> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut access = ProcessedSiblingInstructionSysvarAccess::default();
+        if 1 == unsafe {
+            crate::syscalls::sol_get_processed_sibling_instruction_sysvar_access(
+                index as u64,
+                &mut access,
+            )
+        } {
+            Some(access)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_get_processed_sibling_instruction_sysvar_access(index)
+}
+
 // Stack height when processing transaction-level instructions
 pub const TRANSACTION_LEVEL_STACK_HEIGHT: usize = 1;
 
@@ -0,0 +1,287 @@
+//! A canonical digest of what a transaction does, computable both off-chain
+//! and on-chain.
+//!
+//! The _intent digest_ of a transaction commits to:
+//!
+//! - the program id and the hash of the data of each top-level instruction,
+//!   in order,
+//! - the set of signers passed to those instructions, sorted and
+//!   deduplicated.
+//!
+//! A wallet computes it with [`intent_digest`] from the instructions it is
+//! asked to sign, or with [`intent_digest_from_message`] from the message
+//! itself. A program computes the same value with
+//! [`intent_digest_from_instructions_sysvar`], and can compare it with a
+//! digest approved earlier, e.g. stored in an account, to enforce that the
+//! transaction being executed is the one that was approved.
+//!
+//! Account metas other than signers are not part of the digest, so that an
+//! intent remains valid if the writability of an account changes. Signers
+//! that are not passed to any instruction, such as a fee payer that only
+//! pays fees, are invisible to programs and are not part of the digest
+//! either.
+//!
+//! The digest is computed as the SHA-256 hash of:
+//!
+//! - [`INTENT_DIGEST_DOMAIN`],
+//! - the number of instructions as a little-endian `u64`,
+//! - for each instruction, its program id followed by the SHA-256 hash of
+//!   its data,
+//! - the number of signers as a little-endian `u64`,
+//! - the signers, in ascending order.
+
+#[cfg(not(target_os = "solana"))]
+use crate::message::VersionedMessage;
+use crate::{
+    account_info::AccountInfo,
+    hash::{hashv, Hash},
+    instruction::Instruction,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::instructions,
+};
+
+/// Domain separator prefixed to the data hashed into an intent digest.
+pub const INTENT_DIGEST_DOMAIN: &[u8] = b"solana-intent-digest-v1";
+
+/// Compute the intent digest of `instructions`, the top-level instructions
+/// of a transaction.
+pub fn intent_digest(instructions: &[Instruction]) -> Hash {
+    let signers = instructions.iter().flat_map(|instruction| {
+        instruction
+            .accounts
+            .iter()
+            .filter(|account_meta| account_meta.is_signer)
+            .map(|account_meta| &account_meta.pubkey)
+    });
+    digest(
+        instructions
+            .iter()
+            .map(|instruction| (&instruction.program_id, instruction.data.as_slice())),
+        signers,
+    )
+}
+
+/// Compute the intent digest of the transaction with the given message.
+///
+/// Returns `None` if an instruction refers to an account that isn't a static
+/// account key of the message. Such messages are rejected by sanitization,
+/// since program ids and signers can't be loaded from address lookup tables.
+///
+/// This function is not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn intent_digest_from_message(message: &VersionedMessage) -> Option<Hash> {
+    let account_keys = message.static_account_keys();
+    let instructions = message
+        .instructions()
+        .iter()
+        .map(|instruction| {
+            let program_id = account_keys.get(usize::from(instruction.program_id_index))?;
+            Some((program_id, instruction.data.as_slice()))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let signers = message
+        .instructions()
+        .iter()
+        .flat_map(|instruction| &instruction.accounts)
+        .map(|index| usize::from(*index))
+        .filter(|index| message.is_signer(*index))
+        .map(|index| account_keys.get(index))
+        .collect::<Option<Vec<_>>>()?;
+    Some(digest(instructions.into_iter(), signers.into_iter()))
+}
+
+/// Compute the intent digest of the currently executing transaction from the
+/// instructions sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to
+/// [`instructions::ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is too short to hold the
+/// number of instructions.
+pub fn intent_digest_from_instructions_sysvar(
+    instruction_sysvar_account_info: &AccountInfo,
+) -> Result<Hash, ProgramError> {
+    if !instructions::check_id(instruction_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let num_instructions = instruction_sysvar_account_info
+        .try_borrow_data()?
+        .get(..2)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u16::from_le_bytes)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let instructions = (0..usize::from(num_instructions))
+        .map(|index| {
+            instructions::load_instruction_at_checked(index, instruction_sysvar_account_info)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(intent_digest(&instructions))
+}
+
+fn digest<'a>(
+    instructions: impl ExactSizeIterator<Item = (&'a Pubkey, &'a [u8])>,
+    signers: impl Iterator<Item = &'a Pubkey>,
+) -> Hash {
+    let num_instructions = (instructions.len() as u64).to_le_bytes();
+    let instructions: Vec<_> = instructions
+        .map(|(program_id, data)| (program_id, hashv(&[data])))
+        .collect();
+    let mut signers: Vec<_> = signers.collect();
+    signers.sort_unstable();
+    signers.dedup();
+    let num_signers = (signers.len() as u64).to_le_bytes();
+
+    let mut vals: Vec<&[u8]> = Vec::with_capacity(
+        4_usize
+            .saturating_add(instructions.len().saturating_mul(2))
+            .saturating_add(signers.len()),
+    );
+    vals.push(INTENT_DIGEST_DOMAIN);
+    vals.push(&num_instructions);
+    for (program_id, data_hash) in &instructions {
+        vals.push(program_id.as_ref());
+        vals.push(data_hash.as_ref());
+    }
+    vals.push(&num_signers);
+    vals.extend(signers.iter().map(|signer| signer.as_ref()));
+    hashv(&vals)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::Epoch,
+            instruction::AccountMeta,
+            message::{v0, Message, SanitizedMessage},
+            sysvar::instructions::construct_instructions_data,
+        },
+    };
+
+    fn test_instructions(payer: &Pubkey, authority: &Pubkey) -> Vec<Instruction> {
+        let program_id = Pubkey::new_unique();
+        vec![
+            Instruction::new_with_bytes(
+                program_id,
+                &[1, 2, 3],
+                vec![
+                    AccountMeta::new(*payer, true),
+                    AccountMeta::new_readonly(*authority, true),
+                ],
+            ),
+            Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[4],
+                vec![
+                    AccountMeta::new_readonly(*authority, true),
+                    AccountMeta::new(Pubkey::new_unique(), false),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_intent_digest() {
+        let payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instructions = test_instructions(&payer, &authority);
+        let digest = intent_digest(&instructions);
+
+        // Non-signer account metas aren't part of the intent
+        let mut other = instructions.clone();
+        other[1].accounts[1] = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+        assert_eq!(intent_digest(&other), digest);
+
+        // Neither is the order in which signers are passed
+        let mut other = instructions.clone();
+        other[0].accounts.swap(0, 1);
+        assert_eq!(intent_digest(&other), digest);
+
+        let mut other = instructions.clone();
+        other[0].data[0] = 0;
+        assert_ne!(intent_digest(&other), digest);
+
+        let mut other = instructions.clone();
+        other[1].program_id = Pubkey::new_unique();
+        assert_ne!(intent_digest(&other), digest);
+
+        let mut other = instructions.clone();
+        other[0].accounts[0].is_signer = false;
+        assert_ne!(intent_digest(&other), digest);
+
+        let mut other = instructions.clone();
+        other.swap(0, 1);
+        assert_ne!(intent_digest(&other), digest);
+
+        let mut other = instructions.clone();
+        other.pop();
+        assert_ne!(intent_digest(&other), digest);
+    }
+
+    #[test]
+    fn test_intent_digest_from_message() {
+        let payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instructions = test_instructions(&payer, &authority);
+        let digest = intent_digest(&instructions);
+
+        let legacy = VersionedMessage::Legacy(Message::new(&instructions, Some(&payer)));
+        assert_eq!(intent_digest_from_message(&legacy), Some(digest));
+        let v0 = VersionedMessage::V0(
+            v0::Message::try_compile(&payer, &instructions, &[], Hash::default()).unwrap(),
+        );
+        assert_eq!(intent_digest_from_message(&v0), Some(digest));
+
+        // The fee payer isn't part of the intent unless it's passed to an
+        // instruction
+        let fee_payer = Pubkey::new_unique();
+        let legacy = VersionedMessage::Legacy(Message::new(&instructions, Some(&fee_payer)));
+        assert_eq!(intent_digest_from_message(&legacy), Some(digest));
+
+        let mut invalid = Message::new(&instructions, Some(&payer));
+        invalid.instructions[0].program_id_index = u8::MAX;
+        assert_eq!(
+            intent_digest_from_message(&VersionedMessage::Legacy(invalid)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_intent_digest_from_instructions_sysvar() {
+        let payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instructions = test_instructions(&payer, &authority);
+        let message =
+            SanitizedMessage::try_from(Message::new(&instructions, Some(&payer))).unwrap();
+        let mut data = construct_instructions_data(&message.decompile_instructions());
+
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = instructions::id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            intent_digest_from_instructions_sysvar(&account_info),
+            Ok(intent_digest(&instructions))
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            intent_digest_from_instructions_sysvar(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+}
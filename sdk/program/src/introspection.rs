@@ -0,0 +1,206 @@
+//! Access to the per-transaction sysvars passed to an instruction.
+//!
+//! Programs that inspect the transaction they execute in, through the
+//! [instructions sysvar] or the [signatures sysvar], first have to find the
+//! sysvar accounts among the accounts passed to the instruction.
+//! [`TransactionIntrospection`] does that once, and exposes the loaders of
+//! both sysvars without the account arguments. Programs declared with
+//! [`entrypoint_with_introspection!`] receive it as an argument of their
+//! instruction processor.
+//!
+//! A sysvar that isn't passed to the instruction is only an error once it is
+//! accessed, so that instructions that don't need it don't have to pass it.
+//!
+//! [instructions sysvar]: crate::sysvar::instructions
+//! [signatures sysvar]: crate::sysvar::signatures
+//! [`entrypoint_with_introspection!`]: crate::entrypoint_with_introspection
+
+use crate::{
+    account_info::AccountInfo,
+    instruction::Instruction,
+    program_error::ProgramError,
+    sysvar::{
+        instructions,
+        signatures::{self, TxSignature},
+    },
+};
+
+/// The per-transaction sysvar accounts found among the accounts of an
+/// instruction.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TransactionIntrospection<'a, 'info> {
+    instructions_sysvar: Option<&'a AccountInfo<'info>>,
+    signatures_sysvar: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> TransactionIntrospection<'a, 'info> {
+    /// Find the instructions and signatures sysvar accounts in `accounts`.
+    pub fn new(accounts: &'a [AccountInfo<'info>]) -> Self {
+        let find = |is_sysvar: fn(&crate::pubkey::Pubkey) -> bool| {
+            accounts
+                .iter()
+                .find(|account_info| is_sysvar(account_info.key))
+        };
+        Self {
+            instructions_sysvar: find(instructions::check_id),
+            signatures_sysvar: find(signatures::check_id),
+        }
+    }
+
+    /// The instructions sysvar account.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::NotEnoughAccountKeys`] if the account wasn't passed to the
+    /// instruction.
+    pub fn instructions_sysvar(&self) -> Result<&'a AccountInfo<'info>, ProgramError> {
+        self.instructions_sysvar
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    }
+
+    /// The signatures sysvar account.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::NotEnoughAccountKeys`] if the account wasn't passed to the
+    /// instruction.
+    pub fn signatures_sysvar(&self) -> Result<&'a AccountInfo<'info>, ProgramError> {
+        self.signatures_sysvar
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    }
+
+    /// See [`instructions::load_current_index_checked`].
+    pub fn current_instruction_index(&self) -> Result<u16, ProgramError> {
+        instructions::load_current_index_checked(self.instructions_sysvar()?)
+    }
+
+    /// See [`instructions::load_instruction_at_checked`].
+    pub fn load_instruction_at(&self, index: usize) -> Result<Instruction, ProgramError> {
+        instructions::load_instruction_at_checked(index, self.instructions_sysvar()?)
+    }
+
+    /// See [`instructions::get_instruction_relative`].
+    pub fn get_instruction_relative(
+        &self,
+        index_relative_to_current: i64,
+    ) -> Result<Instruction, ProgramError> {
+        instructions::get_instruction_relative(
+            index_relative_to_current,
+            self.instructions_sysvar()?,
+        )
+    }
+
+    /// See [`signatures::load_num_signatures`].
+    pub fn num_signatures(&self) -> Result<usize, ProgramError> {
+        signatures::load_num_signatures(self.signatures_sysvar()?)
+    }
+
+    /// See [`signatures::load_signature_at_checked`].
+    pub fn load_signature_at(&self, index: usize) -> Result<TxSignature, ProgramError> {
+        signatures::load_signature_at_checked(index, self.signatures_sysvar()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::Epoch, instruction::AccountMeta, pubkey::Pubkey,
+            sysvar::instructions::BorrowedInstruction,
+        },
+    };
+
+    #[test]
+    fn test_transaction_introspection() {
+        let program_id = Pubkey::new_unique();
+        let account_key = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[1, 2, 3],
+            vec![AccountMeta::new_readonly(account_key, false)],
+        );
+        let mut instructions_data =
+            instructions::construct_instructions_data(&[BorrowedInstruction {
+                program_id: &instruction.program_id,
+                accounts: vec![instructions::BorrowedAccountMeta {
+                    pubkey: &account_key,
+                    is_signer: false,
+                    is_writable: false,
+                }],
+                data: &instruction.data,
+            }]);
+        let mut signatures_data =
+            signatures::construct_signatures_data(&[TxSignature::from([7; 64])]).unwrap();
+
+        let owner = crate::sysvar::id();
+        let (mut lamports0, mut lamports1, mut lamports2) = (0, 0, 0);
+        let mut account_data = vec![];
+        let instructions_key = instructions::id();
+        let signatures_key = signatures::id();
+        let accounts = [
+            AccountInfo::new(
+                &account_key,
+                false,
+                false,
+                &mut lamports0,
+                &mut account_data,
+                &owner,
+                false,
+                Epoch::default(),
+            ),
+            AccountInfo::new(
+                &signatures_key,
+                false,
+                false,
+                &mut lamports1,
+                &mut signatures_data,
+                &owner,
+                false,
+                Epoch::default(),
+            ),
+            AccountInfo::new(
+                &instructions_key,
+                false,
+                false,
+                &mut lamports2,
+                &mut instructions_data,
+                &owner,
+                false,
+                Epoch::default(),
+            ),
+        ];
+
+        let introspection = TransactionIntrospection::new(&accounts);
+        assert_eq!(
+            introspection.instructions_sysvar().unwrap().key,
+            &instructions_key
+        );
+        assert_eq!(
+            introspection.signatures_sysvar().unwrap().key,
+            &signatures_key
+        );
+        assert_eq!(introspection.current_instruction_index(), Ok(0));
+        assert_eq!(
+            introspection.load_instruction_at(0),
+            Ok(instruction.clone())
+        );
+        assert_eq!(introspection.get_instruction_relative(0), Ok(instruction));
+        assert_eq!(introspection.num_signatures(), Ok(1));
+        assert_eq!(
+            introspection.load_signature_at(0),
+            Ok(TxSignature::from([7; 64]))
+        );
+
+        // Missing sysvars only fail once accessed
+        let introspection = TransactionIntrospection::new(&accounts[..1]);
+        assert_eq!(
+            introspection.load_instruction_at(0),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+        assert_eq!(
+            introspection.num_signatures(),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+}
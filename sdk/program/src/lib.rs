@@ -474,6 +474,7 @@ pub mod account_info;
 pub mod address_lookup_table;
 pub mod alt_bn128;
 pub(crate) mod atomic_u64;
+pub mod base58;
 pub mod big_mod_exp;
 pub mod blake3;
 pub mod borsh;
@@ -483,6 +484,8 @@ pub mod bpf_loader;
 pub mod bpf_loader_deprecated;
 pub mod bpf_loader_upgradeable;
 pub mod clock;
+pub mod compressed_record_proof;
+pub mod compute_budget;
 pub mod compute_units;
 pub mod debug_account_data;
 pub mod decode_error;
@@ -496,6 +499,8 @@ pub mod fee_calculator;
 pub mod hash;
 pub mod incinerator;
 pub mod instruction;
+pub mod intent;
+pub mod introspection;
 pub mod keccak;
 pub mod lamports;
 pub mod last_restart_slot;
@@ -523,6 +528,7 @@ pub mod secp256k1_recover;
 pub mod serde_varint;
 pub mod serialize_utils;
 pub mod short_vec;
+pub mod signature_pointer;
 pub mod slot_hashes;
 pub mod slot_history;
 pub mod stable_layout;
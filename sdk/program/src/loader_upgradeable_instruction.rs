@@ -160,4 +160,42 @@ pub enum UpgradeableLoaderInstruction {
     ///   1. `[signer]` The current authority.
     ///   2. `[signer]` The new authority.
     SetAuthorityChecked,
+
+    /// Declare the sysvars a program may access.
+    ///
+    /// The declaration is stored in the program's ProgramMetadata account,
+    /// whose address is derived from the Program account's address as
+    /// follows:
+    ///
+    /// ```
+    /// # use solana_program::pubkey::Pubkey;
+    /// # use solana_program::bpf_loader_upgradeable;
+    /// # let program_address = &[];
+    /// let (program_metadata_address, _) = Pubkey::find_program_address(
+    ///      &[program_address, bpf_loader_upgradeable::PROGRAM_METADATA_SEED],
+    ///      &bpf_loader_upgradeable::id()
+    ///  );
+    /// ```
+    ///
+    /// The declaration is informational, it is not enforced when the program
+    /// executes. Wallets can use it to warn users before signing a transaction
+    /// invoking a program which introspects it, e.g. through the signatures
+    /// sysvar.
+    ///
+    /// # Account references
+    ///   0. `[writable]` The ProgramMetadata account, created if uninitialized.
+    ///   1. `[]` The Program account.
+    ///   2. `[]` The ProgramData account.
+    ///   3. `[signer]` The program's upgrade authority.
+    ///   4. `[writable, signer]` The payer account that will pay to create the
+    ///      ProgramMetadata account, optional if it is already initialized.
+    ///   5. `[]` System program (`solana_sdk::system_program::id()`), optional
+    ///      if the ProgramMetadata account is already initialized.
+    SetSysvarDependencies {
+        /// Bitmask of the sysvars the program may access, see
+        /// [`SYSVAR_DEPENDENCY_IDS`].
+        ///
+        /// [`SYSVAR_DEPENDENCY_IDS`]: crate::bpf_loader_upgradeable::SYSVAR_DEPENDENCY_IDS
+        sysvar_dependencies: u64,
+    },
 }
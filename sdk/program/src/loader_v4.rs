@@ -14,7 +14,7 @@ crate::declare_id!("LoaderV411111111111111111111111111111111111");
 /// Cooldown before a program can be un-/redeployed again
 pub const DEPLOYMENT_COOLDOWN_IN_SLOTS: u64 = 750;
 
-#[repr(u64)]
+#[repr(u32)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, AbiExample)]
 pub enum LoaderV4Status {
     /// Program is in maintanance
@@ -35,6 +35,15 @@ pub struct LoaderV4State {
     pub authority_address: Pubkey,
     /// Deployment status.
     pub status: LoaderV4Status,
+    /// Minimum number of signatures a transaction must carry to invoke the program.
+    ///
+    /// Enforced by the loader before the program is executed, so that programs
+    /// relying on transaction signatures do not have to check their count manually.
+    /// Zero means no requirement beyond the fee payer signature.
+    ///
+    /// Occupies the upper half of what used to be a `u64` status, which is
+    /// always zero in existing accounts, so the layout is unchanged.
+    pub min_transaction_signers: u32,
     // The raw program data follows this serialized structure in the
     // account's data.
 }
@@ -66,6 +75,10 @@ pub fn is_transfer_authority_instruction(instruction_data: &[u8]) -> bool {
     !instruction_data.is_empty() && 4 == instruction_data[0]
 }
 
+pub fn is_set_min_transaction_signers_instruction(instruction_data: &[u8]) -> bool {
+    !instruction_data.is_empty() && 5 == instruction_data[0]
+}
+
 /// Returns the instructions required to initialize a program/buffer account.
 pub fn create_buffer(
     payer_address: &Pubkey,
@@ -195,6 +208,24 @@ pub fn transfer_authority(
     Instruction::new_with_bincode(id(), &LoaderV4Instruction::TransferAuthority, accounts)
 }
 
+/// Returns the instruction required to set the minimum number of transaction signers of a program.
+pub fn set_min_transaction_signers(
+    program_address: &Pubkey,
+    authority: &Pubkey,
+    min_transaction_signers: u32,
+) -> Instruction {
+    Instruction::new_with_bincode(
+        id(),
+        &LoaderV4Instruction::SetMinTransactionSigners {
+            min_transaction_signers,
+        },
+        vec![
+            AccountMeta::new(*program_address, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, crate::system_program, memoffset::offset_of};
@@ -204,9 +235,20 @@ mod tests {
         assert_eq!(offset_of!(LoaderV4State, slot), 0x00);
         assert_eq!(offset_of!(LoaderV4State, authority_address), 0x08);
         assert_eq!(offset_of!(LoaderV4State, status), 0x28);
+        assert_eq!(offset_of!(LoaderV4State, min_transaction_signers), 0x2c);
         assert_eq!(LoaderV4State::program_data_offset(), 0x30);
     }
 
+    #[test]
+    fn test_layout_compatibility() {
+        // Accounts written before `min_transaction_signers` store the status as a `u64`
+        let mut data = [0; 0x30];
+        data[0x28..0x30].copy_from_slice(&(LoaderV4Status::Finalized as u64).to_le_bytes());
+        let state = unsafe { std::mem::transmute::<[u8; 0x30], LoaderV4State>(data) };
+        assert_eq!(state.status, LoaderV4Status::Finalized);
+        assert_eq!(state.min_transaction_signers, 0);
+    }
+
     #[test]
     fn test_create_buffer_instruction() {
         let payer = Pubkey::new_unique();
@@ -363,4 +405,28 @@ mod tests {
         assert!(!instruction.accounts[1].is_writable);
         assert!(instruction.accounts[1].is_signer);
     }
+
+    #[test]
+    fn test_set_min_transaction_signers_instruction() {
+        let program = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instruction = set_min_transaction_signers(&program, &authority, 3);
+        assert!(is_set_min_transaction_signers_instruction(
+            &instruction.data
+        ));
+        assert_eq!(
+            bincode::deserialize::<LoaderV4Instruction>(&instruction.data).unwrap(),
+            LoaderV4Instruction::SetMinTransactionSigners {
+                min_transaction_signers: 3
+            }
+        );
+        assert_eq!(instruction.program_id, id());
+        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts[0].pubkey, program);
+        assert!(instruction.accounts[0].is_writable);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, authority);
+        assert!(!instruction.accounts[1].is_writable);
+        assert!(instruction.accounts[1].is_signer);
+    }
 }
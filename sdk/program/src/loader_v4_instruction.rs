@@ -69,4 +69,17 @@ pub enum LoaderV4Instruction {
     ///   1. `[signer]` The current authority of the program.
     ///   2. `[signer]` The new authority of the program. Optional if program is currently deployed.
     TransferAuthority,
+
+    /// Sets the minimum number of signatures a transaction must carry to invoke the program.
+    ///
+    /// The loader rejects any invocation of the program, including through CPI,
+    /// from a transaction with fewer signatures than required.
+    ///
+    /// # Account references
+    ///   0. `[writable]` The program account to change the requirement of.
+    ///   1. `[signer]` The authority of the program.
+    SetMinTransactionSigners {
+        /// The new minimum number of transaction signers, zero to remove the requirement.
+        min_transaction_signers: u32,
+    },
 }
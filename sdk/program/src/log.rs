@@ -33,7 +33,7 @@
 //! [`Pubkey`]: crate::pubkey::Pubkey
 //! [`Pubkey::log`]: crate::pubkey::Pubkey::log
 
-use crate::account_info::AccountInfo;
+use crate::{account_info::AccountInfo, base58, sysvar::signatures::SIGNATURE_LEN};
 
 /// Print a message to the log.
 #[macro_export]
@@ -88,6 +88,33 @@ macro_rules! msg {
     ($($arg:tt)*) => ($crate::log::sol_log(&format!($($arg)*)));
 }
 
+/// Print a transaction signature to the log in base58.
+///
+/// Accepts anything that converts to a byte slice, such as a
+/// [`TxSignature`] loaded from the signatures sysvar. Unlike formatting the
+/// signature with [`msg!`], this doesn't allocate or run Rust's formatting
+/// machinery: the signature is encoded into a stack buffer by the
+/// `sol_base58_encode` syscall, so its cost is bounded. Input that can't be
+/// encoded, e.g. because it is longer than a signature, is logged as
+/// hexadecimal instead.
+///
+/// [`TxSignature`]: crate::sysvar::signatures::TxSignature
+///
+/// # Examples
+///
+/// ```
+/// use solana_program::{msg_signature, sysvar::signatures::TxSignature};
+///
+/// let signature = TxSignature::from([7; 64]);
+/// msg_signature!(signature);
+/// ```
+#[macro_export]
+macro_rules! msg_signature {
+    ($signature:expr) => {
+        $crate::log::sol_log_signature(::core::convert::AsRef::<[u8]>::as_ref(&$signature))
+    };
+}
+
 /// Print a string to the log.
 #[inline]
 pub fn sol_log(message: &str) {
@@ -123,6 +150,32 @@ pub fn sol_log_data(data: &[&[u8]]) {
     crate::program_stubs::sol_log_data(data);
 }
 
+/// Print the base58 representation of a signature, see [`msg_signature!`].
+///
+/// [`msg_signature!`]: crate::msg_signature!
+pub fn sol_log_signature(signature: &[u8]) {
+    let mut encoded = [0u8; base58::max_encoded_len(SIGNATURE_LEN)];
+    match base58::encode(signature, &mut encoded) {
+        Ok(len) => sol_log(core::str::from_utf8(&encoded[..len]).unwrap_or_default()),
+        Err(_) => sol_log_hex(signature),
+    }
+}
+
+/// Print `data` as hexadecimal, one line per signature-sized chunk.
+#[allow(clippy::arithmetic_side_effects)]
+fn sol_log_hex(data: &[u8]) {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut line = [0u8; SIGNATURE_LEN * 2];
+    for chunk in data.chunks(SIGNATURE_LEN) {
+        for (byte, digits) in chunk.iter().zip(line.chunks_exact_mut(2)) {
+            digits[0] = HEX_DIGITS[(byte >> 4) as usize];
+            digits[1] = HEX_DIGITS[(byte & 0x0f) as usize];
+        }
+        let len = chunk.len() * 2;
+        sol_log(core::str::from_utf8(&line[..len]).unwrap_or_default());
+    }
+}
+
 /// Print the hexadecimal representation of a slice.
 #[allow(dead_code)]
 pub fn sol_log_slice(slice: &[u8]) {
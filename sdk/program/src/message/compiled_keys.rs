@@ -25,6 +25,10 @@ pub enum CompileError {
     AddressTableLookupIndexOverflow,
     #[error("encountered unknown account key `{0}` during instruction compilation")]
     UnknownInstructionKey(Pubkey),
+    #[error("per-transaction sysvar `{0}` must be passed as a readonly, unsigned account")]
+    InvalidSysvarAccountMeta(Pubkey),
+    #[error("per-transaction sysvar `{0}` is passed more than once to an instruction")]
+    DuplicateSysvarAccountMeta(Pubkey),
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -59,6 +63,32 @@ impl CompiledKeys {
         }
     }
 
+    /// Checks that instructions only pass per-transaction sysvars as readonly,
+    /// unsigned accounts, at most once per instruction.
+    #[cfg_attr(target_os = "solana", allow(dead_code))]
+    pub(crate) fn check_sysvar_account_metas(
+        instructions: &[Instruction],
+    ) -> Result<(), CompileError> {
+        for ix in instructions {
+            for (index, account_meta) in ix.accounts.iter().enumerate() {
+                let key = account_meta.pubkey;
+                if !key.is_per_transaction_sysvar() {
+                    continue;
+                }
+                if account_meta.is_signer || account_meta.is_writable {
+                    return Err(CompileError::InvalidSysvarAccountMeta(key));
+                }
+                if ix.accounts[..index]
+                    .iter()
+                    .any(|account_meta| account_meta.pubkey == key)
+                {
+                    return Err(CompileError::DuplicateSysvarAccountMeta(key));
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn try_into_message_components(
         self,
     ) -> Result<(MessageHeader, Vec<Pubkey>), CompileError> {
@@ -184,7 +214,57 @@ impl CompiledKeys {
 
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::instruction::AccountMeta, bitflags::bitflags};
+    use {
+        super::*,
+        crate::{instruction::AccountMeta, sysvar},
+        bitflags::bitflags,
+    };
+
+    #[test]
+    fn test_check_sysvar_account_metas() {
+        let program_id = Pubkey::new_unique();
+        let check = |accounts: Vec<AccountMeta>| {
+            CompiledKeys::check_sysvar_account_metas(&[Instruction {
+                program_id,
+                accounts,
+                data: vec![],
+            }])
+        };
+
+        let other = Pubkey::new_unique();
+        for sysvar_id in sysvar::PER_TRANSACTION_IDS {
+            assert_eq!(
+                check(vec![
+                    AccountMeta::new(other, true),
+                    AccountMeta::new(other, true),
+                    AccountMeta::new_readonly(sysvar_id, false),
+                ]),
+                Ok(())
+            );
+            assert_eq!(
+                check(vec![AccountMeta::new(sysvar_id, false)]),
+                Err(CompileError::InvalidSysvarAccountMeta(sysvar_id))
+            );
+            assert_eq!(
+                check(vec![AccountMeta::new_readonly(sysvar_id, true)]),
+                Err(CompileError::InvalidSysvarAccountMeta(sysvar_id))
+            );
+            assert_eq!(
+                check(vec![
+                    AccountMeta::new_readonly(sysvar_id, false),
+                    AccountMeta::new_readonly(other, false),
+                    AccountMeta::new_readonly(sysvar_id, false),
+                ]),
+                Err(CompileError::DuplicateSysvarAccountMeta(sysvar_id))
+            );
+        }
+
+        // Other sysvars are loaded from the bank and aren't checked
+        assert_eq!(
+            check(vec![AccountMeta::new(sysvar::clock::id(), false)]),
+            Ok(())
+        );
+    }
 
     bitflags! {
         #[derive(Clone, Copy)]
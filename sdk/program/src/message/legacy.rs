@@ -16,7 +16,7 @@ use {
         bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable,
         hash::Hash,
         instruction::{CompiledInstruction, Instruction},
-        message::{compiled_keys::CompiledKeys, MessageHeader},
+        message::{compiled_keys::CompiledKeys, CompileError, MessageHeader},
         pubkey::Pubkey,
         sanitize::{Sanitize, SanitizeError},
         short_vec, system_instruction, system_program, sysvar, wasm_bindgen,
@@ -60,7 +60,8 @@ lazy_static! {
 
 pub fn is_builtin_key_or_sysvar(key: &Pubkey) -> bool {
     if MAYBE_BUILTIN_KEY_OR_SYSVAR[key.0[0] as usize] {
-        return sysvar::is_sysvar_id(key) || BUILTIN_PROGRAMS_KEYS.contains(key);
+        return (sysvar::is_sysvar_id(key) && !sysvar::FEATURE_GATED_IDS.contains(key))
+            || BUILTIN_PROGRAMS_KEYS.contains(key);
     }
     false
 }
@@ -444,6 +445,36 @@ impl Message {
         Self::new(&instructions, payer)
     }
 
+    /// Create a new message whose instructions of `introspecting_program_id`
+    /// are passed the [signatures sysvar].
+    ///
+    /// The sysvar is appended to the accounts of those instructions with
+    /// [`append_account_meta`], as a readonly, unsigned account.
+    ///
+    /// [signatures sysvar]: crate::sysvar::signatures
+    /// [`append_account_meta`]: crate::sysvar::signatures::append_account_meta
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompileError::InvalidSysvarAccountMeta`] if an instruction passes a per-transaction
+    /// sysvar as a writable or signer account, and
+    /// [`CompileError::DuplicateSysvarAccountMeta`] if it passes one more than once.
+    #[cfg(not(target_os = "solana"))]
+    pub fn try_new_with_signatures_sysvar(
+        mut instructions: Vec<Instruction>,
+        payer: Option<&Pubkey>,
+        introspecting_program_id: &Pubkey,
+    ) -> Result<Self, CompileError> {
+        for instruction in instructions
+            .iter_mut()
+            .filter(|instruction| &instruction.program_id == introspecting_program_id)
+        {
+            sysvar::signatures::append_account_meta(instruction)?;
+        }
+        CompiledKeys::check_sysvar_account_metas(&instructions)?;
+        Ok(Self::new(&instructions, payer))
+    }
+
     pub fn new_with_compiled_instructions(
         num_required_signatures: u8,
         num_readonly_signed_accounts: u8,
@@ -643,6 +674,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_new_with_signatures_sysvar() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let instructions = vec![
+            Instruction::new_with_bincode(program_id, &0, vec![]),
+            system_instruction::transfer(&payer, &program_id, 1),
+        ];
+        let message = Message::try_new_with_signatures_sysvar(
+            instructions.clone(),
+            Some(&payer),
+            &program_id,
+        )
+        .unwrap();
+        let sysvar_index = message
+            .account_keys
+            .iter()
+            .position(sysvar::signatures::check_id)
+            .unwrap();
+        assert!(!message.is_writable(sysvar_index));
+        assert!(!message.is_signer(sysvar_index));
+        assert_eq!(message.instructions[0].accounts, vec![sysvar_index as u8]);
+        assert!(!message.instructions[1]
+            .accounts
+            .contains(&(sysvar_index as u8)));
+
+        let mut instructions = instructions;
+        instructions[0]
+            .accounts
+            .push(AccountMeta::new(sysvar::signatures::id(), false));
+        assert_eq!(
+            Message::try_new_with_signatures_sysvar(instructions, Some(&payer), &program_id),
+            Err(CompileError::InvalidSysvarAccountMeta(
+                sysvar::signatures::id()
+            ))
+        );
+    }
+
     #[test]
     // Ensure there's a way to calculate the number of required signatures.
     fn test_message_signed_keys_len() {
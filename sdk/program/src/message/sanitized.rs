@@ -65,6 +65,20 @@ impl<'a> LegacyMessage<'a> {
     pub fn is_writable(&self, index: usize) -> bool {
         *self.is_writable_account_cache.get(index).unwrap_or(&false)
     }
+
+    /// Demote the accounts at any of `keys` to readonly.
+    pub fn demote_account_keys(&mut self, keys: &[Pubkey]) {
+        for (key, is_writable) in self
+            .message
+            .account_keys
+            .iter()
+            .zip(self.is_writable_account_cache.iter_mut())
+        {
+            if keys.contains(key) {
+                *is_writable = false;
+            }
+        }
+    }
 }
 
 /// Sanitized message of a transaction.
@@ -243,6 +257,17 @@ impl SanitizedMessage {
         }
     }
 
+    /// Demote the accounts at any of `keys` to readonly, such as the
+    /// [`sysvar::FEATURE_GATED_IDS`] whose feature is active.
+    ///
+    /// [`sysvar::FEATURE_GATED_IDS`]: crate::sysvar::FEATURE_GATED_IDS
+    pub fn demote_account_keys(&mut self, keys: &[Pubkey]) {
+        match self {
+            Self::Legacy(message) => message.demote_account_keys(keys),
+            Self::V0(message) => message.demote_account_keys(keys),
+        }
+    }
+
     /// Returns true if the account at the specified index signed this
     /// message.
     pub fn is_signer(&self, index: usize) -> bool {
@@ -303,6 +328,14 @@ impl SanitizedMessage {
         }
     }
 
+    /// Inspect all message keys for sysvars constructed by the runtime for
+    /// each transaction, such as the instructions and signatures sysvars
+    pub fn references_per_transaction_sysvar(&self) -> bool {
+        self.account_keys()
+            .iter()
+            .any(Pubkey::is_per_transaction_sysvar)
+    }
+
     /// Get a list of signers for the instruction at the given index
     pub fn get_ix_signers(&self, ix_index: usize) -> impl Iterator<Item = &Pubkey> {
         self.instructions()
@@ -384,6 +417,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_references_per_transaction_sysvar() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let message = |account_key| {
+            SanitizedMessage::try_from(legacy::Message::new_with_compiled_instructions(
+                1,
+                0,
+                2,
+                vec![payer, account_key, program_id],
+                Hash::default(),
+                vec![CompiledInstruction::new(2, &(), vec![0, 1])],
+            ))
+            .unwrap()
+        };
+
+        assert!(!message(Pubkey::new_unique()).references_per_transaction_sysvar());
+        assert!(!message(crate::sysvar::clock::id()).references_per_transaction_sysvar());
+        assert!(message(crate::sysvar::signatures::id()).references_per_transaction_sysvar());
+        assert!(message(crate::sysvar::instructions::id()).references_per_transaction_sysvar());
+    }
+
     #[test]
     fn test_is_non_loader_key() {
         let key0 = Pubkey::new_unique();
@@ -559,4 +614,43 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_demote_account_keys() {
+        let key0 = Pubkey::new_unique();
+        let key1 = Pubkey::new_unique();
+        let key2 = Pubkey::new_unique();
+        let header = MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 0,
+        };
+
+        // Feature gated sysvars are left writable until the runtime demotes them
+        for sysvar_id in crate::sysvar::FEATURE_GATED_IDS {
+            let mut legacy_message = SanitizedMessage::try_from(legacy::Message {
+                header,
+                account_keys: vec![key0, sysvar_id, key1],
+                ..legacy::Message::default()
+            })
+            .unwrap();
+            let mut v0_message = SanitizedMessage::V0(v0::LoadedMessage::new(
+                v0::Message {
+                    header,
+                    account_keys: vec![key0, key1],
+                    ..v0::Message::default()
+                },
+                LoadedAddresses {
+                    writable: vec![key2, sysvar_id],
+                    readonly: vec![],
+                },
+            ));
+            for (message, sysvar_index) in [(&mut legacy_message, 1), (&mut v0_message, 3)] {
+                assert!(message.is_writable(sysvar_index));
+                message.demote_account_keys(&[sysvar_id]);
+                assert!(!message.is_writable(sysvar_index));
+                assert!((0..3).all(|i| i == sysvar_index || message.is_writable(i)));
+            }
+        }
+    }
 }
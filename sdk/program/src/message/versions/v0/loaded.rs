@@ -143,6 +143,20 @@ impl<'a> LoadedMessage<'a> {
             .unwrap_or(&false)
     }
 
+    /// Demote the accounts at any of `keys` to readonly.
+    pub fn demote_account_keys(&mut self, keys: &[Pubkey]) {
+        let account_keys =
+            AccountKeys::new(&self.message.account_keys, Some(&self.loaded_addresses));
+        for (key, is_writable) in account_keys
+            .iter()
+            .zip(self.is_writable_account_cache.iter_mut())
+        {
+            if keys.contains(key) {
+                *is_writable = false;
+            }
+        }
+    }
+
     pub fn is_signer(&self, i: usize) -> bool {
         i < self.message.header.num_required_signatures as usize
     }
@@ -297,6 +311,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_writable_loaded_sysvars() {
+        let payer = Pubkey::new_unique();
+        let key0 = Pubkey::new_unique();
+        let message = LoadedMessage::new(
+            v0::Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 0,
+                },
+                account_keys: vec![payer],
+                ..v0::Message::default()
+            },
+            LoadedAddresses {
+                writable: vec![
+                    sysvar::clock::id(),
+                    sysvar::instructions::id(),
+                    sysvar::signatures::id(),
+                    sysvar::recent_signatures::id(),
+                    key0,
+                ],
+                readonly: vec![sysvar::slot_hashes::id()],
+            },
+        );
+
+        // Sysvars loaded as writable from a lookup table are demoted
+        for index in 1..5 {
+            assert!(message.is_writable_index(index));
+            assert!(!message.is_writable(index));
+        }
+        assert!(message.is_writable(5));
+        assert!(!message.is_writable(6));
+    }
+
     #[test]
     fn test_demote_writable_program() {
         let key0 = Pubkey::new_unique();
@@ -177,6 +177,16 @@ impl Message {
     /// `recent_blockhash`, list of `instructions`, and a list of
     /// `address_lookup_table_accounts`.
     ///
+    /// Per-transaction sysvars, such as the instructions and signatures
+    /// sysvars, must be passed to each instruction at most once, as readonly,
+    /// unsigned accounts; otherwise compilation fails.
+    ///
+    /// Sysvars, including per-transaction sysvars, may be loaded from address
+    /// lookup tables like any other account, which saves static account key
+    /// space for transactions that introspect several sysvars. Sysvars are
+    /// never writable: the runtime demotes writable references to them,
+    /// whether static or loaded, to readonly.
+    ///
     /// # Examples
     ///
     /// This example uses the [`solana_rpc_client`], [`solana_sdk`], and [`anyhow`] crates.
@@ -257,6 +267,7 @@ impl Message {
         address_lookup_table_accounts: &[AddressLookupTableAccount],
         recent_blockhash: Hash,
     ) -> Result<Self, CompileError> {
+        CompiledKeys::check_sysvar_account_metas(instructions)?;
         let mut compiled_keys = CompiledKeys::compile(instructions, Some(*payer));
 
         let mut address_table_lookups = Vec::with_capacity(address_lookup_table_accounts.len());
@@ -685,4 +696,57 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_try_compile_sysvars_from_lookup_table() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let mut sysvar_keys = [crate::sysvar::clock::id(), crate::sysvar::signatures::id()];
+        sysvar_keys.sort();
+        let instructions = vec![Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(sysvar_keys[0], false),
+                AccountMeta::new_readonly(sysvar_keys[1], false),
+            ],
+            data: vec![],
+        }];
+        let address_lookup_table_accounts = vec![AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: sysvar_keys.to_vec(),
+        }];
+
+        let message = Message::try_compile(
+            &payer,
+            &instructions,
+            &address_lookup_table_accounts,
+            Hash::new_unique(),
+        )
+        .unwrap();
+        assert_eq!(message.account_keys, vec![payer, program_id]);
+        assert_eq!(
+            message.address_table_lookups,
+            vec![MessageAddressTableLookup {
+                account_key: address_lookup_table_accounts[0].key,
+                writable_indexes: vec![],
+                readonly_indexes: vec![0, 1],
+            }]
+        );
+        assert_eq!(message.instructions[0].accounts, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_try_compile_invalid_sysvar_account_meta() {
+        let payer = Pubkey::new_unique();
+        let signatures_id = crate::sysvar::signatures::id();
+        let instructions = vec![Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![AccountMeta::new(signatures_id, false)],
+            data: vec![],
+        }];
+        assert_eq!(
+            Message::try_compile(&payer, &instructions, &[], Hash::new_unique()),
+            Err(CompileError::InvalidSysvarAccountMeta(signatures_id))
+        );
+    }
 }
@@ -2,5 +2,41 @@
 
 pub mod state;
 pub use state::State;
+use {
+    crate::{hash::Hash, pubkey::Pubkey},
+    bytemuck::{Pod, Zeroable},
+};
 
 pub const NONCED_TX_MARKER_IX_INDEX: u8 = 0;
+
+/// The durable nonce consumed by the currently executing transaction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+pub struct TransactionNonceInfo {
+    /// Address of the nonce account the transaction advanced
+    pub nonce_account: Pubkey,
+    /// The durable nonce the transaction used as its recent blockhash
+    pub durable_nonce: Hash,
+}
+
+/// Get the durable nonce consumed by the currently executing transaction
+/// through the `sol_get_transaction_nonce_info` syscall.
+///
+/// Returns `None` if the transaction used a recent blockhash instead. A
+/// transaction whose first instruction advances a nonce account, but whose
+/// blockhash is still recent, is processed as a regular transaction and
+/// doesn't consume its nonce.
+pub fn get_transaction_nonce_info() -> Option<TransactionNonceInfo> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut nonce_info = TransactionNonceInfo::default();
+        if 1 == unsafe { crate::syscalls::sol_get_transaction_nonce_info(&mut nonce_info) } {
+            Some(nonce_info)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_get_transaction_nonce_info()
+}
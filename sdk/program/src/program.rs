@@ -9,8 +9,8 @@
 //! [cpi]: https://docs.solana.com/developing/programming-model/calling-between-programs
 
 use crate::{
-    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, pubkey::Pubkey,
-    stable_layout::stable_instruction::StableInstruction,
+    account_info::AccountInfo, entrypoint::ProgramResult, hash::Hash, instruction::Instruction,
+    pubkey::Pubkey, stable_layout::stable_instruction::StableInstruction,
 };
 
 /// Invoke a cross-program instruction.
@@ -390,6 +390,94 @@ pub fn get_return_data() -> Option<(Pubkey, Vec<u8>)> {
     crate::program_stubs::sol_get_return_data()
 }
 
+/// Maximum size that can be set using [`set_tx_scratch`].
+pub const MAX_TX_SCRATCH_LEN: usize = 1024;
+
+/// Set the running program's transaction scratch buffer.
+///
+/// Every program has a dedicated scratch buffer that lives as long as the
+/// transaction, through which an instruction can pass results to the
+/// instructions that follow without using accounts. Only the program itself
+/// can write its buffer, so the buffer of a program holds exactly what the
+/// program last wrote, unlike the return data set with [`set_return_data`].
+///
+/// Each call replaces the previous contents of the buffer. The maximum size
+/// of the buffer is [`MAX_TX_SCRATCH_LEN`]. The buffer is read with
+/// [`get_tx_scratch`].
+pub fn set_tx_scratch(data: &[u8]) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        crate::syscalls::sol_tx_scratch_write(data.as_ptr(), data.len() as u64)
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_tx_scratch_write(data)
+}
+
+/// Get the transaction scratch buffer of `program_id`.
+///
+/// Returns `None` if `program_id` didn't write its buffer in the current
+/// transaction, or last wrote an empty buffer.
+///
+/// The scratch buffer is written with [`set_tx_scratch`].
+pub fn get_tx_scratch(program_id: &Pubkey) -> Option<Vec<u8>> {
+    #[cfg(target_os = "solana")]
+    {
+        use std::cmp::min;
+
+        let mut buf = [0u8; MAX_TX_SCRATCH_LEN];
+
+        let size = unsafe {
+            crate::syscalls::sol_tx_scratch_read(program_id, buf.as_mut_ptr(), buf.len() as u64)
+        };
+
+        if size == 0 {
+            None
+        } else {
+            let size = min(size as usize, MAX_TX_SCRATCH_LEN);
+            Some(buf[..size].to_vec())
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_tx_scratch_read(program_id)
+}
+
+/// Whether a precompile instruction of the current transaction verified
+/// `signature` of the message hashing to `message_hash` by `pubkey`.
+///
+/// `precompile_id` is the [ed25519 program] or the [secp256k1 program]. For
+/// the secp256k1 program, `signature` excludes the recovery id and `pubkey`
+/// is the 20-byte Ethereum address. `message_hash` is the SHA-256 hash of
+/// the signed message.
+///
+/// Precompile instructions are verified before the transaction executes, so
+/// this lets a program learn that a signature is valid without verifying it
+/// again, wherever the precompile instruction sits in the transaction.
+///
+/// [ed25519 program]: crate::ed25519_program
+/// [secp256k1 program]: crate::secp256k1_program
+pub fn is_verified(
+    precompile_id: &Pubkey,
+    signature: &[u8; 64],
+    pubkey: &[u8],
+    message_hash: &Hash,
+) -> bool {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        crate::syscalls::sol_is_verified(
+            precompile_id,
+            signature.as_ptr(),
+            pubkey.as_ptr(),
+            pubkey.len() as u64,
+            message_hash.as_ref().as_ptr(),
+        ) == 1
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_is_verified(precompile_id, signature, pubkey, message_hash)
+}
+
 /// Do sanity checks of type layout.
 #[doc(hidden)]
 #[allow(clippy::arithmetic_side_effects)]
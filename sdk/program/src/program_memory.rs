@@ -109,6 +109,35 @@ pub fn sol_memcmp(s1: &[u8], s2: &[u8], n: usize) -> i32 {
     result
 }
 
+/// Compare the first `n` bytes of `s1` and `s2` for equality in constant time.
+///
+/// Unlike [`sol_memcmp`], this always reads all `n` bytes, regardless of where
+/// the slices first differ, and doesn't order them. Within a SBF program it is
+/// also cheaper than `sol_memcmp` for values up to 64 bytes long, which makes
+/// it the preferred way to check signatures and other secrets for equality.
+///
+/// # Errors
+///
+/// When executed within a SBF program, the memory regions spanning `n` bytes
+/// from the start of `s1` and `s2` must be mapped program memory. If not, the
+/// program will abort.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of `s1` or `s2`.
+#[inline]
+pub fn sol_memcmp_ct(s1: &[u8], s2: &[u8], n: usize) -> bool {
+    let (s1, s2) = (&s1[..n], &s2[..n]);
+
+    #[cfg(target_os = "solana")]
+    let result = unsafe { crate::syscalls::sol_memcmp_ct_(s1.as_ptr(), s2.as_ptr(), n as u64) };
+
+    #[cfg(not(target_os = "solana"))]
+    let result = crate::program_stubs::sol_memcmp_ct(s1.as_ptr(), s2.as_ptr(), n);
+
+    result == 0
+}
+
 /// Like C `memset`.
 ///
 /// # Arguments
@@ -4,8 +4,17 @@
 
 use {
     crate::{
-        account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction,
-        program_error::UNSUPPORTED_SYSVAR, pubkey::Pubkey,
+        account_info::AccountInfo,
+        entrypoint::ProgramResult,
+        hash::Hash,
+        instruction::{
+            Instruction, ProcessedSiblingInstructionProvenance,
+            ProcessedSiblingInstructionSysvarAccess,
+        },
+        nonce::TransactionNonceInfo,
+        program_error::UNSUPPORTED_SYSVAR,
+        pubkey::Pubkey,
+        sysvar::signatures::TxSignature,
     },
     base64::{prelude::BASE64_STANDARD, Engine},
     itertools::Itertools,
@@ -89,6 +98,13 @@ pub trait SyscallStubs: Sync + Send {
         *result = 0
     }
     /// # Safety
+    unsafe fn sol_memcmp_ct(&self, s1: *const u8, s2: *const u8, n: usize) -> u64 {
+        let s1 = std::slice::from_raw_parts(s1, n);
+        let s2 = std::slice::from_raw_parts(s2, n);
+        let diff = s1.iter().zip(s2).fold(0u8, |diff, (a, b)| diff | (a ^ b));
+        u64::from(std::hint::black_box(diff) != 0)
+    }
+    /// # Safety
     unsafe fn sol_memset(&self, s: *mut u8, c: u8, n: usize) {
         let s = std::slice::from_raw_parts_mut(s, n);
         for val in s.iter_mut().take(n) {
@@ -99,6 +115,10 @@ pub trait SyscallStubs: Sync + Send {
         None
     }
     fn sol_set_return_data(&self, _data: &[u8]) {}
+    fn sol_tx_scratch_write(&self, _data: &[u8]) {}
+    fn sol_tx_scratch_read(&self, _program_id: &Pubkey) -> Option<Vec<u8>> {
+        None
+    }
     fn sol_log_data(&self, fields: &[&[u8]]) {
         println!(
             "data: {}",
@@ -108,9 +128,50 @@ pub trait SyscallStubs: Sync + Send {
     fn sol_get_processed_sibling_instruction(&self, _index: usize) -> Option<Instruction> {
         None
     }
+    fn sol_get_processed_sibling_instruction_provenance(
+        &self,
+        _index: usize,
+    ) -> Option<ProcessedSiblingInstructionProvenance> {
+        None
+    }
+    fn sol_get_processed_sibling_instruction_sysvar_access(
+        &self,
+        _index: usize,
+    ) -> Option<ProcessedSiblingInstructionSysvarAccess> {
+        None
+    }
     fn sol_get_stack_height(&self) -> u64 {
         0
     }
+    fn sol_get_instructions_sysvar_slice(
+        &self,
+        _offset: u64,
+        _length: u64,
+        _var_addr: *mut u8,
+    ) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
+    fn sol_is_verified(
+        &self,
+        _precompile_id: &Pubkey,
+        _signature: &[u8; 64],
+        _pubkey: &[u8],
+        _message_hash: &Hash,
+    ) -> bool {
+        false
+    }
+    fn sol_get_transaction_signature(&self, _index: usize) -> Option<TxSignature> {
+        None
+    }
+    fn sol_ed25519_verify(&self, _pubkey: &Pubkey, _message: &[u8], _signature: &[u8; 64]) -> bool {
+        false
+    }
+    fn sol_get_transaction_nonce_info(&self) -> Option<TransactionNonceInfo> {
+        None
+    }
+    fn sol_get_serialized_message(&self, _data: &mut [u8]) -> usize {
+        0
+    }
 }
 
 struct DefaultSyscallStubs {}
@@ -189,6 +250,10 @@ pub(crate) fn sol_memcmp(s1: *const u8, s2: *const u8, n: usize, result: *mut i3
     }
 }
 
+pub(crate) fn sol_memcmp_ct(s1: *const u8, s2: *const u8, n: usize) -> u64 {
+    unsafe { SYSCALL_STUBS.read().unwrap().sol_memcmp_ct(s1, s2, n) }
+}
+
 pub(crate) fn sol_memset(s: *mut u8, c: u8, n: usize) {
     unsafe {
         SYSCALL_STUBS.read().unwrap().sol_memset(s, c, n);
@@ -203,6 +268,17 @@ pub(crate) fn sol_set_return_data(data: &[u8]) {
     SYSCALL_STUBS.read().unwrap().sol_set_return_data(data)
 }
 
+pub(crate) fn sol_tx_scratch_write(data: &[u8]) {
+    SYSCALL_STUBS.read().unwrap().sol_tx_scratch_write(data)
+}
+
+pub(crate) fn sol_tx_scratch_read(program_id: &Pubkey) -> Option<Vec<u8>> {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_tx_scratch_read(program_id)
+}
+
 pub(crate) fn sol_log_data(data: &[&[u8]]) {
     SYSCALL_STUBS.read().unwrap().sol_log_data(data)
 }
@@ -214,10 +290,39 @@ pub(crate) fn sol_get_processed_sibling_instruction(index: usize) -> Option<Inst
         .sol_get_processed_sibling_instruction(index)
 }
 
+pub(crate) fn sol_get_processed_sibling_instruction_provenance(
+    index: usize,
+) -> Option<ProcessedSiblingInstructionProvenance> {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_processed_sibling_instruction_provenance(index)
+}
+
+pub(crate) fn sol_get_processed_sibling_instruction_sysvar_access(
+    index: usize,
+) -> Option<ProcessedSiblingInstructionSysvarAccess> {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_processed_sibling_instruction_sysvar_access(index)
+}
+
 pub(crate) fn sol_get_stack_height() -> u64 {
     SYSCALL_STUBS.read().unwrap().sol_get_stack_height()
 }
 
+pub(crate) fn sol_get_instructions_sysvar_slice(
+    offset: u64,
+    length: u64,
+    var_addr: *mut u8,
+) -> u64 {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_instructions_sysvar_slice(offset, length, var_addr)
+}
+
 pub(crate) fn sol_get_epoch_rewards_sysvar(var_addr: *mut u8) -> u64 {
     SYSCALL_STUBS
         .read()
@@ -261,3 +366,43 @@ mod tests {
         assert!(!is_nonoverlapping::<u8>(255, 2, 254, 3));
     }
 }
+
+pub(crate) fn sol_is_verified(
+    precompile_id: &Pubkey,
+    signature: &[u8; 64],
+    pubkey: &[u8],
+    message_hash: &Hash,
+) -> bool {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_is_verified(precompile_id, signature, pubkey, message_hash)
+}
+
+pub(crate) fn sol_get_transaction_signature(index: usize) -> Option<TxSignature> {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_transaction_signature(index)
+}
+
+pub(crate) fn sol_ed25519_verify(pubkey: &Pubkey, message: &[u8], signature: &[u8; 64]) -> bool {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_ed25519_verify(pubkey, message, signature)
+}
+
+pub(crate) fn sol_get_transaction_nonce_info() -> Option<TransactionNonceInfo> {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_transaction_nonce_info()
+}
+
+pub(crate) fn sol_get_serialized_message(data: &mut [u8]) -> usize {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_serialized_message(data)
+}
@@ -624,6 +624,41 @@ impl Pubkey {
         }
     }
 
+    /// Find the program address derived from a transaction signature, and
+    /// its bump seed.
+    ///
+    /// A signature is longer than [`MAX_SEED_LEN`], so it is split into its
+    /// first and last 32 bytes, see [`TxSignature::seeds`]. Programs read the
+    /// signatures of the executing transaction from the [signatures sysvar],
+    /// which lets them derive addresses from their own transaction, e.g. to
+    /// store data that other transactions point to by signature.
+    ///
+    /// On-chain, prefer passing the bump seed in the instruction data and
+    /// checking it with [`Pubkey::create_program_address_from_signature`].
+    ///
+    /// [`TxSignature::seeds`]: crate::sysvar::signatures::TxSignature::seeds
+    /// [signatures sysvar]: crate::sysvar::signatures
+    pub fn find_program_address_from_signature(
+        signature: &crate::sysvar::signatures::TxSignature,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Self::find_program_address(&signature.seeds(), program_id)
+    }
+
+    /// Create the program address derived from a transaction signature and
+    /// `bump_seed`.
+    ///
+    /// See [`Pubkey::find_program_address_from_signature`] and
+    /// [`Pubkey::create_program_address`].
+    pub fn create_program_address_from_signature(
+        signature: &crate::sysvar::signatures::TxSignature,
+        bump_seed: u8,
+        program_id: &Pubkey,
+    ) -> Result<Pubkey, PubkeyError> {
+        let [first, last] = signature.seeds();
+        Self::create_program_address(&[first, last, &[bump_seed]], program_id)
+    }
+
     pub fn to_bytes(self) -> [u8; 32] {
         self.0
     }
@@ -632,6 +667,31 @@ impl Pubkey {
         bytes_are_curve_point(self)
     }
 
+    /// Returns `true` if this is the address of a sysvar whose account is
+    /// constructed by the runtime for each transaction, such as the
+    /// instructions and signatures sysvars.
+    pub fn is_per_transaction_sysvar(&self) -> bool {
+        use crate::sysvar::{
+            HEADER_ID, INSTRUCTIONS_ID, SCRATCH_ID, SIGNATURES_ID, TICK_HASH_ID,
+            TRANSACTION_CONTEXT_ID,
+        };
+        matches!(
+            *self,
+            INSTRUCTIONS_ID
+                | SIGNATURES_ID
+                | HEADER_ID
+                | SCRATCH_ID
+                | TICK_HASH_ID
+                | TRANSACTION_CONTEXT_ID
+        )
+    }
+
+    /// Classify this address as a cached sysvar, a per-transaction sysvar or
+    /// not a sysvar.
+    pub fn sysvar_kind(&self) -> crate::sysvar::SysvarKind {
+        crate::sysvar::SysvarKind::of(self)
+    }
+
     /// Log a `Pubkey` from a program
     pub fn log(&self) {
         #[cfg(target_os = "solana")]
@@ -6,5 +6,145 @@
 //! program's usage can be found in [`solana_sdk::secp256k1_instruction`].
 //!
 //! [`solana_sdk::secp256k1_instruction`]: https://docs.rs/solana-sdk/latest/solana_sdk/secp256k1_instruction/index.html
+//!
+//! This module also decodes the offsets of a secp256k1 program instruction,
+//! for programs that introspect one through the [instructions sysvar]. The
+//! instruction data starts with the number of signatures as a `u8`, followed
+//! by a [`Secp256k1SignatureOffsets`] entry per signature, packed without
+//! padding.
+//!
+//! [instructions sysvar]: crate::sysvar::instructions
+
+use crate::program_error::ProgramError;
 
 crate::declare_id!("KeccakSecp256k11111111111111111111111111111");
+
+pub const HASHED_PUBKEY_SERIALIZED_SIZE: usize = 20;
+pub const SIGNATURE_SERIALIZED_SIZE: usize = 64;
+pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+pub const SIGNATURE_OFFSETS_START: usize = 1;
+
+/// The offsets of a signature of a secp256k1 program instruction.
+///
+/// Unlike the ed25519 program, instruction indexes are `u8`s and always
+/// refer to an instruction of the transaction.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Secp256k1SignatureOffsets {
+    /// Offset to the 64-byte signature, followed by the 1-byte recovery ID
+    pub signature_offset: u16,
+    /// Index of the instruction holding the signature
+    pub signature_instruction_index: u8,
+    /// Offset to the 20-byte Ethereum address
+    pub eth_address_offset: u16,
+    /// Index of the instruction holding the Ethereum address
+    pub eth_address_instruction_index: u8,
+    /// Offset to the start of the message data
+    pub message_data_offset: u16,
+    /// Size of the message data
+    pub message_data_size: u16,
+    /// Index of the instruction holding the message data
+    pub message_instruction_index: u8,
+}
+
+impl Secp256k1SignatureOffsets {
+    /// Decode the offsets of the signature at `signature_index` of a
+    /// secp256k1 program instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if `signature_index` is out
+    /// of bounds, and [`ProgramError::InvalidInstructionData`] if the data is
+    /// too short to hold the offsets of every signature.
+    pub fn parse(instruction_data: &[u8], signature_index: u8) -> Result<Self, ProgramError> {
+        if signature_index >= num_signatures(instruction_data)? {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let start = usize::from(signature_index)
+            .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .saturating_add(SIGNATURE_OFFSETS_START);
+        let offsets = instruction_data
+            .get(start..start.saturating_add(SIGNATURE_OFFSETS_SERIALIZED_SIZE))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(Self {
+            signature_offset: u16::from_le_bytes([offsets[0], offsets[1]]),
+            signature_instruction_index: offsets[2],
+            eth_address_offset: u16::from_le_bytes([offsets[3], offsets[4]]),
+            eth_address_instruction_index: offsets[5],
+            message_data_offset: u16::from_le_bytes([offsets[6], offsets[7]]),
+            message_data_size: u16::from_le_bytes([offsets[8], offsets[9]]),
+            message_instruction_index: offsets[10],
+        })
+    }
+}
+
+/// Returns the number of signatures verified by a secp256k1 program
+/// instruction.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidInstructionData`] if the data is too short
+/// to hold the offsets of every signature.
+pub fn num_signatures(instruction_data: &[u8]) -> Result<u8, ProgramError> {
+    let num_signatures = *instruction_data
+        .first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let offsets_end = usize::from(num_signatures)
+        .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+        .saturating_add(SIGNATURE_OFFSETS_START);
+    if instruction_data.len() < offsets_end {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(num_signatures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_signature_offsets() {
+        let data = [
+            2, // number of signatures
+            12, 0, 0, 33, 0, 1, 200, 1, 5, 0, 2, // first signature
+            0xff, 0xff, 3, 4, 0, 5, 6, 0, 7, 0, 8, // second signature
+        ];
+
+        assert_eq!(num_signatures(&data), Ok(2));
+        assert_eq!(
+            Secp256k1SignatureOffsets::parse(&data, 0),
+            Ok(Secp256k1SignatureOffsets {
+                signature_offset: 12,
+                signature_instruction_index: 0,
+                eth_address_offset: 33,
+                eth_address_instruction_index: 1,
+                message_data_offset: 456,
+                message_data_size: 5,
+                message_instruction_index: 2,
+            })
+        );
+        assert_eq!(
+            Secp256k1SignatureOffsets::parse(&data, 1),
+            Ok(Secp256k1SignatureOffsets {
+                signature_offset: u16::MAX,
+                signature_instruction_index: 3,
+                eth_address_offset: 4,
+                eth_address_instruction_index: 5,
+                message_data_offset: 6,
+                message_data_size: 7,
+                message_instruction_index: 8,
+            })
+        );
+        assert_eq!(
+            Secp256k1SignatureOffsets::parse(&data, 2),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            Secp256k1SignatureOffsets::parse(&data[..data.len() - 1], 0),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            num_signatures(&[]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
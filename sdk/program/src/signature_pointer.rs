@@ -0,0 +1,425 @@
+//! Compact encoding of signature pointers for instruction data.
+//!
+//! A _signature pointer_ identifies the transaction that wrote a compressed
+//! record by its slot and signature, see [`compressed_record_proof`]. Stored
+//! as is, a pointer takes 72 bytes, while instruction data is the scarce
+//! resource that compression saves. The encoding in this module shrinks a
+//! pointer to a few bytes:
+//!
+//! - The slot is stored as its distance from a _reference slot_ that the
+//!   writer and the reader of the pointer both know, such as a base slot
+//!   stored in the program's account, or the slot of the previous pointer
+//!   when encoding a sequence of pointers. The distance is zigzag encoded,
+//!   so the slot may be on either side of the reference slot.
+//! - The signature is truncated to a prefix of between
+//!   [`MIN_SIGNATURE_PREFIX_LEN`] and [`SIGNATURE_LEN`] bytes.
+//! - The distance and the prefix length share a single LEB128 varint, whose
+//!   low 6 bits hold the prefix length minus one. A pointer within 1 slot of
+//!   its reference slot has a 1 byte varint, and one within 127 slots a
+//!   2 byte varint.
+//!
+//! # Collision policy
+//!
+//! A prefix identifies a signature only among the signatures of its slot.
+//! [`SignaturePointer::resolve`] fails with
+//! [`SignaturePointerError::Ambiguous`] when several signatures of the slot
+//! share the prefix, rather than pick one of them, so a pointer resolves to
+//! the same transaction for everyone or for no one. Writers pointing into a
+//! completed slot pick the prefix length with [`unique_prefix_len`]. Writers
+//! that can't know every signature of the slot yet rely on the minimum
+//! prefix length, which is long enough that a colliding signature can't be
+//! found by grinding transactions.
+//!
+//! [`compressed_record_proof`]: crate::compressed_record_proof
+
+#![allow(clippy::arithmetic_side_effects)]
+use crate::{
+    clock::Slot,
+    program_error::ProgramError,
+    sysvar::signatures::{TxSignature, SIGNATURE_LEN},
+};
+
+/// The shortest signature prefix a pointer holds.
+///
+/// Finding a transaction whose signature starts with a given 8 byte prefix
+/// takes about 2^64 attempts.
+pub const MIN_SIGNATURE_PREFIX_LEN: usize = 8;
+
+/// Number of low bits of the varint that hold the prefix length minus one.
+const PREFIX_LEN_BITS: u32 = 6;
+
+/// The largest distance between the slot of a pointer and its reference
+/// slot.
+pub const MAX_SLOT_DISTANCE: u64 = u64::MAX >> (PREFIX_LEN_BITS + 1);
+
+/// The longest varint of an encoded pointer.
+const MAX_VARINT_LEN: usize = 10;
+
+/// Error returned when encoding, decoding or resolving a signature pointer.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignaturePointerError {
+    /// The prefix length is not between [`MIN_SIGNATURE_PREFIX_LEN`] and
+    /// [`SIGNATURE_LEN`]
+    #[error("invalid signature prefix length {0}")]
+    InvalidPrefixLen(usize),
+    /// The slot is more than [`MAX_SLOT_DISTANCE`] slots away from the
+    /// reference slot
+    #[error("slot is too far from the reference slot")]
+    SlotTooFar,
+    /// The data ends before the end of the pointer
+    #[error("truncated signature pointer")]
+    Truncated,
+    /// The varint of the pointer is longer than a `u64`
+    #[error("invalid signature pointer varint")]
+    InvalidVarint,
+    /// No signature of the slot starts with the prefix
+    #[error("no signature matches the pointer")]
+    NotFound,
+    /// Several signatures of the slot start with the prefix
+    #[error("several signatures match the pointer")]
+    Ambiguous,
+}
+
+impl From<SignaturePointerError> for ProgramError {
+    fn from(_: SignaturePointerError) -> Self {
+        ProgramError::InvalidInstructionData
+    }
+}
+
+/// A decoded signature pointer, borrowing the signature prefix from the
+/// instruction data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignaturePointer<'a> {
+    /// The slot the transaction was committed in
+    pub slot: Slot,
+    /// The first bytes of the transaction's signature
+    pub signature_prefix: &'a [u8],
+}
+
+impl<'a> SignaturePointer<'a> {
+    /// Whether `signature` starts with the prefix of the pointer.
+    pub fn matches(&self, signature: &TxSignature) -> bool {
+        signature.as_ref().starts_with(self.signature_prefix)
+    }
+
+    /// Find the signature the pointer points to among the signatures of its
+    /// slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignaturePointerError::NotFound`] if no signature matches the prefix.
+    /// Returns [`SignaturePointerError::Ambiguous`] if several signatures match the prefix.
+    pub fn resolve<'s>(
+        &self,
+        slot_signatures: impl IntoIterator<Item = &'s TxSignature>,
+    ) -> Result<&'s TxSignature, SignaturePointerError> {
+        let mut matches = slot_signatures
+            .into_iter()
+            .filter(|signature| self.matches(signature));
+        let signature = matches.next().ok_or(SignaturePointerError::NotFound)?;
+        if matches.next().is_some() {
+            return Err(SignaturePointerError::Ambiguous);
+        }
+        Ok(signature)
+    }
+}
+
+/// The number of bytes [`encode_signature_pointer`] writes for a pointer into
+/// `slot` relative to `reference_slot`, holding `prefix_len` bytes of the
+/// signature.
+///
+/// # Errors
+///
+/// Returns [`SignaturePointerError::InvalidPrefixLen`] if `prefix_len` is out of range.
+/// Returns [`SignaturePointerError::SlotTooFar`] if `slot` is more than [`MAX_SLOT_DISTANCE`]
+/// slots away from `reference_slot`.
+pub fn signature_pointer_len(
+    reference_slot: Slot,
+    slot: Slot,
+    prefix_len: usize,
+) -> Result<usize, SignaturePointerError> {
+    let varint = pack(reference_slot, slot, prefix_len)?;
+    Ok(varint_len(varint) + prefix_len)
+}
+
+/// Append the compact encoding of a pointer to `signature` in `slot` to
+/// `data`, holding the first `prefix_len` bytes of the signature.
+///
+/// See the [module documentation](self) for the encoding.
+///
+/// # Errors
+///
+/// Returns [`SignaturePointerError::InvalidPrefixLen`] if `prefix_len` is out of range.
+/// Returns [`SignaturePointerError::SlotTooFar`] if `slot` is more than [`MAX_SLOT_DISTANCE`]
+/// slots away from `reference_slot`.
+pub fn encode_signature_pointer(
+    data: &mut Vec<u8>,
+    reference_slot: Slot,
+    slot: Slot,
+    signature: &TxSignature,
+    prefix_len: usize,
+) -> Result<(), SignaturePointerError> {
+    let mut varint = pack(reference_slot, slot, prefix_len)?;
+    data.reserve(varint_len(varint) + prefix_len);
+    while varint >= 0x80 {
+        data.push(varint as u8 | 0x80);
+        varint >>= 7;
+    }
+    data.push(varint as u8);
+    data.extend_from_slice(&signature.as_ref()[..prefix_len]);
+    Ok(())
+}
+
+/// Decode the pointer at the start of `data`, encoded relative to
+/// `reference_slot`, and return it with the bytes following it.
+///
+/// # Errors
+///
+/// Returns [`SignaturePointerError::Truncated`] if `data` ends before the end of the pointer.
+/// Returns [`SignaturePointerError::InvalidVarint`] if the varint is longer than a `u64`.
+/// Returns [`SignaturePointerError::InvalidPrefixLen`] if the prefix is shorter than
+/// [`MIN_SIGNATURE_PREFIX_LEN`].
+/// Returns [`SignaturePointerError::SlotTooFar`] if the slot is before the first slot or
+/// after the last one.
+pub fn decode_signature_pointer(
+    reference_slot: Slot,
+    data: &[u8],
+) -> Result<(SignaturePointer<'_>, &[u8]), SignaturePointerError> {
+    let mut varint = 0u64;
+    let mut header_len = 0;
+    loop {
+        let byte = *data
+            .get(header_len)
+            .ok_or(SignaturePointerError::Truncated)?;
+        // The 10th byte only holds the top bit of a u64
+        if header_len == MAX_VARINT_LEN - 1 && byte > 1 {
+            return Err(SignaturePointerError::InvalidVarint);
+        }
+        varint |= u64::from(byte & 0x7f) << (7 * header_len);
+        header_len += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    let prefix_len = (varint & ((1 << PREFIX_LEN_BITS) - 1)) as usize + 1;
+    if prefix_len < MIN_SIGNATURE_PREFIX_LEN {
+        return Err(SignaturePointerError::InvalidPrefixLen(prefix_len));
+    }
+    let zigzag = varint >> PREFIX_LEN_BITS;
+    let distance = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+    let slot = reference_slot
+        .checked_add_signed(distance)
+        .ok_or(SignaturePointerError::SlotTooFar)?;
+
+    let rest = &data[header_len..];
+    if rest.len() < prefix_len {
+        return Err(SignaturePointerError::Truncated);
+    }
+    let (signature_prefix, rest) = rest.split_at(prefix_len);
+    Ok((
+        SignaturePointer {
+            slot,
+            signature_prefix,
+        },
+        rest,
+    ))
+}
+
+/// The shortest prefix length, no shorter than [`MIN_SIGNATURE_PREFIX_LEN`],
+/// at which `signature` is the only one of `slot_signatures` to have its
+/// prefix.
+///
+/// `slot_signatures` are every signature of the slot, and may include
+/// `signature` itself, which is skipped.
+pub fn unique_prefix_len<'s>(
+    signature: &TxSignature,
+    slot_signatures: impl IntoIterator<Item = &'s TxSignature>,
+) -> usize {
+    slot_signatures
+        .into_iter()
+        .filter(|other| *other != signature)
+        .map(|other| {
+            let common_len = signature
+                .as_ref()
+                .iter()
+                .zip(other.as_ref())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (common_len + 1).min(SIGNATURE_LEN)
+        })
+        .fold(MIN_SIGNATURE_PREFIX_LEN, usize::max)
+}
+
+/// Pack the zigzag encoded slot distance and the prefix length into the
+/// varint value.
+fn pack(reference_slot: Slot, slot: Slot, prefix_len: usize) -> Result<u64, SignaturePointerError> {
+    if !(MIN_SIGNATURE_PREFIX_LEN..=SIGNATURE_LEN).contains(&prefix_len) {
+        return Err(SignaturePointerError::InvalidPrefixLen(prefix_len));
+    }
+    let distance = slot.abs_diff(reference_slot);
+    if distance > MAX_SLOT_DISTANCE {
+        return Err(SignaturePointerError::SlotTooFar);
+    }
+    let zigzag = if slot >= reference_slot {
+        distance * 2
+    } else {
+        distance * 2 - 1
+    };
+    Ok(zigzag << PREFIX_LEN_BITS | (prefix_len - 1) as u64)
+}
+
+fn varint_len(varint: u64) -> usize {
+    let bits = u64::BITS - (varint | 1).leading_zeros();
+    ((bits + 6) / 7) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature(prefix: &[u8]) -> TxSignature {
+        let mut signature = [0xaa; SIGNATURE_LEN];
+        signature[..prefix.len()].copy_from_slice(prefix);
+        TxSignature::from(signature)
+    }
+
+    #[test]
+    fn test_encode_decode_signature_pointer() {
+        let signature = signature(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let reference_slot = 1_000;
+        for (slot, prefix_len, expected_len) in [
+            (1_000, MIN_SIGNATURE_PREFIX_LEN, 1 + 8),
+            (999, MIN_SIGNATURE_PREFIX_LEN, 1 + 8),
+            (1_001, 16, 2 + 16),
+            (1_063, SIGNATURE_LEN, 2 + 64),
+            (937, SIGNATURE_LEN, 2 + 64),
+            (1_127, MIN_SIGNATURE_PREFIX_LEN, 2 + 8),
+            (1_128, MIN_SIGNATURE_PREFIX_LEN, 3 + 8),
+            (0, MIN_SIGNATURE_PREFIX_LEN, 3 + 8),
+            (reference_slot + MAX_SLOT_DISTANCE, SIGNATURE_LEN, 10 + 64),
+        ] {
+            let mut data = vec![];
+            encode_signature_pointer(&mut data, reference_slot, slot, &signature, prefix_len)
+                .unwrap();
+            assert_eq!(data.len(), expected_len, "slot {slot}");
+            assert_eq!(
+                signature_pointer_len(reference_slot, slot, prefix_len),
+                Ok(expected_len)
+            );
+
+            // Pointers can be followed by more data
+            data.push(42);
+            let (pointer, rest) = decode_signature_pointer(reference_slot, &data).unwrap();
+            assert_eq!(pointer.slot, slot);
+            assert_eq!(pointer.signature_prefix, &signature.as_ref()[..prefix_len]);
+            assert!(pointer.matches(&signature));
+            assert_eq!(rest, &[42]);
+
+            // The slot is decoded relative to the reference slot
+            let (pointer, _) = decode_signature_pointer(reference_slot + 1, &data).unwrap();
+            assert_eq!(pointer.slot, slot + 1);
+        }
+    }
+
+    #[test]
+    fn test_encode_signature_pointer_errors() {
+        let signature = signature(&[]);
+        let mut data = vec![];
+        for prefix_len in [0, MIN_SIGNATURE_PREFIX_LEN - 1, SIGNATURE_LEN + 1] {
+            assert_eq!(
+                encode_signature_pointer(&mut data, 0, 0, &signature, prefix_len),
+                Err(SignaturePointerError::InvalidPrefixLen(prefix_len))
+            );
+        }
+        for (reference_slot, slot) in [
+            (0, MAX_SLOT_DISTANCE + 1),
+            (MAX_SLOT_DISTANCE + 1, 0),
+            (0, Slot::MAX),
+            (Slot::MAX, 0),
+        ] {
+            assert_eq!(
+                encode_signature_pointer(&mut data, reference_slot, slot, &signature, 8),
+                Err(SignaturePointerError::SlotTooFar)
+            );
+            assert_eq!(
+                signature_pointer_len(reference_slot, slot, 8),
+                Err(SignaturePointerError::SlotTooFar)
+            );
+        }
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_decode_signature_pointer_errors() {
+        let signature = signature(&[]);
+        let mut data = vec![];
+        encode_signature_pointer(&mut data, 10, 5, &signature, 8).unwrap();
+        for len in 0..data.len() {
+            assert_eq!(
+                decode_signature_pointer(10, &data[..len]),
+                Err(SignaturePointerError::Truncated)
+            );
+        }
+
+        // The slot must be a valid slot relative to the reference slot
+        assert_eq!(
+            decode_signature_pointer(4, &data),
+            Err(SignaturePointerError::SlotTooFar)
+        );
+
+        // Prefixes shorter than the minimum are rejected
+        let mut short_prefix = vec![MIN_SIGNATURE_PREFIX_LEN as u8 - 2];
+        short_prefix.extend_from_slice(&[0; SIGNATURE_LEN]);
+        assert_eq!(
+            decode_signature_pointer(0, &short_prefix),
+            Err(SignaturePointerError::InvalidPrefixLen(
+                MIN_SIGNATURE_PREFIX_LEN - 1
+            ))
+        );
+
+        // Varints longer than a u64
+        let mut overlong = vec![0xff; MAX_VARINT_LEN - 1];
+        overlong.push(2);
+        assert_eq!(
+            decode_signature_pointer(0, &overlong),
+            Err(SignaturePointerError::InvalidVarint)
+        );
+        assert_eq!(
+            decode_signature_pointer(0, &[0xff; MAX_VARINT_LEN + 1]),
+            Err(SignaturePointerError::InvalidVarint)
+        );
+    }
+
+    #[test]
+    fn test_resolve_signature_pointer() {
+        let target = signature(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let sibling = signature(&[1, 2, 3, 4, 5, 6, 7, 8, 10]);
+        let other = signature(&[2]);
+        let slot_signatures = [other, sibling, target];
+
+        // The shared 8 byte prefix takes a 9th byte to tell them apart
+        let prefix_len = unique_prefix_len(&target, &slot_signatures);
+        assert_eq!(prefix_len, 9);
+        assert_eq!(unique_prefix_len(&other, &slot_signatures), 8);
+        assert_eq!(unique_prefix_len(&target, &[target, target]), 8);
+
+        let mut data = vec![];
+        encode_signature_pointer(&mut data, 7, 7, &target, prefix_len).unwrap();
+        let (pointer, _) = decode_signature_pointer(7, &data).unwrap();
+        assert_eq!(pointer.resolve(&slot_signatures), Ok(&target));
+
+        // A prefix shared by several signatures is ambiguous
+        let mut data = vec![];
+        encode_signature_pointer(&mut data, 7, 7, &target, 8).unwrap();
+        let (pointer, _) = decode_signature_pointer(7, &data).unwrap();
+        assert_eq!(
+            pointer.resolve(&slot_signatures),
+            Err(SignaturePointerError::Ambiguous)
+        );
+        assert_eq!(
+            pointer.resolve(&[other]),
+            Err(SignaturePointerError::NotFound)
+        );
+    }
+}
@@ -1,5 +1,9 @@
 use crate::{
-    instruction::{AccountMeta, ProcessedSiblingInstruction},
+    instruction::{
+        AccountMeta, ProcessedSiblingInstruction, ProcessedSiblingInstructionProvenance,
+        ProcessedSiblingInstructionSysvarAccess,
+    },
+    nonce::TransactionNonceInfo,
     pubkey::Pubkey,
 };
 
@@ -55,10 +59,13 @@ define_syscall!(fn sol_memcpy_(dst: *mut u8, src: *const u8, n: u64));
 define_syscall!(fn sol_memmove_(dst: *mut u8, src: *const u8, n: u64));
 define_syscall!(fn sol_memcmp_(s1: *const u8, s2: *const u8, n: u64, result: *mut i32));
 define_syscall!(fn sol_memset_(s: *mut u8, c: u8, n: u64));
+define_syscall!(fn sol_memcmp_ct_(s1: *const u8, s2: *const u8, n: u64) -> u64);
 define_syscall!(fn sol_invoke_signed_c(instruction_addr: *const u8, account_infos_addr: *const u8, account_infos_len: u64, signers_seeds_addr: *const u8, signers_seeds_len: u64) -> u64);
 define_syscall!(fn sol_invoke_signed_rust(instruction_addr: *const u8, account_infos_addr: *const u8, account_infos_len: u64, signers_seeds_addr: *const u8, signers_seeds_len: u64) -> u64);
 define_syscall!(fn sol_set_return_data(data: *const u8, length: u64));
 define_syscall!(fn sol_get_return_data(data: *mut u8, length: u64, program_id: *mut Pubkey) -> u64);
+define_syscall!(fn sol_tx_scratch_write(data: *const u8, length: u64));
+define_syscall!(fn sol_tx_scratch_read(program_id: *const Pubkey, data: *mut u8, length: u64) -> u64);
 define_syscall!(fn sol_log_data(data: *const u8, data_len: u64));
 define_syscall!(fn sol_get_processed_sibling_instruction(index: u64, meta: *mut ProcessedSiblingInstruction, program_id: *mut Pubkey, data: *mut u8, accounts: *mut AccountMeta) -> u64);
 define_syscall!(fn sol_get_stack_height() -> u64);
@@ -72,6 +79,16 @@ define_syscall!(fn sol_get_epoch_rewards_sysvar(addr: *mut u8) -> u64);
 define_syscall!(fn sol_poseidon(parameters: u64, endianness: u64, vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64);
 define_syscall!(fn sol_remaining_compute_units() -> u64);
 define_syscall!(fn sol_alt_bn128_compression(op: u64, input: *const u8, input_size: u64, result: *mut u8) -> u64);
+define_syscall!(fn sol_base58_encode(input: *const u8, input_len: u64, output: *mut u8, output_len: u64, written: *mut u64) -> u64);
+define_syscall!(fn sol_base58_decode(input: *const u8, input_len: u64, output: *mut u8, output_len: u64, written: *mut u64) -> u64);
+define_syscall!(fn sol_get_processed_sibling_instruction_provenance(index: u64, provenance: *mut ProcessedSiblingInstructionProvenance) -> u64);
+define_syscall!(fn sol_get_processed_sibling_instruction_sysvar_access(index: u64, access: *mut ProcessedSiblingInstructionSysvarAccess) -> u64);
+define_syscall!(fn sol_get_instructions_sysvar_slice(offset: u64, length: u64, addr: *mut u8) -> u64);
+define_syscall!(fn sol_is_verified(precompile_id: *const Pubkey, signature: *const u8, pubkey: *const u8, pubkey_len: u64, message_hash: *const u8) -> u64);
+define_syscall!(fn sol_get_transaction_signature(index: u64, signature: *mut u8) -> u64);
+define_syscall!(fn sol_ed25519_verify(pubkey: *const u8, message: *const u8, message_len: u64, signature: *const u8) -> u64);
+define_syscall!(fn sol_get_transaction_nonce_info(nonce_info: *mut TransactionNonceInfo) -> u64);
+define_syscall!(fn sol_get_serialized_message(data: *mut u8, length: u64) -> u64);
 
 #[cfg(target_feature = "static-syscalls")]
 pub const fn sys_hash(name: &str) -> usize {
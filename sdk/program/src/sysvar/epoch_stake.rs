@@ -0,0 +1,249 @@
+//! The activated stake of the current epoch.
+//!
+//! The _epoch stake sysvar_ provides access to the total activated stake of
+//! the current epoch, along with the activated stake delegated to each vote
+//! account. This allows staking and governance programs to weigh votes by
+//! stake on-chain without relying on oracle accounts.
+//!
+//! Similar to the signatures sysvar, data in the epoch stake sysvar is not
+//! accessed through a type that implements the [`Sysvar`] trait. Instead, the
+//! epoch stake sysvar is accessed through several free functions within this
+//! module.
+//!
+//! [`Sysvar`]: crate::sysvar::Sysvar
+//!
+//! The account data is laid out as:
+//!
+//! - `[0..8]`: total activated stake, `u64` little-endian
+//! - `[8..12]`: number of vote account entries, `u32` little-endian
+//! - followed by that many entries of 40 bytes each, sorted by vote account
+//!   address: the 32-byte vote account address followed by its activated
+//!   stake as a `u64` little-endian.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+#[cfg(not(target_os = "solana"))]
+use crate::serialize_utils::append_slice;
+use crate::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, sanitize::SanitizeError,
+};
+
+/// Epoch stake sysvar, dummy type.
+///
+/// This type exists for consistency with other sysvar modules, but is a dummy
+/// type that does not contain sysvar data. It implements the [`SysvarId`] trait
+/// but does not implement the [`Sysvar`] trait.
+///
+/// [`SysvarId`]: crate::sysvar::SysvarId
+/// [`Sysvar`]: crate::sysvar::Sysvar
+///
+/// Use the free functions in this module to access the epoch stake sysvar.
+pub struct EpochStake();
+
+crate::declare_sysvar_id!("SysvarEpochStake111111111111111111111111111", EpochStake);
+
+const TOTAL_STAKE_LEN: usize = 8;
+const NUM_ENTRIES_LEN: usize = 4;
+const HEADER_LEN: usize = TOTAL_STAKE_LEN + NUM_ENTRIES_LEN;
+const ENTRY_LEN: usize = 32 + 8;
+
+/// Construct the account data for the epoch stake sysvar.
+///
+/// `vote_account_stakes` does not need to be sorted, entries are sorted by
+/// vote account address so that programs can look them up by binary search.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_epoch_stake_data(
+    total_stake: u64,
+    vote_account_stakes: &[(Pubkey, u64)],
+) -> Vec<u8> {
+    let mut entries = vote_account_stakes.to_vec();
+    entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut data = Vec::with_capacity(HEADER_LEN + entries.len() * ENTRY_LEN);
+    append_slice(&mut data, &total_stake.to_le_bytes());
+    append_slice(&mut data, &(entries.len() as u32).to_le_bytes());
+    for (vote_pubkey, stake) in entries {
+        append_slice(&mut data, vote_pubkey.as_ref());
+        append_slice(&mut data, &stake.to_le_bytes());
+    }
+    data
+}
+
+/// Load the total activated stake of the current epoch.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+pub fn load_total_stake_checked(
+    epoch_stake_sysvar_account_info: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    if !check_id(epoch_stake_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let epoch_stake_sysvar = epoch_stake_sysvar_account_info.try_borrow_data()?;
+    deserialize_total_stake(&epoch_stake_sysvar).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Load the activated stake delegated to the given vote account in the
+/// current epoch.
+///
+/// Returns zero if the vote account has no activated stake in the current
+/// epoch.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+pub fn load_vote_account_stake_checked(
+    vote_pubkey: &Pubkey,
+    epoch_stake_sysvar_account_info: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    if !check_id(epoch_stake_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let epoch_stake_sysvar = epoch_stake_sysvar_account_info.try_borrow_data()?;
+    deserialize_vote_account_stake(vote_pubkey, &epoch_stake_sysvar)
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+fn deserialize_total_stake(data: &[u8]) -> Result<u64, SanitizeError> {
+    let bytes = data
+        .get(..TOTAL_STAKE_LEN)
+        .ok_or(SanitizeError::IndexOutOfBounds)?;
+    let mut total_stake = [0u8; TOTAL_STAKE_LEN];
+    total_stake.copy_from_slice(bytes);
+    Ok(u64::from_le_bytes(total_stake))
+}
+
+fn deserialize_vote_account_stake(vote_pubkey: &Pubkey, data: &[u8]) -> Result<u64, SanitizeError> {
+    let num_entries = data
+        .get(TOTAL_STAKE_LEN..HEADER_LEN)
+        .ok_or(SanitizeError::IndexOutOfBounds)?;
+    let mut num_entries_bytes = [0u8; NUM_ENTRIES_LEN];
+    num_entries_bytes.copy_from_slice(num_entries);
+    let num_entries = u32::from_le_bytes(num_entries_bytes) as usize;
+
+    // Ensure the data actually holds every entry the header claims
+    let entries = data
+        .get(HEADER_LEN..HEADER_LEN + num_entries * ENTRY_LEN)
+        .ok_or(SanitizeError::IndexOutOfBounds)?;
+
+    // Entries are sorted by vote account address
+    let (mut low, mut high) = (0, num_entries);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let entry = &entries[mid * ENTRY_LEN..(mid + 1) * ENTRY_LEN];
+        match entry[..32].cmp(vote_pubkey.as_ref()) {
+            core::cmp::Ordering::Less => low = mid + 1,
+            core::cmp::Ordering::Greater => high = mid,
+            core::cmp::Ordering::Equal => {
+                let mut stake = [0u8; 8];
+                stake.copy_from_slice(&entry[32..]);
+                return Ok(u64::from_le_bytes(stake));
+            }
+        }
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::clock::Epoch};
+
+    #[test]
+    fn test_construct_epoch_stake_data() {
+        let vote_a = Pubkey::from([2; 32]);
+        let vote_b = Pubkey::from([1; 32]);
+        let data = construct_epoch_stake_data(300, &[(vote_a, 200), (vote_b, 100)]);
+
+        let mut expected_data = vec![];
+        expected_data.extend_from_slice(&300u64.to_le_bytes());
+        expected_data.extend_from_slice(&2u32.to_le_bytes());
+        expected_data.extend_from_slice(vote_b.as_ref());
+        expected_data.extend_from_slice(&100u64.to_le_bytes());
+        expected_data.extend_from_slice(vote_a.as_ref());
+        expected_data.extend_from_slice(&200u64.to_le_bytes());
+
+        assert_eq!(data, expected_data);
+    }
+
+    #[test]
+    fn test_load_epoch_stake_checked() {
+        let vote_pubkeys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let vote_account_stakes: Vec<(Pubkey, u64)> = vote_pubkeys
+            .iter()
+            .enumerate()
+            .map(|(i, vote_pubkey)| (*vote_pubkey, (i as u64 + 1) * 1_000))
+            .collect();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let mut data = construct_epoch_stake_data(15_000, &vote_account_stakes);
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        assert_eq!(load_total_stake_checked(&account_info), Ok(15_000));
+        for (vote_pubkey, stake) in &vote_account_stakes {
+            assert_eq!(
+                load_vote_account_stake_checked(vote_pubkey, &account_info),
+                Ok(*stake)
+            );
+        }
+        assert_eq!(
+            load_vote_account_stake_checked(&Pubkey::new_unique(), &account_info),
+            Ok(0)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_total_stake_checked(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            load_vote_account_stake_checked(&vote_pubkeys[0], &account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_load_epoch_stake_truncated() {
+        let vote_pubkey = Pubkey::new_unique();
+        let mut data = construct_epoch_stake_data(1, &[(vote_pubkey, 1)]);
+        data.truncate(data.len() - 1);
+        assert_eq!(
+            deserialize_vote_account_stake(&vote_pubkey, &data),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            deserialize_total_stake(&[0; 7]),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+    }
+
+    // Only the entries counted by the header are searched, so trailing bytes
+    // are never read
+    sysvar_spoofing_tests! {
+        data: construct_epoch_stake_data(300, &[
+            (Pubkey::from([1; 32]), 100),
+            (Pubkey::from([2; 32]), 200),
+        ]),
+        loaders: {
+            load_total_stake_checked: load_total_stake_checked => oversized: Ok(300),
+            load_vote_account_stake_checked: |account_info| {
+                load_vote_account_stake_checked(&Pubkey::from([2; 32]), account_info)
+            } => oversized: Ok(200),
+        },
+    }
+}
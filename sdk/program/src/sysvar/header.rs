@@ -0,0 +1,795 @@
+//! Information about the message of the current transaction.
+//!
+//! The _header sysvar_ provides access to properties of the
+//! currently-running transaction's message that are not visible through its
+//! instructions: the version of the message and its [`MessageHeader`].
+//! Programs that introspect the transaction, e.g. through the [signatures
+//! sysvar], can use it to tell whether the message may load accounts from
+//! address lookup tables, and which of its accounts are signers.
+//!
+//! [signatures sysvar]: crate::sysvar::signatures
+//!
+//! Like the instructions and signatures sysvars, the header sysvar is
+//! constructed by the runtime for each transaction that references it, and
+//! is accessed through the free functions within this module rather than
+//! through the [`Sysvar`] trait.
+//!
+//! [`Sysvar`]: crate::sysvar::Sysvar
+//!
+//! The sysvar data starts with a byte holding the version of the message,
+//! [`LEGACY_TRANSACTION_VERSION`] for legacy messages, followed by the
+//! [`FreshnessTag`] of the transaction. When the
+//! `message_header_in_header_sysvar` feature is active, the tag is followed
+//! by the three bytes of the [`MessageHeader`], which [`load_header_checked`]
+//! returns along with the version.
+//!
+//! # Signer layout
+//!
+//! The first `num_required_signatures` static account keys of the message
+//! are its signers, in the order of the signatures in the signatures sysvar,
+//! the last `num_readonly_signed_accounts` of which are read-only. The last
+//! `num_readonly_unsigned_accounts` of the remaining static account keys are
+//! read-only. Accounts a v0 message loads from address lookup tables follow
+//! the static keys and are never signers; the message header doesn't count
+//! them.
+//!
+//! [`signer_account_info_at`] combines the header with the signer keys of the
+//! signatures sysvar to find the account of a signer among an instruction's
+//! accounts, and [`is_fee_payer`] to tell whether an account is the fee payer.
+//! The fee payer is the first signer of the message, which programs can't
+//! infer from the order of an instruction's accounts.
+//!
+//! # Freshness
+//!
+//! The freshness tag records the slot the transaction executes in and the
+//! hash of its message, and lets programs detect header data that wasn't
+//! constructed by the runtime for the currently executing transaction, e.g.
+//! an account with the sysvar's address but data captured from a previous
+//! transaction and passed back in a simulation. [`check_freshness`] compares
+//! the tag with the current slot, which programs get from the [`Clock`]
+//! sysvar.
+//!
+//! The index of a transaction within its block isn't known when the leader
+//! executes it, so the tag identifies the transaction by its message hash,
+//! which is the same at execution and replay.
+//!
+//! # Transaction position
+//!
+//! The header sysvar deliberately doesn't expose the position of the
+//! transaction within its entry or block, nor the number of transactions in
+//! the block. The leader executes a transaction before it knows which entry
+//! the transaction will be recorded in, and transactions of the same batch
+//! that fail to be recorded are dropped from the entry, so the position is
+//! only known at replay. Sysvar data that differed between the leader and
+//! the validators replaying its block would let programs behave differently
+//! on each, and fork the cluster, so the runtime can't fill in the position
+//! at replay either, not even alongside an "unavailable" marker written by
+//! the leader.
+//!
+//! Compression pointers should therefore identify a transaction by its slot
+//! and signature, or by the message hash of the [`FreshnessTag`], which
+//! programs and provers see alike. Provers that need the position within the
+//! slot can read it from the ledger once the block is recorded, e.g. with
+//! `solana-ledger-tool create-introspection-snapshot`.
+//!
+//! [`Clock`]: crate::clock::Clock
+
+use crate::{
+    account_info::AccountInfo, clock::Slot, hash::Hash, message::MessageHeader, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::signatures,
+};
+#[cfg(not(target_os = "solana"))]
+use crate::{
+    message::SanitizedMessage,
+    sanitize::SanitizeError,
+    serialize_utils::{append_slice, append_u8},
+};
+
+/// Header sysvar, dummy type.
+///
+/// This type exists for consistency with other sysvar modules, but is a dummy
+/// type that does not contain sysvar data. It implements the [`SysvarId`] trait
+/// but does not implement the [`Sysvar`] trait.
+///
+/// [`SysvarId`]: crate::sysvar::SysvarId
+/// [`Sysvar`]: crate::sysvar::Sysvar
+///
+/// Use the free functions in this module to access the header sysvar.
+pub struct Header();
+
+crate::declare_sysvar_id!("SysvarHeader1111111111111111111111111111111", Header);
+
+/// Value of the transaction version byte for legacy messages.
+pub const LEGACY_TRANSACTION_VERSION: u8 = u8::MAX;
+
+/// Length in bytes of the transaction version that starts the header sysvar.
+pub const TRANSACTION_VERSION_LEN: usize = 1;
+
+/// Length in bytes of the slot of the freshness tag.
+pub const FRESHNESS_SLOT_LEN: usize = 8;
+
+/// Length in bytes of the message hash of the freshness tag.
+pub const FRESHNESS_MESSAGE_HASH_LEN: usize = 32;
+
+/// Byte offset of the freshness tag within the header sysvar data.
+pub const FRESHNESS_TAG_OFFSET: usize = TRANSACTION_VERSION_LEN;
+
+/// Length in bytes of the header sysvar data.
+pub const HEADER_LEN: usize =
+    FRESHNESS_TAG_OFFSET + FRESHNESS_SLOT_LEN + FRESHNESS_MESSAGE_HASH_LEN;
+
+/// Byte offset of the message header within the header sysvar data, when
+/// the sysvar includes it.
+pub const MESSAGE_HEADER_OFFSET: usize = HEADER_LEN;
+
+/// Length in bytes of the message header: the number of required signatures
+/// and the numbers of read-only signed and unsigned accounts.
+pub const MESSAGE_HEADER_LEN: usize = 3;
+
+/// Length in bytes of the header sysvar data, when it includes the message
+/// header.
+pub const HEADER_WITH_MESSAGE_HEADER_LEN: usize = MESSAGE_HEADER_OFFSET + MESSAGE_HEADER_LEN;
+
+/// Identifies the transaction the runtime constructed the header sysvar for.
+///
+/// See the [module documentation](self#freshness).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FreshnessTag {
+    /// The slot the transaction executes in
+    pub slot: Slot,
+    /// The hash of the transaction's message
+    pub message_hash: Hash,
+}
+
+/// The version of the currently executing `Transaction`'s message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionVersion {
+    /// A legacy message, which can't load accounts from address lookup
+    /// tables
+    Legacy,
+    /// A versioned message, e.g. `Number(0)` for a v0 message
+    Number(u8),
+}
+
+impl From<u8> for TransactionVersion {
+    fn from(version: u8) -> Self {
+        match version {
+            LEGACY_TRANSACTION_VERSION => Self::Legacy,
+            version => Self::Number(version),
+        }
+    }
+}
+
+/// The version and [`MessageHeader`] of the currently executing
+/// `Transaction`'s message, as returned by [`load_header_checked`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransactionHeader {
+    pub version: TransactionVersion,
+    pub message_header: MessageHeader,
+}
+
+impl From<TransactionVersion> for u8 {
+    fn from(version: TransactionVersion) -> Self {
+        match version {
+            TransactionVersion::Legacy => LEGACY_TRANSACTION_VERSION,
+            TransactionVersion::Number(version) => version,
+        }
+    }
+}
+
+/// The contents of the header sysvar, as decoded by clients and tests.
+#[cfg(not(target_os = "solana"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeaderData {
+    pub version: TransactionVersion,
+    pub freshness_tag: FreshnessTag,
+    /// `None` if the sysvar doesn't include the message header
+    pub message_header: Option<MessageHeader>,
+}
+
+#[cfg(not(target_os = "solana"))]
+impl HeaderData {
+    /// Decode the account data of the header sysvar.
+    pub fn deserialize(data: &[u8]) -> Result<Self, SanitizeError> {
+        let message_header = match data.len() {
+            HEADER_LEN => None,
+            HEADER_WITH_MESSAGE_HEADER_LEN => Some(MessageHeader {
+                num_required_signatures: data[MESSAGE_HEADER_OFFSET],
+                num_readonly_signed_accounts: data[MESSAGE_HEADER_OFFSET + 1],
+                num_readonly_unsigned_accounts: data[MESSAGE_HEADER_OFFSET + 2],
+            }),
+            len if len < HEADER_LEN => return Err(SanitizeError::IndexOutOfBounds),
+            _ => return Err(SanitizeError::InvalidValue),
+        };
+        let (slot, message_hash) =
+            data[FRESHNESS_TAG_OFFSET..HEADER_LEN].split_at(FRESHNESS_SLOT_LEN);
+        Ok(Self {
+            version: TransactionVersion::from(data[0]),
+            freshness_tag: FreshnessTag {
+                slot: Slot::from_le_bytes(slot.try_into().unwrap()),
+                message_hash: Hash::new(message_hash),
+            },
+            message_header,
+        })
+    }
+}
+
+/// Construct the account data for the header sysvar.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_header_data(message: &SanitizedMessage, freshness_tag: &FreshnessTag) -> Vec<u8> {
+    let version = match message {
+        SanitizedMessage::Legacy(_) => TransactionVersion::Legacy,
+        SanitizedMessage::V0(_) => TransactionVersion::Number(0),
+    };
+    let mut data = Vec::with_capacity(HEADER_LEN);
+    append_u8(&mut data, version.into());
+    append_slice(&mut data, &freshness_tag.slot.to_le_bytes());
+    append_slice(&mut data, freshness_tag.message_hash.as_ref());
+    data
+}
+
+/// Construct the account data for the header sysvar, followed by the header
+/// of `message`.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_header_data_with_message_header(
+    message: &SanitizedMessage,
+    freshness_tag: &FreshnessTag,
+) -> Vec<u8> {
+    let mut data = construct_header_data(message, freshness_tag);
+    let message_header = message.header();
+    data.reserve(MESSAGE_HEADER_LEN);
+    append_u8(&mut data, message_header.num_required_signatures);
+    append_u8(&mut data, message_header.num_readonly_signed_accounts);
+    append_u8(&mut data, message_header.num_readonly_unsigned_accounts);
+    data
+}
+
+/// Load the version of the currently executing `Transaction`'s message.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is not a complete header.
+pub fn load_transaction_version(
+    header_sysvar_account_info: &AccountInfo,
+) -> Result<TransactionVersion, ProgramError> {
+    if !check_id(header_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let header_sysvar = header_sysvar_account_info.try_borrow_data()?;
+    if header_sysvar.len() < HEADER_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(TransactionVersion::from(header_sysvar[0]))
+}
+
+/// Load the version and [`MessageHeader`] of the currently executing
+/// `Transaction`'s message.
+///
+/// See the [module documentation](self#signer-layout) for how the message
+/// header describes the signers.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data doesn't include the message
+/// header, because the `message_header_in_header_sysvar` feature is not active.
+pub fn load_header_checked(
+    header_sysvar_account_info: &AccountInfo,
+) -> Result<TransactionHeader, ProgramError> {
+    if !check_id(header_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let header_sysvar = header_sysvar_account_info.try_borrow_data()?;
+    let message_header = header_sysvar
+        .get(MESSAGE_HEADER_OFFSET..HEADER_WITH_MESSAGE_HEADER_LEN)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(TransactionHeader {
+        version: TransactionVersion::from(header_sysvar[0]),
+        message_header: MessageHeader {
+            num_required_signatures: message_header[0],
+            num_readonly_signed_accounts: message_header[1],
+            num_readonly_unsigned_accounts: message_header[2],
+        },
+    })
+}
+
+/// Load the [`FreshnessTag`] of the header sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is not a complete header.
+pub fn load_freshness_tag_checked(
+    header_sysvar_account_info: &AccountInfo,
+) -> Result<FreshnessTag, ProgramError> {
+    if !check_id(header_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let header_sysvar = header_sysvar_account_info.try_borrow_data()?;
+    let tag = header_sysvar
+        .get(FRESHNESS_TAG_OFFSET..HEADER_LEN)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let (slot, message_hash) = tag.split_at(FRESHNESS_SLOT_LEN);
+    Ok(FreshnessTag {
+        slot: Slot::from_le_bytes(slot.try_into().unwrap()),
+        message_hash: Hash::new(message_hash),
+    })
+}
+
+/// Check that the header sysvar was constructed for a transaction executing in
+/// `current_slot`, and return its [`FreshnessTag`].
+///
+/// Programs get the current slot from the [`Clock`] sysvar.
+///
+/// [`Clock`]: crate::clock::Clock
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is not a complete header, or
+/// if it was constructed in another slot, after logging both slots.
+pub fn check_freshness(
+    header_sysvar_account_info: &AccountInfo,
+    current_slot: Slot,
+) -> Result<FreshnessTag, ProgramError> {
+    let tag = load_freshness_tag_checked(header_sysvar_account_info)?;
+    if tag.slot != current_slot {
+        msg!(
+            "Stale header sysvar: constructed in slot {} but the current slot is {}",
+            tag.slot,
+            current_slot
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(tag)
+}
+
+/// Find the [`AccountInfo`] of the transaction signer at `index` among the
+/// instruction's `account_infos`.
+///
+/// Signers are indexed as in the signatures sysvar, the fee payer first.
+/// `account_infos` must include the header sysvar, which bounds `index` by the
+/// number of signers, and the signatures sysvar, which holds the key of each
+/// signer. Returns `None` if the signer wasn't passed to the instruction.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::NotEnoughAccountKeys`] if either sysvar is missing from
+/// `account_infos`.
+/// Returns [`ProgramError::InvalidArgument`] if `index` is not the index of a signer.
+/// Returns [`ProgramError::InvalidAccountData`] if the header sysvar doesn't include the message
+/// header, or the signatures sysvar doesn't include signers, because the
+/// `message_header_in_header_sysvar` or `signer_pubkeys_in_signatures_sysvar` feature is not
+/// active.
+pub fn signer_account_info_at<'a, 'b>(
+    index: usize,
+    account_infos: &'b [AccountInfo<'a>],
+) -> Result<Option<&'b AccountInfo<'a>>, ProgramError> {
+    let find_sysvar = |id: &Pubkey| {
+        account_infos
+            .iter()
+            .find(|account_info| account_info.key == id)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+    let header = load_header_checked(find_sysvar(&ID)?)?;
+    if index >= header.message_header.num_required_signatures as usize {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let (signer, _) =
+        signatures::load_signer_and_signature_at_checked(index, find_sysvar(&signatures::ID)?)?;
+    Ok(account_infos
+        .iter()
+        .find(|account_info| account_info.key == &signer))
+}
+
+/// Whether `account_info` is the fee payer of the currently executing
+/// transaction.
+///
+/// The fee payer is the first signer of the message. The header sysvar
+/// doesn't hold account keys, so the key of the fee payer is read from the
+/// signatures sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if either sysvar account's ID is not the expected
+/// one.
+/// Returns [`ProgramError::InvalidAccountData`] if the header sysvar doesn't include the message
+/// header, or the signatures sysvar doesn't include signers, because the
+/// `message_header_in_header_sysvar` or `signer_pubkeys_in_signatures_sysvar` feature is not
+/// active.
+pub fn is_fee_payer(
+    account_info: &AccountInfo,
+    header_sysvar_account_info: &AccountInfo,
+    signatures_sysvar_account_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    let header = load_header_checked(header_sysvar_account_info)?;
+    if header.message_header.num_required_signatures == 0 {
+        return Ok(false);
+    }
+    let (fee_payer, _) =
+        signatures::load_signer_and_signature_at_checked(0, signatures_sysvar_account_info)?;
+    Ok(account_info.key == &fee_payer)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::Epoch,
+            hash::Hash,
+            message::{
+                v0::{self, LoadedAddresses},
+                LegacyMessage, Message,
+            },
+            pubkey::Pubkey,
+        },
+    };
+
+    #[test]
+    fn test_load_transaction_version() {
+        let payer = Pubkey::new_unique();
+        let legacy_message =
+            SanitizedMessage::Legacy(LegacyMessage::new(Message::new(&[], Some(&payer))));
+        let v0_message = SanitizedMessage::V0(v0::LoadedMessage::new(
+            v0::Message::try_compile(&payer, &[], &[], Hash::default()).unwrap(),
+            LoadedAddresses::default(),
+        ));
+
+        for (message, expected_version) in [
+            (legacy_message, TransactionVersion::Legacy),
+            (v0_message, TransactionVersion::Number(0)),
+        ] {
+            let mut data = construct_header_data(&message, &FreshnessTag::default());
+            assert_eq!(data.len(), HEADER_LEN);
+            let owner = crate::sysvar::id();
+            let mut lamports = 0;
+            let key = id();
+            let mut account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                Epoch::default(),
+            );
+            assert_eq!(
+                load_transaction_version(&account_info),
+                Ok(expected_version)
+            );
+
+            let key = Pubkey::new_unique();
+            account_info.key = &key;
+            assert_eq!(
+                load_transaction_version(&account_info),
+                Err(ProgramError::UnsupportedSysvar)
+            );
+        }
+
+        let mut data = vec![TransactionVersion::Legacy.into()];
+        let mut lamports = 0;
+        let owner = crate::sysvar::id();
+        let account_info = AccountInfo::new(
+            &ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            load_transaction_version(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            load_freshness_tag_checked(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_load_header_checked() {
+        let payer = Pubkey::new_unique();
+        let cosigner = Pubkey::new_unique();
+        let instruction = crate::instruction::Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![
+                crate::instruction::AccountMeta::new_readonly(cosigner, true),
+                crate::instruction::AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+        );
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(
+            &[instruction],
+            Some(&payer),
+        )));
+        let mut data =
+            construct_header_data_with_message_header(&message, &FreshnessTag::default());
+        assert_eq!(data.len(), HEADER_WITH_MESSAGE_HEADER_LEN);
+        let mut legacy_data = construct_header_data(&message, &FreshnessTag::default());
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        let expected_header = TransactionHeader {
+            version: TransactionVersion::Legacy,
+            message_header: MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 1,
+                num_readonly_unsigned_accounts: 2,
+            },
+        };
+        assert_eq!(*message.header(), expected_header.message_header);
+        assert_eq!(load_header_checked(&account_info), Ok(expected_header));
+        // The message header doesn't change where the other fields are
+        assert_eq!(
+            load_transaction_version(&account_info),
+            Ok(TransactionVersion::Legacy)
+        );
+        assert_eq!(
+            load_freshness_tag_checked(&account_info),
+            Ok(FreshnessTag::default())
+        );
+
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut legacy_data[..]));
+        assert_eq!(
+            load_header_checked(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_header_checked(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_signer_account_info_at() {
+        let payer = Pubkey::new_unique();
+        let cosigner = Pubkey::new_unique();
+        let instruction = crate::instruction::Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![crate::instruction::AccountMeta::new_readonly(
+                cosigner, true,
+            )],
+        );
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(
+            &[instruction],
+            Some(&payer),
+        )));
+        let mut header_data =
+            construct_header_data_with_message_header(&message, &FreshnessTag::default());
+        let signatures = [[1; 64], [2; 64]];
+        let mut signatures_data = signatures::construct_signer_signatures_data(
+            [&payer, &cosigner].into_iter().zip(signatures.iter()),
+            None,
+        )
+        .unwrap();
+        let owner = crate::sysvar::id();
+        let (mut lamports, mut signatures_lamports, mut cosigner_lamports) = (0, 0, 0);
+        let mut cosigner_data = vec![];
+        let header_key = ID;
+        let signatures_key = signatures::ID;
+        let account_infos = [
+            AccountInfo::new(
+                &header_key,
+                false,
+                false,
+                &mut lamports,
+                &mut header_data,
+                &owner,
+                false,
+                Epoch::default(),
+            ),
+            AccountInfo::new(
+                &signatures_key,
+                false,
+                false,
+                &mut signatures_lamports,
+                &mut signatures_data,
+                &owner,
+                false,
+                Epoch::default(),
+            ),
+            AccountInfo::new(
+                &cosigner,
+                true,
+                false,
+                &mut cosigner_lamports,
+                &mut cosigner_data,
+                &owner,
+                false,
+                Epoch::default(),
+            ),
+        ];
+
+        // The payer wasn't passed to the instruction
+        assert_eq!(
+            signer_account_info_at(0, &account_infos).map(|info| info.map(|info| info.key)),
+            Ok(None)
+        );
+        assert_eq!(
+            signer_account_info_at(1, &account_infos).map(|info| info.map(|info| info.key)),
+            Ok(Some(&cosigner))
+        );
+        assert_eq!(
+            signer_account_info_at(2, &account_infos).map(|info| info.map(|info| info.key)),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            signer_account_info_at(0, &account_infos[1..]).map(|info| info.map(|info| info.key)),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+        assert_eq!(
+            signer_account_info_at(0, &account_infos[..1]).map(|info| info.map(|info| info.key)),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        let (mut payer_lamports, mut payer_data) = (0, vec![]);
+        let payer_account_info = AccountInfo::new(
+            &payer,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            is_fee_payer(&payer_account_info, &account_infos[0], &account_infos[1]),
+            Ok(true)
+        );
+        // The cosigner is a signer, but not the fee payer
+        assert_eq!(
+            is_fee_payer(&account_infos[2], &account_infos[0], &account_infos[1]),
+            Ok(false)
+        );
+        assert_eq!(
+            is_fee_payer(&payer_account_info, &account_infos[1], &account_infos[0]),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_check_freshness() {
+        let payer = Pubkey::new_unique();
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(&[], Some(&payer))));
+        let freshness_tag = FreshnessTag {
+            slot: 42,
+            message_hash: Hash::new_unique(),
+        };
+        let mut data = construct_header_data(&message, &freshness_tag);
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        assert_eq!(load_freshness_tag_checked(&account_info), Ok(freshness_tag));
+        assert_eq!(check_freshness(&account_info, 42), Ok(freshness_tag));
+        assert_eq!(
+            check_freshness(&account_info, 43),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_freshness_tag_checked(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            check_freshness(&account_info, 42),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_header_data_deserialize() {
+        let payer = Pubkey::new_unique();
+        let message = SanitizedMessage::V0(v0::LoadedMessage::new(
+            v0::Message::try_compile(&payer, &[], &[], Hash::default()).unwrap(),
+            LoadedAddresses::default(),
+        ));
+        let freshness_tag = FreshnessTag {
+            slot: 7,
+            message_hash: Hash::new_unique(),
+        };
+
+        let data = construct_header_data(&message, &freshness_tag);
+        assert_eq!(
+            HeaderData::deserialize(&data),
+            Ok(HeaderData {
+                version: TransactionVersion::Number(0),
+                freshness_tag,
+                message_header: None,
+            })
+        );
+        let data = construct_header_data_with_message_header(&message, &freshness_tag);
+        assert_eq!(
+            HeaderData::deserialize(&data),
+            Ok(HeaderData {
+                version: TransactionVersion::Number(0),
+                freshness_tag,
+                message_header: Some(*message.header()),
+            })
+        );
+
+        assert_eq!(
+            HeaderData::deserialize(&data[..HEADER_LEN - 1]),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            HeaderData::deserialize(&data[..HEADER_LEN + 1]),
+            Err(SanitizeError::InvalidValue)
+        );
+    }
+
+    const SPOOFING_FRESHNESS_TAG: FreshnessTag = FreshnessTag {
+        slot: 42,
+        message_hash: Hash::new_from_array([7; 32]),
+    };
+
+    fn spoofing_data() -> Vec<u8> {
+        let payer = Pubkey::new_from_array([1; 32]);
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(Message::new(&[], Some(&payer))));
+        construct_header_data_with_message_header(&message, &SPOOFING_FRESHNESS_TAG)
+    }
+
+    // The header has a fixed size, so trailing bytes are never read.
+    // `signer_account_info_at` and `is_fee_payer` take both sysvars and are
+    // covered by their own tests.
+    sysvar_spoofing_tests! {
+        data: spoofing_data(),
+        loaders: {
+            load_transaction_version: load_transaction_version
+                => oversized: Ok(TransactionVersion::Legacy),
+            load_header_checked: load_header_checked => oversized: Ok(TransactionHeader {
+                version: TransactionVersion::Legacy,
+                message_header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 0,
+                },
+            }),
+            load_freshness_tag_checked: load_freshness_tag_checked
+                => oversized: Ok(SPOOFING_FRESHNESS_TAG),
+            check_freshness: |account_info| check_freshness(account_info, 42)
+                => oversized: Ok(SPOOFING_FRESHNESS_TAG),
+        },
+    }
+}
@@ -16,6 +16,13 @@
 //!
 //! [`Sysvar`]: crate::sysvar::Sysvar
 //!
+//! When the `integrity_checksum_in_sysvars` feature is active, the serialized
+//! instructions are followed by their SHA-256 hash, before the current
+//! instruction index, which the runtime updates as the transaction executes.
+//! Programs that don't trust the environment they run in to construct the
+//! sysvar, e.g. a permissive test validator, can check it with
+//! [`verify_integrity`].
+//!
 //! See also the Solana [documentation on the instructions sysvar][sdoc].
 //!
 //! [sdoc]: https://docs.solana.com/developing/runtime-facilities/sysvars#instructions
@@ -69,6 +76,19 @@ pub fn construct_instructions_data(instructions: &[BorrowedInstruction]) -> Vec<
     data
 }
 
+/// Insert the integrity checksum of the instructions into the account data
+/// constructed by [`construct_instructions_data`], before the current
+/// instruction index.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn append_integrity_checksum(data: &mut Vec<u8>) {
+    let current_index = data.split_off(data.len() - 2);
+    let checksum = crate::hash::hash(data);
+    data.extend_from_slice(checksum.as_ref());
+    data.extend_from_slice(&current_index);
+}
+
 /// Borrowed version of `AccountMeta`.
 ///
 /// This struct is used by the runtime when constructing the sysvar. It is not
@@ -164,6 +184,7 @@ pub fn load_current_index(data: &[u8]) -> u16 {
 /// # Errors
 ///
 /// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the data is too short to hold the index.
 pub fn load_current_index_checked(
     instruction_sysvar_account_info: &AccountInfo,
 ) -> Result<u16, ProgramError> {
@@ -174,10 +195,57 @@ pub fn load_current_index_checked(
     let instruction_sysvar = instruction_sysvar_account_info.try_borrow_data()?;
     let mut instr_fixed_data = [0u8; 2];
     let len = instruction_sysvar.len();
+    if len < 2 {
+        return Err(ProgramError::InvalidAccountData);
+    }
     instr_fixed_data.copy_from_slice(&instruction_sysvar[len - 2..len]);
     Ok(u16::from_le_bytes(instr_fixed_data))
 }
 
+/// Check the integrity checksum of the instructions sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the checksum doesn't match the serialized
+/// instructions, or is missing because the `integrity_checksum_in_sysvars` feature is not
+/// active, after logging which.
+pub fn verify_integrity(instruction_sysvar_account_info: &AccountInfo) -> Result<(), ProgramError> {
+    if !check_id(instruction_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let instruction_sysvar = instruction_sysvar_account_info.try_borrow_data()?;
+    let payload_len =
+        instructions_end(&instruction_sysvar).map_err(|_| ProgramError::InvalidAccountData)?;
+    let checksum = instruction_sysvar
+        .get(payload_len..instruction_sysvar.len().saturating_sub(2))
+        .ok_or(ProgramError::InvalidAccountData)?;
+    crate::sysvar::verify_integrity_checksum(
+        "instructions",
+        &instruction_sysvar[..payload_len],
+        checksum,
+    )
+}
+
+/// Byte offset following the last serialized instruction
+fn instructions_end(data: &[u8]) -> Result<usize, SanitizeError> {
+    let mut current = 0;
+    let num_instructions = read_u16(&mut current, data)? as usize;
+    if num_instructions == 0 {
+        return Ok(current);
+    }
+
+    // Skip to the last instruction, then over its accounts and program id
+    current += (num_instructions - 1) * 2;
+    current = read_u16(&mut current, data)? as usize;
+    let num_accounts = read_u16(&mut current, data)? as usize;
+    current += num_accounts * (1 + 32) + 32;
+    let data_len = read_u16(&mut current, data)? as usize;
+    read_slice(&mut current, data, data_len)?;
+    Ok(current)
+}
+
 /// Store the current `Instruction`'s index in the instructions sysvar data.
 pub fn store_current_index(data: &mut [u8], instruction_index: u16) {
     let last_index = data.len() - 2;
@@ -246,6 +314,8 @@ pub fn load_instruction_at(index: usize, data: &[u8]) -> Result<Instruction, San
 /// # Errors
 ///
 /// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if the instruction index is out of
+/// bounds, after logging the requested index and the number of instructions.
 pub fn load_instruction_at_checked(
     index: usize,
     instruction_sysvar_account_info: &AccountInfo,
@@ -256,7 +326,10 @@ pub fn load_instruction_at_checked(
 
     let instruction_sysvar = instruction_sysvar_account_info.try_borrow_data()?;
     deserialize_instruction(index, &instruction_sysvar).map_err(|err| match err {
-        SanitizeError::IndexOutOfBounds => ProgramError::InvalidArgument,
+        SanitizeError::IndexOutOfBounds => {
+            log_index_out_of_bounds(index, &instruction_sysvar);
+            ProgramError::InvalidArgument
+        }
         _ => ProgramError::InvalidInstructionData,
     })
 }
@@ -267,6 +340,8 @@ pub fn load_instruction_at_checked(
 /// # Errors
 ///
 /// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if the instruction index is out of
+/// bounds, after logging the requested index and the number of instructions.
 pub fn get_instruction_relative(
     index_relative_to_current: i64,
     instruction_sysvar_account_info: &AccountInfo,
@@ -275,11 +350,11 @@ pub fn get_instruction_relative(
         return Err(ProgramError::UnsupportedSysvar);
     }
 
+    let current_index = load_current_index_checked(instruction_sysvar_account_info)? as i64;
     let instruction_sysvar = instruction_sysvar_account_info.data.borrow();
-    #[allow(deprecated)]
-    let current_index = load_current_index(&instruction_sysvar) as i64;
     let index = current_index.saturating_add(index_relative_to_current);
     if index < 0 {
+        log_index_out_of_bounds(index, &instruction_sysvar);
         return Err(ProgramError::InvalidArgument);
     }
     #[allow(deprecated)]
@@ -288,11 +363,68 @@ pub fn get_instruction_relative(
         &instruction_sysvar,
     )
     .map_err(|err| match err {
-        SanitizeError::IndexOutOfBounds => ProgramError::InvalidArgument,
+        SanitizeError::IndexOutOfBounds => {
+            log_index_out_of_bounds(index, &instruction_sysvar);
+            ProgramError::InvalidArgument
+        }
         _ => ProgramError::InvalidInstructionData,
     })
 }
 
+/// Return value of the `sol_get_instructions_sysvar_slice` syscall when the
+/// requested range is not within the instructions sysvar data.
+pub const OFFSET_LENGTH_EXCEEDS_SYSVAR: u64 = 1;
+
+/// Return value of the `sol_get_instructions_sysvar_slice` syscall when the
+/// transaction does not load the instructions sysvar.
+pub const SYSVAR_NOT_FOUND: u64 = 2;
+
+/// Copy `dst.len()` bytes of the instructions sysvar data, starting at
+/// `offset`, into `dst`.
+///
+/// This reads the sysvar through the `sol_get_instructions_sysvar_slice`
+/// syscall rather than through an [`AccountInfo`], so programs can jump
+/// straight to the instruction they care about, e.g. using the offset table
+/// at the start of the data, without deserializing the instructions before
+/// it. The instructions sysvar must still be loaded by the transaction, i.e.
+/// passed to one of its instructions.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidArgument`] if the range is not within the
+/// instructions sysvar data.
+/// Returns [`ProgramError::UnsupportedSysvar`] if the transaction does not
+/// load the instructions sysvar.
+pub fn load_instructions_sysvar_slice(offset: usize, dst: &mut [u8]) -> Result<(), ProgramError> {
+    #[cfg(target_os = "solana")]
+    let result = unsafe {
+        crate::syscalls::sol_get_instructions_sysvar_slice(
+            offset as u64,
+            dst.len() as u64,
+            dst.as_mut_ptr(),
+        )
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    let result = crate::program_stubs::sol_get_instructions_sysvar_slice(
+        offset as u64,
+        dst.len() as u64,
+        dst.as_mut_ptr(),
+    );
+
+    match result {
+        crate::entrypoint::SUCCESS => Ok(()),
+        OFFSET_LENGTH_EXCEEDS_SYSVAR => Err(ProgramError::InvalidArgument),
+        SYSVAR_NOT_FOUND => Err(ProgramError::UnsupportedSysvar),
+        err => Err(ProgramError::from(err)),
+    }
+}
+
+fn log_index_out_of_bounds(index: impl core::fmt::Display, data: &[u8]) {
+    let num_instructions = read_u16(&mut 0, data).unwrap_or(0);
+    crate::sysvar::log_index_out_of_bounds("instructions", index, num_instructions as usize);
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -302,9 +434,77 @@ mod tests {
             message::{Message as LegacyMessage, SanitizedMessage},
             pubkey::Pubkey,
         },
-        std::convert::TryFrom,
+        std::{cell::RefCell, convert::TryFrom, rc::Rc},
     };
 
+    fn construct_instructions_data_from(instructions: &[Instruction]) -> Vec<u8> {
+        let instructions: Vec<_> = instructions
+            .iter()
+            .map(|instruction| BorrowedInstruction {
+                program_id: &instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: &instruction.data,
+            })
+            .collect();
+        construct_instructions_data(&instructions)
+    }
+
+    sysvar_test_suite! {
+        entry: |index: usize| Instruction::new_with_bytes(
+            Pubkey::from([index as u8; 32]),
+            &[index as u8],
+            vec![AccountMeta::new_readonly(Pubkey::new_unique(), index % 2 == 0)],
+        ),
+        data: construct_instructions_data_from,
+        load: load_instruction_at_checked,
+        // A transaction fits in a 1232 byte packet, and each of its
+        // instructions takes at least 3 bytes
+        max_count: 1232 / 3,
+    }
+
+    fn spoofing_instruction(index: u8) -> Instruction {
+        Instruction::new_with_bytes(
+            Pubkey::from([index; 32]),
+            &[index],
+            vec![AccountMeta::new_readonly(
+                Pubkey::from([index + 1; 32]),
+                false,
+            )],
+        )
+    }
+
+    /// Two instructions and the integrity checksum, executing the first
+    fn spoofing_data() -> Vec<u8> {
+        let mut data =
+            construct_instructions_data_from(&[spoofing_instruction(1), spoofing_instruction(2)]);
+        append_integrity_checksum(&mut data);
+        data
+    }
+
+    // The current index is stored at the end of the data, so trailing bytes
+    // replace it
+    sysvar_spoofing_tests! {
+        data: spoofing_data(),
+        loaders: {
+            load_current_index_checked: load_current_index_checked => oversized: Ok(u16::MAX),
+            load_instruction_at_checked: |account_info| {
+                load_instruction_at_checked(1, account_info)
+            } => oversized: Ok(spoofing_instruction(2)),
+            get_instruction_relative: |account_info| get_instruction_relative(0, account_info)
+                => oversized: Err(ProgramError::InvalidArgument),
+            verify_integrity: verify_integrity
+                => oversized: Err(ProgramError::InvalidAccountData),
+        },
+    }
+
     #[test]
     fn test_load_store_instruction() {
         let mut data = [4u8; 10];
@@ -410,6 +610,13 @@ mod tests {
         }
         assert_eq!(0, load_current_index_checked(&account_info).unwrap());
 
+        let mut short_data = [0];
+        account_info.data = Rc::new(RefCell::new(&mut short_data));
+        assert_eq!(
+            Err(ProgramError::InvalidAccountData),
+            load_current_index_checked(&account_info)
+        );
+
         let key = Pubkey::new_unique();
         account_info.key = &key;
         assert_eq!(
@@ -418,6 +625,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_integrity() {
+        let instructions = [
+            Instruction::new_with_bincode(
+                Pubkey::new_unique(),
+                &0,
+                vec![AccountMeta::new(Pubkey::new_unique(), false)],
+            ),
+            Instruction::new_with_bytes(Pubkey::new_unique(), &[1, 2, 3], vec![]),
+        ];
+        let serialized = construct_instructions_data_from(&instructions);
+        assert_eq!(instructions_end(&serialized), Ok(serialized.len() - 2));
+        assert_eq!(
+            instructions_end(&construct_instructions_data_from(&[])),
+            Ok(2)
+        );
+
+        let mut data = serialized.clone();
+        append_integrity_checksum(&mut data);
+        assert_eq!(
+            data.len(),
+            serialized.len() + crate::sysvar::INTEGRITY_CHECKSUM_LEN
+        );
+        store_current_index(&mut data, 1);
+        let key = id();
+        let mut lamports = 0;
+        let owner = crate::sysvar::id();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // The current index isn't covered by the checksum
+        assert_eq!(verify_integrity(&account_info), Ok(()));
+        assert_eq!(load_current_index_checked(&account_info), Ok(1));
+        assert_eq!(
+            load_instruction_at_checked(1, &account_info),
+            Ok(instructions[1].clone())
+        );
+
+        // A corrupted instruction
+        account_info.try_borrow_mut_data().unwrap()[serialized.len() - 3] ^= 1;
+        assert_eq!(
+            verify_integrity(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Constructed without a checksum
+        let mut data = serialized;
+        let mut lamports = 0;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            verify_integrity(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            verify_integrity(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
     #[test]
     fn test_get_instruction_relative() {
         let instruction0 = Instruction::new_with_bincode(
@@ -86,19 +86,251 @@ use {
     lazy_static::lazy_static,
 };
 
+/// Generates the tests shared by sysvars whose entries are loaded by index
+/// through free functions, such as the instructions and signatures sysvars.
+///
+/// - `entry`: builds a distinct entry from its index
+/// - `data`: constructs the sysvar account data holding the given entries
+/// - `load`: the checked loader, taking an index and the sysvar account
+/// - `max_count`: the largest number of entries the sysvar holds
+///
+/// Must be invoked from the sysvar module's `tests` module.
+#[cfg(test)]
+macro_rules! sysvar_test_suite {
+    (
+        entry: $entry:expr,
+        data: $data:expr,
+        load: $load:expr,
+        max_count: $max_count:expr $(,)?
+    ) => {
+        mod sysvar_test_suite {
+            use {
+                super::*,
+                crate::{
+                    account_info::AccountInfo, clock::Epoch, program_error::ProgramError,
+                    pubkey::Pubkey,
+                },
+            };
+
+            fn with_account_info<R>(
+                key: &Pubkey,
+                data: &[u8],
+                f: impl FnOnce(&AccountInfo) -> R,
+            ) -> R {
+                let owner = crate::sysvar::id();
+                let mut lamports = 0;
+                let mut data = data.to_vec();
+                let account_info = AccountInfo::new(
+                    key,
+                    false,
+                    false,
+                    &mut lamports,
+                    &mut data,
+                    &owner,
+                    false,
+                    Epoch::default(),
+                );
+                f(&account_info)
+            }
+
+            #[test]
+            fn test_wrong_id() {
+                let entries: Vec<_> = (0..2).map($entry).collect();
+                let data = ($data)(&entries);
+                with_account_info(&Pubkey::new_unique(), &data, |account_info| {
+                    assert_eq!(
+                        ($load)(0, account_info),
+                        Err(ProgramError::UnsupportedSysvar)
+                    );
+                });
+            }
+
+            #[test]
+            fn test_empty_data() {
+                with_account_info(&ID, &[], |account_info| {
+                    assert!(($load)(0, account_info).is_err());
+                });
+            }
+
+            #[test]
+            fn test_truncated_data() {
+                let entries: Vec<_> = (0..3).map($entry).collect();
+                let data = ($data)(&entries);
+                for len in 0..data.len() {
+                    with_account_info(&ID, &data[..len], |account_info| {
+                        for (index, entry) in entries.iter().enumerate() {
+                            // Truncated data never panics or yields another entry
+                            let result = ($load)(index, account_info);
+                            assert!(result.is_err() || result.as_ref() == Ok(entry));
+                        }
+                    });
+                }
+            }
+
+            #[test]
+            fn test_max_count() {
+                let max_count: usize = $max_count;
+                let entries: Vec<_> = (0..max_count).map($entry).collect();
+                let data = ($data)(&entries);
+                with_account_info(&ID, &data, |account_info| {
+                    for (index, entry) in entries.iter().enumerate() {
+                        assert_eq!(($load)(index, account_info).as_ref(), Ok(entry));
+                    }
+                    assert_eq!(
+                        ($load)(max_count, account_info),
+                        Err(ProgramError::InvalidArgument)
+                    );
+                });
+            }
+        }
+    };
+}
+
+/// Generates negative tests passing spoofed accounts to every checked loader
+/// of a sysvar, and asserts the exact result of each:
+///
+/// - the genuine data under another key fails with
+///   [`ProgramError::UnsupportedSysvar`]
+/// - the genuine key and data under another owner load like the sysvar, since
+///   the runtime only ever loads the sysvar at its address
+/// - the genuine data followed by [`SPOOFED_TRAILING_BYTES`] yields the
+///   loader's `oversized` result
+///
+/// - `data`: the genuine sysvar account data, which every loader accepts
+/// - `loaders`: each loader as a closure taking the sysvar account, followed
+///   by its result on the oversized data
+///
+/// Must be invoked from the sysvar module's `tests` module.
+#[cfg(test)]
+macro_rules! sysvar_spoofing_tests {
+    (
+        data: $data:expr,
+        loaders: {
+            $($name:ident: $load:expr => oversized: $oversized:expr),+ $(,)?
+        } $(,)?
+    ) => {
+        mod sysvar_spoofing_tests {
+            use {
+                super::*,
+                crate::{
+                    account_info::AccountInfo, clock::Epoch, program_error::ProgramError,
+                    pubkey::Pubkey, sysvar::SPOOFED_TRAILING_BYTES,
+                },
+            };
+
+            fn with_account_info<R>(
+                key: &Pubkey,
+                owner: &Pubkey,
+                data: &[u8],
+                f: impl FnOnce(&AccountInfo) -> R,
+            ) -> R {
+                let mut lamports = 0;
+                let mut data = data.to_vec();
+                let account_info = AccountInfo::new(
+                    key,
+                    false,
+                    false,
+                    &mut lamports,
+                    &mut data,
+                    owner,
+                    false,
+                    Epoch::default(),
+                );
+                f(&account_info)
+            }
+
+            #[test]
+            fn test_spoofed_key() {
+                let data: Vec<u8> = $data;
+                $(
+                    let result = with_account_info(
+                        &Pubkey::new_unique(),
+                        &crate::sysvar::id(),
+                        &data,
+                        $load,
+                    );
+                    assert_eq!(
+                        result.map_err(ProgramError::from).err(),
+                        Some(ProgramError::UnsupportedSysvar),
+                        "{}",
+                        stringify!($name),
+                    );
+                )+
+            }
+
+            #[test]
+            fn test_spoofed_owner() {
+                let data: Vec<u8> = $data;
+                $(
+                    let genuine = with_account_info(&ID, &crate::sysvar::id(), &data, $load);
+                    assert!(genuine.is_ok(), "{}",
+                        stringify!($name));
+                    let spoofed = with_account_info(&ID, &Pubkey::new_unique(), &data, $load);
+                    assert_eq!(spoofed, genuine, "{}",
+                        stringify!($name));
+                )+
+            }
+
+            #[test]
+            fn test_oversized_data() {
+                let mut data: Vec<u8> = $data;
+                data.extend_from_slice(&SPOOFED_TRAILING_BYTES);
+                $(
+                    let result = with_account_info(&ID, &crate::sysvar::id(), &data, $load);
+                    assert_eq!(result, $oversized, "{}",
+                        stringify!($name));
+                )+
+            }
+        }
+    };
+}
+
+/// Bytes appended to the genuine data of a sysvar by the tests generated with
+/// `sysvar_spoofing_tests!`.
+#[cfg(test)]
+const SPOOFED_TRAILING_BYTES: [u8; 64] = [u8::MAX; 64];
+
 pub mod clock;
 pub mod epoch_rewards;
 pub mod epoch_schedule;
+pub mod epoch_stake;
 pub mod fees;
+pub mod header;
 pub mod instructions;
 pub mod last_restart_slot;
 pub mod recent_blockhashes;
+pub mod recent_signatures;
 pub mod rent;
 pub mod rewards;
+pub mod scratch;
 pub mod signatures;
 pub mod slot_hashes;
 pub mod slot_history;
 pub mod stake_history;
+pub mod tick_hash;
+pub mod transaction_context;
+
+// The IDs of the sysvars, as `const`s that can be used in `match` patterns
+// and `const` contexts. Each is equal to the `ID` of the sysvar's module.
+// The deprecated fees and recent blockhashes sysvars declare their IDs as
+// `static`s and have no equivalent here.
+pub const CLOCK_ID: Pubkey = clock::ID;
+pub const EPOCH_REWARDS_ID: Pubkey = epoch_rewards::ID;
+pub const EPOCH_SCHEDULE_ID: Pubkey = epoch_schedule::ID;
+pub const EPOCH_STAKE_ID: Pubkey = epoch_stake::ID;
+pub const HEADER_ID: Pubkey = header::ID;
+pub const INSTRUCTIONS_ID: Pubkey = instructions::ID;
+pub const LAST_RESTART_SLOT_ID: Pubkey = last_restart_slot::ID;
+pub const RECENT_SIGNATURES_ID: Pubkey = recent_signatures::ID;
+pub const RENT_ID: Pubkey = rent::ID;
+pub const REWARDS_ID: Pubkey = rewards::ID;
+pub const SCRATCH_ID: Pubkey = scratch::ID;
+pub const SIGNATURES_ID: Pubkey = signatures::ID;
+pub const SLOT_HASHES_ID: Pubkey = slot_hashes::ID;
+pub const SLOT_HISTORY_ID: Pubkey = slot_history::ID;
+pub const STAKE_HISTORY_ID: Pubkey = stake_history::ID;
+pub const TICK_HASH_ID: Pubkey = tick_hash::ID;
+pub const TRANSACTION_CONTEXT_ID: Pubkey = transaction_context::ID;
 
 lazy_static! {
     pub static ref ALL_IDS: Vec<Pubkey> = vec![
@@ -117,6 +349,12 @@ lazy_static! {
         signatures::id(),
         epoch_rewards::id(),
         last_restart_slot::id(),
+        epoch_stake::id(),
+        recent_signatures::id(),
+        header::id(),
+        scratch::id(),
+        tick_hash::id(),
+        transaction_context::id(),
     ];
 }
 
@@ -125,6 +363,136 @@ pub fn is_sysvar_id(id: &Pubkey) -> bool {
     ALL_IDS.iter().any(|key| key == id)
 }
 
+/// Sysvars that are only demoted to readonly in transaction messages once the
+/// feature introducing them is active.
+///
+/// Demoting an account changes which accounts a transaction writes, so these
+/// are left writable by [`SanitizedMessage`] and the runtime demotes them with
+/// [`SanitizedMessage::demote_account_keys`] when their feature activates.
+///
+/// [`SanitizedMessage`]: crate::message::SanitizedMessage
+/// [`SanitizedMessage::demote_account_keys`]: crate::message::SanitizedMessage::demote_account_keys
+pub const FEATURE_GATED_IDS: [Pubkey; 6] = [
+    EPOCH_STAKE_ID,
+    RECENT_SIGNATURES_ID,
+    HEADER_ID,
+    SCRATCH_ID,
+    TICK_HASH_ID,
+    TRANSACTION_CONTEXT_ID,
+];
+
+/// Sysvars whose accounts are constructed by the runtime for each transaction
+/// rather than loaded from the bank.
+pub const PER_TRANSACTION_IDS: [Pubkey; 6] = [
+    INSTRUCTIONS_ID,
+    SIGNATURES_ID,
+    HEADER_ID,
+    SCRATCH_ID,
+    TICK_HASH_ID,
+    TRANSACTION_CONTEXT_ID,
+];
+
+/// How the runtime provides the account at a given address, as returned by
+/// [`Pubkey::sysvar_kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SysvarKind {
+    /// The address isn't a sysvar
+    NotSysvar,
+    /// A sysvar stored in the bank, whose value is cached by the runtime and
+    /// also available through syscalls
+    Cached,
+    /// A sysvar constructed by the runtime for each transaction that
+    /// references it, see [`PER_TRANSACTION_IDS`]
+    PerTransaction,
+}
+
+impl SysvarKind {
+    /// Classify `id`, checking the per-transaction sysvars first.
+    pub fn of(id: &Pubkey) -> Self {
+        if id.is_per_transaction_sysvar() {
+            Self::PerTransaction
+        } else if is_sysvar_id(id) {
+            Self::Cached
+        } else {
+            Self::NotSysvar
+        }
+    }
+}
+
+/// Length in bytes of the checksum following the payload of the instructions
+/// and signatures sysvars when the `integrity_checksum_in_sysvars` feature is
+/// active.
+///
+/// The checksum is the SHA-256 hash of the payload, see
+/// [`instructions::verify_integrity`] and [`signatures::verify_integrity`].
+pub const INTEGRITY_CHECKSUM_LEN: usize = crate::hash::HASH_BYTES;
+
+/// Compare the integrity checksum of a sysvar with the hash of its payload,
+/// logging why they differ in the form
+/// `sysvar error: sysvar=<name> checksum=<missing|mismatch>`.
+pub(crate) fn verify_integrity_checksum(
+    sysvar_name: &str,
+    payload: &[u8],
+    checksum: &[u8],
+) -> Result<(), ProgramError> {
+    if checksum.is_empty() {
+        crate::msg!("sysvar error: sysvar={sysvar_name} checksum=missing");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if checksum != crate::hash::hash(payload).as_ref() {
+        crate::msg!("sysvar error: sysvar={sysvar_name} checksum=mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Log the context of a failed indexed load from a sysvar.
+///
+/// The checked loaders only return [`ProgramError::InvalidArgument`] for an
+/// out of bounds index, so this logs a single line with the sysvar name, the
+/// requested index and the number of entries available, in the form
+/// `sysvar error: sysvar=<name> index=<index> count=<count>`.
+pub(crate) fn log_index_out_of_bounds(
+    sysvar_name: &str,
+    index: impl core::fmt::Display,
+    count: usize,
+) {
+    crate::msg!("sysvar error: sysvar={sysvar_name} index={index} count={count}");
+}
+
+/// Load the entry at `index` of a sysvar section holding `count` entries of
+/// `N` bytes each, starting at `entries_offset` in `data`.
+///
+/// Most sysvars that are read by index store a count followed by fixed-size
+/// entries. Their checked loaders parse the count, whose width depends on the
+/// sysvar, and leave the bounds checks and offset arithmetic to this
+/// function, which never panics on malformed data.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidArgument`] if `index` is not less than
+/// `count`, after logging the index and count under `sysvar_name`.
+/// Returns [`ProgramError::InvalidAccountData`] if `data` is too short to
+/// hold the entry.
+pub fn load_entry_at_checked<'a, const N: usize>(
+    sysvar_name: &str,
+    data: &'a [u8],
+    entries_offset: usize,
+    count: usize,
+    index: usize,
+) -> Result<&'a [u8; N], ProgramError> {
+    if index >= count {
+        log_index_out_of_bounds(sysvar_name, index, count);
+        return Err(ProgramError::InvalidArgument);
+    }
+    index
+        .checked_mul(N)
+        .and_then(|start| start.checked_add(entries_offset))
+        .and_then(|start| data.get(start..start.checked_add(N)?))
+        .and_then(|entry| entry.try_into().ok())
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
 /// Declares an ID that implements [`SysvarId`].
 #[macro_export]
 macro_rules! declare_sysvar_id(
@@ -187,6 +555,56 @@ pub trait SysvarId {
     fn check_id(pubkey: &Pubkey) -> bool;
 }
 
+/// A sysvar whose account can be checked before its data is read.
+///
+/// This is implemented for every type implementing [`SysvarId`], including
+/// the dummy types of the sysvars accessed through free functions, such as
+/// [`signatures::Signatures`] and [`instructions::Instructions`], so that
+/// helpers generic over the sysvar can be written downstream:
+///
+/// ```
+/// use solana_program::{
+///     account_info::AccountInfo,
+///     program_error::ProgramError,
+///     sysvar::{clock::Clock, signatures::Signatures, SysvarAccount},
+/// };
+///
+/// fn find_sysvar<'a, 'b, S: SysvarAccount>(
+///     accounts: &'a [AccountInfo<'b>],
+/// ) -> Result<&'a AccountInfo<'b>, ProgramError> {
+///     accounts
+///         .iter()
+///         .find(|account_info| S::check_account(account_info).is_ok())
+///         .ok_or(ProgramError::NotEnoughAccountKeys)
+/// }
+///
+/// fn process(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+///     let _clock = find_sysvar::<Clock>(accounts)?;
+///     let _signatures = find_sysvar::<Signatures>(accounts)?;
+///     Ok(())
+/// }
+/// ```
+pub trait SysvarAccount: SysvarId {
+    /// Check that `account_info` is the account of this sysvar.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::UnsupportedSysvar`] if the account's ID is not the sysvar's ID.
+    /// Returns [`ProgramError::IllegalOwner`] if the account is not owned by [`ID`], e.g. a
+    /// per-transaction sysvar whose feature is not active on the cluster.
+    fn check_account(account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if !Self::check_id(account_info.key) {
+            return Err(ProgramError::UnsupportedSysvar);
+        }
+        if !check_id(account_info.owner) {
+            return Err(ProgramError::IllegalOwner);
+        }
+        Ok(())
+    }
+}
+
+impl<T: SysvarId> SysvarAccount for T {}
+
 /// A type that holds sysvar data.
 pub trait Sysvar:
     SysvarId + Default + Sized + serde::Serialize + serde::de::DeserializeOwned
@@ -261,6 +679,100 @@ mod tests {
         std::{cell::RefCell, rc::Rc},
     };
 
+    #[test]
+    fn test_sysvar_account() {
+        fn check<S: SysvarAccount>(key: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
+            let mut lamports = 0;
+            let mut data = vec![];
+            let account_info = AccountInfo::new(
+                key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                owner,
+                false,
+                Epoch::default(),
+            );
+            S::check_account(&account_info)
+        }
+
+        assert_eq!(check::<clock::Clock>(&clock::id(), &ID), Ok(()));
+        assert_eq!(
+            check::<instructions::Instructions>(&instructions::id(), &ID),
+            Ok(())
+        );
+        assert_eq!(
+            check::<signatures::Signatures>(&signatures::id(), &ID),
+            Ok(())
+        );
+        assert_eq!(
+            check::<clock::Clock>(&signatures::id(), &ID),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            check::<signatures::Signatures>(&signatures::id(), &Pubkey::default()),
+            Err(ProgramError::IllegalOwner)
+        );
+    }
+
+    #[test]
+    fn test_sysvar_kind() {
+        for id in ALL_IDS.iter() {
+            let expected = if PER_TRANSACTION_IDS.contains(id) {
+                SysvarKind::PerTransaction
+            } else {
+                SysvarKind::Cached
+            };
+            assert_eq!(id.sysvar_kind(), expected, "{id}");
+            assert_eq!(
+                id.is_per_transaction_sysvar(),
+                expected == SysvarKind::PerTransaction
+            );
+        }
+        assert!(PER_TRANSACTION_IDS.iter().all(is_sysvar_id));
+        assert_eq!(clock::id().sysvar_kind(), SysvarKind::Cached);
+        assert_eq!(signatures::id().sysvar_kind(), SysvarKind::PerTransaction);
+        assert_eq!(ID.sysvar_kind(), SysvarKind::NotSysvar);
+        assert_eq!(Pubkey::new_unique().sysvar_kind(), SysvarKind::NotSysvar);
+        assert!(!Pubkey::new_unique().is_per_transaction_sysvar());
+    }
+
+    #[test]
+    fn test_sysvar_id_consts() {
+        const IDS: [Pubkey; 17] = [
+            CLOCK_ID,
+            EPOCH_REWARDS_ID,
+            EPOCH_SCHEDULE_ID,
+            EPOCH_STAKE_ID,
+            HEADER_ID,
+            INSTRUCTIONS_ID,
+            LAST_RESTART_SLOT_ID,
+            RECENT_SIGNATURES_ID,
+            RENT_ID,
+            REWARDS_ID,
+            SCRATCH_ID,
+            SIGNATURES_ID,
+            SLOT_HASHES_ID,
+            SLOT_HISTORY_ID,
+            STAKE_HISTORY_ID,
+            TICK_HASH_ID,
+            TRANSACTION_CONTEXT_ID,
+        ];
+        // Every sysvar except the deprecated ones has a const
+        assert_eq!(IDS.len(), ALL_IDS.len() - 2);
+        assert!(IDS.iter().all(is_sysvar_id));
+
+        let name = |id: &Pubkey| match *id {
+            CLOCK_ID => "clock",
+            SIGNATURES_ID => "signatures",
+            _ => "other",
+        };
+        assert_eq!(name(&clock::id()), "clock");
+        assert_eq!(name(&signatures::id()), "signatures");
+        assert_eq!(name(&rent::id()), "other");
+    }
+
     #[repr(C)]
     #[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
     struct TestSysvar {
@@ -311,4 +823,43 @@ mod tests {
         account_info.data = Rc::new(RefCell::new(&mut small_data));
         assert_eq!(test_sysvar.to_account_info(&mut account_info), None);
     }
+
+    #[test]
+    fn test_load_entry_at_checked() {
+        // A count byte followed by three 2-byte entries
+        let data = [3, 1, 1, 2, 2, 3, 3];
+        for index in 0..3 {
+            let entry = [index as u8 + 1; 2];
+            assert_eq!(
+                load_entry_at_checked::<2>("test", &data, 1, 3, index),
+                Ok(&entry)
+            );
+        }
+        assert_eq!(
+            load_entry_at_checked::<2>("test", &data, 1, 3, 3),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        // A count larger than the data holds
+        assert_eq!(
+            load_entry_at_checked::<2>("test", &data, 1, 4, 3),
+            Err(ProgramError::InvalidAccountData)
+        );
+        for len in 0..data.len() {
+            assert_eq!(
+                load_entry_at_checked::<2>("test", &data[..len], 1, 3, 2),
+                Err(ProgramError::InvalidAccountData)
+            );
+        }
+
+        // Offsets that overflow don't panic
+        assert_eq!(
+            load_entry_at_checked::<2>("test", &data, usize::MAX, 3, 1),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            load_entry_at_checked::<2>("test", &data, 1, usize::MAX, usize::MAX - 1),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
 }
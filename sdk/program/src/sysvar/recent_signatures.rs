@@ -0,0 +1,336 @@
+//! Merkle roots of the transaction signatures of recent slots.
+//!
+//! The _recent signatures sysvar_ provides access to the [`RecentSignatures`]
+//! type, which holds, for each of the last [`MAX_ENTRIES`] slots, the root of
+//! a merkle tree over the transaction signatures committed in that slot. Like
+//! [`SlotHashes`], entries are ordered by slot, most recent first, and a slot
+//! is added to the sysvar when its bank is frozen, so transactions can only
+//! prove signatures of earlier slots.
+//!
+//! [`SlotHashes`]: crate::slot_hashes::SlotHashes
+//!
+//! The leaves of the tree of a slot are the first signature, the transaction
+//! ID, of every transaction committed in the slot, sorted by their bytes. The
+//! tree is built the same way as by the `solana-merkle-tree` crate:
+//!
+//! - a leaf is `hashv(&[&[0], signature])`,
+//! - an intermediate node is `hashv(&[&[1], left, right])`,
+//! - the last node of a level with an odd number of nodes is paired with
+//!   itself.
+//!
+//! The root of a slot without transactions is [`Hash::default`].
+//!
+//! A program that is handed a signature, its index among the sorted leaves and
+//! the sibling hashes along its path can check that the signature appeared in
+//! a recent slot with [`load_root_checked`] and [`verify_signature_proof`],
+//! without deserializing the whole sysvar.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+pub use crate::clock::Slot;
+use crate::{
+    account_info::AccountInfo,
+    hash::{hashv, Hash, HASH_BYTES},
+    program_error::ProgramError,
+    sysvar::Sysvar,
+};
+
+crate::declare_sysvar_id!(
+    "SysvarRecentSignatures111111111111111111111",
+    RecentSignatures
+);
+
+/// Number of slots whose signature roots are retained.
+pub const MAX_ENTRIES: usize = 150;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const INTERMEDIATE_PREFIX: &[u8] = &[1];
+
+const LEN_LEN: usize = 8;
+const ENTRY_LEN: usize = 8 + HASH_BYTES;
+
+pub type SignatureRoot = (Slot, Hash);
+
+/// The signature roots of recent slots, most recent slot first.
+#[repr(C)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default, Clone)]
+pub struct RecentSignatures(Vec<SignatureRoot>);
+
+impl RecentSignatures {
+    /// Record the signature root of `slot`, evicting the oldest entry once
+    /// [`MAX_ENTRIES`] slots are retained.
+    pub fn add(&mut self, slot: Slot, root: Hash) {
+        match self.0.binary_search_by(|(probe, _)| slot.cmp(probe)) {
+            Ok(index) => self.0[index] = (slot, root),
+            Err(index) => self.0.insert(index, (slot, root)),
+        }
+        self.0.truncate(MAX_ENTRIES);
+    }
+
+    /// The signature root of `slot`, if it is retained.
+    pub fn get(&self, slot: Slot) -> Option<&Hash> {
+        self.0
+            .binary_search_by(|(probe, _)| slot.cmp(probe))
+            .ok()
+            .map(|index| &self.0[index].1)
+    }
+
+    pub fn roots(&self) -> &[SignatureRoot] {
+        &self.0
+    }
+}
+
+impl Sysvar for RecentSignatures {
+    // override
+    fn size_of() -> usize {
+        // hard-coded so that we don't have to construct an empty
+        6_008 // golden, update if MAX_ENTRIES changes
+    }
+}
+
+fn hash_leaf(signature: &[u8]) -> Hash {
+    hashv(&[LEAF_PREFIX, signature])
+}
+
+fn hash_intermediate(left: &Hash, right: &Hash) -> Hash {
+    hashv(&[INTERMEDIATE_PREFIX, left.as_ref(), right.as_ref()])
+}
+
+/// Compute the signature root of a slot.
+///
+/// `signatures` must already be sorted, see the [module
+/// documentation](self).
+///
+/// This function is used by the runtime and by off-chain provers and is not
+/// available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn compute_signature_root<T: AsRef<[u8]>>(signatures: &[T]) -> Hash {
+    let mut level: Vec<Hash> = signatures
+        .iter()
+        .map(|signature| hash_leaf(signature.as_ref()))
+        .collect();
+    if level.is_empty() {
+        return Hash::default();
+    }
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| hash_intermediate(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+    level[0]
+}
+
+/// Compute the proof that the signature at `index` of the sorted `signatures`
+/// of a slot is part of the slot's signature root.
+///
+/// Returns the sibling hashes from the leaf up to the root, or `None` if
+/// `index` is out of bounds.
+///
+/// This function is not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn compute_signature_proof<T: AsRef<[u8]>>(
+    signatures: &[T],
+    index: usize,
+) -> Option<Vec<Hash>> {
+    if index >= signatures.len() {
+        return None;
+    }
+    let mut level: Vec<Hash> = signatures
+        .iter()
+        .map(|signature| hash_leaf(signature.as_ref()))
+        .collect();
+    let mut index = index;
+    let mut proof = vec![];
+    while level.len() > 1 {
+        let sibling = level.get(index ^ 1).unwrap_or(&level[index]);
+        proof.push(*sibling);
+        level = level
+            .chunks(2)
+            .map(|pair| hash_intermediate(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Check that `signature`, at `index` among the sorted leaves of a slot's
+/// tree, hashes up to `root` along `proof`.
+pub fn verify_signature_proof(signature: &[u8], index: usize, proof: &[Hash], root: &Hash) -> bool {
+    let mut index = index;
+    let mut node = hash_leaf(signature);
+    for sibling in proof {
+        node = if index % 2 == 0 {
+            hash_intermediate(&node, sibling)
+        } else {
+            hash_intermediate(sibling, &node)
+        };
+        index /= 2;
+    }
+    // Every level consumes a bit of the index, anything left over means the
+    // proof is too short for the claimed position
+    index == 0 && node == *root
+}
+
+/// Load the signature root of `slot` from the recent signatures sysvar.
+///
+/// Returns `None` if the slot is not retained. The account data is searched
+/// in place, without deserializing the sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+pub fn load_root_checked(
+    slot: Slot,
+    recent_signatures_sysvar_account_info: &AccountInfo,
+) -> Result<Option<Hash>, ProgramError> {
+    if !check_id(recent_signatures_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let data = recent_signatures_sysvar_account_info.try_borrow_data()?;
+    find_root(slot, &data).ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Binary search the serialized sysvar for `slot`. Returns `None` if the data
+/// is malformed.
+fn find_root(slot: Slot, data: &[u8]) -> Option<Option<Hash>> {
+    let mut len = [0u8; LEN_LEN];
+    len.copy_from_slice(data.get(..LEN_LEN)?);
+    let len = usize::try_from(u64::from_le_bytes(len)).ok()?;
+    let entries = data.get(LEN_LEN..LEN_LEN.checked_add(len.checked_mul(ENTRY_LEN)?)?)?;
+
+    // Entries are sorted by slot, most recent first
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let entry = &entries[mid * ENTRY_LEN..(mid + 1) * ENTRY_LEN];
+        let mut probe = [0u8; 8];
+        probe.copy_from_slice(&entry[..8]);
+        match slot.cmp(&u64::from_le_bytes(probe)) {
+            core::cmp::Ordering::Greater => high = mid,
+            core::cmp::Ordering::Less => low = mid + 1,
+            core::cmp::Ordering::Equal => return Some(Some(Hash::new(&entry[8..]))),
+        }
+    }
+    Some(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::clock::Epoch};
+
+    #[test]
+    fn test_size_of() {
+        let mut recent_signatures = RecentSignatures::default();
+        for slot in 0..MAX_ENTRIES as Slot {
+            recent_signatures.add(slot, Hash::default());
+        }
+        assert_eq!(
+            RecentSignatures::size_of(),
+            bincode::serialized_size(&recent_signatures).unwrap() as usize
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        let mut recent_signatures = RecentSignatures::default();
+        for slot in 0..MAX_ENTRIES as Slot + 2 {
+            recent_signatures.add(slot, Hash::new_unique());
+        }
+        assert_eq!(recent_signatures.roots().len(), MAX_ENTRIES);
+        assert_eq!(recent_signatures.roots()[0].0, MAX_ENTRIES as Slot + 1);
+        assert!(recent_signatures.get(1).is_none());
+        assert!(recent_signatures.get(2).is_some());
+    }
+
+    #[test]
+    fn test_signature_proofs() {
+        assert_eq!(compute_signature_root::<[u8; 64]>(&[]), Hash::default());
+        assert_eq!(compute_signature_proof::<[u8; 64]>(&[], 0), None);
+
+        for num_signatures in 1..=9u8 {
+            let signatures: Vec<[u8; 64]> = (0..num_signatures).map(|i| [i; 64]).collect();
+            let root = compute_signature_root(&signatures);
+            for (index, signature) in signatures.iter().enumerate() {
+                let proof = compute_signature_proof(&signatures, index).unwrap();
+                assert!(verify_signature_proof(signature, index, &proof, &root));
+                assert!(!verify_signature_proof(&[0xff; 64], index, &proof, &root));
+                assert!(!verify_signature_proof(
+                    signature,
+                    index,
+                    &proof,
+                    &Hash::new_unique()
+                ));
+                if !proof.is_empty() {
+                    assert!(!verify_signature_proof(
+                        signature,
+                        index,
+                        &proof[1..],
+                        &root
+                    ));
+                }
+            }
+            assert_eq!(compute_signature_proof(&signatures, signatures.len()), None);
+        }
+    }
+
+    #[test]
+    fn test_load_root_checked() {
+        let mut recent_signatures = RecentSignatures::default();
+        let roots: Vec<Hash> = (0..10).map(|_| Hash::new_unique()).collect();
+        for (slot, root) in roots.iter().enumerate() {
+            // Leave a gap at slot 4
+            if slot != 4 {
+                recent_signatures.add(slot as Slot, *root);
+            }
+        }
+
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let mut data = bincode::serialize(&recent_signatures).unwrap();
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        for (slot, root) in roots.iter().enumerate() {
+            let expected = (slot != 4).then_some(*root);
+            assert_eq!(load_root_checked(slot as Slot, &account_info), Ok(expected));
+        }
+        assert_eq!(load_root_checked(10, &account_info), Ok(None));
+
+        let key = crate::pubkey::Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_root_checked(0, &account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+
+        assert_eq!(find_root(0, &[1, 0, 0, 0, 0, 0, 0, 0]), None);
+    }
+
+    fn spoofing_data() -> Vec<u8> {
+        let mut recent_signatures = RecentSignatures::default();
+        recent_signatures.add(1, Hash::new_from_array([1; 32]));
+        recent_signatures.add(2, Hash::new_from_array([2; 32]));
+        bincode::serialize(&recent_signatures).unwrap()
+    }
+
+    // Only the serialized entries are searched, so trailing bytes are never
+    // read
+    sysvar_spoofing_tests! {
+        data: spoofing_data(),
+        loaders: {
+            load_root_checked: |account_info| load_root_checked(1, account_info)
+                => oversized: Ok(Some(Hash::new_from_array([1; 32]))),
+        },
+    }
+}
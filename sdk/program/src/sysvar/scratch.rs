@@ -0,0 +1,172 @@
+//! A zeroed buffer that instructions of the current transaction can write to.
+//!
+//! The _scratch sysvar_ lets the instructions of a transaction pass data to
+//! the instructions that follow, e.g. for a program to record the result of
+//! a verification that a later instruction relies on, without allocating an
+//! account for it.
+//!
+//! The runtime constructs the scratch sysvar account for each transaction
+//! that references it, with [`SCRATCH_LEN`] bytes of data initialized to
+//! zero. It is never stored, so nothing written to it outlives the
+//! transaction.
+//!
+//! Unlike other sysvars, the data of the scratch sysvar can be changed by any
+//! program it is passed to, even though the account is passed as readonly:
+//! like the other per-transaction sysvars, it must not be marked writable in
+//! the transaction message, which also keeps transactions using it from
+//! contending for account locks. Its length and lamports can't be changed.
+//!
+//! Changes the caller of a cross-program invocation made to the scratch sysvar
+//! are visible to the callee, and the changes the callee makes are copied
+//! back to the caller when it returns, as for writable accounts. The sysvar
+//! can only be changed once the `scratch_sysvar` feature is active.
+//!
+//! Like the instructions and signatures sysvars, the scratch sysvar is
+//! accessed through the free functions within this module rather than
+//! through the [`Sysvar`] trait.
+//!
+//! [`Sysvar`]: crate::sysvar::Sysvar
+
+use crate::{account_info::AccountInfo, program_error::ProgramError};
+
+/// Scratch sysvar, dummy type.
+///
+/// This type exists for consistency with other sysvar modules, but is a dummy
+/// type that does not contain sysvar data. It implements the [`SysvarId`] trait
+/// but does not implement the [`Sysvar`] trait.
+///
+/// [`SysvarId`]: crate::sysvar::SysvarId
+/// [`Sysvar`]: crate::sysvar::Sysvar
+///
+/// Use the free functions in this module to access the scratch sysvar.
+pub struct Scratch();
+
+crate::declare_sysvar_id!("SysvarScratch111111111111111111111111111111", Scratch);
+
+/// Length in bytes of the scratch sysvar data.
+pub const SCRATCH_LEN: usize = 1024;
+
+/// Construct the account data for the scratch sysvar.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_scratch_data() -> Vec<u8> {
+    vec![0; SCRATCH_LEN]
+}
+
+/// Copy `dst.len()` bytes of the scratch sysvar starting at `offset` into
+/// `dst`.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if the range is not within the sysvar data.
+pub fn read_scratch_checked(
+    scratch_sysvar_account_info: &AccountInfo,
+    offset: usize,
+    dst: &mut [u8],
+) -> Result<(), ProgramError> {
+    if !check_id(scratch_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let scratch = scratch_sysvar_account_info.try_borrow_data()?;
+    let src = offset
+        .checked_add(dst.len())
+        .and_then(|end| scratch.get(offset..end))
+        .ok_or(ProgramError::InvalidArgument)?;
+    dst.copy_from_slice(src);
+    Ok(())
+}
+
+/// Copy `src` into the scratch sysvar starting at `offset`.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if the range is not within the sysvar data.
+pub fn write_scratch_checked(
+    scratch_sysvar_account_info: &AccountInfo,
+    offset: usize,
+    src: &[u8],
+) -> Result<(), ProgramError> {
+    if !check_id(scratch_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let mut scratch = scratch_sysvar_account_info.try_borrow_mut_data()?;
+    let dst = offset
+        .checked_add(src.len())
+        .and_then(|end| scratch.get_mut(offset..end))
+        .ok_or(ProgramError::InvalidArgument)?;
+    dst.copy_from_slice(src);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{clock::Epoch, pubkey::Pubkey},
+    };
+
+    #[test]
+    fn test_read_write_scratch() {
+        let mut data = construct_scratch_data();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        let mut buf = [1; 4];
+        read_scratch_checked(&account_info, SCRATCH_LEN - 4, &mut buf).unwrap();
+        assert_eq!(buf, [0; 4]);
+
+        write_scratch_checked(&account_info, 8, &[1, 2, 3, 4]).unwrap();
+        read_scratch_checked(&account_info, 8, &mut buf).unwrap();
+        assert_eq!(buf, [1, 2, 3, 4]);
+        assert_eq!(account_info.data.borrow()[6..14], [0, 0, 1, 2, 3, 4, 0, 0]);
+
+        assert_eq!(
+            read_scratch_checked(&account_info, SCRATCH_LEN - 3, &mut buf),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            write_scratch_checked(&account_info, usize::MAX, &buf),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            read_scratch_checked(&account_info, 0, &mut buf),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            write_scratch_checked(&account_info, 0, &buf),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    sysvar_spoofing_tests! {
+        data: construct_scratch_data(),
+        loaders: {
+            read_scratch_checked: |account_info| {
+                let mut buf = [1; 4];
+                read_scratch_checked(account_info, SCRATCH_LEN - 4, &mut buf).map(|()| buf)
+            } => oversized: Ok([0; 4]),
+            write_scratch_checked: |account_info| {
+                write_scratch_checked(account_info, SCRATCH_LEN - 4, &[1; 4])
+            } => oversized: Ok(()),
+        },
+    }
+}
@@ -1,11 +1,9 @@
 //! The serialized signature array of the current transaction.
 //!
 //! The _signatures sysvar_ provides access to the serialized transaction
-//! signatures of the currently-running transaction. This allows for [signature
-//! introspection][in], which is required to enable recursive, self-referential
+//! signatures of the currently-running transaction. This allows for signature
+//! introspection, which is required to enable recursive, self-referential
 //! data pointers for transaction signature-based compression.
-//! TODO:
-//! [in]: https://docs.solana.com/implemented-proposals/signature_introspection
 //!
 //! Similar to the instruction sysvar, data in the signatures sysvar is not accessed
 //! through a type that implements the [`Sysvar`] trait. Instead, the signatures
@@ -13,18 +11,85 @@
 //!
 //! [`Sysvar`]: crate::sysvar::Sysvar
 //!
-//! See also the Solana [documentation on the header sysvar][sdoc].
-//! TODO:
-//! [sdoc]: https://docs.solana.com/developing/runtime-facilities/sysvars#signatures
+//! Programs can also read the signatures with [`get_transaction_signature`],
+//! which doesn't require the sysvar account to be passed to the instruction.
+//! Once the `enable_get_serialized_message_syscall` feature is active,
+//! [`get_serialized_message`] returns the message they sign.
+//!
+//! The runtime only populates the sysvar, and registers the syscall behind
+//! [`get_transaction_signature`], once the `enable_signatures_sysvar` feature
+//! is active. Until then, the sysvar address loads like any account that
+//! doesn't exist.
+//!
+//! The signatures in the sysvar are only ever passed in from a
+//! `SanitizedTransaction` whose signatures have been verified, in the order of
+//! the signers in the transaction message. Partially signed transactions are
+//! rejected before the sysvar is constructed.
+//!
+//! Paths that skip signature verification, such as simulation with signature
+//! verification disabled or replaying a ledger without verifying it, build
+//! the sysvar from the transaction's signature bytes as they are, without
+//! dropping or marking the unverified ones. Replay must execute transactions
+//! exactly as the leader did, which had verified them, and a simulation
+//! should behave as the transaction would once it's fully signed.
+//!
+//! When the `signer_pubkeys_in_signatures_sysvar` feature is active, the
+//! sysvar uses a versioned layout that pairs each signature with the public
+//! key of its signer: a [`VERSIONED_LAYOUT_MARKER`] byte, the layout version
+//! and the number of signatures, followed by one `(pubkey, signature)` entry
+//! per signature. Use [`load_signer_and_signature_at_checked`] to learn which
+//! key produced a signature, and [`verify_signers`] to check that enough keys
+//! of a multisig signed. The other loaders read every layout.
+//!
+//! When the `u16_signature_count_in_signatures_sysvar` feature is active, and
+//! the signer pubkeys feature isn't, the sysvar uses the wide count layout: a
+//! [`VERSIONED_LAYOUT_MARKER`] byte, [`WIDE_COUNT_LAYOUT_VERSION`] and the
+//! number of signatures as a little-endian `u16`, followed by the signatures.
+//! It isn't limited to [`MAX_SIGNATURES`] signatures.
+//!
+//! In practice the number of signers is capped by the transaction rather than
+//! by the sysvar: the message header counts required signatures in a `u8`,
+//! and a transaction must fit in a single packet, which leaves room for about
+//! a dozen signers. Larger signer counts need a larger transaction format.
+//!
+//! When the `include_address_table_lookups_in_signatures_sysvar` feature is
+//! active, the signatures are followed by the address lookup tables used by
+//! the transaction: a one byte count, then one [`AddressTableLookupDigest`]
+//! per lookup, in the order of the transaction's address table lookups. Use
+//! [`load_address_table_lookup_at_checked`] to check that an account was
+//! loaded from an expected lookup table.
+//!
+//! Programs that depend on a layout can check it with [`load_layout_version`],
+//! which fails with [`LoadLayoutVersionError::UnknownVersion`] rather than
+//! misparsing the data once the runtime moves to a layout they don't know.
+//!
+//! When the `integrity_checksum_in_sysvars` feature is active, the runtime
+//! always includes the address table lookup count, zero if the transaction
+//! doesn't use lookup tables, and follows the lookups with the SHA-256 hash of
+//! everything before it. Check it with [`verify_integrity`].
+//!
+//! See also the [header sysvar], which describes which accounts of the
+//! message signed it.
+//!
+//! [header sysvar]: crate::sysvar::header
 
 #![allow(clippy::arithmetic_side_effects)]
 
+#[cfg(not(target_os = "solana"))]
 use crate::{
-    account_info::AccountInfo,
-    program_error::ProgramError, sanitize::SanitizeError,
+    instruction::{AccountMeta, Instruction},
+    message::{CompileError, SanitizedMessage},
+    serialize_utils::{append_slice, append_u16, append_u8},
+};
+use {
+    crate::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::{Pubkey, MAX_SEED_LEN},
+        sanitize::SanitizeError,
+    },
+    std::cell::Ref,
 };
-#[cfg(not(target_os = "solana"))]
-use crate::serialize_utils::{append_slice, append_u8};
 
 /// Signatures sysvar, dummy type.
 ///
@@ -38,145 +103,2547 @@ use crate::serialize_utils::{append_slice, append_u8};
 /// Use the free functions in this module to access the instructions sysvar.
 pub struct Signatures();
 
-/// Signature slice alias type
-/// 
-/// This type exists to give us better readability without having to add the
-/// Solana SDK as a dependency. This is safe, as Signature data is only ever 
-/// passed in from a SanitizedTransaction.
-type Signature = [u8;64];
-
 crate::declare_sysvar_id!("SysvarSignatures111111111111111111111111111", Signatures);
 
-/// Construct the account data for the header sysvar.
+/// Length in bytes of each signature entry in the signatures sysvar.
+pub const SIGNATURE_LEN: usize = 64;
+
+/// Length in bytes of the signature count that prefixes the signatures sysvar.
+pub const NUM_SIGNATURES_LEN: usize = 1;
+
+/// Value of the first byte of the sysvar data in the versioned layout.
+///
+/// In the original layout the first byte is the number of signatures, which
+/// never reaches this value since a transaction can't hold that many.
+pub const VERSIONED_LAYOUT_MARKER: u8 = u8::MAX;
+
+/// Version of the layout whose entries pair each signature with its signer.
+pub const SIGNER_PAIRS_LAYOUT_VERSION: u8 = 1;
+
+/// Maximum number of signatures the signatures sysvar holds.
+///
+/// The number of signatures is stored in a single byte, whose maximum marks
+/// the versioned layout. Transactions are limited to far fewer signatures by
+/// the packet size.
+pub const MAX_SIGNATURES: usize = VERSIONED_LAYOUT_MARKER as usize - 1;
+
+/// Maximum number of address table lookups the signatures sysvar holds.
+pub const MAX_ADDRESS_TABLE_LOOKUPS: usize = u8::MAX as usize;
+
+/// Length in bytes of the header of the versioned layout: the marker, the
+/// layout version and the number of signatures.
+pub const VERSIONED_HEADER_LEN: usize = 3;
+
+/// Version of the layout storing the number of signatures as a little-endian
+/// `u16`, followed by the signatures alone.
+pub const WIDE_COUNT_LAYOUT_VERSION: u8 = 2;
+
+/// Length in bytes of the header of the wide count layout: the marker, the
+/// layout version and the `u16` number of signatures.
+pub const WIDE_COUNT_HEADER_LEN: usize = 4;
+
+/// Maximum number of signatures the wide count layout holds.
+pub const MAX_WIDE_COUNT_SIGNATURES: usize = u16::MAX as usize;
+
+/// Length in bytes of a signer public key in the versioned layout.
+pub const SIGNER_PUBKEY_LEN: usize = 32;
+
+/// Length in bytes of each `(pubkey, signature)` entry in the versioned
+/// layout.
+pub const SIGNER_ENTRY_LEN: usize = SIGNER_PUBKEY_LEN + SIGNATURE_LEN;
+
+/// Length in bytes of the address table lookup count that follows the
+/// signatures, when the sysvar includes address table lookups.
+pub const NUM_ADDRESS_TABLE_LOOKUPS_LEN: usize = 1;
+
+/// Length in bytes of each address table lookup entry in the signatures sysvar.
+pub const ADDRESS_TABLE_LOOKUP_LEN: usize = 32 + 4 * 2;
+
+/// Error returned when constructing the signatures sysvar data.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SysvarSerializeError {
+    /// More signatures than [`MAX_SIGNATURES`]
+    #[error("too many signatures: {0}")]
+    TooManySignatures(usize),
+    /// No signatures, while every transaction has at least its fee payer's
+    #[error("no signatures")]
+    NoSignatures,
+    /// More address table lookups than [`MAX_ADDRESS_TABLE_LOOKUPS`]
+    #[error("too many address table lookups: {0}")]
+    TooManyAddressTableLookups(usize),
+}
+
+/// The layouts of the signatures sysvar data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SignaturesLayoutVersion {
+    /// The number of signatures as a single byte, followed by the signatures
+    Legacy,
+    /// The versioned layout pairing each signature with its signer, see
+    /// [`SIGNER_PAIRS_LAYOUT_VERSION`]
+    SignerPairs,
+    /// The versioned layout storing the number of signatures as a `u16`, see
+    /// [`WIDE_COUNT_LAYOUT_VERSION`]
+    WideCount,
+}
+
+impl SignaturesLayoutVersion {
+    /// The version byte following [`VERSIONED_LAYOUT_MARKER`], or `None` for
+    /// the legacy layout, which has neither.
+    pub const fn version(self) -> Option<u8> {
+        match self {
+            Self::Legacy => None,
+            Self::SignerPairs => Some(SIGNER_PAIRS_LAYOUT_VERSION),
+            Self::WideCount => Some(WIDE_COUNT_LAYOUT_VERSION),
+        }
+    }
+
+    /// Whether the layout pairs each signature with the public key of its
+    /// signer.
+    pub const fn has_signers(self) -> bool {
+        matches!(self, Self::SignerPairs)
+    }
+}
+
+/// A transaction signature as stored in the signatures sysvar.
+///
+/// This is a plain 64-byte array so that programs can use it without
+/// depending on the Solana SDK, and converts to and from `[u8; 64]`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TxSignature(pub [u8; SIGNATURE_LEN]);
+
+impl TxSignature {
+    /// Wrap the raw bytes of a signature.
+    pub const fn new(bytes: [u8; SIGNATURE_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Borrow the raw bytes of the signature.
+    pub const fn as_bytes(&self) -> &[u8; SIGNATURE_LEN] {
+        &self.0
+    }
+
+    /// Return the raw bytes of the signature.
+    pub const fn to_bytes(self) -> [u8; SIGNATURE_LEN] {
+        self.0
+    }
+
+    /// The base58 encoding of the signature, as displayed by clients and
+    /// explorers.
+    #[cfg(not(target_os = "solana"))]
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.0).into_string()
+    }
+
+    /// Split the signature into the seeds used to derive its program address.
+    ///
+    /// A signature is longer than [`MAX_SEED_LEN`], so it is split into two
+    /// seeds: its first and last 32 bytes, in that order. Off-chain clients
+    /// must split signatures the same way to derive the same addresses.
+    pub fn seeds(&self) -> [&[u8]; 2] {
+        let (first, last) = self.0.split_at(MAX_SEED_LEN);
+        [first, last]
+    }
+}
+
+impl From<[u8; SIGNATURE_LEN]> for TxSignature {
+    fn from(bytes: [u8; SIGNATURE_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<TxSignature> for [u8; SIGNATURE_LEN] {
+    fn from(signature: TxSignature) -> Self {
+        signature.0
+    }
+}
+
+impl AsRef<[u8]> for TxSignature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq<[u8; SIGNATURE_LEN]> for TxSignature {
+    fn eq(&self, other: &[u8; SIGNATURE_LEN]) -> bool {
+        &self.0 == other
+    }
+}
+
+/// An address lookup table used by the current transaction, and where the
+/// addresses it resolved to are in the transaction's account keys.
+///
+/// Loaded addresses follow the static account keys of a transaction, the
+/// writable addresses of every lookup first and then the readonly ones, so the
+/// addresses resolved from each table form one writable and one readonly range
+/// of account indexes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AddressTableLookupDigest {
+    /// Address of the lookup table account
+    pub account_key: Pubkey,
+    /// Account index of the first writable address loaded from the table
+    pub writable_start: u16,
+    /// Number of writable addresses loaded from the table
+    pub writable_len: u16,
+    /// Account index of the first readonly address loaded from the table
+    pub readonly_start: u16,
+    /// Number of readonly addresses loaded from the table
+    pub readonly_len: u16,
+}
+
+impl AddressTableLookupDigest {
+    /// Returns `true` if the account at `account_index` was loaded from this
+    /// lookup table.
+    pub fn contains(&self, account_index: usize) -> bool {
+        let in_range = |start: u16, len: u16| {
+            let start = start as usize;
+            account_index >= start && account_index < start + len as usize
+        };
+        in_range(self.writable_start, self.writable_len)
+            || in_range(self.readonly_start, self.readonly_len)
+    }
+}
+
+/// Byte offset of the signature at `index` within the signatures sysvar data,
+/// in the original layout.
+///
+/// This does not check `index` against the number of signatures stored in
+/// the sysvar.
+pub const fn signature_offset(index: usize) -> usize {
+    NUM_SIGNATURES_LEN + index * SIGNATURE_LEN
+}
+
+/// Length in bytes of the signatures sysvar data holding `count` signatures,
+/// in the original layout.
+///
+/// Useful for sizing fixed buffers, e.g. `[0u8; sysvar_data_len(2)]`.
+pub const fn sysvar_data_len(count: usize) -> usize {
+    signature_offset(count)
+}
+
+/// Pass the signatures sysvar to `instruction`, as the readonly, unsigned
+/// account the runtime requires.
+///
+/// The account is appended to the accounts of the instruction, unless the
+/// instruction already passes it.
+///
+/// # Errors
+///
+/// Returns [`CompileError::InvalidSysvarAccountMeta`] if the instruction already passes the
+/// signatures sysvar as a writable or signer account.
+#[cfg(not(target_os = "solana"))]
+pub fn append_account_meta(instruction: &mut Instruction) -> Result<(), CompileError> {
+    match instruction
+        .accounts
+        .iter()
+        .find(|account_meta| check_id(&account_meta.pubkey))
+    {
+        Some(account_meta) if account_meta.is_signer || account_meta.is_writable => {
+            Err(CompileError::InvalidSysvarAccountMeta(ID))
+        }
+        Some(_) => Ok(()),
+        None => {
+            instruction
+                .accounts
+                .push(AccountMeta::new_readonly(ID, false));
+            Ok(())
+        }
+    }
+}
+
+/// Construct the account data for the signatures sysvar.
+///
+/// This is a thin wrapper around [`construct_signatures_data_from_iter`].
 ///
 /// This function is used by the runtime and not available to Solana programs.
 #[cfg(not(target_os = "solana"))]
-pub fn construct_signatures_data(signatures: &[Signature]) -> Vec<u8> {
-    serialize_signatures(signatures)
+pub fn construct_signatures_data(
+    signatures: &[TxSignature],
+) -> Result<Vec<u8>, SysvarSerializeError> {
+    construct_signatures_data_from_iter(signatures.iter().map(TxSignature::as_bytes))
 }
 
 /// Construct the account data for the signatures sysvar.
 ///
+/// Unlike [`construct_signatures_data`], the number of signatures isn't
+/// checked: it wraps around in the data if there are more than 255.
+///
 /// This function is used by the runtime and not available to Solana programs.
 #[cfg(not(target_os = "solana"))]
-pub fn serialize_signatures(signatures: &[Signature]) -> Vec<u8> {
-    let mut data = Vec::with_capacity(1 + signatures.len() * 64);
+#[deprecated(
+    since = "1.18.0",
+    note = "Please use `construct_signatures_data` instead, which checks the number of signatures"
+)]
+pub fn serialize_signatures(signatures: &[TxSignature]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(sysvar_data_len(signatures.len()));
     append_u8(&mut data, signatures.len() as u8);
+    for signature in signatures {
+        append_slice(&mut data, signature.as_bytes());
+    }
+    data
+}
+
+#[cfg(not(target_os = "solana"))]
+fn check_num_signatures(num_signatures: usize) -> Result<u8, SysvarSerializeError> {
+    match num_signatures {
+        0 => Err(SysvarSerializeError::NoSignatures),
+        1..=MAX_SIGNATURES => Ok(num_signatures as u8),
+        _ => Err(SysvarSerializeError::TooManySignatures(num_signatures)),
+    }
+}
+
+/// Construct the account data for the signatures sysvar from the signatures'
+/// bytes.
+///
+/// Callers holding signatures of another type, such as the SDK's
+/// `Signature`, can map them to their bytes instead of collecting them into
+/// [`TxSignature`]s first.
+///
+/// # Errors
+///
+/// Returns [`SysvarSerializeError::NoSignatures`] if there are no signatures,
+/// and [`SysvarSerializeError::TooManySignatures`] if there are more than
+/// [`MAX_SIGNATURES`].
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_signatures_data_from_iter<'a>(
+    signatures: impl ExactSizeIterator<Item = &'a [u8; SIGNATURE_LEN]>,
+) -> Result<Vec<u8>, SysvarSerializeError> {
+    let num_signatures = check_num_signatures(signatures.len())?;
+    let mut data = Vec::with_capacity(sysvar_data_len(signatures.len()));
+    append_u8(&mut data, num_signatures);
     for sig in signatures {
         append_slice(&mut data, sig);
     }
-    data
+    Ok(data)
+}
+
+/// The address lookup tables used by `message`, in the order of its address
+/// table lookups.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn address_table_lookup_digests(message: &SanitizedMessage) -> Vec<AddressTableLookupDigest> {
+    let SanitizedMessage::V0(message) = message else {
+        return Vec::new();
+    };
+    let lookups = &message.message.address_table_lookups;
+    let num_static_keys = message.message.account_keys.len();
+    let num_writable_loaded = lookups
+        .iter()
+        .map(|lookup| lookup.writable_indexes.len())
+        .sum::<usize>();
+
+    let mut writable_start = num_static_keys;
+    let mut readonly_start = num_static_keys + num_writable_loaded;
+    lookups
+        .iter()
+        .map(|lookup| {
+            let digest = AddressTableLookupDigest {
+                account_key: lookup.account_key,
+                writable_start: writable_start as u16,
+                writable_len: lookup.writable_indexes.len() as u16,
+                readonly_start: readonly_start as u16,
+                readonly_len: lookup.readonly_indexes.len() as u16,
+            };
+            writable_start += lookup.writable_indexes.len();
+            readonly_start += lookup.readonly_indexes.len();
+            digest
+        })
+        .collect()
+}
+
+/// Construct the account data for the signatures sysvar, followed by the
+/// address lookup tables used by the transaction.
+///
+/// # Errors
+///
+/// Returns the errors of [`construct_signatures_data_from_iter`], and
+/// [`SysvarSerializeError::TooManyAddressTableLookups`] if there are more
+/// than [`MAX_ADDRESS_TABLE_LOOKUPS`] lookups.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_signatures_data_with_address_table_lookups<'a>(
+    signatures: impl ExactSizeIterator<Item = &'a [u8; SIGNATURE_LEN]>,
+    lookups: &[AddressTableLookupDigest],
+) -> Result<Vec<u8>, SysvarSerializeError> {
+    let mut data = construct_signatures_data_from_iter(signatures)?;
+    append_address_table_lookups(&mut data, lookups)?;
+    Ok(data)
+}
+
+/// Construct the account data for the signatures sysvar in the versioned
+/// layout, pairing each signature with the public key of its signer, and
+/// followed by the address lookup tables used by the transaction if
+/// `lookups` is `Some`.
+///
+/// # Errors
+///
+/// Returns the same errors as
+/// [`construct_signatures_data_with_address_table_lookups`].
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_signer_signatures_data<'a>(
+    entries: impl ExactSizeIterator<Item = (&'a Pubkey, &'a [u8; SIGNATURE_LEN])>,
+    lookups: Option<&[AddressTableLookupDigest]>,
+) -> Result<Vec<u8>, SysvarSerializeError> {
+    let num_signatures = check_num_signatures(entries.len())?;
+    let mut data = Vec::with_capacity(VERSIONED_HEADER_LEN + entries.len() * SIGNER_ENTRY_LEN);
+    append_u8(&mut data, VERSIONED_LAYOUT_MARKER);
+    append_u8(&mut data, SIGNER_PAIRS_LAYOUT_VERSION);
+    append_u8(&mut data, num_signatures);
+    for (pubkey, signature) in entries {
+        append_slice(&mut data, pubkey.as_ref());
+        append_slice(&mut data, signature);
+    }
+    if let Some(lookups) = lookups {
+        append_address_table_lookups(&mut data, lookups)?;
+    }
+    Ok(data)
+}
+
+/// Construct the account data for the signatures sysvar in the wide count
+/// layout, followed by the address lookup tables used by the transaction if
+/// `lookups` is `Some`.
+///
+/// # Errors
+///
+/// Returns [`SysvarSerializeError::NoSignatures`] if there are no signatures,
+/// [`SysvarSerializeError::TooManySignatures`] if there are more than
+/// [`MAX_WIDE_COUNT_SIGNATURES`], and
+/// [`SysvarSerializeError::TooManyAddressTableLookups`] if there are more
+/// than [`MAX_ADDRESS_TABLE_LOOKUPS`] lookups.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_wide_count_signatures_data<'a>(
+    signatures: impl ExactSizeIterator<Item = &'a [u8; SIGNATURE_LEN]>,
+    lookups: Option<&[AddressTableLookupDigest]>,
+) -> Result<Vec<u8>, SysvarSerializeError> {
+    let num_signatures = match signatures.len() {
+        0 => return Err(SysvarSerializeError::NoSignatures),
+        len => u16::try_from(len).map_err(|_| SysvarSerializeError::TooManySignatures(len))?,
+    };
+    let mut data = Vec::with_capacity(WIDE_COUNT_HEADER_LEN + signatures.len() * SIGNATURE_LEN);
+    append_u8(&mut data, VERSIONED_LAYOUT_MARKER);
+    append_u8(&mut data, WIDE_COUNT_LAYOUT_VERSION);
+    append_u16(&mut data, num_signatures);
+    for signature in signatures {
+        append_slice(&mut data, signature);
+    }
+    if let Some(lookups) = lookups {
+        append_address_table_lookups(&mut data, lookups)?;
+    }
+    Ok(data)
+}
+
+#[cfg(not(target_os = "solana"))]
+fn append_address_table_lookups(
+    data: &mut Vec<u8>,
+    lookups: &[AddressTableLookupDigest],
+) -> Result<(), SysvarSerializeError> {
+    if lookups.len() > MAX_ADDRESS_TABLE_LOOKUPS {
+        return Err(SysvarSerializeError::TooManyAddressTableLookups(
+            lookups.len(),
+        ));
+    }
+    data.reserve(NUM_ADDRESS_TABLE_LOOKUPS_LEN + lookups.len() * ADDRESS_TABLE_LOOKUP_LEN);
+    append_u8(data, lookups.len() as u8);
+    for lookup in lookups {
+        append_slice(data, lookup.account_key.as_ref());
+        append_u16(data, lookup.writable_start);
+        append_u16(data, lookup.writable_len);
+        append_u16(data, lookup.readonly_start);
+        append_u16(data, lookup.readonly_len);
+    }
+    Ok(())
+}
+
+/// Append the integrity checksum to the account data constructed by one of
+/// the `construct_*` functions, first appending an empty address table lookup
+/// list if the data doesn't include one.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn append_integrity_checksum(data: &mut Vec<u8>) {
+    if address_table_lookups_offset(data) == Ok(data.len()) {
+        append_u8(data, 0);
+    }
+    let checksum = crate::hash::hash(data);
+    append_slice(data, checksum.as_ref());
+}
+
+/// The contents of the signatures sysvar, as decoded by clients and tests.
+#[cfg(not(target_os = "solana"))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignaturesData {
+    pub signatures: Vec<TxSignature>,
+    /// The signer of each signature, `None` if the sysvar uses the original
+    /// layout
+    pub signers: Option<Vec<Pubkey>>,
+    /// `None` if the sysvar does not include address table lookups
+    pub address_table_lookups: Option<Vec<AddressTableLookupDigest>>,
+}
+
+#[cfg(not(target_os = "solana"))]
+impl SignaturesData {
+    /// Decode the account data of the signatures sysvar.
+    ///
+    /// The data is first checked with [`validate_signatures_data`].
+    pub fn deserialize(data: &[u8]) -> Result<Self, SanitizeError> {
+        validate_signatures_data(data)?;
+        let layout = Layout::parse(data)?;
+        let signatures = (0..layout.num_signatures)
+            .map(|index| deserialize_signature(index, data))
+            .collect::<Result<_, _>>()?;
+        let signers = if layout.version.has_signers() {
+            let signers = (0..layout.num_signatures)
+                .map(|index| deserialize_signer(index, data))
+                .collect::<Result<_, _>>()?;
+            Some(signers)
+        } else {
+            None
+        };
+
+        let address_table_lookups = if data.len() == layout.entries_end() {
+            None
+        } else {
+            let address_table_lookups: Vec<_> = (0..deserialize_num_address_table_lookups(data)?)
+                .map(|index| deserialize_address_table_lookup(index, data))
+                .collect::<Result<_, _>>()?;
+            Some(address_table_lookups)
+        };
+
+        Ok(Self {
+            signatures,
+            signers,
+            address_table_lookups,
+        })
+    }
 }
 
-/// Load a `Signature` in the currently executing `Transaction` at the
+/// Load a [`TxSignature`] in the currently executing `Transaction` at the
 /// specified index.
 ///
+/// Each call borrows the sysvar data and parses the number of signatures
+/// again. Programs loading more than one signature should construct a
+/// [`SignaturesView`] once and look signatures up through it instead of
+/// calling this function in a loop.
+///
 /// # Errors
 ///
 /// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
-/// Returns [`ProgramError::InvalidArgument`] if the signature index is out of bounds.
+/// Returns [`ProgramError::InvalidArgument`] if the signature index is out of
+/// bounds, after logging the requested index and the number of signatures.
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is empty or truncated.
+/// Returns [`ProgramError::InvalidInstructionData`] if the sysvar uses an unknown layout.
 pub fn load_signature_at_checked(
     index: usize,
     signature_sysvar_account_info: &AccountInfo,
-) -> Result<Signature, ProgramError> {
+) -> Result<TxSignature, ProgramError> {
     if !check_id(signature_sysvar_account_info.key) {
         return Err(ProgramError::UnsupportedSysvar);
     }
 
     let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
-    deserialize_signature(index, &signature_sysvar).map_err(|err| match err {
-        SanitizeError::IndexOutOfBounds => ProgramError::InvalidArgument,
+    let layout = parse_layout_checked(&signature_sysvar)?;
+    if layout.version.has_signers() {
+        let entry = crate::sysvar::load_entry_at_checked::<SIGNER_ENTRY_LEN>(
+            "signatures",
+            &signature_sysvar,
+            layout.entries_offset,
+            layout.num_signatures,
+            index,
+        )?;
+        let mut signature = [0; SIGNATURE_LEN];
+        signature.copy_from_slice(&entry[SIGNER_PUBKEY_LEN..]);
+        Ok(TxSignature(signature))
+    } else {
+        crate::sysvar::load_entry_at_checked::<SIGNATURE_LEN>(
+            "signatures",
+            &signature_sysvar,
+            layout.entries_offset,
+            layout.num_signatures,
+            index,
+        )
+        .map(|signature| TxSignature(*signature))
+    }
+}
+
+/// Load a [`TxSignature`] at the specified index from the already borrowed
+/// data of the signatures sysvar.
+///
+/// Unlike [`load_signature_at_checked`], this neither checks the account ID
+/// nor borrows the account data, which saves compute units when a program
+/// loads several signatures after validating the account once. The caller
+/// must have checked that `data` belongs to the account at [`ID`]: any other
+/// account can hold data that parses as signatures.
+///
+/// # Errors
+///
+/// Returns [`SanitizeError::IndexOutOfBounds`] if the signature index is out of bounds or the
+/// data is truncated.
+/// Returns [`SanitizeError::InvalidValue`] if the sysvar uses an unknown layout.
+pub fn load_signature_at_unchecked(
+    index: usize,
+    data: &[u8],
+) -> Result<TxSignature, SanitizeError> {
+    deserialize_signature(index, data)
+}
+
+/// Load the signature at the specified index in the currently executing
+/// `Transaction`, and the public key of the signer that produced it.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if the signature index is out of
+/// bounds, after logging the requested index and the number of signatures.
+/// Returns [`ProgramError::InvalidAccountData`] if the sysvar uses the original layout, which
+/// doesn't include signers, because the `signer_pubkeys_in_signatures_sysvar` feature is not
+/// active.
+pub fn load_signer_and_signature_at_checked(
+    index: usize,
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<(Pubkey, TxSignature), ProgramError> {
+    let signature = load_signature_at_checked(index, signature_sysvar_account_info)?;
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    let signer = deserialize_signer(index, &signature_sysvar).map_err(|err| match err {
+        SanitizeError::InvalidValue => ProgramError::InvalidAccountData,
         _ => ProgramError::InvalidInstructionData,
-    })
+    })?;
+    Ok((signer, signature))
 }
 
-fn deserialize_signature(index: usize, data: &[u8]) -> Result<Signature, SanitizeError> {
-    // Make sure data is not empty
-    if data.is_empty() {
-        return Err(SanitizeError::IndexOutOfBounds);
+/// Check that at least `threshold` of the `required` public keys signed the
+/// currently executing `Transaction`.
+///
+/// Multisig programs can enforce an m-of-n policy with a single call, against
+/// the signers recorded in the sysvar, rather than passing every member
+/// account to the instruction and checking `is_signer`. Keys repeated in
+/// `required` count once.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidArgument`] if `threshold` is larger than the number of keys in
+/// `required`.
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the sysvar uses a layout that doesn't include
+/// signers, because the `signer_pubkeys_in_signatures_sysvar` feature is not active, or the
+/// account data is truncated.
+/// Returns [`ProgramError::MissingRequiredSignature`] if fewer than `threshold` of the keys
+/// signed, after logging how many did.
+pub fn verify_signers(
+    required: &[Pubkey],
+    threshold: usize,
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if threshold > required.len() {
+        return Err(ProgramError::InvalidArgument);
     }
-    
-    // Read the number of signatures from the first byte
-    let num_signatures = data[0] as usize;
-    
-    // Make sure the index is not out of bounds
-    if index >= num_signatures {
-        return Err(SanitizeError::IndexOutOfBounds);
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
     }
 
-    // Calculate the starting position for the signature in the data
-    let start = 1 + index * 64; // Skip the first byte which holds the number of signatures
-    let end = start + 64;
-
-    // Ensure there are enough remaining bytes in the data
-    if end > data.len() {
-        return Err(SanitizeError::IndexOutOfBounds);
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    let layout = parse_layout_checked(&signature_sysvar)?;
+    if !layout.version.has_signers() {
+        return Err(ProgramError::InvalidAccountData);
     }
+    let entries = signature_sysvar
+        .get(layout.entries_offset..layout.entries_end())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let is_signer = |pubkey: &Pubkey| {
+        entries
+            .chunks_exact(SIGNER_ENTRY_LEN)
+            .any(|entry| &entry[..SIGNER_PUBKEY_LEN] == pubkey.as_ref())
+    };
 
-    // Read the signature
-    let mut signature: [u8; 64] = [0; 64];
-    signature.copy_from_slice(&data[start..end]);
-    Ok(signature)
+    let mut num_signed = 0;
+    for (position, pubkey) in required.iter().enumerate() {
+        if num_signed >= threshold {
+            return Ok(());
+        }
+        if !required[..position].contains(pubkey) && is_signer(pubkey) {
+            num_signed += 1;
+        }
+    }
+    if num_signed >= threshold {
+        Ok(())
+    } else {
+        crate::msg!(
+            "{} of the required signers signed, below the threshold of {}",
+            num_signed,
+            threshold
+        );
+        Err(ProgramError::MissingRequiredSignature)
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::clock::Epoch;
+/// Load the number of signatures of the currently executing `Transaction`.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is empty or uses an unknown
+/// layout.
+pub fn load_num_signatures(
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
 
-    use {
-        super::*,
-        crate::pubkey::Pubkey,
-    };
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    Layout::parse(&signature_sysvar)
+        .map(|layout| layout.num_signatures)
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
 
-    #[test]
-    fn test_load_signature_at_checked() {
-        let owner = Pubkey::new_unique();
-        let mut lamports = 1_000_000_000;
-        let mut data: Vec<u8> = vec![3;193];
-        data[1..65].copy_from_slice(&[0;64]);
-        data[65..129].copy_from_slice(&[1;64]);
-        data[129..193].copy_from_slice(&[2;64]);
-        let account_info = AccountInfo::new(
-            &ID,
-            false,
-            true,
-            &mut lamports,
-            &mut data,
-            &owner,
-            false,
-            Epoch::default(),
-        );
+/// Load every signature of the currently executing `Transaction`, in the
+/// order of the signers in the transaction message.
+///
+/// The returned vector holds at most as many signatures as the sysvar
+/// declares, which the single count byte bounds. This allocates, so it is
+/// only available off-chain; programs iterate a [`SignaturesView`] instead.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is too short to hold the
+/// number of signatures it declares.
+#[cfg(not(target_os = "solana"))]
+pub fn load_all_signatures(
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<Vec<TxSignature>, ProgramError> {
+    let view = SignaturesView::new(signature_sysvar_account_info)?;
+    Ok(view.iter().collect())
+}
 
-        let sig = load_signature_at_checked(0, &account_info).unwrap();
-        assert_eq!(sig, [0;64]);
+/// Error returned by [`load_layout_version`].
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum LoadLayoutVersionError {
+    /// The sysvar uses a versioned layout this program doesn't know, likely
+    /// introduced by a runtime upgrade
+    #[error("unknown signatures sysvar layout version {0}")]
+    UnknownVersion(u8),
+    /// The account isn't the signatures sysvar, or its data is malformed
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+}
 
-        let sig = load_signature_at_checked(1, &account_info).unwrap();
-        assert_eq!(sig, [1;64]);
+impl From<LoadLayoutVersionError> for ProgramError {
+    fn from(err: LoadLayoutVersionError) -> Self {
+        match err {
+            LoadLayoutVersionError::UnknownVersion(_) => ProgramError::InvalidAccountData,
+            LoadLayoutVersionError::Program(err) => err,
+        }
+    }
+}
 
-        let sig = load_signature_at_checked(2, &account_info).unwrap();
-        assert_eq!(sig, [2;64]);
+/// Load the layout of the signatures sysvar of the currently executing
+/// `Transaction`.
+///
+/// Programs that only support some layouts should check the result before
+/// reading the sysvar data by hand, so that a runtime upgrade to a new
+/// layout fails the instruction instead of being misparsed. The loaders of
+/// this module read every known layout.
+///
+/// # Errors
+///
+/// Returns [`LoadLayoutVersionError::UnknownVersion`] with the version byte if the sysvar uses a
+/// versioned layout this program doesn't know.
+/// Returns [`LoadLayoutVersionError::Program`] with [`ProgramError::UnsupportedSysvar`] if the
+/// given account's ID is not equal to [`ID`], and with [`ProgramError::InvalidAccountData`] if
+/// the account data is too short to hold the header.
+pub fn load_layout_version(
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<SignaturesLayoutVersion, LoadLayoutVersionError> {
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar.into());
+    }
 
-        assert!(matches!(load_signature_at_checked(3, &account_info), Err(ProgramError::InvalidArgument)));
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    match Layout::parse(&signature_sysvar) {
+        Ok(layout) => Ok(layout.version),
+        Err(SanitizeError::InvalidValue) => {
+            Err(LoadLayoutVersionError::UnknownVersion(signature_sysvar[1]))
+        }
+        Err(_) => Err(ProgramError::InvalidAccountData.into()),
     }
+}
 
-    #[test]
-    fn test_construct_signatures_data() {
-        let signatures: [Signature; 5] = [
-            [0;64],
-            [1;64],
-            [2;64],
-            [3;64],
-            [4;64],
-        ];
-        let data = construct_signatures_data(&signatures);
+/// Error returned by [`load_signature_at_compat`].
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum LoadSignatureCompatError {
+    /// The cluster doesn't provide the signatures sysvar yet, so the account
+    /// at its address isn't owned by the sysvar program
+    #[error("the signatures sysvar is not available")]
+    NotAvailable,
+    /// Loading from the sysvar failed, see [`load_signature_at_checked`]
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+}
+
+impl From<LoadSignatureCompatError> for ProgramError {
+    fn from(err: LoadSignatureCompatError) -> Self {
+        match err {
+            LoadSignatureCompatError::NotAvailable => ProgramError::UnsupportedSysvar,
+            LoadSignatureCompatError::Program(err) => err,
+        }
+    }
+}
+
+/// Load a [`TxSignature`] in the currently executing `Transaction` at the
+/// specified index, on clusters with or without the signatures sysvar.
+///
+/// On clusters that don't construct the signatures sysvar, the account at
+/// its address is loaded like any other, and doesn't exist. This function
+/// tells that case apart from a sysvar that is present, so that programs can
+/// fall back to another path until the sysvar is available, and then use it
+/// without being redeployed. The signatures can't be recovered from the
+/// instructions sysvar, so there is no emulated path.
+///
+/// # Errors
+///
+/// Returns [`LoadSignatureCompatError::NotAvailable`] if the account at the sysvar address is
+/// not owned by the sysvar program.
+/// Returns [`LoadSignatureCompatError::Program`] with the error of
+/// [`load_signature_at_checked`] otherwise.
+pub fn load_signature_at_compat(
+    index: usize,
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<TxSignature, LoadSignatureCompatError> {
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar.into());
+    }
+    if !crate::sysvar::check_id(signature_sysvar_account_info.owner) {
+        return Err(LoadSignatureCompatError::NotAvailable);
+    }
+    Ok(load_signature_at_checked(
+        index,
+        signature_sysvar_account_info,
+    )?)
+}
+
+/// Get the signature at `index` of the currently executing transaction
+/// through the `sol_get_transaction_signature` syscall.
+///
+/// Unlike [`load_signature_at_checked`], this doesn't require the signatures
+/// sysvar account to be passed to the instruction. Signatures are ordered
+/// like the signer account keys of the message.
+///
+/// Each call consumes a base compute unit cost, plus a copy cost when a
+/// signature is returned.
+///
+/// Returns `None` if `index` is out of bounds.
+pub fn get_transaction_signature(index: usize) -> Option<TxSignature> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut signature = [0u8; SIGNATURE_LEN];
+        if 1 == unsafe {
+            crate::syscalls::sol_get_transaction_signature(index as u64, signature.as_mut_ptr())
+        } {
+            Some(TxSignature(signature))
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_get_transaction_signature(index)
+}
+
+/// Get the serialized message of the currently executing transaction through
+/// the `sol_get_serialized_message` syscall.
+///
+/// These are the bytes the signers of the transaction signed, so together
+/// with [`get_transaction_signature`] they let a program verify the signatures
+/// of its transaction, or compute its hash.
+///
+/// Copies as much of the message as fits into `data`, and returns the length
+/// of the whole message, which is 0 if the runtime didn't provide one. A
+/// transaction fits in a 1232 byte packet, so a buffer of that size always
+/// holds the message.
+///
+/// Each call consumes a base compute unit cost, plus a cost proportional to
+/// the length of `data`.
+pub fn get_serialized_message(data: &mut [u8]) -> usize {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        crate::syscalls::sol_get_serialized_message(data.as_mut_ptr(), data.len() as u64) as usize
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    crate::program_stubs::sol_get_serialized_message(data)
+}
+
+/// A view of the signatures sysvar that parses the number of signatures once.
+///
+/// [`load_signature_at_checked`] checks the account ID, borrows the account
+/// data and reads the number of signatures on every call. A view does so once
+/// when constructed, and holds the borrow of the account data until it is
+/// dropped, so that looking up signatures afterwards only costs a bounds check
+/// and a copy.
+///
+/// # Examples
+///
+/// ```
+/// # use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+/// use solana_program::sysvar::signatures::{SignaturesView, TxSignature};
+///
+/// fn is_signed_with(
+///     signatures_sysvar: &AccountInfo,
+///     expected: &[TxSignature],
+/// ) -> Result<bool, ProgramError> {
+///     let view = SignaturesView::new(signatures_sysvar)?;
+///     Ok(expected
+///         .iter()
+///         .all(|expected| view.iter().any(|signature| signature == *expected)))
+/// }
+/// ```
+pub struct SignaturesView<'a> {
+    data: Ref<'a, [u8]>,
+    layout: Layout,
+}
+
+impl<'a> SignaturesView<'a> {
+    /// Borrow the data of the signatures sysvar and parse the number of
+    /// signatures.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+    /// Returns [`ProgramError::InvalidAccountData`] if the account data is too short to hold the
+    /// number of signatures it declares.
+    /// Returns [`ProgramError::AccountBorrowFailed`] if the account data is mutably borrowed.
+    pub fn new(signature_sysvar_account_info: &'a AccountInfo) -> Result<Self, ProgramError> {
+        if !check_id(signature_sysvar_account_info.key) {
+            return Err(ProgramError::UnsupportedSysvar);
+        }
+
+        let data = Ref::map(signature_sysvar_account_info.try_borrow_data()?, |data| {
+            &**data
+        });
+        let layout = SignaturesSysvar::new(&data)?.layout;
+        Ok(Self { data, layout })
+    }
+
+    /// The number of signatures of the currently executing `Transaction`.
+    pub fn len(&self) -> usize {
+        self.layout.num_signatures
+    }
+
+    /// Returns `true` if the sysvar holds no signatures.
+    pub fn is_empty(&self) -> bool {
+        self.layout.num_signatures == 0
+    }
+
+    /// The signature at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<TxSignature> {
+        (index < self.layout.num_signatures).then(|| self.signature_at(index))
+    }
+
+    /// Iterate over the signatures, in the order of the signers in the
+    /// transaction message.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = TxSignature> + '_ {
+        (0..self.layout.num_signatures).map(|index| self.signature_at(index))
+    }
+
+    /// Borrow the signatures in place, without copying them.
+    pub fn sysvar(&self) -> SignaturesSysvar<'_> {
+        SignaturesSysvar {
+            data: &self.data,
+            layout: self.layout,
+        }
+    }
+
+    /// The data length was checked against the number of signatures when the
+    /// view was constructed, so `index` only needs to be in bounds.
+    fn signature_at(&self, index: usize) -> TxSignature {
+        TxSignature(*self.sysvar().signature_at(index))
+    }
+}
+
+/// Zero-copy access to the signatures in the data of the signatures sysvar.
+///
+/// Where [`SignaturesView`] copies each signature out of the account data,
+/// this yields references into the data, so that scanning every signature
+/// costs neither a borrow nor a copy per signature. Get one from a view with
+/// [`SignaturesView::sysvar`], which checks the account ID and holds the
+/// borrow of the account data, or from data already borrowed with
+/// [`SignaturesSysvar::new`].
+///
+/// # Examples
+///
+/// ```
+/// # use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+/// use solana_program::sysvar::signatures::SignaturesView;
+///
+/// fn count_signatures_with_prefix(
+///     signatures_sysvar: &AccountInfo,
+///     prefix: &[u8],
+/// ) -> Result<usize, ProgramError> {
+///     let view = SignaturesView::new(signatures_sysvar)?;
+///     Ok(view
+///         .sysvar()
+///         .iter()
+///         .filter(|signature| signature.starts_with(prefix))
+///         .count())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SignaturesSysvar<'a> {
+    data: &'a [u8],
+    layout: Layout,
+}
+
+impl<'a> SignaturesSysvar<'a> {
+    /// Parse the number of signatures of signatures sysvar data.
+    ///
+    /// This doesn't check which account `data` belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::InvalidAccountData`] if the data is too short to hold the number
+    /// of signatures it declares.
+    pub fn new(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let layout = Layout::parse(data).map_err(|_| ProgramError::InvalidAccountData)?;
+        if data.len() < layout.entries_end() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self { data, layout })
+    }
+
+    /// The number of signatures of the currently executing `Transaction`.
+    pub fn len(&self) -> usize {
+        self.layout.num_signatures
+    }
+
+    /// Returns `true` if the sysvar holds no signatures.
+    pub fn is_empty(&self) -> bool {
+        self.layout.num_signatures == 0
+    }
+
+    /// The signature at `index`, or `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a [u8; SIGNATURE_LEN]> {
+        (index < self.layout.num_signatures).then(|| self.signature_at(index))
+    }
+
+    /// Iterate over the signatures, in the order of the signers in the
+    /// transaction message.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a [u8; SIGNATURE_LEN]> + 'a {
+        let sysvar = *self;
+        (0..self.layout.num_signatures).map(move |index| sysvar.signature_at(index))
+    }
+
+    /// The data length was checked against the number of signatures on
+    /// construction, so `index` only needs to be in bounds.
+    fn signature_at(&self, index: usize) -> &'a [u8; SIGNATURE_LEN] {
+        let start = self.layout.signature_offset(index);
+        self.data[start..start + SIGNATURE_LEN]
+            .try_into()
+            .expect("signature length")
+    }
+}
+
+/// Load the number of address lookup tables used by the currently executing
+/// `Transaction`.
+///
+/// Returns zero if the sysvar does not include address table lookups.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+pub fn load_num_address_table_lookups_checked(
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    deserialize_num_address_table_lookups(&signature_sysvar)
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Load the [`AddressTableLookupDigest`] of the address lookup table at the
+/// specified index in the currently executing `Transaction`.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if the lookup index is out of
+/// bounds, after logging the requested index and the number of lookups.
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is truncated.
+/// Returns [`ProgramError::InvalidInstructionData`] if the sysvar uses an unknown layout.
+pub fn load_address_table_lookup_at_checked(
+    index: usize,
+    signature_sysvar_account_info: &AccountInfo,
+) -> Result<AddressTableLookupDigest, ProgramError> {
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    let lookups_offset = parse_layout_checked(&signature_sysvar)?.entries_end();
+    let num_lookups = deserialize_num_address_table_lookups(&signature_sysvar)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    crate::sysvar::load_entry_at_checked::<ADDRESS_TABLE_LOOKUP_LEN>(
+        "signatures lookups",
+        &signature_sysvar,
+        lookups_offset + NUM_ADDRESS_TABLE_LOOKUPS_LEN,
+        num_lookups,
+        index,
+    )
+    .map(parse_address_table_lookup)
+}
+
+/// Check the integrity checksum of the signatures sysvar.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the checksum doesn't match the signatures
+/// and address table lookups, or is missing because the `integrity_checksum_in_sysvars` feature
+/// is not active, after logging which.
+pub fn verify_integrity(signature_sysvar_account_info: &AccountInfo) -> Result<(), ProgramError> {
+    if !check_id(signature_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let signature_sysvar = signature_sysvar_account_info.try_borrow_data()?;
+    let payload_len =
+        payload_len(&signature_sysvar).map_err(|_| ProgramError::InvalidAccountData)?;
+    let (payload, checksum) = signature_sysvar.split_at(payload_len);
+    crate::sysvar::verify_integrity_checksum("signatures", payload, checksum)
+}
+
+/// Find the program address derived from the signature at `index` of the
+/// currently executing transaction.
+///
+/// The signature is read with [`get_transaction_signature`], so the sysvar
+/// account doesn't need to be passed to the instruction. Returns `None` if
+/// `index` is out of bounds. See [`Pubkey::find_program_address_from_signature`].
+pub fn find_program_address_from_transaction_signature(
+    index: usize,
+    program_id: &Pubkey,
+) -> Option<(Pubkey, u8)> {
+    get_transaction_signature(index)
+        .map(|signature| Pubkey::find_program_address_from_signature(&signature, program_id))
+}
+
+/// Where the entries of the signatures sysvar are, in any layout.
+#[derive(Clone, Copy, Debug)]
+struct Layout {
+    num_signatures: usize,
+    entries_offset: usize,
+    entry_len: usize,
+    version: SignaturesLayoutVersion,
+}
+
+impl Layout {
+    fn parse(data: &[u8]) -> Result<Self, SanitizeError> {
+        match *data.first().ok_or(SanitizeError::IndexOutOfBounds)? {
+            VERSIONED_LAYOUT_MARKER => {
+                match *data.get(1).ok_or(SanitizeError::IndexOutOfBounds)? {
+                    SIGNER_PAIRS_LAYOUT_VERSION => {
+                        let num_signatures = *data.get(2).ok_or(SanitizeError::IndexOutOfBounds)?;
+                        Ok(Self {
+                            num_signatures: num_signatures as usize,
+                            entries_offset: VERSIONED_HEADER_LEN,
+                            entry_len: SIGNER_ENTRY_LEN,
+                            version: SignaturesLayoutVersion::SignerPairs,
+                        })
+                    }
+                    WIDE_COUNT_LAYOUT_VERSION => {
+                        let num_signatures = data
+                            .get(2..WIDE_COUNT_HEADER_LEN)
+                            .ok_or(SanitizeError::IndexOutOfBounds)?;
+                        Ok(Self {
+                            num_signatures: u16::from_le_bytes([
+                                num_signatures[0],
+                                num_signatures[1],
+                            ]) as usize,
+                            entries_offset: WIDE_COUNT_HEADER_LEN,
+                            entry_len: SIGNATURE_LEN,
+                            version: SignaturesLayoutVersion::WideCount,
+                        })
+                    }
+                    _ => Err(SanitizeError::InvalidValue),
+                }
+            }
+            num_signatures => Ok(Self {
+                num_signatures: num_signatures as usize,
+                entries_offset: NUM_SIGNATURES_LEN,
+                entry_len: SIGNATURE_LEN,
+                version: SignaturesLayoutVersion::Legacy,
+            }),
+        }
+    }
+
+    fn entry_offset(&self, index: usize) -> usize {
+        self.entries_offset + index * self.entry_len
+    }
+
+    fn signature_offset(&self, index: usize) -> usize {
+        let signer_len = if self.version.has_signers() {
+            SIGNER_PUBKEY_LEN
+        } else {
+            0
+        };
+        self.entry_offset(index) + signer_len
+    }
+
+    /// Byte offset following the last entry
+    fn entries_end(&self) -> usize {
+        self.entry_offset(self.num_signatures)
+    }
+}
+
+/// Parse the layout of the sysvar for the checked loaders, which return
+/// [`ProgramError::InvalidInstructionData`] for an unknown layout.
+fn parse_layout_checked(data: &[u8]) -> Result<Layout, ProgramError> {
+    Layout::parse(data).map_err(|err| match err {
+        SanitizeError::InvalidValue => ProgramError::InvalidInstructionData,
+        _ => ProgramError::InvalidAccountData,
+    })
+}
+
+fn deserialize_signature(index: usize, data: &[u8]) -> Result<TxSignature, SanitizeError> {
+    let layout = Layout::parse(data)?;
+
+    // Make sure the index is not out of bounds
+    if index >= layout.num_signatures {
+        return Err(SanitizeError::IndexOutOfBounds);
+    }
+
+    // Calculate the starting position for the signature in the data
+    let start = layout.signature_offset(index);
+    let end = start + SIGNATURE_LEN;
+
+    // Ensure there are enough remaining bytes in the data
+    if end > data.len() {
+        return Err(SanitizeError::IndexOutOfBounds);
+    }
+
+    // Read the signature
+    let mut signature = [0; SIGNATURE_LEN];
+    signature.copy_from_slice(&data[start..end]);
+    Ok(TxSignature(signature))
+}
+
+/// Returns [`SanitizeError::InvalidValue`] if the layout doesn't include
+/// signers.
+fn deserialize_signer(index: usize, data: &[u8]) -> Result<Pubkey, SanitizeError> {
+    let layout = Layout::parse(data)?;
+    if !layout.version.has_signers() {
+        return Err(SanitizeError::InvalidValue);
+    }
+    if index >= layout.num_signatures {
+        return Err(SanitizeError::IndexOutOfBounds);
+    }
+
+    let start = layout.entry_offset(index);
+    data.get(start..start + SIGNER_PUBKEY_LEN)
+        .and_then(|signer| Pubkey::try_from(signer).ok())
+        .ok_or(SanitizeError::IndexOutOfBounds)
+}
+
+/// Byte offset of the address table lookup count, which directly follows the
+/// signatures.
+fn address_table_lookups_offset(data: &[u8]) -> Result<usize, SanitizeError> {
+    Ok(Layout::parse(data)?.entries_end())
+}
+
+/// Check that `data` is well-formed signatures sysvar data, without trusting
+/// any count it holds.
+///
+/// The loaders in this module only check the bounds of what they read, so
+/// they accept data that the runtime never constructs. The runtime uses this
+/// function to reject signatures sysvar accounts that don't come from the
+/// `construct_*` functions, such as test overrides.
+///
+/// # Errors
+///
+/// Returns [`SanitizeError::IndexOutOfBounds`] if the data is shorter than its
+/// counts require, and [`SanitizeError::InvalidValue`] if the layout version
+/// is unknown, the number of signatures is zero or more than the layout
+/// allows, or the data holds trailing bytes other than a matching integrity
+/// checksum.
+pub fn validate_signatures_data(data: &[u8]) -> Result<(), SanitizeError> {
+    let layout = Layout::parse(data)?;
+    let max_signatures = match layout.version {
+        SignaturesLayoutVersion::Legacy | SignaturesLayoutVersion::SignerPairs => MAX_SIGNATURES,
+        SignaturesLayoutVersion::WideCount => MAX_WIDE_COUNT_SIGNATURES,
+    };
+    if layout.num_signatures == 0 || layout.num_signatures > max_signatures {
+        return Err(SanitizeError::InvalidValue);
+    }
+    if data.len() < layout.entries_end() {
+        return Err(SanitizeError::IndexOutOfBounds);
+    }
+
+    let (payload, checksum) = data.split_at(payload_len(data)?);
+    if checksum.is_empty() || checksum == crate::hash::hash(payload).as_ref() {
+        Ok(())
+    } else {
+        Err(SanitizeError::InvalidValue)
+    }
+}
+
+/// Byte offset following the address table lookups, or the last entry if the
+/// data doesn't include lookups.
+fn payload_len(data: &[u8]) -> Result<usize, SanitizeError> {
+    let offset = address_table_lookups_offset(data)?;
+    if data.len() == offset {
+        return Ok(offset);
+    }
+    let end = offset
+        + NUM_ADDRESS_TABLE_LOOKUPS_LEN
+        + deserialize_num_address_table_lookups(data)? * ADDRESS_TABLE_LOOKUP_LEN;
+    if end > data.len() {
+        return Err(SanitizeError::IndexOutOfBounds);
+    }
+    Ok(end)
+}
+
+fn deserialize_num_address_table_lookups(data: &[u8]) -> Result<usize, SanitizeError> {
+    let offset = address_table_lookups_offset(data)?;
+    match data.get(offset) {
+        Some(num_lookups) => Ok(*num_lookups as usize),
+        None if data.len() == offset => Ok(0),
+        None => Err(SanitizeError::IndexOutOfBounds),
+    }
+}
+
+fn deserialize_address_table_lookup(
+    index: usize,
+    data: &[u8],
+) -> Result<AddressTableLookupDigest, SanitizeError> {
+    if index >= deserialize_num_address_table_lookups(data)? {
+        return Err(SanitizeError::IndexOutOfBounds);
+    }
+
+    let start = address_table_lookups_offset(data)?
+        + NUM_ADDRESS_TABLE_LOOKUPS_LEN
+        + index * ADDRESS_TABLE_LOOKUP_LEN;
+    data.get(start..start + ADDRESS_TABLE_LOOKUP_LEN)
+        .and_then(|entry| entry.try_into().ok())
+        .map(parse_address_table_lookup)
+        .ok_or(SanitizeError::IndexOutOfBounds)
+}
+
+fn parse_address_table_lookup(entry: &[u8; ADDRESS_TABLE_LOOKUP_LEN]) -> AddressTableLookupDigest {
+    let read_u16 = |offset: usize| u16::from_le_bytes([entry[offset], entry[offset + 1]]);
+
+    let mut account_key = [0; 32];
+    account_key.copy_from_slice(&entry[..32]);
+    AddressTableLookupDigest {
+        account_key: Pubkey::from(account_key),
+        writable_start: read_u16(32),
+        writable_len: read_u16(34),
+        readonly_start: read_u16(36),
+        readonly_len: read_u16(38),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::clock::Epoch};
+
+    #[test]
+    fn test_append_account_meta() {
+        let program_id = Pubkey::new_unique();
+        let other = AccountMeta::new(Pubkey::new_unique(), true);
+        let mut instruction = Instruction::new_with_bytes(program_id, &[], vec![other.clone()]);
+        append_account_meta(&mut instruction).unwrap();
+        let expected_accounts = vec![other.clone(), AccountMeta::new_readonly(ID, false)];
+        assert_eq!(instruction.accounts, expected_accounts);
+
+        // Passing the sysvar again leaves the accounts as they are
+        append_account_meta(&mut instruction).unwrap();
+        assert_eq!(instruction.accounts, expected_accounts);
+
+        for account_meta in [
+            AccountMeta::new(ID, false),
+            AccountMeta::new_readonly(ID, true),
+        ] {
+            let mut instruction =
+                Instruction::new_with_bytes(program_id, &[], vec![account_meta.clone()]);
+            assert_eq!(
+                append_account_meta(&mut instruction),
+                Err(CompileError::InvalidSysvarAccountMeta(ID))
+            );
+            assert_eq!(instruction.accounts, vec![account_meta]);
+        }
+    }
+
+    sysvar_test_suite! {
+        entry: |index: usize| TxSignature::from([index as u8; SIGNATURE_LEN]),
+        data: |signatures: &[TxSignature]| construct_signatures_data(signatures).unwrap(),
+        load: load_signature_at_checked,
+        // The number of signatures is stored in a single byte, whose maximum
+        // marks the versioned layout
+        max_count: VERSIONED_LAYOUT_MARKER as usize - 1,
+    }
+
+    fn spoofing_signer(index: u8) -> Pubkey {
+        Pubkey::new_from_array([index; 32])
+    }
+
+    fn spoofing_signature(index: u8) -> TxSignature {
+        TxSignature::from([index; SIGNATURE_LEN])
+    }
+
+    fn spoofing_lookup() -> AddressTableLookupDigest {
+        AddressTableLookupDigest {
+            account_key: Pubkey::new_from_array([9; 32]),
+            writable_start: 2,
+            writable_len: 1,
+            readonly_start: 3,
+            readonly_len: 1,
+        }
+    }
+
+    /// Signer pairs, an address table lookup and the integrity checksum, as
+    /// constructed with every signatures sysvar feature active
+    fn spoofing_data() -> Vec<u8> {
+        let signers = [spoofing_signer(1), spoofing_signer(2)];
+        let signatures = [spoofing_signature(1).0, spoofing_signature(2).0];
+        let mut data = construct_signer_signatures_data(
+            signers.iter().zip(signatures.iter()),
+            Some(&[spoofing_lookup()]),
+        )
+        .unwrap();
+        append_integrity_checksum(&mut data);
+        data
+    }
+
+    // `load_signature_at_compat` checks the owner, see
+    // `test_load_signature_at_compat`. Trailing bytes only break the checksum.
+    sysvar_spoofing_tests! {
+        data: spoofing_data(),
+        loaders: {
+            load_signature_at_checked: |account_info| load_signature_at_checked(1, account_info)
+                => oversized: Ok(spoofing_signature(2)),
+            load_signer_and_signature_at_checked: |account_info| {
+                load_signer_and_signature_at_checked(1, account_info)
+            } => oversized: Ok((spoofing_signer(2), spoofing_signature(2))),
+            load_num_signatures: load_num_signatures => oversized: Ok(2),
+            load_all_signatures: load_all_signatures
+                => oversized: Ok(vec![spoofing_signature(1), spoofing_signature(2)]),
+            load_layout_version: load_layout_version
+                => oversized: Ok(SignaturesLayoutVersion::SignerPairs),
+            load_num_address_table_lookups_checked: load_num_address_table_lookups_checked
+                => oversized: Ok(1),
+            load_address_table_lookup_at_checked: |account_info| {
+                load_address_table_lookup_at_checked(0, account_info)
+            } => oversized: Ok(spoofing_lookup()),
+            verify_signers: |account_info| {
+                verify_signers(&[spoofing_signer(2), spoofing_signer(3)], 1, account_info)
+            } => oversized: Ok(()),
+            verify_integrity: verify_integrity
+                => oversized: Err(ProgramError::InvalidAccountData),
+        },
+    }
+
+    #[test]
+    fn test_load_signature_at_checked() {
+        let owner = Pubkey::new_unique();
+        let mut lamports = 1_000_000_000;
+        let mut data: Vec<u8> = vec![3; 193];
+        data[1..65].copy_from_slice(&[0; 64]);
+        data[65..129].copy_from_slice(&[1; 64]);
+        data[129..193].copy_from_slice(&[2; 64]);
+        let account_info = AccountInfo::new(
+            &ID,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        let sig = load_signature_at_checked(0, &account_info).unwrap();
+        assert_eq!(sig, [0; 64]);
+
+        let sig = load_signature_at_checked(1, &account_info).unwrap();
+        assert_eq!(sig, [1; 64]);
+
+        let sig = load_signature_at_checked(2, &account_info).unwrap();
+        assert_eq!(sig, [2; 64]);
+
+        assert!(matches!(
+            load_signature_at_checked(3, &account_info),
+            Err(ProgramError::InvalidArgument)
+        ));
+    }
+
+    #[test]
+    fn test_load_signature_at_unchecked() {
+        let signatures = [[0; 64], [1; 64]].map(TxSignature::from);
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let layouts = [
+            construct_signatures_data(&signatures).unwrap(),
+            construct_signer_signatures_data(
+                signers
+                    .iter()
+                    .zip(signatures.iter().map(|signature| &signature.0)),
+                None,
+            )
+            .unwrap(),
+            construct_wide_count_signatures_data(
+                signatures.iter().map(|signature| &signature.0),
+                None,
+            )
+            .unwrap(),
+        ];
+        for data in layouts {
+            for (index, signature) in signatures.iter().enumerate() {
+                assert_eq!(load_signature_at_unchecked(index, &data), Ok(*signature));
+            }
+            assert_eq!(
+                load_signature_at_unchecked(signatures.len(), &data),
+                Err(SanitizeError::IndexOutOfBounds)
+            );
+            assert_eq!(
+                load_signature_at_unchecked(1, &data[..data.len() - 1]),
+                Err(SanitizeError::IndexOutOfBounds)
+            );
+        }
+
+        assert_eq!(
+            load_signature_at_unchecked(0, &[VERSIONED_LAYOUT_MARKER, u8::MAX, 1]),
+            Err(SanitizeError::InvalidValue)
+        );
+        assert_eq!(
+            load_signature_at_unchecked(0, &[]),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_load_signature_at_compat() {
+        let signatures = [[0; 64], [1; 64]].map(TxSignature::from);
+        let mut data = construct_signatures_data(&signatures).unwrap();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        assert_eq!(
+            load_signature_at_compat(1, &account_info),
+            Ok(signatures[1])
+        );
+        assert_eq!(
+            load_signature_at_compat(2, &account_info),
+            Err(LoadSignatureCompatError::Program(
+                ProgramError::InvalidArgument
+            ))
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_signature_at_compat(0, &account_info),
+            Err(LoadSignatureCompatError::Program(
+                ProgramError::UnsupportedSysvar
+            ))
+        );
+
+        // Without the sysvar, the account at its address doesn't exist
+        let system_program = Pubkey::default();
+        let mut lamports = 0;
+        let mut data = vec![];
+        let account_info = AccountInfo::new(
+            &ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &system_program,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            load_signature_at_compat(0, &account_info),
+            Err(LoadSignatureCompatError::NotAvailable)
+        );
+        assert_eq!(
+            ProgramError::from(LoadSignatureCompatError::NotAvailable),
+            ProgramError::UnsupportedSysvar
+        );
+    }
+
+    #[test]
+    fn test_signatures_view() {
+        let signatures = [[0; 64], [1; 64], [2; 64]].map(TxSignature::from);
+        let mut data = construct_signatures_data(&signatures).unwrap();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        {
+            let view = SignaturesView::new(&account_info).unwrap();
+            assert_eq!(view.len(), 3);
+            assert!(!view.is_empty());
+            for (index, signature) in signatures.iter().enumerate() {
+                assert_eq!(view.get(index), Some(*signature));
+                assert_eq!(
+                    load_signature_at_checked(index, &account_info),
+                    Ok(*signature)
+                );
+            }
+            assert_eq!(view.get(3), None);
+            assert!(view.iter().eq(signatures));
+
+            // The account data stays borrowed while the view is alive
+            assert_eq!(
+                account_info.try_borrow_mut_data().err(),
+                Some(ProgramError::AccountBorrowFailed)
+            );
+        }
+
+        // Data too short for the number of signatures it declares
+        account_info.data.borrow_mut()[0] = 4;
+        assert_eq!(
+            SignaturesView::new(&account_info).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            SignaturesView::new(&account_info).err(),
+            Some(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_signatures_sysvar() {
+        let signatures = [[0; 64], [1; 64], [2; 64]].map(TxSignature::from);
+        let legacy_data = construct_signatures_data(&signatures).unwrap();
+        let signer_data = construct_signer_signatures_data(
+            [
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ]
+            .iter()
+            .zip(signatures.iter().map(TxSignature::as_bytes)),
+            None,
+        )
+        .unwrap();
+
+        for data in [&legacy_data, &signer_data] {
+            let sysvar = SignaturesSysvar::new(data).unwrap();
+            assert_eq!(sysvar.len(), 3);
+            assert!(!sysvar.is_empty());
+            assert_eq!(sysvar.get(3), None);
+            for (index, signature) in sysvar.iter().enumerate() {
+                assert_eq!(signature, signatures[index].as_bytes());
+                assert_eq!(sysvar.get(index), Some(signature));
+
+                // The signature is borrowed from the data, not copied
+                let offset = signature.as_ptr() as usize - data.as_ptr() as usize;
+                assert_eq!(&data[offset..offset + SIGNATURE_LEN], signature);
+            }
+
+            assert_eq!(
+                SignaturesSysvar::new(&data[..data.len() - 1]).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+        }
+        assert!(SignaturesSysvar::new(&[0]).unwrap().is_empty());
+        assert_eq!(
+            SignaturesSysvar::new(&[]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_construct_signatures_data() {
+        let signatures = [[0; 64], [1; 64], [2; 64], [3; 64], [4; 64]].map(TxSignature::from);
+        let data = construct_signatures_data(&signatures).unwrap();
 
         let mut expected_data: Vec<u8> = vec![5];
-        expected_data.extend_from_slice(&[0;64]);
-        expected_data.extend_from_slice(&[1;64]);
-        expected_data.extend_from_slice(&[2;64]);
-        expected_data.extend_from_slice(&[3;64]);
-        expected_data.extend_from_slice(&[4;64]);
+        expected_data.extend_from_slice(&[0; 64]);
+        expected_data.extend_from_slice(&[1; 64]);
+        expected_data.extend_from_slice(&[2; 64]);
+        expected_data.extend_from_slice(&[3; 64]);
+        expected_data.extend_from_slice(&[4; 64]);
 
         assert_eq!(data, expected_data);
+        assert_eq!(data.len(), sysvar_data_len(signatures.len()));
+
+        let signatures = vec![TxSignature::from([0; 64]); MAX_SIGNATURES + 1];
+        assert_eq!(
+            construct_signatures_data(&signatures[..MAX_SIGNATURES])
+                .unwrap()
+                .first(),
+            Some(&(MAX_SIGNATURES as u8))
+        );
+        assert_eq!(
+            construct_signatures_data(&signatures),
+            Err(SysvarSerializeError::TooManySignatures(MAX_SIGNATURES + 1))
+        );
+        assert_eq!(
+            construct_signatures_data(&[]),
+            Err(SysvarSerializeError::NoSignatures)
+        );
+
+        let signers = vec![Pubkey::default(); MAX_SIGNATURES + 1];
+        assert_eq!(
+            construct_signer_signatures_data(
+                signers
+                    .iter()
+                    .zip(signatures.iter().map(TxSignature::as_bytes)),
+                None,
+            ),
+            Err(SysvarSerializeError::TooManySignatures(MAX_SIGNATURES + 1))
+        );
+        let lookups = vec![AddressTableLookupDigest::default(); MAX_ADDRESS_TABLE_LOOKUPS + 1];
+        assert_eq!(
+            construct_signatures_data_with_address_table_lookups(
+                signatures[..1].iter().map(TxSignature::as_bytes),
+                &lookups,
+            ),
+            Err(SysvarSerializeError::TooManyAddressTableLookups(
+                MAX_ADDRESS_TABLE_LOOKUPS + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_construct_signatures_data_from_iter() {
+        let signatures = [[0; 64], [1; 64], [2; 64]];
+        let tx_signatures = signatures.map(TxSignature::from);
+        let expected_data = construct_signatures_data(&tx_signatures).unwrap();
+
+        assert_eq!(
+            construct_signatures_data_from_iter(signatures.iter()).unwrap(),
+            expected_data
+        );
+        let signature_refs: Vec<&[u8; 64]> = signatures.iter().collect();
+        assert_eq!(
+            construct_signatures_data_from_iter(signature_refs.into_iter()).unwrap(),
+            expected_data
+        );
+        #[allow(deprecated)]
+        let serialized = serialize_signatures(&tx_signatures);
+        assert_eq!(serialized, expected_data);
+        assert_eq!(
+            construct_signatures_data_from_iter(std::iter::empty()),
+            Err(SysvarSerializeError::NoSignatures)
+        );
+    }
+
+    #[test]
+    fn test_find_program_address_from_signature() {
+        let mut bytes = [0; SIGNATURE_LEN];
+        bytes[..32].copy_from_slice(&[1; 32]);
+        bytes[32..].copy_from_slice(&[2; 32]);
+        let signature = TxSignature::from(bytes);
+        assert_eq!(signature.seeds(), [&[1; 32][..], &[2; 32][..]]);
+
+        let program_id = Pubkey::new_unique();
+        let (address, bump_seed) = Pubkey::find_program_address(&[&[1; 32], &[2; 32]], &program_id);
+        assert_eq!(
+            Pubkey::find_program_address_from_signature(&signature, &program_id),
+            (address, bump_seed)
+        );
+        assert_eq!(
+            Pubkey::create_program_address_from_signature(&signature, bump_seed, &program_id),
+            Ok(address)
+        );
+        assert_ne!(
+            Pubkey::create_program_address_from_signature(
+                &TxSignature::from([3; SIGNATURE_LEN]),
+                bump_seed,
+                &program_id
+            ),
+            Ok(address)
+        );
+    }
+
+    #[test]
+    fn test_tx_signature() {
+        let bytes = [9; SIGNATURE_LEN];
+        let signature = TxSignature::from(bytes);
+        assert_eq!(signature.as_bytes(), &bytes);
+        assert_eq!(<[u8; SIGNATURE_LEN]>::from(signature), bytes);
+        assert_eq!(signature.to_base58(), bs58::encode(bytes).into_string());
+        assert_eq!(signature, bytes);
+    }
+
+    #[test]
+    fn test_address_table_lookup_digests() {
+        use crate::message::{
+            v0::{self, LoadedAddresses, LoadedMessage, MessageAddressTableLookup},
+            MessageHeader,
+        };
+
+        let table0 = Pubkey::new_unique();
+        let table1 = Pubkey::new_unique();
+        let message = SanitizedMessage::V0(LoadedMessage::new(
+            v0::Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+                address_table_lookups: vec![
+                    MessageAddressTableLookup {
+                        account_key: table0,
+                        writable_indexes: vec![0, 1],
+                        readonly_indexes: vec![2],
+                    },
+                    MessageAddressTableLookup {
+                        account_key: table1,
+                        writable_indexes: vec![0],
+                        readonly_indexes: vec![1, 2],
+                    },
+                ],
+                ..v0::Message::default()
+            },
+            LoadedAddresses {
+                writable: (0..3).map(|_| Pubkey::new_unique()).collect(),
+                readonly: (0..3).map(|_| Pubkey::new_unique()).collect(),
+            },
+        ));
+
+        let lookups = address_table_lookup_digests(&message);
+        assert_eq!(
+            lookups,
+            vec![
+                AddressTableLookupDigest {
+                    account_key: table0,
+                    writable_start: 2,
+                    writable_len: 2,
+                    readonly_start: 5,
+                    readonly_len: 1,
+                },
+                AddressTableLookupDigest {
+                    account_key: table1,
+                    writable_start: 4,
+                    writable_len: 1,
+                    readonly_start: 6,
+                    readonly_len: 2,
+                },
+            ]
+        );
+        assert!(!lookups[0].contains(1));
+        assert!(lookups[0].contains(3));
+        assert!(!lookups[0].contains(4));
+        assert!(lookups[0].contains(5));
+        assert!(lookups[1].contains(4));
+        assert!(lookups[1].contains(7));
+        assert!(!lookups[1].contains(8));
+    }
+
+    #[test]
+    fn test_load_address_table_lookup_at_checked() {
+        let lookups = [
+            AddressTableLookupDigest {
+                account_key: Pubkey::new_unique(),
+                writable_start: 2,
+                writable_len: 1,
+                readonly_start: 4,
+                readonly_len: 0,
+            },
+            AddressTableLookupDigest {
+                account_key: Pubkey::new_unique(),
+                writable_start: 3,
+                writable_len: 1,
+                readonly_start: 4,
+                readonly_len: 2,
+            },
+        ];
+        let signatures = [[0; 64], [1; 64]].map(TxSignature::from);
+        let mut data = construct_signatures_data_with_address_table_lookups(
+            signatures.iter().map(TxSignature::as_bytes),
+            &lookups,
+        )
+        .unwrap();
+        assert_eq!(
+            data.len(),
+            sysvar_data_len(2) + NUM_ADDRESS_TABLE_LOOKUPS_LEN + 2 * ADDRESS_TABLE_LOOKUP_LEN
+        );
+
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        // Signatures are still read as before
+        assert_eq!(
+            load_signature_at_checked(1, &account_info),
+            Ok(signatures[1])
+        );
+        assert_eq!(load_num_address_table_lookups_checked(&account_info), Ok(2));
+        for (index, lookup) in lookups.iter().enumerate() {
+            assert_eq!(
+                load_address_table_lookup_at_checked(index, &account_info),
+                Ok(*lookup)
+            );
+        }
+        assert_eq!(
+            load_address_table_lookup_at_checked(2, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_num_address_table_lookups_checked(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            load_address_table_lookup_at_checked(0, &account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+
+        // Without address table lookups, there are no lookups to load
+        let data = construct_signatures_data(&signatures).unwrap();
+        assert_eq!(deserialize_num_address_table_lookups(&data), Ok(0));
+        assert_eq!(
+            deserialize_address_table_lookup(0, &data),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_signatures_data_deserialize() {
+        let signatures = vec![TxSignature::from([1; 64]), TxSignature::from([2; 64])];
+        let data = construct_signatures_data(&signatures).unwrap();
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Ok(SignaturesData {
+                signatures: signatures.clone(),
+                signers: None,
+                address_table_lookups: None,
+            })
+        );
+
+        let address_table_lookups = vec![AddressTableLookupDigest {
+            account_key: Pubkey::new_unique(),
+            writable_start: 3,
+            writable_len: 1,
+            readonly_start: 4,
+            readonly_len: 1,
+        }];
+        let mut data = construct_signatures_data_with_address_table_lookups(
+            signatures.iter().map(TxSignature::as_bytes),
+            &address_table_lookups,
+        )
+        .unwrap();
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Ok(SignaturesData {
+                signatures,
+                signers: None,
+                address_table_lookups: Some(address_table_lookups),
+            })
+        );
+
+        let mut checksummed = data.clone();
+        append_integrity_checksum(&mut checksummed);
+        assert_eq!(
+            SignaturesData::deserialize(&checksummed).unwrap(),
+            SignaturesData::deserialize(&data).unwrap()
+        );
+        *checksummed.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            SignaturesData::deserialize(&checksummed),
+            Err(SanitizeError::InvalidValue)
+        );
+
+        data.push(0);
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Err(SanitizeError::InvalidValue)
+        );
+        data.truncate(data.len() - 2);
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            SignaturesData::deserialize(&[]),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_validate_signatures_data() {
+        let signatures = vec![TxSignature::from([1; 64]), TxSignature::from([2; 64])];
+        let data = construct_signatures_data(&signatures).unwrap();
+        assert_eq!(validate_signatures_data(&data), Ok(()));
+        let mut checksummed = data.clone();
+        append_integrity_checksum(&mut checksummed);
+        assert_eq!(validate_signatures_data(&checksummed), Ok(()));
+
+        // The count byte isn't trusted
+        let mut overcounted = data.clone();
+        overcounted[0] = MAX_SIGNATURES as u8;
+        assert_eq!(
+            validate_signatures_data(&overcounted),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            validate_signatures_data(&[0]),
+            Err(SanitizeError::InvalidValue)
+        );
+        let mut signer_pairs = vec![
+            VERSIONED_LAYOUT_MARKER,
+            SIGNER_PAIRS_LAYOUT_VERSION,
+            u8::MAX,
+        ];
+        signer_pairs.resize(
+            VERSIONED_HEADER_LEN + u8::MAX as usize * SIGNER_ENTRY_LEN,
+            0,
+        );
+        assert_eq!(
+            validate_signatures_data(&signer_pairs),
+            Err(SanitizeError::InvalidValue)
+        );
+        assert_eq!(
+            validate_signatures_data(&[VERSIONED_LAYOUT_MARKER, u8::MAX, 1]),
+            Err(SanitizeError::InvalidValue)
+        );
+        assert_eq!(
+            validate_signatures_data(&[]),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+
+        // Trailing bytes are lookups, a checksum, or invalid
+        let mut trailing = data.clone();
+        trailing.push(1);
+        assert_eq!(
+            validate_signatures_data(&trailing),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        trailing[data.len()] = 0;
+        trailing.push(0);
+        assert_eq!(
+            validate_signatures_data(&trailing),
+            Err(SanitizeError::InvalidValue)
+        );
+        *checksummed.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            validate_signatures_data(&checksummed),
+            Err(SanitizeError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn test_verify_integrity() {
+        let signatures = vec![TxSignature::from([1; 64]), TxSignature::from([2; 64])];
+        let data = construct_signatures_data(&signatures).unwrap();
+        let mut checksummed = data.clone();
+        append_integrity_checksum(&mut checksummed);
+
+        // An empty lookup list is added so the checksum isn't read as lookups
+        assert_eq!(
+            checksummed.len(),
+            data.len() + NUM_ADDRESS_TABLE_LOOKUPS_LEN + crate::sysvar::INTEGRITY_CHECKSUM_LEN
+        );
+        assert_eq!(
+            SignaturesData::deserialize(&checksummed),
+            Ok(SignaturesData {
+                signatures,
+                signers: None,
+                address_table_lookups: Some(vec![]),
+            })
+        );
+
+        let key = id();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let mut account_data = checksummed.clone();
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut account_data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(verify_integrity(&account_info), Ok(()));
+        assert_eq!(load_num_signatures(&account_info), Ok(2));
+        assert_eq!(load_num_address_table_lookups_checked(&account_info), Ok(0));
+
+        account_info.try_borrow_mut_data().unwrap()[1] ^= 1;
+        assert_eq!(
+            verify_integrity(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Constructed without a checksum
+        let mut lamports = 0;
+        let mut account_data = data;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut account_data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            verify_integrity(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            verify_integrity(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_load_signer_and_signature_at_checked() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let signatures = [[1; 64], [2; 64]].map(TxSignature::from);
+        let mut data = construct_signer_signatures_data(
+            signers
+                .iter()
+                .zip(signatures.iter().map(TxSignature::as_bytes)),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            data.len(),
+            VERSIONED_HEADER_LEN + signers.len() * SIGNER_ENTRY_LEN
+        );
+        let mut legacy_data = construct_signatures_data(&signatures).unwrap();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        for (index, (signer, signature)) in signers.iter().zip(signatures).enumerate() {
+            assert_eq!(
+                load_signer_and_signature_at_checked(index, &account_info),
+                Ok((*signer, signature))
+            );
+            assert_eq!(
+                load_signature_at_checked(index, &account_info),
+                Ok(signature)
+            );
+        }
+        assert_eq!(
+            load_signer_and_signature_at_checked(2, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+        {
+            let view = SignaturesView::new(&account_info).unwrap();
+            assert!(view.iter().eq(signatures));
+        }
+
+        // Unknown layout version
+        account_info.data.borrow_mut()[1] = u8::MAX;
+        assert_eq!(
+            load_signer_and_signature_at_checked(0, &account_info),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        account_info.data.borrow_mut()[1] = SIGNER_PAIRS_LAYOUT_VERSION;
+
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut legacy_data[..]));
+        assert_eq!(
+            load_signer_and_signature_at_checked(0, &account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_signer_and_signature_at_checked(0, &account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_verify_signers() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let signatures = [[1; 64], [2; 64]].map(TxSignature::from);
+        let mut data = construct_signer_signatures_data(
+            signers
+                .iter()
+                .zip(signatures.iter().map(TxSignature::as_bytes)),
+            None,
+        )
+        .unwrap();
+        let mut legacy_data = construct_signatures_data(&signatures).unwrap();
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        let other = Pubkey::new_unique();
+        let required = [signers[1], other, signers[0]];
+        assert_eq!(verify_signers(&required, 0, &account_info), Ok(()));
+        assert_eq!(verify_signers(&required, 2, &account_info), Ok(()));
+        assert_eq!(
+            verify_signers(&required, 3, &account_info),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(
+            verify_signers(&required, 4, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        // A repeated signer only counts once
+        let required = [signers[0], signers[0], other];
+        assert_eq!(verify_signers(&required, 1, &account_info), Ok(()));
+        assert_eq!(
+            verify_signers(&required, 2, &account_info),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // The original layout doesn't record signers
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut legacy_data[..]));
+        assert_eq!(
+            verify_signers(&signers, 1, &account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_load_num_and_all_signatures() {
+        let signatures = [[0; 64], [1; 64], [2; 64]].map(TxSignature::from);
+        let mut data = construct_signatures_data(&signatures).unwrap();
+        let mut signer_data = construct_signer_signatures_data(
+            [
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ]
+            .iter()
+            .zip(signatures.iter().map(TxSignature::as_bytes)),
+            None,
+        )
+        .unwrap();
+        let mut empty_data: [u8; 0] = [];
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(load_num_signatures(&account_info), Ok(3));
+        assert_eq!(load_all_signatures(&account_info), Ok(signatures.to_vec()));
+
+        // Data too short for the number of signatures it declares
+        account_info.data.borrow_mut()[0] = 4;
+        assert_eq!(load_num_signatures(&account_info), Ok(4));
+        assert_eq!(
+            load_all_signatures(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut signer_data[..]));
+        assert_eq!(load_num_signatures(&account_info), Ok(3));
+        assert_eq!(load_all_signatures(&account_info), Ok(signatures.to_vec()));
+
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut empty_data[..]));
+        assert_eq!(
+            load_num_signatures(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_num_signatures(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            load_all_signatures(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_signatures_data_deserialize_signers() {
+        let signers = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let signatures = vec![TxSignature::from([1; 64]), TxSignature::from([2; 64])];
+        let entries = || {
+            signers
+                .iter()
+                .zip(signatures.iter().map(TxSignature::as_bytes))
+        };
+        assert_eq!(
+            SignaturesData::deserialize(
+                &construct_signer_signatures_data(entries(), None).unwrap()
+            ),
+            Ok(SignaturesData {
+                signatures: signatures.clone(),
+                signers: Some(signers.clone()),
+                address_table_lookups: None,
+            })
+        );
+
+        let address_table_lookups = vec![AddressTableLookupDigest {
+            account_key: Pubkey::new_unique(),
+            writable_start: 3,
+            writable_len: 1,
+            readonly_start: 4,
+            readonly_len: 1,
+        }];
+        let data =
+            construct_signer_signatures_data(entries(), Some(&address_table_lookups)).unwrap();
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Ok(SignaturesData {
+                signatures: signatures.clone(),
+                signers: Some(signers.clone()),
+                address_table_lookups: Some(address_table_lookups),
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_layout_version() {
+        let signatures = [[1; 64], [2; 64]].map(TxSignature::from);
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = ID;
+        let mut check = |mut data: Vec<u8>| {
+            let account_info = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                Epoch::default(),
+            );
+            load_layout_version(&account_info)
+        };
+
+        assert_eq!(
+            check(construct_signatures_data(&signatures).unwrap()),
+            Ok(SignaturesLayoutVersion::Legacy)
+        );
+        assert_eq!(
+            check(
+                construct_signer_signatures_data(
+                    signers
+                        .iter()
+                        .zip(signatures.iter().map(TxSignature::as_bytes)),
+                    None,
+                )
+                .unwrap()
+            ),
+            Ok(SignaturesLayoutVersion::SignerPairs)
+        );
+        assert_eq!(
+            check(
+                construct_wide_count_signatures_data(
+                    signatures.iter().map(TxSignature::as_bytes),
+                    None,
+                )
+                .unwrap()
+            ),
+            Ok(SignaturesLayoutVersion::WideCount)
+        );
+        assert_eq!(
+            check(vec![VERSIONED_LAYOUT_MARKER, u8::MAX, 0]),
+            Err(LoadLayoutVersionError::UnknownVersion(u8::MAX))
+        );
+        assert_eq!(
+            ProgramError::from(LoadLayoutVersionError::UnknownVersion(u8::MAX)),
+            ProgramError::InvalidAccountData
+        );
+        assert_eq!(
+            check(vec![VERSIONED_LAYOUT_MARKER]),
+            Err(ProgramError::InvalidAccountData.into())
+        );
+        assert_eq!(check(vec![]), Err(ProgramError::InvalidAccountData.into()));
+
+        let mut data = construct_signatures_data(&signatures).unwrap();
+        let other_key = Pubkey::new_unique();
+        let account_info = AccountInfo::new(
+            &other_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+        assert_eq!(
+            load_layout_version(&account_info),
+            Err(ProgramError::UnsupportedSysvar.into())
+        );
+
+        assert_eq!(SignaturesLayoutVersion::Legacy.version(), None);
+        assert_eq!(
+            SignaturesLayoutVersion::WideCount.version(),
+            Some(WIDE_COUNT_LAYOUT_VERSION)
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_wide_count_layout() {
+        let signatures: Vec<_> = (0..300u16)
+            .map(|i| {
+                let mut signature = [0; SIGNATURE_LEN];
+                signature[..2].copy_from_slice(&i.to_le_bytes());
+                TxSignature::from(signature)
+            })
+            .collect();
+        let data = construct_wide_count_signatures_data(
+            signatures.iter().map(TxSignature::as_bytes),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            data[..WIDE_COUNT_HEADER_LEN],
+            [VERSIONED_LAYOUT_MARKER, WIDE_COUNT_LAYOUT_VERSION, 44, 1]
+        );
+        assert_eq!(
+            data.len(),
+            WIDE_COUNT_HEADER_LEN + signatures.len() * SIGNATURE_LEN
+        );
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Ok(SignaturesData {
+                signatures: signatures.clone(),
+                signers: None,
+                address_table_lookups: None,
+            })
+        );
+        assert_eq!(deserialize_signature(299, &data), Ok(signatures[299]));
+        assert_eq!(
+            deserialize_signature(300, &data),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        assert_eq!(
+            deserialize_signer(0, &data),
+            Err(SanitizeError::InvalidValue)
+        );
+        assert_eq!(
+            deserialize_signature(0, &data[..WIDE_COUNT_HEADER_LEN - 1]),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+
+        let address_table_lookups = vec![AddressTableLookupDigest {
+            account_key: Pubkey::new_unique(),
+            writable_start: 1,
+            writable_len: 1,
+            readonly_start: 2,
+            readonly_len: 0,
+        }];
+        let data = construct_wide_count_signatures_data(
+            signatures[..1].iter().map(TxSignature::as_bytes),
+            Some(&address_table_lookups),
+        )
+        .unwrap();
+        assert_eq!(
+            SignaturesData::deserialize(&data),
+            Ok(SignaturesData {
+                signatures: signatures[..1].to_vec(),
+                signers: None,
+                address_table_lookups: Some(address_table_lookups),
+            })
+        );
+
+        assert_eq!(
+            construct_wide_count_signatures_data(std::iter::empty(), None),
+            Err(SysvarSerializeError::NoSignatures)
+        );
+        let too_many = vec![[0; SIGNATURE_LEN]; MAX_WIDE_COUNT_SIGNATURES + 1];
+        assert_eq!(
+            construct_wide_count_signatures_data(too_many.iter(), None),
+            Err(SysvarSerializeError::TooManySignatures(
+                MAX_WIDE_COUNT_SIGNATURES + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_signature_offset() {
+        const DATA_LEN: usize = sysvar_data_len(3);
+        assert_eq!(DATA_LEN, 193);
+        assert_eq!(sysvar_data_len(0), NUM_SIGNATURES_LEN);
+
+        let data =
+            construct_signatures_data(&[[0; 64], [1; 64], [2; 64]].map(TxSignature::from)).unwrap();
+        assert_eq!(data.len(), DATA_LEN);
+        for i in 0..3 {
+            let start = signature_offset(i);
+            assert_eq!(&data[start..start + SIGNATURE_LEN], &[i as u8; 64]);
+        }
+    }
+}
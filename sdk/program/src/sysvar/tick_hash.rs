@@ -0,0 +1,128 @@
+//! The hash of the most recent PoH tick when the current transaction executes.
+//!
+//! The _tick hash sysvar_ gives programs a source of entropy that the signers
+//! of a transaction can't choose. Entropy derived from a transaction's
+//! signatures or recent blockhash is known to the signers before they submit
+//! the transaction, so a signer can grind signatures, or wait for a
+//! blockhash, until the outcome suits them. The tick hash is produced by the
+//! leader's proof of history after the transaction was signed.
+//!
+//! # Security model
+//!
+//! The tick hash is not unpredictable to everyone:
+//!
+//! - The leader producing the block knows the tick hash before it decides
+//!   whether, and after which tick, to include a transaction, and so can
+//!   censor or delay transactions whose outcome it doesn't like. Programs
+//!   where the leader or a party colluding with it gains from the outcome
+//!   should use a commit-reveal scheme or a VRF oracle instead.
+//! - The same tick hash is seen by every transaction executed between two
+//!   ticks, so a transaction can observe the value used by an earlier
+//!   transaction of the same block.
+//! - Before the first tick of a slot is registered, the sysvar holds the hash
+//!   of the last tick of the parent slot, which is also the most recent
+//!   blockhash and is known to signers.
+//!
+//! The hash is only that of a tick, never that of an entry holding
+//! transactions, since entry hashes depend on the transactions executed and
+//! aren't known when the transactions execute.
+//!
+//! Like the instructions and signatures sysvars, the tick hash sysvar is
+//! constructed by the runtime for each transaction that references it, and
+//! is accessed through the free functions within this module rather than
+//! through the [`Sysvar`] trait.
+//!
+//! [`Sysvar`]: crate::sysvar::Sysvar
+
+use crate::{account_info::AccountInfo, hash::Hash, program_error::ProgramError};
+
+/// Tick hash sysvar, dummy type.
+///
+/// This type exists for consistency with other sysvar modules, but is a dummy
+/// type that does not contain sysvar data. It implements the [`SysvarId`] trait
+/// but does not implement the [`Sysvar`] trait.
+///
+/// [`SysvarId`]: crate::sysvar::SysvarId
+/// [`Sysvar`]: crate::sysvar::Sysvar
+///
+/// Use the free functions in this module to access the tick hash sysvar.
+pub struct TickHash();
+
+crate::declare_sysvar_id!("SysvarTickHash11111111111111111111111111111", TickHash);
+
+/// Length in bytes of the tick hash sysvar data.
+pub const TICK_HASH_LEN: usize = 32;
+
+/// Construct the account data for the tick hash sysvar.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_tick_hash_data(tick_hash: &Hash) -> Vec<u8> {
+    tick_hash.to_bytes().to_vec()
+}
+
+/// Load the tick hash of the currently executing transaction.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is not [`TICK_HASH_LEN`] bytes.
+pub fn load_tick_hash_checked(
+    tick_hash_sysvar_account_info: &AccountInfo,
+) -> Result<Hash, ProgramError> {
+    if !check_id(tick_hash_sysvar_account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let data = tick_hash_sysvar_account_info.try_borrow_data()?;
+    <[u8; TICK_HASH_LEN]>::try_from(&data[..])
+        .map(Hash::new_from_array)
+        .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::pubkey::Pubkey};
+
+    #[test]
+    fn test_load_tick_hash_checked() {
+        let tick_hash = Hash::new_unique();
+        let mut data = construct_tick_hash_data(&tick_hash);
+        let mut truncated = vec![0; TICK_HASH_LEN - 1];
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(load_tick_hash_checked(&account_info), Ok(tick_hash));
+
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut truncated[..]));
+        assert_eq!(
+            load_tick_hash_checked(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_tick_hash_checked(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    sysvar_spoofing_tests! {
+        data: construct_tick_hash_data(&Hash::new_from_array([7; 32])),
+        loaders: {
+            load_tick_hash_checked: load_tick_hash_checked
+                => oversized: Err(ProgramError::InvalidAccountData),
+        },
+    }
+}
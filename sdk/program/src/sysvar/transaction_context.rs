@@ -0,0 +1,488 @@
+//! The message of the current transaction and the hashes of its signatures,
+//! in a single account.
+//!
+//! Programs that need the full context of the transaction they execute in
+//! would otherwise pass the [header], [signatures] and [instructions]
+//! sysvars, paying for three account metas in the transaction. The
+//! _transaction context sysvar_ holds the version and [`MessageHeader`] of
+//! the message, its account keys, its recent blockhash and the hashes of its
+//! signatures, and is read through accessor functions that only borrow the
+//! bytes of the requested field, so that programs don't deserialize the parts
+//! they don't use.
+//!
+//! [header]: crate::sysvar::header
+//! [signatures]: crate::sysvar::signatures
+//! [instructions]: crate::sysvar::instructions
+//!
+//! Like the other per-transaction sysvars, the transaction context sysvar is
+//! constructed by the runtime for each transaction that references it, and
+//! is accessed through the free functions within this module rather than
+//! through the [`Sysvar`] trait.
+//!
+//! [`Sysvar`]: crate::sysvar::Sysvar
+//!
+//! # Layout
+//!
+//! The sysvar data starts with [`FIXED_LEN`] bytes of fixed size fields,
+//! followed by the variable length sections whose lengths they hold:
+//!
+//! - the version byte of the message, [`LEGACY_TRANSACTION_VERSION`] for
+//!   legacy messages
+//! - the three bytes of the [`MessageHeader`]
+//! - the number of account keys, as a little-endian `u16`
+//! - the number of signatures, as a little-endian `u16`
+//! - the 32-byte recent blockhash of the message
+//! - the 32-byte account keys, the static keys of the message followed by
+//!   the keys it loads from address lookup tables
+//! - the 32-byte SHA-256 hash of each 64-byte signature, see
+//!   [`hash_signature`], in the order of the signers
+//!
+//! Signatures are stored as hashes to halve their size. Programs that hold a
+//! signature, e.g. from instruction data, can compare its hash with
+//! [`load_signature_hash_at_checked`]; programs that need the signature bytes
+//! use the signatures sysvar.
+//!
+//! [`LEGACY_TRANSACTION_VERSION`]: crate::sysvar::header::LEGACY_TRANSACTION_VERSION
+
+#![allow(clippy::arithmetic_side_effects)]
+
+#[cfg(not(target_os = "solana"))]
+use crate::{
+    message::SanitizedMessage,
+    serialize_utils::{append_slice, append_u16, append_u8},
+};
+use {
+    crate::{
+        account_info::AccountInfo,
+        hash::{hash, Hash, HASH_BYTES},
+        message::MessageHeader,
+        program_error::ProgramError,
+        pubkey::{Pubkey, PUBKEY_BYTES},
+        sysvar::{
+            header::{TransactionHeader, TransactionVersion},
+            load_entry_at_checked,
+            signatures::SIGNATURE_LEN,
+        },
+    },
+    std::cell::Ref,
+};
+
+/// Transaction context sysvar, dummy type.
+///
+/// This type exists for consistency with other sysvar modules, but is a dummy
+/// type that does not contain sysvar data. It implements the [`SysvarId`] trait
+/// but does not implement the [`Sysvar`] trait.
+///
+/// [`SysvarId`]: crate::sysvar::SysvarId
+/// [`Sysvar`]: crate::sysvar::Sysvar
+///
+/// Use the free functions in this module to access the transaction context sysvar.
+pub struct SysvarTransactionContext();
+
+crate::declare_sysvar_id!(
+    "SysvarTransactionContext1111111111111111111",
+    SysvarTransactionContext
+);
+
+/// Byte offset of the message header within the sysvar data.
+pub const MESSAGE_HEADER_OFFSET: usize = 1;
+
+/// Byte offset of the number of account keys within the sysvar data.
+pub const NUM_ACCOUNT_KEYS_OFFSET: usize = MESSAGE_HEADER_OFFSET + 3;
+
+/// Byte offset of the number of signatures within the sysvar data.
+pub const NUM_SIGNATURES_OFFSET: usize = NUM_ACCOUNT_KEYS_OFFSET + 2;
+
+/// Byte offset of the recent blockhash within the sysvar data.
+pub const RECENT_BLOCKHASH_OFFSET: usize = NUM_SIGNATURES_OFFSET + 2;
+
+/// Length in bytes of the fixed size fields that start the sysvar data, and
+/// byte offset of the account keys.
+pub const FIXED_LEN: usize = RECENT_BLOCKHASH_OFFSET + HASH_BYTES;
+
+/// Hash a signature as it is stored in the transaction context sysvar.
+pub fn hash_signature(signature: &[u8; SIGNATURE_LEN]) -> Hash {
+    hash(signature)
+}
+
+/// Construct the account data for the transaction context sysvar.
+///
+/// `signatures` are the signatures of the transaction of `message`, which
+/// like its account keys are fewer than `u16::MAX` for sanitized messages.
+///
+/// This function is used by the runtime and not available to Solana programs.
+#[cfg(not(target_os = "solana"))]
+pub fn construct_transaction_context_data<'a>(
+    message: &SanitizedMessage,
+    signatures: impl ExactSizeIterator<Item = &'a [u8; SIGNATURE_LEN]>,
+) -> Vec<u8> {
+    let version = match message {
+        SanitizedMessage::Legacy(_) => TransactionVersion::Legacy,
+        SanitizedMessage::V0(_) => TransactionVersion::Number(0),
+    };
+    let account_keys = message.account_keys();
+    construct_data(
+        version,
+        message.header(),
+        message.recent_blockhash(),
+        account_keys.iter(),
+        signatures,
+    )
+}
+
+#[cfg(not(target_os = "solana"))]
+fn construct_data<'a>(
+    version: TransactionVersion,
+    message_header: &MessageHeader,
+    recent_blockhash: &Hash,
+    account_keys: impl ExactSizeIterator<Item = &'a Pubkey>,
+    signatures: impl ExactSizeIterator<Item = &'a [u8; SIGNATURE_LEN]>,
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(
+        FIXED_LEN
+            .saturating_add(account_keys.len().saturating_mul(PUBKEY_BYTES))
+            .saturating_add(signatures.len().saturating_mul(HASH_BYTES)),
+    );
+    append_u8(&mut data, version.into());
+    append_u8(&mut data, message_header.num_required_signatures);
+    append_u8(&mut data, message_header.num_readonly_signed_accounts);
+    append_u8(&mut data, message_header.num_readonly_unsigned_accounts);
+    append_u16(&mut data, account_keys.len() as u16);
+    append_u16(&mut data, signatures.len() as u16);
+    append_slice(&mut data, recent_blockhash.as_ref());
+    for account_key in account_keys {
+        append_slice(&mut data, account_key.as_ref());
+    }
+    for signature in signatures {
+        append_slice(&mut data, hash_signature(signature).as_ref());
+    }
+    data
+}
+
+/// Borrow the data of the sysvar, checking its ID and that it holds the fixed
+/// size fields.
+fn borrow_data<'a, 'info>(
+    account_info: &'a AccountInfo<'info>,
+) -> Result<Ref<'a, &'info mut [u8]>, ProgramError> {
+    if !check_id(account_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+    let data = account_info.try_borrow_data()?;
+    if data.len() < FIXED_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(data)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> usize {
+    u16::from_le_bytes([data[offset], data[offset + 1]]) as usize
+}
+
+/// Load the version and [`MessageHeader`] of the currently executing
+/// `Transaction`'s message.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is shorter than
+/// [`FIXED_LEN`].
+pub fn load_transaction_header_checked(
+    transaction_context_sysvar_account_info: &AccountInfo,
+) -> Result<TransactionHeader, ProgramError> {
+    let data = borrow_data(transaction_context_sysvar_account_info)?;
+    Ok(TransactionHeader {
+        version: TransactionVersion::from(data[0]),
+        message_header: MessageHeader {
+            num_required_signatures: data[MESSAGE_HEADER_OFFSET],
+            num_readonly_signed_accounts: data[MESSAGE_HEADER_OFFSET + 1],
+            num_readonly_unsigned_accounts: data[MESSAGE_HEADER_OFFSET + 2],
+        },
+    })
+}
+
+/// Load the recent blockhash of the currently executing `Transaction`'s
+/// message.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is shorter than
+/// [`FIXED_LEN`].
+pub fn load_recent_blockhash_checked(
+    transaction_context_sysvar_account_info: &AccountInfo,
+) -> Result<Hash, ProgramError> {
+    let data = borrow_data(transaction_context_sysvar_account_info)?;
+    Ok(Hash::new(&data[RECENT_BLOCKHASH_OFFSET..FIXED_LEN]))
+}
+
+/// Load the number of account keys of the currently executing
+/// `Transaction`'s message, including those loaded from address lookup
+/// tables.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is shorter than
+/// [`FIXED_LEN`].
+pub fn load_num_account_keys_checked(
+    transaction_context_sysvar_account_info: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    let data = borrow_data(transaction_context_sysvar_account_info)?;
+    Ok(read_u16(&data, NUM_ACCOUNT_KEYS_OFFSET))
+}
+
+/// Load the account key at `index` of the currently executing
+/// `Transaction`'s message.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if `index` is out of bounds, after logging the
+/// requested index and the number of account keys.
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is truncated.
+pub fn load_account_key_at_checked(
+    index: usize,
+    transaction_context_sysvar_account_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let data = borrow_data(transaction_context_sysvar_account_info)?;
+    let num_account_keys = read_u16(&data, NUM_ACCOUNT_KEYS_OFFSET);
+    load_entry_at_checked::<PUBKEY_BYTES>(
+        "transaction_context.account_keys",
+        &data,
+        FIXED_LEN,
+        num_account_keys,
+        index,
+    )
+    .map(|key| Pubkey::from(*key))
+}
+
+/// Load the number of signatures of the currently executing `Transaction`.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is shorter than
+/// [`FIXED_LEN`].
+pub fn load_num_signatures_checked(
+    transaction_context_sysvar_account_info: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    let data = borrow_data(transaction_context_sysvar_account_info)?;
+    Ok(read_u16(&data, NUM_SIGNATURES_OFFSET))
+}
+
+/// Load the hash of the signature at `index` of the currently executing
+/// `Transaction`, see [`hash_signature`].
+///
+/// # Errors
+///
+/// Returns [`ProgramError::UnsupportedSysvar`] if the given account's ID is not equal to [`ID`].
+/// Returns [`ProgramError::InvalidArgument`] if `index` is out of bounds, after logging the
+/// requested index and the number of signatures.
+/// Returns [`ProgramError::InvalidAccountData`] if the account data is truncated.
+pub fn load_signature_hash_at_checked(
+    index: usize,
+    transaction_context_sysvar_account_info: &AccountInfo,
+) -> Result<Hash, ProgramError> {
+    let data = borrow_data(transaction_context_sysvar_account_info)?;
+    let num_account_keys = read_u16(&data, NUM_ACCOUNT_KEYS_OFFSET);
+    let num_signatures = read_u16(&data, NUM_SIGNATURES_OFFSET);
+    load_entry_at_checked::<HASH_BYTES>(
+        "transaction_context.signatures",
+        &data,
+        FIXED_LEN.saturating_add(num_account_keys.saturating_mul(PUBKEY_BYTES)),
+        num_signatures,
+        index,
+    )
+    .map(|hash| Hash::new_from_array(*hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::Epoch,
+            instruction::{AccountMeta, Instruction},
+            message::{
+                v0::{self, LoadedAddresses},
+                LegacyMessage, Message,
+            },
+        },
+    };
+
+    sysvar_test_suite! {
+        entry: |index: usize| Pubkey::new_from_array([index as u8; 32]),
+        data: |account_keys: &[Pubkey]| construct_data(
+            TransactionVersion::Legacy,
+            &MessageHeader::default(),
+            &Hash::default(),
+            account_keys.iter(),
+            std::iter::empty(),
+        ),
+        load: load_account_key_at_checked,
+        max_count: 256,
+    }
+
+    const SPOOFING_MESSAGE_HEADER: MessageHeader = MessageHeader {
+        num_required_signatures: 2,
+        num_readonly_signed_accounts: 1,
+        num_readonly_unsigned_accounts: 1,
+    };
+
+    fn spoofing_data() -> Vec<u8> {
+        construct_data(
+            TransactionVersion::Number(0),
+            &SPOOFING_MESSAGE_HEADER,
+            &Hash::new_from_array([7; 32]),
+            [
+                Pubkey::from([1; 32]),
+                Pubkey::from([2; 32]),
+                Pubkey::from([3; 32]),
+            ]
+            .iter(),
+            [[1; SIGNATURE_LEN], [2; SIGNATURE_LEN]].iter(),
+        )
+    }
+
+    // Every section is sized by the counts of the fixed size fields, so
+    // trailing bytes are never read
+    sysvar_spoofing_tests! {
+        data: spoofing_data(),
+        loaders: {
+            load_transaction_header_checked: load_transaction_header_checked
+                => oversized: Ok(TransactionHeader {
+                    version: TransactionVersion::Number(0),
+                    message_header: SPOOFING_MESSAGE_HEADER,
+                }),
+            load_recent_blockhash_checked: load_recent_blockhash_checked
+                => oversized: Ok(Hash::new_from_array([7; 32])),
+            load_num_account_keys_checked: load_num_account_keys_checked => oversized: Ok(3),
+            load_account_key_at_checked: |account_info| load_account_key_at_checked(2, account_info)
+                => oversized: Ok(Pubkey::from([3; 32])),
+            load_num_signatures_checked: load_num_signatures_checked => oversized: Ok(2),
+            load_signature_hash_at_checked: |account_info| {
+                load_signature_hash_at_checked(1, account_info)
+            } => oversized: Ok(hash_signature(&[2; SIGNATURE_LEN])),
+        },
+    }
+
+    #[test]
+    fn test_load_transaction_context() {
+        let payer = Pubkey::new_unique();
+        let cosigner = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let recent_blockhash = Hash::new_unique();
+        let mut legacy_message = Message::new(
+            &[Instruction::new_with_bytes(
+                program_id,
+                &[],
+                vec![AccountMeta::new_readonly(cosigner, true)],
+            )],
+            Some(&payer),
+        );
+        legacy_message.recent_blockhash = recent_blockhash;
+        let message = SanitizedMessage::Legacy(LegacyMessage::new(legacy_message));
+        let signatures = [[1; SIGNATURE_LEN], [2; SIGNATURE_LEN]];
+        let mut data = construct_transaction_context_data(&message, signatures.iter());
+        assert_eq!(data.len(), FIXED_LEN + 3 * PUBKEY_BYTES + 2 * HASH_BYTES);
+
+        let owner = crate::sysvar::id();
+        let mut lamports = 0;
+        let key = id();
+        let mut account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Epoch::default(),
+        );
+
+        assert_eq!(
+            load_transaction_header_checked(&account_info),
+            Ok(TransactionHeader {
+                version: TransactionVersion::Legacy,
+                message_header: *message.header(),
+            })
+        );
+        assert_eq!(
+            load_recent_blockhash_checked(&account_info),
+            Ok(recent_blockhash)
+        );
+        assert_eq!(load_num_account_keys_checked(&account_info), Ok(3));
+        for (index, account_key) in message.account_keys().iter().enumerate() {
+            assert_eq!(
+                load_account_key_at_checked(index, &account_info),
+                Ok(*account_key)
+            );
+        }
+        assert_eq!(
+            load_account_key_at_checked(3, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(load_num_signatures_checked(&account_info), Ok(2));
+        for (index, signature) in signatures.iter().enumerate() {
+            assert_eq!(
+                load_signature_hash_at_checked(index, &account_info),
+                Ok(hash_signature(signature))
+            );
+        }
+        assert_eq!(
+            load_signature_hash_at_checked(2, &account_info),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        // Truncated signature hashes don't affect the other fields
+        let mut truncated_data = construct_transaction_context_data(&message, signatures.iter());
+        truncated_data.truncate(truncated_data.len() - 1);
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut truncated_data[..]));
+        assert_eq!(
+            load_account_key_at_checked(2, &account_info),
+            Ok(program_id)
+        );
+        assert_eq!(
+            load_signature_hash_at_checked(1, &account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let mut short_data = vec![0; FIXED_LEN - 1];
+        account_info.data = std::rc::Rc::new(std::cell::RefCell::new(&mut short_data[..]));
+        assert_eq!(
+            load_recent_blockhash_checked(&account_info),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let key = Pubkey::new_unique();
+        account_info.key = &key;
+        assert_eq!(
+            load_transaction_header_checked(&account_info),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_construct_transaction_context_data_v0() {
+        let payer = Pubkey::new_unique();
+        let loaded_key = Pubkey::new_unique();
+        let message = SanitizedMessage::V0(v0::LoadedMessage::new(
+            v0::Message::try_compile(&payer, &[], &[], Hash::default()).unwrap(),
+            LoadedAddresses {
+                writable: vec![loaded_key],
+                readonly: vec![],
+            },
+        ));
+        let data = construct_transaction_context_data(&message, [[7; SIGNATURE_LEN]].iter());
+        assert_eq!(
+            TransactionVersion::from(data[0]),
+            TransactionVersion::Number(0)
+        );
+        assert_eq!(read_u16(&data, NUM_ACCOUNT_KEYS_OFFSET), 2);
+        // Loaded keys follow the static keys
+        assert_eq!(
+            &data[FIXED_LEN + PUBKEY_BYTES..FIXED_LEN + 2 * PUBKEY_BYTES],
+            loaded_key.as_ref()
+        );
+    }
+}
@@ -0,0 +1,104 @@
+#pragma once
+/**
+ * @brief Solana base58 system calls
+**/
+
+#include <sol/types.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Maximum number of bytes that can be encoded in one call
+ */
+#define BASE58_MAX_ENCODE_INPUT_LENGTH 128
+
+/**
+ * Maximum number of base58 characters that can be decoded in one call
+ */
+#define BASE58_MAX_DECODE_INPUT_LENGTH 177
+
+/**
+ * Base58 encoding
+ *
+ * @param input Bytes to encode
+ * @param input_len Number of bytes to encode
+ * @param output Buffer to hold the base58 characters
+ * @param output_len Length of the output buffer
+ * @param written Number of characters written to the output buffer
+ * @return 0 if executed successfully
+ */
+/* DO NOT MODIFY THIS GENERATED FILE. INSTEAD CHANGE sdk/sbf/c/inc/sol/inc/base58.inc AND RUN `cargo run --bin gen-headers` */
+#ifndef SOL_SBFV2
+uint64_t sol_base58_encode(
+        const uint8_t *input,
+        const uint64_t input_len,
+        uint8_t *output,
+        const uint64_t output_len,
+        uint64_t *written
+);
+#else
+typedef uint64_t(*sol_base58_encode_pointer_type)(
+        const uint8_t *input,
+        const uint64_t input_len,
+        uint8_t *output,
+        const uint64_t output_len,
+        uint64_t *written
+);
+static uint64_t sol_base58_encode(
+        const uint8_t *input arg1,
+        const uint64_t input_len arg2,
+        uint8_t *output arg3,
+        const uint64_t output_len arg4,
+        uint64_t *written
+ arg5) {
+  sol_base58_encode_pointer_type sol_base58_encode_pointer = (sol_base58_encode_pointer_type) 1738955045;
+  return sol_base58_encode_pointer(arg1, arg2, arg3, arg4, arg5);
+}
+#endif
+
+/**
+ * Base58 decoding
+ *
+ * @param input Base58 characters to decode
+ * @param input_len Number of characters to decode
+ * @param output Buffer to hold the decoded bytes
+ * @param output_len Length of the output buffer
+ * @param written Number of bytes written to the output buffer
+ * @return 0 if executed successfully
+ */
+/* DO NOT MODIFY THIS GENERATED FILE. INSTEAD CHANGE sdk/sbf/c/inc/sol/inc/base58.inc AND RUN `cargo run --bin gen-headers` */
+#ifndef SOL_SBFV2
+uint64_t sol_base58_decode(
+        const uint8_t *input,
+        const uint64_t input_len,
+        uint8_t *output,
+        const uint64_t output_len,
+        uint64_t *written
+);
+#else
+typedef uint64_t(*sol_base58_decode_pointer_type)(
+        const uint8_t *input,
+        const uint64_t input_len,
+        uint8_t *output,
+        const uint64_t output_len,
+        uint64_t *written
+);
+static uint64_t sol_base58_decode(
+        const uint8_t *input arg1,
+        const uint64_t input_len arg2,
+        uint8_t *output arg3,
+        const uint64_t output_len arg4,
+        uint64_t *written
+ arg5) {
+  sol_base58_decode_pointer_type sol_base58_decode_pointer = (sol_base58_decode_pointer_type) 4166076106;
+  return sol_base58_decode_pointer(arg1, arg2, arg3, arg4, arg5);
+}
+#endif
+
+#ifdef __cplusplus
+}
+#endif
+
+/**@}*/
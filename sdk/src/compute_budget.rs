@@ -2,13 +2,17 @@
 
 #![cfg(feature = "full")]
 
+pub use solana_program::compute_budget::{
+    check_id, id, try_from_instructions, ComputeBudgetLimits, ID,
+};
 use {
-    crate::instruction::Instruction,
+    crate::{
+        borsh0_10::try_from_slice_unchecked, instruction::Instruction, message::SanitizedMessage,
+        pubkey::Pubkey, transaction::TransactionError,
+    },
     borsh::{BorshDeserialize, BorshSerialize},
 };
 
-crate::declare_id!("ComputeBudget111111111111111111111111111111");
-
 /// Compute Budget Instructions
 #[derive(
     AbiExample,
@@ -43,6 +47,86 @@ pub enum ComputeBudgetInstruction {
     SetComputeUnitPrice(u64),
     /// Set a specific transaction-wide account data size limit, in bytes, is allowed to load.
     SetLoadedAccountsDataSizeLimit(u32),
+    /// Declare invariants of the transaction, which the runtime checks
+    /// against the message before executing it. A transaction may contain
+    /// several guards, all of which must hold.
+    TransactionGuard(TransactionGuard),
+}
+
+/// Invariants of a transaction declared by a
+/// `ComputeBudgetInstruction::TransactionGuard` instruction.
+///
+/// A field that is `None` isn't checked.
+#[derive(
+    AbiExample,
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Debug,
+    Default,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+pub struct TransactionGuard {
+    /// Maximum number of signers of the transaction
+    pub max_signers: Option<u8>,
+    /// Account that must sign the transaction
+    pub required_signer: Option<Pubkey>,
+    /// Program that a top-level instruction of the transaction must invoke
+    pub required_program_id: Option<Pubkey>,
+}
+
+impl TransactionGuard {
+    /// Whether `message` satisfies every invariant of the guard.
+    pub fn is_satisfied_by(&self, message: &SanitizedMessage) -> bool {
+        let num_signers = message.header().num_required_signatures;
+        let max_signers_held = self
+            .max_signers
+            .map_or(true, |max_signers| num_signers <= max_signers);
+        let required_signer_held = self.required_signer.map_or(true, |required_signer| {
+            message
+                .account_keys()
+                .iter()
+                .take(usize::from(num_signers))
+                .any(|key| key == &required_signer)
+        });
+        let required_program_id_held =
+            self.required_program_id
+                .map_or(true, |required_program_id| {
+                    message
+                        .program_instructions_iter()
+                        .any(|(program_id, _)| program_id == &required_program_id)
+                });
+        max_signers_held && required_signer_held && required_program_id_held
+    }
+}
+
+/// Check the `ComputeBudgetInstruction::TransactionGuard` instructions of
+/// `message` against it.
+///
+/// Compute budget instructions that can't be decoded are left to the
+/// processing of the compute budget, which rejects them.
+///
+/// # Errors
+///
+/// Returns [`TransactionError::TransactionGuardViolated`] with the index of the first guard
+/// instruction that `message` doesn't satisfy.
+pub fn check_transaction_guards(message: &SanitizedMessage) -> Result<(), TransactionError> {
+    for (index, (program_id, instruction)) in message.program_instructions_iter().enumerate() {
+        if !check_id(program_id) {
+            continue;
+        }
+        if let Ok(ComputeBudgetInstruction::TransactionGuard(guard)) =
+            try_from_slice_unchecked(&instruction.data)
+        {
+            if !guard.is_satisfied_by(message) {
+                return Err(TransactionError::TransactionGuardViolated(index as u8));
+            }
+        }
+    }
+    Ok(())
 }
 
 impl ComputeBudgetInstruction {
@@ -72,4 +156,86 @@ impl ComputeBudgetInstruction {
     pub fn set_loaded_accounts_data_size_limit(bytes: u32) -> Instruction {
         Instruction::new_with_borsh(id(), &Self::SetLoadedAccountsDataSizeLimit(bytes), vec![])
     }
+
+    /// Create a `ComputeBudgetInstruction::TransactionGuard` `Instruction`
+    pub fn transaction_guard(guard: TransactionGuard) -> Instruction {
+        Instruction::new_with_borsh(id(), &Self::TransactionGuard(guard), vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{message::Message, system_instruction, system_program},
+    };
+
+    #[test]
+    fn test_compute_budget_limits_from_instructions() {
+        let instructions = [
+            ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+            ComputeBudgetInstruction::set_compute_unit_limit(300_000),
+            ComputeBudgetInstruction::set_compute_unit_price(7),
+            ComputeBudgetInstruction::set_loaded_accounts_data_size_limit(1024),
+            ComputeBudgetInstruction::transaction_guard(TransactionGuard::default()),
+        ];
+        assert_eq!(
+            ComputeBudgetLimits::try_from_instruction_data(
+                instructions
+                    .iter()
+                    .map(|instruction| (&instruction.program_id, instruction.data.as_slice()))
+            ),
+            Ok(ComputeBudgetLimits {
+                compute_unit_limit: Some(300_000),
+                compute_unit_price: Some(7),
+                heap_frame_bytes: Some(64 * 1024),
+                loaded_accounts_data_size_limit: Some(1024),
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_transaction_guards() {
+        let payer = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let transfer = system_instruction::transfer(&payer, &signer, 1);
+        let message_with_guard = |guard: TransactionGuard| {
+            let instructions = [
+                ComputeBudgetInstruction::set_compute_unit_limit(300_000),
+                transfer.clone(),
+                ComputeBudgetInstruction::transaction_guard(guard),
+            ];
+            SanitizedMessage::try_from(Message::new(&instructions, Some(&payer))).unwrap()
+        };
+
+        for guard in [
+            TransactionGuard::default(),
+            TransactionGuard {
+                max_signers: Some(1),
+                required_signer: Some(payer),
+                required_program_id: Some(system_program::id()),
+            },
+        ] {
+            assert_eq!(check_transaction_guards(&message_with_guard(guard)), Ok(()));
+        }
+        for guard in [
+            TransactionGuard {
+                max_signers: Some(0),
+                ..TransactionGuard::default()
+            },
+            TransactionGuard {
+                required_signer: Some(signer),
+                ..TransactionGuard::default()
+            },
+            TransactionGuard {
+                required_program_id: Some(Pubkey::new_unique()),
+                ..TransactionGuard::default()
+            },
+        ] {
+            assert_eq!(
+                check_transaction_guards(&message_with_guard(guard)),
+                Err(TransactionError::TransactionGuardViolated(2))
+            );
+        }
+    }
 }
@@ -4,31 +4,21 @@
 
 #![cfg(feature = "full")]
 
+pub use solana_program::ed25519_program::{
+    get_signature_offsets, get_verified_pubkey, load_verified_signature_checked, num_signatures,
+    Ed25519SignatureOffsets, Ed25519VerifiedSignature, DATA_START, PUBKEY_SERIALIZED_SIZE,
+    SIGNATURE_OFFSETS_SERIALIZED_SIZE, SIGNATURE_OFFSETS_START, SIGNATURE_SERIALIZED_SIZE,
+};
 use {
-    crate::{feature_set::FeatureSet, instruction::Instruction, precompiles::PrecompileError},
-    bytemuck::{bytes_of, Pod, Zeroable},
+    crate::{
+        feature_set::FeatureSet,
+        instruction::Instruction,
+        precompiles::{PrecompileError, SignedMessage},
+    },
+    bytemuck::bytes_of,
     ed25519_dalek::{ed25519::signature::Signature, Signer, Verifier},
 };
 
-pub const PUBKEY_SERIALIZED_SIZE: usize = 32;
-pub const SIGNATURE_SERIALIZED_SIZE: usize = 64;
-pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
-// bytemuck requires structures to be aligned
-pub const SIGNATURE_OFFSETS_START: usize = 2;
-pub const DATA_START: usize = SIGNATURE_OFFSETS_SERIALIZED_SIZE + SIGNATURE_OFFSETS_START;
-
-#[derive(Default, Debug, Copy, Clone, Zeroable, Pod, Eq, PartialEq)]
-#[repr(C)]
-pub struct Ed25519SignatureOffsets {
-    signature_offset: u16,             // offset to ed25519 signature of 64 bytes
-    signature_instruction_index: u16,  // instruction index to find signature
-    public_key_offset: u16,            // offset to public key of 32 bytes
-    public_key_instruction_index: u16, // instruction index to find public key
-    message_data_offset: u16,          // offset to start of message data
-    message_data_size: u16,            // size of message data
-    message_instruction_index: u16,    // index of instruction data to get message data
-}
-
 pub fn new_ed25519_instruction(keypair: &ed25519_dalek::Keypair, message: &[u8]) -> Instruction {
     let signature = keypair.sign(message).to_bytes();
     let pubkey = keypair.public.to_bytes();
@@ -82,6 +72,82 @@ pub fn new_ed25519_instruction(keypair: &ed25519_dalek::Keypair, message: &[u8])
     }
 }
 
+/// Create an ed25519 program instruction that verifies signatures held by the
+/// other instructions of its own transaction.
+///
+/// `instructions` are the other instructions of the transaction, in order,
+/// and the returned instruction is meant to be inserted among them at
+/// `precompile_index`. Every instruction index in `offsets` is the absolute
+/// index of an instruction of the final transaction, and must not refer to
+/// the returned instruction itself, which holds nothing but the offsets.
+///
+/// No public key, signature or message is copied into the returned
+/// instruction, so a program that carries a signed payload in its own
+/// instruction data gets it verified for the cost of the offsets alone. The
+/// program can then check with [`load_verified_signature_checked`] that the
+/// precompile verified its data.
+///
+/// The offsets are validated against the layout of `instructions` and the
+/// signatures are verified at build time, the same way the runtime will
+/// verify them, so an instruction that would fail its transaction is never
+/// built.
+///
+/// The signatures of the transaction itself can't be verified this way, as
+/// they sign the message holding the returned instruction; programs can read
+/// them from the signatures sysvar instead.
+pub fn new_self_referential(
+    instructions: &[Instruction],
+    precompile_index: usize,
+    offsets: &[Ed25519SignatureOffsets],
+) -> Result<Instruction, PrecompileError> {
+    let num_signatures =
+        u8::try_from(offsets.len()).map_err(|_| PrecompileError::InvalidInstructionDataSize)?;
+    let precompile_index = u16::try_from(precompile_index)
+        .ok()
+        .filter(|index| usize::from(*index) <= instructions.len() && *index != u16::MAX)
+        .ok_or(PrecompileError::InvalidDataOffsets)?;
+    if offsets.iter().any(|offsets| {
+        [
+            offsets.signature_instruction_index,
+            offsets.public_key_instruction_index,
+            offsets.message_instruction_index,
+        ]
+        .iter()
+        .any(|index| *index == precompile_index || *index == u16::MAX)
+    }) {
+        return Err(PrecompileError::InvalidDataOffsets);
+    }
+
+    let mut instruction_data = Vec::with_capacity(
+        offsets
+            .len()
+            .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .saturating_add(SIGNATURE_OFFSETS_START),
+    );
+    // add padding byte so that offset structure is aligned
+    instruction_data.extend_from_slice(bytes_of(&[num_signatures, 0]));
+    for offsets in offsets {
+        instruction_data.extend_from_slice(bytes_of(offsets));
+    }
+
+    let mut instruction_datas: Vec<&[u8]> = instructions
+        .iter()
+        .map(|instruction| instruction.data.as_slice())
+        .collect();
+    instruction_datas.insert(usize::from(precompile_index), &instruction_data);
+    verify(
+        &instruction_data,
+        &instruction_datas,
+        &FeatureSet::all_enabled(),
+    )?;
+
+    Ok(Instruction {
+        program_id: solana_sdk::ed25519_program::id(),
+        accounts: vec![],
+        data: instruction_data,
+    })
+}
+
 pub fn verify(
     data: &[u8],
     instruction_datas: &[&[u8]],
@@ -151,6 +217,45 @@ pub fn verify(
     Ok(())
 }
 
+/// The signature, public key and message of every signature of an ed25519
+/// program instruction, without verifying them.
+pub fn signed_messages<'a>(
+    data: &'a [u8],
+    instruction_datas: &'a [&[u8]],
+) -> Result<Vec<SignedMessage<'a>>, PrecompileError> {
+    let num_signatures =
+        num_signatures(data).map_err(|_| PrecompileError::InvalidInstructionDataSize)?;
+    (0..num_signatures)
+        .map(|signature_index| {
+            let offsets = get_signature_offsets(data, signature_index)
+                .map_err(|_| PrecompileError::InvalidDataOffsets)?;
+            Ok(SignedMessage {
+                signature: get_data_slice(
+                    data,
+                    instruction_datas,
+                    offsets.signature_instruction_index,
+                    offsets.signature_offset,
+                    SIGNATURE_SERIALIZED_SIZE,
+                )?,
+                pubkey: get_data_slice(
+                    data,
+                    instruction_datas,
+                    offsets.public_key_instruction_index,
+                    offsets.public_key_offset,
+                    PUBKEY_SERIALIZED_SIZE,
+                )?,
+                message: get_data_slice(
+                    data,
+                    instruction_datas,
+                    offsets.message_instruction_index,
+                    offsets.message_data_offset,
+                    offsets.message_data_size as usize,
+                )?,
+            })
+        })
+        .collect()
+}
+
 fn get_data_slice<'a>(
     data: &'a [u8],
     instruction_datas: &'a [&[u8]],
@@ -185,6 +290,7 @@ pub mod test {
             ed25519_instruction::new_ed25519_instruction,
             feature_set::FeatureSet,
             hash::Hash,
+            pubkey::Pubkey,
             signature::{Keypair, Signer},
             transaction::Transaction,
         },
@@ -379,4 +485,132 @@ pub mod test {
         );
         assert!(tx.verify_precompiles(&feature_set).is_err());
     }
+
+    #[test]
+    fn test_get_verified_pubkey() {
+        let privkey = ed25519_dalek::Keypair::generate(&mut thread_rng());
+        let message = b"hello";
+        let instruction = new_ed25519_instruction(&privkey, message);
+
+        assert_eq!(num_signatures(&instruction.data), Ok(1));
+        assert_eq!(
+            get_verified_pubkey(&instruction.data, 0)
+                .unwrap()
+                .to_bytes(),
+            privkey.public.to_bytes()
+        );
+        let offsets = get_signature_offsets(&instruction.data, 0).unwrap();
+        let message_start = offsets.message_data_offset as usize;
+        let message_end = message_start + offsets.message_data_size as usize;
+        assert_eq!(&instruction.data[message_start..message_end], message);
+    }
+
+    #[test]
+    fn test_signed_messages() {
+        let privkey = ed25519_dalek::Keypair::generate(&mut thread_rng());
+        let message = b"hello";
+        let instruction = new_ed25519_instruction(&privkey, message);
+        let signature = ed25519_dalek::Signer::sign(&privkey, message).to_bytes();
+
+        let messages = crate::precompiles::signed_messages(
+            &crate::ed25519_program::id(),
+            &instruction.data,
+            &[&instruction.data],
+        )
+        .unwrap();
+        assert_eq!(
+            messages,
+            vec![SignedMessage {
+                signature: &signature,
+                pubkey: &privkey.public.to_bytes(),
+                message,
+            }]
+        );
+        assert_eq!(
+            messages[0].verified_signature_key(&crate::ed25519_program::id()),
+            crate::precompiles::verified_signature_key(
+                &crate::ed25519_program::id(),
+                &signature,
+                &privkey.public.to_bytes(),
+                &crate::hash::hash(message),
+            )
+        );
+
+        // Offsets pointing past the end of the data
+        assert_eq!(
+            signed_messages(&instruction.data[..DATA_START], &[]),
+            Err(PrecompileError::InvalidDataOffsets)
+        );
+    }
+
+    #[test]
+    fn test_new_self_referential() {
+        let privkey = ed25519_dalek::Keypair::generate(&mut thread_rng());
+        let message = b"hello";
+        let signature = ed25519_dalek::Signer::sign(&privkey, message).to_bytes();
+
+        // An instruction of some program carrying a signed payload
+        let mut payload = vec![7u8; 3];
+        payload.extend_from_slice(&privkey.public.to_bytes());
+        payload.extend_from_slice(&signature);
+        payload.extend_from_slice(message);
+        let program_instruction =
+            Instruction::new_with_bytes(Pubkey::new_unique(), &payload, vec![]);
+        let offsets = Ed25519SignatureOffsets {
+            signature_offset: 3 + PUBKEY_SERIALIZED_SIZE as u16,
+            signature_instruction_index: 1,
+            public_key_offset: 3,
+            public_key_instruction_index: 1,
+            message_data_offset: 3 + (PUBKEY_SERIALIZED_SIZE + SIGNATURE_SERIALIZED_SIZE) as u16,
+            message_data_size: message.len() as u16,
+            message_instruction_index: 1,
+        };
+
+        let instruction =
+            new_self_referential(&[program_instruction.clone()], 0, &[offsets]).unwrap();
+        assert_eq!(instruction.data.len(), DATA_START);
+        assert_eq!(get_signature_offsets(&instruction.data, 0), Ok(offsets));
+
+        let mint_keypair = Keypair::new();
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction, program_instruction.clone()],
+            Some(&mint_keypair.pubkey()),
+            &[&mint_keypair],
+            Hash::default(),
+        );
+        assert!(tx.verify_precompiles(&FeatureSet::all_enabled()).is_ok());
+
+        // The offsets are checked against the final position of the
+        // instruction
+        assert_eq!(
+            new_self_referential(&[program_instruction.clone()], 1, &[offsets]),
+            Err(PrecompileError::InvalidDataOffsets)
+        );
+        assert_eq!(
+            new_self_referential(&[program_instruction.clone()], 2, &[offsets]),
+            Err(PrecompileError::InvalidDataOffsets)
+        );
+        assert_eq!(
+            new_self_referential(
+                &[program_instruction.clone()],
+                0,
+                &[Ed25519SignatureOffsets {
+                    message_instruction_index: u16::MAX,
+                    ..offsets
+                }]
+            ),
+            Err(PrecompileError::InvalidDataOffsets)
+        );
+        assert_eq!(
+            new_self_referential(
+                &[program_instruction],
+                0,
+                &[Ed25519SignatureOffsets {
+                    message_data_size: 4,
+                    ..offsets
+                }]
+            ),
+            Err(PrecompileError::InvalidSignature)
+        );
+    }
 }
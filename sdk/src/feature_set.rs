@@ -704,6 +704,118 @@ pub mod programify_feature_gate_program {
     solana_sdk::declare_id!("8GdovDzVwWU5edz2G697bbB7GZjrUc6aQZLWyNNAtHdg");
 }
 
+pub mod epoch_stake_sysvar {
+    solana_sdk::declare_id!("ErNSmHhd1T2kAZCrbk4sr98oWCBmB7bH23WLRXsmxBLJ");
+}
+
+pub mod enable_base58_syscalls {
+    solana_sdk::declare_id!("9PNsUutAtSxEqhmc2RNKmcZrMGipk8SxR6iTvk9zK542");
+}
+
+pub mod sibling_instruction_provenance_syscall_enabled {
+    solana_sdk::declare_id!("2GMg2i6zu6vfXX61dkaJhuKC1iC5caQq4KQFjrMyYC4C");
+}
+
+pub mod enable_bpf_loader_set_sysvar_dependencies_ix {
+    solana_sdk::declare_id!("9zsvz8Y46CPjwjHBPSWAdmybUuaUc9m2xzFNK4bEgmNA");
+}
+
+pub mod include_address_table_lookups_in_signatures_sysvar {
+    solana_sdk::declare_id!("GCf9JchQvbWuMGx47ZLtGoD5pA6MnUWPaTgQXYahkCi8");
+}
+
+pub mod instructions_sysvar_slice_syscall_enabled {
+    solana_sdk::declare_id!("6XmBDHogUEnJd5C8enBXHcWUJdzLVbxNJFTTapghTySe");
+}
+
+pub mod recent_signatures_sysvar {
+    solana_sdk::declare_id!("HDwGCWzbkfnEm3jB1tHbgeekS6LACbp8dWWRNm6DzBM6");
+}
+
+pub mod enable_sol_memcmp_ct_syscall {
+    solana_sdk::declare_id!("CMGKNAkG8ycwpo2BseDrNNoMVxqCHfdee16oJpXstKZo");
+}
+
+pub mod header_sysvar {
+    solana_sdk::declare_id!("Ft3tXDqTwVtmjjxVQsMK7ZrdLMvzrcSUFxKxaJdugoqi");
+}
+
+pub mod reject_cpi_to_sysvars {
+    solana_sdk::declare_id!("BErCmgVf5E5t8jpLA5Xs5xsnQFwhakxMWVMk8xTHLqDD");
+}
+
+pub mod scratch_sysvar {
+    solana_sdk::declare_id!("3736aNm4qSMYuvJBAcMmYUh7wDRoStXP9VdSorPQf1Hs");
+}
+
+pub mod enable_tx_scratch_syscalls {
+    solana_sdk::declare_id!("HHQVZEMi3EkL6uS9TPrbX4r3SUdAL28nSbMwZZVuNPR");
+}
+
+pub mod sibling_instruction_sysvar_access_syscall_enabled {
+    solana_sdk::declare_id!("DHMof93ThgMc98Yt4RyWeHZeFsjyRsJBq8HtAuGqC6wr");
+}
+
+pub mod enable_sol_is_verified_syscall {
+    solana_sdk::declare_id!("87vUxLuujNcCm5otFLoEkg1yKBKWMgW7gaNtu6BkzT5u");
+}
+
+pub mod tick_hash_sysvar {
+    solana_sdk::declare_id!("BSncxcFHfZ9iW9h3vsEeqRQuTyVAEEU4Ybs95EMaX3PE");
+}
+
+pub mod enable_get_transaction_signature_syscall {
+    solana_sdk::declare_id!("9u5vLBvzqbZucjVGddAifDjovmGVPV2PhiP9tMhwc7DG");
+}
+
+pub mod signer_pubkeys_in_signatures_sysvar {
+    solana_sdk::declare_id!("AfMgcbinDzGu3w2d1sRapkF45GrCTVM6UoUTbtbmQGNj");
+}
+
+pub mod message_header_in_header_sysvar {
+    solana_sdk::declare_id!("E9SzinwitGQszTyhybbcUb7Z1Vd9NtUgTiN9D95o2ALm");
+}
+
+pub mod u16_signature_count_in_signatures_sysvar {
+    solana_sdk::declare_id!("GJRaDDtrZ2MRAKk4nKhqho9X69ksEtXtwzyE7S7M8s6x");
+}
+
+pub mod enable_signatures_sysvar {
+    solana_sdk::declare_id!("9D5yKyCHdg2cMjAJ85y9BAXNmp5sREuLYeKkvwLQmm6y");
+}
+
+pub mod enable_sol_ed25519_verify_syscall {
+    solana_sdk::declare_id!("DocgwZTmqq9rWh1Eypq2kdC2RyWAS3ACvuc8FMQHopoy");
+}
+
+pub mod enable_get_transaction_nonce_info_syscall {
+    solana_sdk::declare_id!("Eu6b3orfs1Xhn5wWxr7CqXnvVqnxcHuZVT5dWNxRpbC");
+}
+
+pub mod integrity_checksum_in_sysvars {
+    solana_sdk::declare_id!("GqqCaKDArHQNLm3iyxh43wb84LFeiwVyakmU7Va8dmrD");
+}
+
+pub mod transaction_context_sysvar {
+    solana_sdk::declare_id!("4NoJLuyiG9Vm4rxN5P6aGp3m5pusCFw6MwABhKkwDmdD");
+}
+
+pub mod enable_transaction_guard_instruction {
+    solana_sdk::declare_id!("HzdBE7Yg8WVRPmxpcKDV1CcTa69yew37uWGcVGup7xh8");
+}
+
+pub mod enable_get_serialized_message_syscall {
+    solana_sdk::declare_id!("Eox2tH3f5G87wV77uQCrAjx73rhfZapP8WcoEW671iVn");
+}
+
+pub mod enable_loader_v4_min_transaction_signers {
+    solana_sdk::declare_id!("6Mdn62n436sjmnkqW5Kw2SZnmcE3tdiQyQSDHaT5QGRn");
+}
+
+pub mod sysvar_dependency_bits_v2 {
+    solana_sdk::declare_id!("68PgDgti5SPtupaXBHaFvbrosby2aHmei3maBWGTDg9w");
+}
+
 lazy_static! {
     /// Map of feature identifiers to user-visible description
     pub static ref FEATURE_NAMES: HashMap<Pubkey, &'static str> = [
@@ -875,6 +987,34 @@ lazy_static! {
         (better_error_codes_for_tx_lamport_check::id(), "better error codes for tx lamport check #33353"),
         (enable_alt_bn128_compression_syscall::id(), "add alt_bn128 compression syscalls"),
         (programify_feature_gate_program::id(), "move feature gate activation logic to an on-chain program #32783"),
+        (epoch_stake_sysvar::id(), "enable new sysvar epoch_stake"),
+        (enable_base58_syscalls::id(), "add base58 encode and decode syscalls"),
+        (sibling_instruction_provenance_syscall_enabled::id(), "enable the sol_get_processed_sibling_instruction_provenance syscall"),
+        (enable_bpf_loader_set_sysvar_dependencies_ix::id(), "enable bpf upgradeable loader SetSysvarDependencies instruction"),
+        (include_address_table_lookups_in_signatures_sysvar::id(), "include address table lookups in the signatures sysvar"),
+        (instructions_sysvar_slice_syscall_enabled::id(), "enable the sol_get_instructions_sysvar_slice syscall"),
+        (recent_signatures_sysvar::id(), "enable new sysvar recent_signatures"),
+        (enable_sol_memcmp_ct_syscall::id(), "enable the sol_memcmp_ct syscall"),
+        (header_sysvar::id(), "enable new per-transaction sysvar header"),
+        (reject_cpi_to_sysvars::id(), "reject invoking a sysvar as a program with UnsupportedSysvar"),
+        (scratch_sysvar::id(), "enable new per-transaction sysvar scratch"),
+        (enable_tx_scratch_syscalls::id(), "enable the sol_tx_scratch_write and sol_tx_scratch_read syscalls"),
+        (sibling_instruction_sysvar_access_syscall_enabled::id(), "enable the sol_get_processed_sibling_instruction_sysvar_access syscall"),
+        (enable_sol_is_verified_syscall::id(), "cache precompile signature verifications and enable the sol_is_verified syscall"),
+        (tick_hash_sysvar::id(), "enable the tick hash sysvar"),
+        (enable_get_transaction_signature_syscall::id(), "enable the sol_get_transaction_signature syscall"),
+        (signer_pubkeys_in_signatures_sysvar::id(), "pair each signature with its signer pubkey in the signatures sysvar"),
+        (message_header_in_header_sysvar::id(), "include the message header in the header sysvar"),
+        (u16_signature_count_in_signatures_sysvar::id(), "store the signature count of the signatures sysvar as a u16"),
+        (enable_signatures_sysvar::id(), "populate the signatures sysvar"),
+        (enable_sol_ed25519_verify_syscall::id(), "enable the sol_ed25519_verify syscall"),
+        (enable_get_transaction_nonce_info_syscall::id(), "enable the sol_get_transaction_nonce_info syscall"),
+        (integrity_checksum_in_sysvars::id(), "append integrity checksums to the instructions and signatures sysvars"),
+        (transaction_context_sysvar::id(), "enable the transaction context sysvar"),
+        (enable_transaction_guard_instruction::id(), "enable the compute budget transaction guard instruction"),
+        (enable_get_serialized_message_syscall::id(), "enable the sol_get_serialized_message syscall"),
+        (enable_loader_v4_min_transaction_signers::id(), "enforce the minimum number of transaction signers of loader-v4 programs"),
+        (sysvar_dependency_bits_v2::id(), "accept the sysvar dependency bits of the sysvars added after epoch stake"),
         /*************** ADD NEW FEATURES HERE ***************/
     ]
     .iter()
@@ -893,6 +1033,56 @@ lazy_static! {
     };
 }
 
+lazy_static! {
+    /// Named groups of features that gate parts of the same capability, so
+    /// that they can be queried and activated as a set
+    pub static ref FEATURE_GROUPS: HashMap<&'static str, Vec<Pubkey>> = [
+        (
+            "transaction-introspection",
+            vec![
+                include_address_table_lookups_in_signatures_sysvar::id(),
+                header_sysvar::id(),
+                scratch_sysvar::id(),
+                reject_cpi_to_sysvars::id(),
+                sibling_instruction_provenance_syscall_enabled::id(),
+                instructions_sysvar_slice_syscall_enabled::id(),
+                enable_tx_scratch_syscalls::id(),
+                sibling_instruction_sysvar_access_syscall_enabled::id(),
+                enable_sol_is_verified_syscall::id(),
+                tick_hash_sysvar::id(),
+                enable_get_transaction_signature_syscall::id(),
+                signer_pubkeys_in_signatures_sysvar::id(),
+                message_header_in_header_sysvar::id(),
+                u16_signature_count_in_signatures_sysvar::id(),
+                enable_signatures_sysvar::id(),
+                transaction_context_sysvar::id(),
+                enable_transaction_guard_instruction::id(),
+                enable_get_serialized_message_syscall::id(),
+                enable_sol_ed25519_verify_syscall::id(),
+                enable_get_transaction_nonce_info_syscall::id(),
+                integrity_checksum_in_sysvars::id(),
+                recent_signatures_sysvar::id(),
+                epoch_stake_sysvar::id(),
+                enable_base58_syscalls::id(),
+                enable_sol_memcmp_ct_syscall::id(),
+                enable_bpf_loader_set_sysvar_dependencies_ix::id(),
+                enable_loader_v4_min_transaction_signers::id(),
+                sysvar_dependency_bits_v2::id(),
+            ],
+        ),
+    ]
+    .into_iter()
+    .collect();
+}
+
+/// Names of the feature groups that `feature_id` is a member of
+pub fn feature_groups_of(feature_id: &Pubkey) -> impl Iterator<Item = &'static str> + '_ {
+    FEATURE_GROUPS
+        .iter()
+        .filter(move |(_, features)| features.contains(feature_id))
+        .map(|(name, _)| *name)
+}
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct FullInflationFeaturePair {
     pub vote_id: Pubkey, // Feature that grants the candidate the ability to enable full inflation
@@ -1040,6 +1230,65 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_feature_groups() {
+        for features in FEATURE_GROUPS.values() {
+            assert!(!features.is_empty());
+            assert!(features
+                .iter()
+                .all(|feature| FEATURE_NAMES.contains_key(feature)));
+        }
+        assert_eq!(
+            feature_groups_of(&header_sysvar::id()).collect::<Vec<_>>(),
+            vec!["transaction-introspection"]
+        );
+        assert_eq!(feature_groups_of(&Pubkey::new_unique()).count(), 0);
+    }
+
+    #[test]
+    fn test_transaction_introspection_feature_group() {
+        // Every feature gating a part of transaction introspection, which must
+        // all be listed so that `solana feature status --group` is complete
+        let introspection_features = [
+            epoch_stake_sysvar::id(),
+            enable_base58_syscalls::id(),
+            sibling_instruction_provenance_syscall_enabled::id(),
+            enable_bpf_loader_set_sysvar_dependencies_ix::id(),
+            include_address_table_lookups_in_signatures_sysvar::id(),
+            instructions_sysvar_slice_syscall_enabled::id(),
+            recent_signatures_sysvar::id(),
+            enable_sol_memcmp_ct_syscall::id(),
+            header_sysvar::id(),
+            reject_cpi_to_sysvars::id(),
+            scratch_sysvar::id(),
+            enable_tx_scratch_syscalls::id(),
+            sibling_instruction_sysvar_access_syscall_enabled::id(),
+            enable_sol_is_verified_syscall::id(),
+            tick_hash_sysvar::id(),
+            enable_get_transaction_signature_syscall::id(),
+            signer_pubkeys_in_signatures_sysvar::id(),
+            message_header_in_header_sysvar::id(),
+            u16_signature_count_in_signatures_sysvar::id(),
+            enable_signatures_sysvar::id(),
+            enable_sol_ed25519_verify_syscall::id(),
+            enable_get_transaction_nonce_info_syscall::id(),
+            integrity_checksum_in_sysvars::id(),
+            transaction_context_sysvar::id(),
+            enable_transaction_guard_instruction::id(),
+            enable_get_serialized_message_syscall::id(),
+            enable_loader_v4_min_transaction_signers::id(),
+            sysvar_dependency_bits_v2::id(),
+        ];
+        let group = &FEATURE_GROUPS["transaction-introspection"];
+        for feature in &introspection_features {
+            assert!(
+                group.contains(feature),
+                "{feature} is missing from the group"
+            );
+        }
+        assert_eq!(group.len(), introspection_features.len());
+    }
+
     #[test]
     fn test_feature_set_activate_deactivate() {
         let mut feature_set = FeatureSet::default();
@@ -45,16 +45,17 @@ pub use solana_program::address_lookup_table_account;
 #[cfg(not(target_os = "solana"))]
 pub use solana_program::program_stubs;
 pub use solana_program::{
-    account_info, address_lookup_table, alt_bn128, big_mod_exp, blake3, borsh, borsh0_10, borsh0_9,
-    bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, clock, config, custom_heap_default,
-    custom_panic_default, debug_account_data, declare_deprecated_sysvar_id, declare_sysvar_id,
-    decode_error, ed25519_program, epoch_rewards, epoch_schedule, fee_calculator, impl_sysvar_get,
-    incinerator, instruction, keccak, lamports, loader_instruction, loader_upgradeable_instruction,
-    loader_v4, loader_v4_instruction, message, msg, native_token, nonce, poseidon, program,
-    program_error, program_memory, program_option, program_pack, rent, sanitize, sdk_ids,
-    secp256k1_program, secp256k1_recover, serde_varint, serialize_utils, short_vec, slot_hashes,
-    slot_history, stable_layout, stake, stake_history, syscalls, system_instruction,
-    system_program, sysvar, unchecked_div_by_const, vote, wasm_bindgen,
+    account_info, address_lookup_table, alt_bn128, base58, big_mod_exp, blake3, borsh, borsh0_10,
+    borsh0_9, bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable, clock,
+    compressed_record_proof, config, custom_heap_default, custom_panic_default, debug_account_data,
+    declare_deprecated_sysvar_id, declare_sysvar_id, decode_error, ed25519_program, epoch_rewards,
+    epoch_schedule, fee_calculator, impl_sysvar_get, incinerator, instruction, intent,
+    introspection, keccak, lamports, loader_instruction, loader_upgradeable_instruction, loader_v4,
+    loader_v4_instruction, message, msg, native_token, nonce, poseidon, program, program_error,
+    program_memory, program_option, program_pack, rent, sanitize, sdk_ids, secp256k1_program,
+    secp256k1_recover, serde_varint, serialize_utils, short_vec, slot_hashes, slot_history,
+    stable_layout, stake, stake_history, syscalls, system_instruction, system_program, sysvar,
+    unchecked_div_by_const, vote, wasm_bindgen,
 };
 
 pub mod account;
@@ -94,6 +95,7 @@ pub mod rpc_port;
 pub mod secp256k1_instruction;
 pub mod shred_version;
 pub mod signature;
+pub mod signatures_account;
 pub mod signer;
 pub mod system_transaction;
 pub mod timing;
@@ -4,7 +4,10 @@
 
 use {
     crate::{
-        decode_error::DecodeError, feature_set::FeatureSet, instruction::CompiledInstruction,
+        decode_error::DecodeError,
+        feature_set::FeatureSet,
+        hash::{hash, hashv, Hash},
+        instruction::CompiledInstruction,
         pubkey::Pubkey,
     },
     lazy_static::lazy_static,
@@ -103,6 +106,58 @@ pub fn get_precompiles<'a>() -> &'a [Precompile] {
     &PRECOMPILES
 }
 
+/// A signature of a precompile instruction, located in the data of the
+/// instructions of its transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedMessage<'a> {
+    pub signature: &'a [u8],
+    /// The public key, or Ethereum address for secp256k1, that signed
+    pub pubkey: &'a [u8],
+    pub message: &'a [u8],
+}
+
+impl SignedMessage<'_> {
+    /// The key under which a verified signature is recorded, and queried with
+    /// the `sol_is_verified` syscall: the SHA-256 hash of the precompile's
+    /// program id, the signature, the public key and the SHA-256 hash of the
+    /// message.
+    pub fn verified_signature_key(&self, program_id: &Pubkey) -> Hash {
+        verified_signature_key(program_id, self.signature, self.pubkey, &hash(self.message))
+    }
+}
+
+/// See [`SignedMessage::verified_signature_key`].
+pub fn verified_signature_key(
+    program_id: &Pubkey,
+    signature: &[u8],
+    pubkey: &[u8],
+    message_hash: &Hash,
+) -> Hash {
+    hashv(&[
+        program_id.as_ref(),
+        signature,
+        pubkey,
+        message_hash.as_ref(),
+    ])
+}
+
+/// The signatures of a precompile instruction, without verifying them.
+///
+/// Returns an empty list if `program_id` is not a precompile.
+pub fn signed_messages<'a>(
+    program_id: &Pubkey,
+    data: &'a [u8],
+    instruction_datas: &'a [&[u8]],
+) -> Result<Vec<SignedMessage<'a>>, PrecompileError> {
+    if crate::ed25519_program::check_id(program_id) {
+        crate::ed25519_instruction::signed_messages(data, instruction_datas)
+    } else if crate::secp256k1_program::check_id(program_id) {
+        crate::secp256k1_instruction::signed_messages(data, instruction_datas)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
 /// Check that a program is precompiled and if so verify it
 pub fn verify_if_precompile(
     program_id: &Pubkey,
@@ -794,7 +794,7 @@ use {
             libsecp256k1_fail_on_bad_count2, FeatureSet,
         },
         instruction::Instruction,
-        precompiles::PrecompileError,
+        precompiles::{PrecompileError, SignedMessage},
     },
     digest::Digest,
     serde_derive::{Deserialize, Serialize},
@@ -1023,6 +1023,51 @@ pub fn verify(
     Ok(())
 }
 
+/// The signature, Ethereum address and message of every signature of a
+/// secp256k1 program instruction, without verifying them.
+///
+/// The signatures exclude their recovery ID.
+pub fn signed_messages<'a>(
+    data: &'a [u8],
+    instruction_datas: &'a [&[u8]],
+) -> Result<Vec<SignedMessage<'a>>, PrecompileError> {
+    let count = *data
+        .first()
+        .ok_or(PrecompileError::InvalidInstructionDataSize)? as usize;
+    (0..count)
+        .map(|i| {
+            let start = i
+                .saturating_mul(SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+                .saturating_add(1);
+            let end = start.saturating_add(SIGNATURE_OFFSETS_SERIALIZED_SIZE);
+            let offsets: SecpSignatureOffsets = data
+                .get(start..end)
+                .and_then(|offsets| bincode::deserialize(offsets).ok())
+                .ok_or(PrecompileError::InvalidInstructionDataSize)?;
+            Ok(SignedMessage {
+                signature: get_data_slice(
+                    instruction_datas,
+                    offsets.signature_instruction_index,
+                    offsets.signature_offset,
+                    SIGNATURE_SERIALIZED_SIZE,
+                )?,
+                pubkey: get_data_slice(
+                    instruction_datas,
+                    offsets.eth_address_instruction_index,
+                    offsets.eth_address_offset,
+                    HASHED_PUBKEY_SERIALIZED_SIZE,
+                )?,
+                message: get_data_slice(
+                    instruction_datas,
+                    offsets.message_instruction_index,
+                    offsets.message_data_offset,
+                    offsets.message_data_size as usize,
+                )?,
+            })
+        })
+        .collect()
+}
+
 fn get_data_slice<'a>(
     instruction_datas: &'a [&[u8]],
     instruction_index: u8,
@@ -4,7 +4,7 @@
 // legacy module paths
 pub use crate::signer::{keypair::*, null_signer::*, presigner::*, *};
 use {
-    crate::pubkey::Pubkey,
+    crate::{pubkey::Pubkey, sysvar::signatures::TxSignature},
     generic_array::{typenum::U64, GenericArray},
     std::{
         borrow::{Borrow, Cow},
@@ -54,6 +54,15 @@ impl Signature {
     pub fn verify(&self, pubkey_bytes: &[u8], message_bytes: &[u8]) -> bool {
         self.verify_verbose(pubkey_bytes, message_bytes).is_ok()
     }
+
+    /// The bytes of the signature, e.g. to serialize it into the signatures
+    /// sysvar.
+    pub fn as_bytes(&self) -> &[u8; SIGNATURE_BYTES] {
+        self.0
+            .as_slice()
+            .try_into()
+            .expect("signature is SIGNATURE_BYTES long")
+    }
 }
 
 pub trait Signable {
@@ -96,6 +105,12 @@ impl From<Signature> for [u8; 64] {
     }
 }
 
+impl From<Signature> for TxSignature {
+    fn from(signature: Signature) -> Self {
+        TxSignature::new(signature.into())
+    }
+}
+
 impl From<[u8; SIGNATURE_BYTES]> for Signature {
     #[inline]
     fn from(signature: [u8; SIGNATURE_BYTES]) -> Self {
@@ -210,4 +225,12 @@ mod tests {
         // can do is `is_err()` here.
         assert!(signature.verify_verbose(pubkey.as_ref(), &[0u8]).is_err());
     }
+
+    #[test]
+    fn test_signature_as_bytes() {
+        let bytes = [7u8; SIGNATURE_BYTES];
+        let signature = Signature::from(bytes);
+        assert_eq!(signature.as_bytes(), &bytes);
+        assert_eq!(&signature.as_bytes()[..], signature.as_ref());
+    }
 }
@@ -0,0 +1,73 @@
+//! Helpers for the signatures sysvar.
+
+use {
+    crate::{
+        account::{Account, AccountSharedData},
+        clock::INITIAL_RENT_EPOCH,
+        signature::Signature,
+    },
+    solana_program::sysvar::{
+        self,
+        signatures::{construct_signatures_data_from_iter, SysvarSerializeError},
+    },
+};
+
+/// Create the signatures sysvar account of a transaction signed with
+/// `signatures`.
+///
+/// The account is owned by the sysvar program and holds the signatures in
+/// the original layout of the sysvar, which every version of the loaders in
+/// [`solana_program::sysvar::signatures`] reads. Embedders of the runtime,
+/// fuzzers and tests should use this function rather than filling an account
+/// by hand.
+///
+/// # Errors
+///
+/// Returns [`SysvarSerializeError::NoSignatures`] if there are no signatures,
+/// and [`SysvarSerializeError::TooManySignatures`] if there are more than the
+/// layout holds.
+pub fn create_account(
+    signatures: &[Signature],
+    lamports: u64,
+) -> Result<AccountSharedData, SysvarSerializeError> {
+    let data = construct_signatures_data_from_iter(signatures.iter().map(Signature::as_bytes))?;
+    Ok(AccountSharedData::from(Account {
+        lamports,
+        data,
+        owner: sysvar::id(),
+        executable: false,
+        rent_epoch: INITIAL_RENT_EPOCH,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::account::ReadableAccount,
+        solana_program::sysvar::signatures::{SignaturesData, TxSignature, MAX_SIGNATURES},
+    };
+
+    #[test]
+    fn test_create_account() {
+        let signatures = [Signature::from([1; 64]), Signature::from([2; 64])];
+        let account = create_account(&signatures, 42).unwrap();
+        assert_eq!(account.lamports(), 42);
+        assert_eq!(account.owner(), &sysvar::id());
+        assert_eq!(
+            SignaturesData::deserialize(account.data())
+                .unwrap()
+                .signatures,
+            vec![TxSignature::from([1; 64]), TxSignature::from([2; 64])]
+        );
+
+        assert_eq!(
+            create_account(&[], 42),
+            Err(SysvarSerializeError::NoSignatures)
+        );
+        assert_eq!(
+            create_account(&vec![Signature::default(); MAX_SIGNATURES + 1], 42),
+            Err(SysvarSerializeError::TooManySignatures(MAX_SIGNATURES + 1))
+        );
+    }
+}
@@ -169,6 +169,10 @@ pub enum TransactionError {
     /// The total balance before the transaction does not equal the total balance after the transaction
     #[error("Sum of account balances before and after transaction do not match")]
     UnbalancedTransaction,
+
+    /// Transaction doesn't satisfy the invariants declared by a transaction guard instruction
+    #[error("Transaction violates the transaction guard of instruction {0}")]
+    TransactionGuardViolated(u8),
 }
 
 impl From<SanitizeError> for TransactionError {
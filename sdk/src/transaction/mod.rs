@@ -127,7 +127,7 @@ use {
         wasm_bindgen,
     },
     serde::Serialize,
-    solana_program::{system_instruction::SystemInstruction, system_program},
+    solana_program::{system_instruction::SystemInstruction, system_program, sysvar},
     solana_sdk::feature_set,
     std::result,
 };
@@ -135,6 +135,7 @@ use {
 mod error;
 mod sanitized;
 mod versioned;
+pub mod wire_format;
 
 pub use {error::*, sanitized::*, versioned::*};
 
@@ -1072,6 +1073,20 @@ impl Transaction {
             .iter()
             .all(|signature| *signature != Signature::default())
     }
+
+    /// Returns true if an instruction of the transaction is passed the
+    /// signatures sysvar, through which programs introspect the signatures
+    /// of the transaction.
+    pub fn uses_signature_introspection(&self) -> bool {
+        self.message.instructions.iter().any(|instruction| {
+            instruction.accounts.iter().any(|account_index| {
+                self.message
+                    .account_keys
+                    .get(usize::from(*account_index))
+                    .map_or(false, sysvar::signatures::check_id)
+            })
+        })
+    }
 }
 
 pub fn uses_durable_nonce(tx: &Transaction) -> Option<&CompiledInstruction> {
@@ -1550,6 +1565,26 @@ mod tests {
         (from_pubkey, nonce_pubkey, tx)
     }
 
+    #[test]
+    fn test_uses_signature_introspection() {
+        let keypair = Keypair::new();
+        let program_id = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bincode(program_id, &0, vec![]);
+
+        let message = Message::new(&[instruction.clone()], Some(&keypair.pubkey()));
+        let tx = Transaction::new(&[&keypair], message, Hash::default());
+        assert!(!tx.uses_signature_introspection());
+
+        let message = Message::try_new_with_signatures_sysvar(
+            vec![instruction],
+            Some(&keypair.pubkey()),
+            &program_id,
+        )
+        .unwrap();
+        let tx = Transaction::new(&[&keypair], message, Hash::default());
+        assert!(tx.uses_signature_introspection());
+    }
+
     #[test]
     fn tx_uses_nonce_ok() {
         let (_, _, tx) = nonced_transfer_tx();
@@ -173,6 +173,12 @@ impl SanitizedTransaction {
         &self.message
     }
 
+    /// Demote the accounts at any of `keys` to readonly, see
+    /// [`SanitizedMessage::demote_account_keys`].
+    pub fn demote_account_keys(&mut self, keys: &[Pubkey]) {
+        self.message.demote_account_keys(keys)
+    }
+
     /// Return the hash of the signed message
     pub fn message_hash(&self) -> &Hash {
         &self.message_hash
@@ -245,7 +251,7 @@ impl SanitizedTransaction {
     }
 
     /// Return the serialized message data to sign.
-    fn message_data(&self) -> Vec<u8> {
+    pub fn message_data(&self) -> Vec<u8> {
         match &self.message {
             SanitizedMessage::Legacy(legacy_message) => legacy_message.message.serialize(),
             SanitizedMessage::V0(loaded_msg) => loaded_msg.message.serialize(),
@@ -0,0 +1,189 @@
+//! Positions of the signature section within a serialized transaction.
+//!
+//! Both legacy and versioned transactions are serialized with their
+//! signatures first:
+//!
+//! - the number of signatures, encoded as a compact-u16 of one to
+//!   [`MAX_NUM_SIGNATURES_LEN`] bytes,
+//! - the signatures, [`SIGNATURE_LEN`] bytes each,
+//! - the serialized message, up to the end of the transaction.
+//!
+//! The functions in this module slice raw transaction bytes along those
+//! boundaries without deserializing the message, for indexers and provers
+//! that need the position of a signature within the wire format.
+
+use {
+    crate::{sanitize::SanitizeError, short_vec},
+    solana_program::message::MESSAGE_HEADER_LENGTH,
+};
+
+/// Offset of the compact-u16 signature count within a serialized transaction.
+pub const NUM_SIGNATURES_OFFSET: usize = 0;
+
+/// Maximum length in bytes of the compact-u16 signature count.
+pub const MAX_NUM_SIGNATURES_LEN: usize = 3;
+
+/// Length in bytes of each signature entry.
+pub const SIGNATURE_LEN: usize = crate::signature::SIGNATURE_BYTES;
+
+/// The layout of the signature section of a serialized transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignaturesSection {
+    /// Number of signatures of the transaction
+    pub num_signatures: usize,
+    /// Offset of the first signature, i.e. the length of the signature count
+    pub signatures_offset: usize,
+}
+
+impl SignaturesSection {
+    /// Parse the signature count at the start of `transaction_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SanitizeError::InvalidValue`] if the signature count isn't a
+    /// valid compact-u16, and [`SanitizeError::IndexOutOfBounds`] if the
+    /// bytes are too short to hold the signatures and a message header.
+    pub fn parse(transaction_bytes: &[u8]) -> Result<Self, SanitizeError> {
+        let (num_signatures, signatures_offset) =
+            short_vec::decode_shortu16_len(&transaction_bytes[NUM_SIGNATURES_OFFSET..])
+                .map_err(|_| SanitizeError::InvalidValue)?;
+        let section = Self {
+            num_signatures,
+            signatures_offset,
+        };
+        if transaction_bytes.len()
+            < section
+                .message_offset()
+                .saturating_add(MESSAGE_HEADER_LENGTH)
+        {
+            return Err(SanitizeError::IndexOutOfBounds);
+        }
+        Ok(section)
+    }
+
+    /// Offset of the signature at `index`, or `None` if the transaction has
+    /// no such signature.
+    pub fn signature_offset(&self, index: usize) -> Option<usize> {
+        (index < self.num_signatures).then(|| {
+            self.signatures_offset
+                .saturating_add(index.saturating_mul(SIGNATURE_LEN))
+        })
+    }
+
+    /// Offset of the serialized message, which directly follows the
+    /// signatures.
+    pub fn message_offset(&self) -> usize {
+        self.signatures_offset
+            .saturating_add(self.num_signatures.saturating_mul(SIGNATURE_LEN))
+    }
+}
+
+/// The bytes of the signature at `index` of a serialized transaction.
+///
+/// # Errors
+///
+/// Returns [`SanitizeError::IndexOutOfBounds`] if the transaction has no
+/// signature at `index`, and the errors of [`SignaturesSection::parse`].
+pub fn signature_bytes(
+    transaction_bytes: &[u8],
+    index: usize,
+) -> Result<&[u8; SIGNATURE_LEN], SanitizeError> {
+    let offset = SignaturesSection::parse(transaction_bytes)?
+        .signature_offset(index)
+        .ok_or(SanitizeError::IndexOutOfBounds)?;
+    transaction_bytes[offset..offset.saturating_add(SIGNATURE_LEN)]
+        .try_into()
+        .map_err(|_| SanitizeError::IndexOutOfBounds)
+}
+
+/// The serialized message of a serialized transaction, the bytes its
+/// signatures sign.
+///
+/// # Errors
+///
+/// Returns the errors of [`SignaturesSection::parse`].
+pub fn message_bytes(transaction_bytes: &[u8]) -> Result<&[u8], SanitizeError> {
+    let offset = SignaturesSection::parse(transaction_bytes)?.message_offset();
+    Ok(&transaction_bytes[offset..])
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            hash::Hash,
+            message::{v0, VersionedMessage},
+            signature::{Keypair, Signer},
+            system_instruction,
+            transaction::{Transaction, VersionedTransaction},
+        },
+    };
+
+    #[test]
+    fn test_signatures_section() {
+        let payer = Keypair::new();
+        let other = Keypair::new();
+        let instruction = system_instruction::transfer(&payer.pubkey(), &other.pubkey(), 1);
+        let legacy = VersionedTransaction::from(Transaction::new_signed_with_payer(
+            &[instruction.clone()],
+            Some(&payer.pubkey()),
+            &[&payer],
+            Hash::new_unique(),
+        ));
+        let v0 = VersionedTransaction::try_new(
+            VersionedMessage::V0(
+                v0::Message::try_compile(&payer.pubkey(), &[instruction], &[], Hash::new_unique())
+                    .unwrap(),
+            ),
+            &[&payer],
+        )
+        .unwrap();
+
+        for transaction in [legacy, v0] {
+            let bytes = bincode::serialize(&transaction).unwrap();
+            let section = SignaturesSection::parse(&bytes).unwrap();
+            assert_eq!(
+                section,
+                SignaturesSection {
+                    num_signatures: 1,
+                    signatures_offset: 1,
+                }
+            );
+            assert_eq!(section.signature_offset(1), None);
+            assert_eq!(
+                signature_bytes(&bytes, 0).unwrap(),
+                transaction.signatures[0].as_ref()
+            );
+            assert_eq!(
+                signature_bytes(&bytes, 1),
+                Err(SanitizeError::IndexOutOfBounds)
+            );
+            assert_eq!(
+                message_bytes(&bytes).unwrap(),
+                transaction.message.serialize()
+            );
+            assert!(transaction.signatures[0]
+                .verify(payer.pubkey().as_ref(), message_bytes(&bytes).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_signatures_section_truncated() {
+        assert_eq!(
+            SignaturesSection::parse(&[]),
+            Err(SanitizeError::InvalidValue)
+        );
+        // Multi-byte signature count
+        let mut bytes = vec![0x80, 0x01];
+        assert_eq!(
+            SignaturesSection::parse(&bytes),
+            Err(SanitizeError::IndexOutOfBounds)
+        );
+        bytes.resize(2 + 128 * SIGNATURE_LEN + MESSAGE_HEADER_LENGTH, 0);
+        let section = SignaturesSection::parse(&bytes).unwrap();
+        assert_eq!(section.num_signatures, 128);
+        assert_eq!(section.signature_offset(127), Some(2 + 127 * SIGNATURE_LEN));
+        assert_eq!(message_bytes(&bytes).unwrap().len(), MESSAGE_HEADER_LENGTH);
+    }
+}
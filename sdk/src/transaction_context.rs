@@ -11,6 +11,7 @@ use {
         system_instruction::{
             MAX_PERMITTED_ACCOUNTS_DATA_ALLOCATIONS_PER_TRANSACTION, MAX_PERMITTED_DATA_LENGTH,
         },
+        sysvar,
     },
     solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE,
     std::mem::MaybeUninit,
@@ -153,6 +154,8 @@ pub struct TransactionContext {
     rent: Option<Rent>,
     #[cfg(not(target_os = "solana"))]
     is_cap_accounts_data_allocations_per_transaction_enabled: bool,
+    #[cfg(not(target_os = "solana"))]
+    is_scratch_sysvar_enabled: bool,
     /// Useful for debugging to filter by or to look it up on the explorer
     #[cfg(all(not(target_os = "solana"), debug_assertions))]
     signature: Signature,
@@ -182,6 +185,7 @@ impl TransactionContext {
             accounts_resize_delta: RefCell::new(0),
             rent,
             is_cap_accounts_data_allocations_per_transaction_enabled: false,
+            is_scratch_sysvar_enabled: false,
             #[cfg(all(not(target_os = "solana"), debug_assertions))]
             signature: Signature::default(),
         }
@@ -468,6 +472,12 @@ impl TransactionContext {
     pub fn enable_cap_accounts_data_allocations_per_transaction(&mut self) {
         self.is_cap_accounts_data_allocations_per_transaction_enabled = true;
     }
+
+    /// Enables any program changing the data of the scratch sysvar
+    #[cfg(not(target_os = "solana"))]
+    pub fn enable_scratch_sysvar(&mut self) {
+        self.is_scratch_sysvar_enabled = true;
+    }
 }
 
 /// Return data at the end of a transaction
@@ -1115,6 +1125,15 @@ impl<'a> BorrowedAccount<'a> {
             .unwrap_or_default()
     }
 
+    /// Returns true if this is the scratch sysvar constructed by the runtime,
+    /// whose data can be changed by any program once `scratch_sysvar` is active
+    #[cfg(not(target_os = "solana"))]
+    pub fn is_scratch_sysvar(&self) -> bool {
+        self.transaction_context.is_scratch_sysvar_enabled
+            && sysvar::scratch::check_id(self.get_key())
+            && sysvar::check_id(self.get_owner())
+    }
+
     /// Returns an error if the account data can not be mutated by the current program
     #[cfg(not(target_os = "solana"))]
     pub fn can_data_be_changed(&self) -> Result<(), InstructionError> {
@@ -1128,6 +1147,10 @@ impl<'a> BorrowedAccount<'a> {
         if self.is_executable() {
             return Err(InstructionError::ExecutableDataModified);
         }
+        // by any program for the scratch sysvar
+        if self.is_scratch_sysvar() {
+            return Ok(());
+        }
         // and only if the account is writable
         if !self.is_writable() {
             return Err(InstructionError::ReadonlyDataModified);
@@ -775,6 +775,11 @@ impl TryFrom<tx_by_addr::TransactionError> for TransactionError {
                         account_index: transaction_details.index as u8,
                     });
                 }
+                37 => {
+                    return Ok(TransactionError::TransactionGuardViolated(
+                        transaction_details.index as u8,
+                    ));
+                }
                 _ => {}
             }
         }
@@ -931,6 +936,9 @@ impl From<TransactionError> for tx_by_addr::TransactionError {
                 TransactionError::UnbalancedTransaction => {
                     tx_by_addr::TransactionErrorType::UnbalancedTransaction
                 }
+                TransactionError::TransactionGuardViolated(_) => {
+                    tx_by_addr::TransactionErrorType::TransactionGuardViolated
+                }
             } as i32,
             instruction_error: match transaction_error {
                 TransactionError::InstructionError(index, ref instruction_error) => {
@@ -1124,6 +1132,11 @@ impl From<TransactionError> for tx_by_addr::TransactionError {
                         index: account_index as u32,
                     })
                 }
+                TransactionError::TransactionGuardViolated(index) => {
+                    Some(tx_by_addr::TransactionDetails {
+                        index: index as u32,
+                    })
+                }
 
                 _ => None,
             },
@@ -1816,6 +1829,14 @@ mod test {
             tx_by_addr_transaction_error.try_into().unwrap()
         );
 
+        let transaction_error = TransactionError::TransactionGuardViolated(10);
+        let tx_by_addr_transaction_error: tx_by_addr::TransactionError =
+            transaction_error.clone().into();
+        assert_eq!(
+            transaction_error,
+            tx_by_addr_transaction_error.try_into().unwrap()
+        );
+
         let transaction_error = TransactionError::UnbalancedTransaction;
         let tx_by_addr_transaction_error: tx_by_addr::TransactionError =
             transaction_error.clone().into();
@@ -1833,7 +1854,8 @@ mod test {
             match error {
                 tx_by_addr::TransactionErrorType::DuplicateInstruction
                 | tx_by_addr::TransactionErrorType::InsufficientFundsForRent
-                | tx_by_addr::TransactionErrorType::ProgramExecutionTemporarilyRestricted => {
+                | tx_by_addr::TransactionErrorType::ProgramExecutionTemporarilyRestricted
+                | tx_by_addr::TransactionErrorType::TransactionGuardViolated => {
                     let tx_by_addr_error = tx_by_addr::TransactionError {
                         transaction_error: error as i32,
                         instruction_error: None,
@@ -6,7 +6,8 @@ use {
     bincode::deserialize,
     serde_json::json,
     solana_sdk::{
-        instruction::CompiledInstruction, loader_instruction::LoaderInstruction,
+        bpf_loader_upgradeable, instruction::CompiledInstruction,
+        loader_instruction::LoaderInstruction,
         loader_upgradeable_instruction::UpgradeableLoaderInstruction, message::AccountKeys,
     },
 };
@@ -188,6 +189,34 @@ pub fn parse_bpf_upgradeable_loader(
                 }),
             })
         }
+        UpgradeableLoaderInstruction::SetSysvarDependencies {
+            sysvar_dependencies,
+        } => {
+            check_num_bpf_upgradeable_loader_accounts(&instruction.accounts, 4)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: "setSysvarDependencies".to_string(),
+                info: json!({
+                    "sysvarDependencies": bpf_loader_upgradeable::sysvar_dependencies_to_ids(sysvar_dependencies)
+                        .iter()
+                        .map(|sysvar_id| sysvar_id.to_string())
+                        .collect::<Vec<_>>(),
+                    "programMetadataAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "programAccount": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "programDataAccount": account_keys[instruction.accounts[2] as usize].to_string(),
+                    "authority": account_keys[instruction.accounts[3] as usize].to_string(),
+                    "payerAccount": if instruction.accounts.len() > 4 {
+                        Some(account_keys[instruction.accounts[4] as usize].to_string())
+                    } else {
+                        None
+                    },
+                    "systemProgram": if instruction.accounts.len() > 5 {
+                        Some(account_keys[instruction.accounts[5] as usize].to_string())
+                    } else {
+                        None
+                    },
+                }),
+            })
+        }
     }
 }
 
@@ -779,4 +808,58 @@ mod test {
         )
         .is_err());
     }
+
+    #[test]
+    fn test_parse_bpf_upgradeable_loader_set_sysvar_dependencies_ix() {
+        let program_address = Pubkey::new_unique();
+        let authority_address = Pubkey::new_unique();
+        let payer_address = Pubkey::new_unique();
+        let (programdata_address, _) = Pubkey::find_program_address(
+            &[program_address.as_ref()],
+            &bpf_loader_upgradeable::id(),
+        );
+        let (program_metadata_address, _) =
+            bpf_loader_upgradeable::find_program_metadata_address(&program_address);
+        let sysvar_dependencies =
+            bpf_loader_upgradeable::sysvar_dependencies_from_ids(&[sysvar::signatures::id()])
+                .unwrap();
+        let instruction = bpf_loader_upgradeable::set_sysvar_dependencies(
+            &program_address,
+            &authority_address,
+            Some(&payer_address),
+            sysvar_dependencies,
+        );
+        let mut message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_bpf_upgradeable_loader(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "setSysvarDependencies".to_string(),
+                info: json!({
+                    "sysvarDependencies": [sysvar::signatures::id().to_string()],
+                    "programMetadataAccount": program_metadata_address.to_string(),
+                    "programAccount": program_address.to_string(),
+                    "programDataAccount": programdata_address.to_string(),
+                    "authority": authority_address.to_string(),
+                    "payerAccount": payer_address.to_string(),
+                    "systemProgram": system_program::id().to_string(),
+                }),
+            }
+        );
+        assert!(parse_bpf_upgradeable_loader(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys[0..3], None)
+        )
+        .is_err());
+        let keys = message.account_keys.clone();
+        message.instructions[0].accounts.truncate(3);
+        assert!(parse_bpf_upgradeable_loader(
+            &message.instructions[0],
+            &AccountKeys::new(&keys, None)
+        )
+        .is_err());
+    }
 }